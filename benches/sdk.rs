@@ -0,0 +1,138 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Benchmarks of the SDK, run with `cargo bench --features bench`.
+//!
+//! - `round_trip`: the latency of a request until its response, through a fake agent;
+//! - `complete_state_parse`: the decoding and parsing of a complete state with N workloads;
+//! - `log_throughput`: the receiving of N log entries streamed by a fake agent.
+
+use std::{env, hint::black_box, process, time::Duration};
+
+use ankaios_sdk::bench::{
+    FakeAgent, decode_complete_state_response, encoded_complete_state_response,
+};
+use ankaios_sdk::{
+    ANKAIOS_CONTROL_SOCKET_ENV, Ankaios, LogResponse, LogsRequest, WorkloadInstanceName,
+};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use tokio::runtime::Runtime;
+
+/// The workload counts of the complete states.
+const WORKLOAD_COUNTS: [usize; 3] = [10, 100, 1000];
+/// The log entry counts streamed per iteration.
+const LOG_ENTRY_COUNTS: [usize; 2] = [100, 1000];
+
+/// Starts a fake agent and connects the SDK to it through the control socket.
+fn setup(workload_count: usize) -> (Runtime, FakeAgent, Ankaios) {
+    let socket_path = env::temp_dir().join(format!("ankaios-bench-{}.sock", process::id()));
+    // SAFETY: the variable is set before the runtime is created, no other thread is running.
+    unsafe {
+        env::set_var(
+            ANKAIOS_CONTROL_SOCKET_ENV,
+            socket_path.to_str().expect("invalid socket path"),
+        );
+    }
+    let runtime = Runtime::new().expect("failed to create the runtime");
+    let agent = runtime
+        .block_on(FakeAgent::start(socket_path, workload_count))
+        .expect("failed to start the fake agent");
+    let ankaios = runtime
+        .block_on(Ankaios::new_with_timeout(Duration::from_secs(5)))
+        .expect("failed to connect to the fake agent");
+    (runtime, agent, ankaios)
+}
+
+fn bench_round_trip(c: &mut Criterion) {
+    let (runtime, _agent, ankaios) = setup(1);
+    let mut group = c.benchmark_group("round_trip");
+    group.bench_function("get_state", |b| {
+        b.iter(|| runtime.block_on(ankaios.get_state(Vec::new())).unwrap());
+    });
+    group.bench_function("update_configs", |b| {
+        b.iter(|| {
+            runtime
+                .block_on(
+                    ankaios.update_configs([("bench".to_owned(), "value")].into_iter().collect()),
+                )
+                .unwrap();
+        });
+    });
+    group.finish();
+}
+
+fn bench_complete_state_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("complete_state_parse");
+    for workload_count in WORKLOAD_COUNTS {
+        let encoded = encoded_complete_state_response(workload_count);
+        group.throughput(Throughput::Bytes(encoded.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(workload_count),
+            &encoded,
+            |b, encoded| {
+                b.iter(|| decode_complete_state_response(black_box(encoded)).unwrap());
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_log_throughput(c: &mut Criterion) {
+    let (runtime, agent, ankaios) = setup(1);
+    let mut log_campaign = runtime
+        .block_on(ankaios.request_logs(LogsRequest {
+            workload_names: vec![WorkloadInstanceName::new(
+                "agent_A".to_owned(),
+                "workload_0".to_owned(),
+                "workload_0_id".to_owned(),
+            )],
+            follow: true,
+            ..Default::default()
+        }))
+        .unwrap();
+
+    let mut group = c.benchmark_group("log_throughput");
+    for entry_count in LOG_ENTRY_COUNTS {
+        group.throughput(Throughput::Elements(entry_count as u64));
+        group.bench_function(BenchmarkId::from_parameter(entry_count), |b| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let (_, received) = tokio::join!(agent.send_log_entries(entry_count), async {
+                        let mut received = 0;
+                        while received < entry_count {
+                            match log_campaign.logs_receiver.recv().await {
+                                Some(LogResponse::LogEntries(entries)) => {
+                                    received += entries.len();
+                                }
+                                Some(_) => {}
+                                None => break,
+                            }
+                        }
+                        received
+                    });
+                    received
+                })
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_round_trip,
+    bench_complete_state_parse,
+    bench_log_throughput
+);
+criterion_main!(benches);
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use ankaios_sdk::{Ankaios, LogResponse, LogsRequest, Workload};
+use ankaios_sdk::prelude::*;
 
 #[tokio::main]
 async fn main() {
@@ -22,7 +22,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a new workload outputting test logs.
     let workload = Workload::builder()
@@ -82,6 +82,9 @@ async fn main() {
                 );
                 break;
             }
+            LogResponse::Resubscribed => {
+                println!("Reconnected to the agent, some logs might be missing.");
+            }
         }
     }
 
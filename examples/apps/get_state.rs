@@ -12,11 +12,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use ankaios_sdk::{Ankaios, AnkaiosError, Workload, WorkloadStateEnum};
+use ankaios_sdk::prelude::*;
 use std::thread::sleep;
 use tokio::time::Duration;
 
-async fn print_workload_states(ank: &mut Ankaios) {
+async fn print_workload_states(ank: &Ankaios) {
     if let Ok(complete_state) = ank.get_state(vec!["workloadStates".to_owned()]).await {
         // Get the workload states present in the complete state
         let workload_states = Vec::from(complete_state.get_workload_states());
@@ -41,7 +41,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a new workload
     let workload = Workload::builder()
@@ -73,7 +73,7 @@ async fn main() {
         Ok(_) => {
             println!("Workload reached the RUNNING state.");
         }
-        Err(AnkaiosError::TimeoutError(_)) => {
+        Err(AnkaiosError::TimeoutError { .. }) => {
             println!("Workload didn't reach the required state in time.");
         }
         Err(err) => {
@@ -83,7 +83,7 @@ async fn main() {
 
     loop {
         // Get the state of the workloads
-        print_workload_states(&mut ank).await;
+        print_workload_states(&ank).await;
 
         // Wait for 5 seconds
         sleep(Duration::from_secs(5));
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use ankaios_sdk::{Ankaios, AnkaiosError, Workload, WorkloadStateEnum};
+use ankaios_sdk::prelude::*;
 use tokio::time::Duration;
 
 #[tokio::main]
@@ -23,7 +23,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a new workload
     let workload = Workload::builder()
@@ -71,7 +71,7 @@ async fn main() {
         Ok(()) => {
             println!("Workload reached the RUNNING state.");
         }
-        Err(AnkaiosError::TimeoutError(_)) => {
+        Err(AnkaiosError::TimeoutError { .. }) => {
             println!("Workload didn't reach the required state in time.");
         }
         Err(err) => {
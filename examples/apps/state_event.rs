@@ -22,7 +22,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a new workload outputting test logs.
     let workload = Workload::builder()
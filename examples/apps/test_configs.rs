@@ -16,7 +16,7 @@ use ankaios_sdk::{Ankaios, Workload};
 use std::{collections::HashMap, thread::sleep};
 use tokio::time::Duration;
 
-async fn print_workload_states(ank: &mut Ankaios) {
+async fn print_workload_states(ank: &Ankaios) {
     if let Ok(complete_state) = ank.get_state(vec!["workloadStates".to_owned()]).await {
         // Get the workload states present in the complete state
         let workload_states = Vec::from(complete_state.get_workload_states());
@@ -41,7 +41,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a new workload
     let workload = Workload::builder()
@@ -79,7 +79,7 @@ async fn main() {
 
     // Get workloads
     sleep(Duration::from_secs(5));
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Modify config
     configs
@@ -99,7 +99,7 @@ async fn main() {
 
     // Get workloads
     sleep(Duration::from_secs(5));
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Delete workload
     ank.delete_workload("dynamic_nginx".to_owned())
@@ -108,5 +108,5 @@ async fn main() {
 
     // Get workloads
     sleep(Duration::from_secs(5));
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 }
@@ -15,7 +15,7 @@
 use ankaios_sdk::{Ankaios, File, FileContent, Workload};
 use tokio::time::Duration;
 
-async fn print_workload_states(ank: &mut Ankaios) {
+async fn print_workload_states(ank: &Ankaios) {
     if let Ok(workload_states) = ank.get_workload_states().await {
         for workload_state in workload_states.as_list() {
             println!(
@@ -38,7 +38,7 @@ async fn main() {
     // The connection to the control interface is automatically done at this step.
     println!("PLEASE PROVIDE VALID FILES' PATHS IF YOU WANT TO FULLY USE THIS EXAMPLE");
 
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create a workload with text file
     let workload_with_text_file = Workload::builder()
@@ -75,7 +75,7 @@ async fn main() {
 
     // Wait and check states
     tokio::time::sleep(Duration::from_secs(5)).await;
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Send second workload with binary file to Ankaios
     println!("Applying workload with binary file...");
@@ -85,7 +85,7 @@ async fn main() {
 
     // Wait and check states
     tokio::time::sleep(Duration::from_secs(5)).await;
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Test file manipulation - create a workload and then update its files
     println!("Creating workload with initial file...");
@@ -111,7 +111,7 @@ async fn main() {
         .expect("Failed to apply dynamic workload");
 
     tokio::time::sleep(Duration::from_secs(5)).await;
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Update the workload with additional files
     println!("Updating workload with additional files...");
@@ -122,7 +122,7 @@ async fn main() {
         .expect("Failed to update dynamic workload");
 
     tokio::time::sleep(Duration::from_secs(5)).await;
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 
     // Test retrieving and displaying file information
     println!("Retrieving file information from workloads...");
@@ -168,5 +168,5 @@ async fn main() {
 
     // Final state check
     tokio::time::sleep(Duration::from_secs(5)).await;
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 }
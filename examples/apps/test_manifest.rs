@@ -16,7 +16,7 @@ use ankaios_sdk::{Ankaios, Manifest};
 use std::thread::sleep;
 use tokio::time::Duration;
 
-async fn print_workload_states(ank: &mut Ankaios) {
+async fn print_workload_states(ank: &Ankaios) {
     if let Ok(complete_state) = ank.get_state(vec!["workloadStates".to_owned()]).await {
         // Get the workload states present in the complete state
         let workload_states = Vec::from(complete_state.get_workload_states());
@@ -41,7 +41,7 @@ async fn main() {
 
     // Create a new Ankaios object.
     // The connection to the control interface is automatically done at this step.
-    let mut ank = Ankaios::new().await.expect("Failed to initialize");
+    let ank = Ankaios::new().await.expect("Failed to initialize");
 
     // Create manifest
     let manifest_str = r#"apiVersion: v1
@@ -70,7 +70,7 @@ configs:
     }
 
     sleep(Duration::from_secs(5));
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
     sleep(Duration::from_secs(5));
 
     match ank.delete_manifest(manifest).await {
@@ -83,5 +83,5 @@ configs:
     }
 
     sleep(Duration::from_secs(5));
-    print_workload_states(&mut ank).await;
+    print_workload_states(&ank).await;
 }
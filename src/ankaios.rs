@@ -18,32 +18,80 @@
 //! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
 
 use std::collections::HashMap;
+use std::env;
+use std::future::{Future, pending};
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex as StdMutex, Weak};
+use std::time::SystemTime;
 use std::vec;
-use tokio::sync::mpsc;
-use tokio::time::{Duration, sleep, timeout as tokio_timeout};
+use tokio::sync::{Mutex, broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, Instant, sleep, sleep_until, timeout as tokio_timeout, timeout_at};
+use tokio_util::sync::CancellationToken;
 
+use crate::components::audit_log::{AuditEntry, AuditLog};
 #[cfg_attr(test, mockall_double::double)]
 use crate::components::control_interface::ControlInterface;
+use crate::components::control_interface::{ConnectionInfo, WRITER_QUEUE_SIZE};
 use crate::components::event_types::{EventEntry, EventsCampaignResponse};
-use crate::components::log_types::{LogCampaignResponse, LogsRequest};
+use crate::components::log_types::{
+    LogCampaignInfo, LogCampaignResponse, LogEntry, LogOverflowPolicy, LogResponse, LogTail,
+    LogsRequest, forward_tailed_log_entries, forward_with_overflow_policy,
+};
 use crate::components::manifest::{CONFIGS_PREFIX, Manifest};
+use crate::components::mask::{Mask, MaskSet};
+use crate::components::placement::{self, PlacementStrategy};
 use crate::components::request::{
     AnkaiosLogsRequest, EventsCancelRequest, EventsRequest, GetStateRequest, LogsCancelRequest,
     Request, UpdateStateRequest,
 };
-use crate::components::response::{Response, ResponseType, UpdateStateSuccess};
-use crate::components::workload_mod::{WORKLOADS_PREFIX, Workload};
+use crate::components::response::{
+    ApplyWorkloadResult, BatchResult, ConfigUpdateResult, Ensured, Response, ResponseType,
+    TappedResponse, UpdateStateSuccess,
+};
+use crate::components::server_capabilities::ServerCapabilities;
+use crate::components::state_cache::{CachePolicy, StateCache, normalize_field_masks};
+use crate::components::workload_mod::{File, WORKLOADS_PREFIX, Workload};
 use crate::components::workload_state_mod::{
-    WorkloadExecutionState, WorkloadInstanceName, WorkloadStateCollection, WorkloadStateEnum,
+    ClusterSummary, WorkloadDiagnosis, WorkloadExecutionState, WorkloadInstanceName,
+    WorkloadStateCollection, WorkloadStateEnum, WorkloadStatesFilter, WorkloadStatesWatch,
+    WorkloadSubStateEnum, coalesce_workload_states,
 };
-use crate::{AgentAttributes, AnkaiosError, CompleteState};
+use crate::logging::sdk_log;
+use crate::scope::{AnkaiosScope, SharedScopeCampaigns};
+use crate::{
+    AgentAttributes, AgentInfo, AnkaiosError, CompleteState, ConfigValue, Plan, StateQuery,
+};
+#[cfg(feature = "unstable-proto")]
+use crate::{ankaios_api::ank_base, components::request::RawRequest};
 
+/// The prefix for the desired state in the state.
+pub(crate) const DESIRED_STATE_PREFIX: &str = "desiredState";
 /// The prefix for the agents in the state.
-const AGENTS_PREFIX: &str = "agents";
+pub(crate) const AGENTS_PREFIX: &str = "agents";
 /// The prefix for the workload states in the state.
-const WORKLOAD_STATES_PREFIX: &str = "workloadStates";
+pub(crate) const WORKLOAD_STATES_PREFIX: &str = "workloadStates";
+/// The environment variable containing the name of the workload using the SDK.
+const OWN_WORKLOAD_NAME_ENV: &str = "ANKAIOS_WORKLOAD_NAME";
+/// The environment variable containing the name of the agent running the workload using the SDK.
+const OWN_AGENT_NAME_ENV: &str = "ANKAIOS_AGENT_NAME";
+/// The operation reported by the timeout of the waiting for a workload state.
+const WAIT_FOR_STATE_OPERATION: &str = "WaitForWorkloadState";
+/// The operation reported by the timeout of the waiting for a condition on the state.
+const WAIT_FOR_CONDITION_OPERATION: &str = "WaitForCondition";
 /// The default timeout, if not manually provided.
 const DEFAULT_TIMEOUT: u64 = 5; // seconds
+/// The default interval between two checks of the workload state.
+const DEFAULT_STATE_CHECK_INTERVAL: u64 = 100; // milliseconds
+/// The request type reported when joining an identical state request in flight times out.
+const GET_STATE_REQUEST_TYPE: &str = "GetStateRequest";
+/// Field mask of the API version of the desired state.
+const API_VERSION_FIELD_MASK: &str = "desiredState.apiVersion";
+/// Field mask of the small part of the state requested by the keepalive checks.
+pub(crate) const KEEPALIVE_FIELD_MASK: &str = API_VERSION_FIELD_MASK;
+/// Time without log entries after which the backlog of a log tail is considered complete.
+const TAIL_BACKLOG_QUIET_PERIOD: u64 = 500; // milliseconds
 /// The size of the channel used to receive responses.
 pub(crate) const CHANNEL_SIZE: usize = 100;
 
@@ -82,6 +130,21 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # })
 /// ```
 ///
+/// ## Share the Ankaios object between tasks:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::Ankaios;
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// let ankaios = Ankaios::new().await.unwrap();
+/// let ankaios_clone = ankaios.clone();
+/// let handle = tokio::spawn(async move { ankaios_clone.get_agents().await });
+/// let state = ankaios.get_state(Vec::default()).await.unwrap();
+/// let agents = handle.await.unwrap().unwrap();
+/// # })
+/// ```
+///
 /// ## Apply a manifest:
 ///
 /// ```rust,no_run
@@ -89,7 +152,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use tokio::runtime::Runtime;
 /// #
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let manifest: Manifest;
 /// # let manifest = Manifest::from_string("").unwrap();
@@ -105,7 +168,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use tokio::runtime::Runtime;
 /// #
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let manifest: Manifest;
 /// # let manifest = Manifest::from_string("").unwrap();
@@ -121,7 +184,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use tokio::runtime::Runtime;
 /// #
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload: Workload;
 /// # let workload = Workload::builder().build().unwrap();
@@ -137,7 +200,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use tokio::runtime::Runtime;
 /// #
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload_name: String;
 /// # let workload_name = String::new();
@@ -152,7 +215,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::Ankaios;
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload_name: String;
 /// # let workload_name = String::new();
@@ -167,7 +230,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::Ankaios;
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let state = ankaios.get_state(Vec::default()).await.unwrap();
 /// println!("{:?}", state);
@@ -180,7 +243,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::Ankaios;
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let agents = ankaios.get_agents().await.unwrap();
 /// println!("{:?}", agents);
@@ -193,7 +256,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::Ankaios;
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload_states_collection = ankaios.get_workload_states().await.unwrap();
 /// let workload_states = workload_states_collection.as_list();
@@ -206,7 +269,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::Ankaios;
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let agent_name: String;
 /// # let agent_name = String::new();
@@ -221,7 +284,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::{Ankaios, WorkloadInstanceName};
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload_instance_name: WorkloadInstanceName;
 /// # let workload_instance_name = WorkloadInstanceName::default();
@@ -236,7 +299,7 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # use ankaios_sdk::{Ankaios, AnkaiosError, WorkloadInstanceName, WorkloadStateEnum};
 /// # use tokio::runtime::Runtime;
 /// # Runtime::new().unwrap().block_on(async {
-/// # let mut ankaios = Ankaios::new().await.unwrap();
+/// # let ankaios = Ankaios::new().await.unwrap();
 /// #
 /// let workload_instance_name: WorkloadInstanceName;
 /// # let workload_instance_name = WorkloadInstanceName::default();
@@ -244,21 +307,319 @@ pub(crate) const CHANNEL_SIZE: usize = 100;
 /// # let expected_state = WorkloadStateEnum::Running;
 /// match ankaios.wait_for_workload_to_reach_state(workload_instance_name, expected_state).await {
 ///     Ok(_) => println!("Workload reached the expected state."),
-///     Err(AnkaiosError::TimeoutError(_)) => println!("Timeout while waiting for workload to reach state."),
+///     Err(AnkaiosError::TimeoutError { .. }) => println!("Timeout while waiting for workload to reach state."),
 ///     Err(err) => println!("Error while waiting for workload to reach state: {}", err),
 /// }
 /// # })
 /// ```
+///
+/// ## Share the connection between tasks:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, AnkaiosHandle};
+/// # use tokio::runtime::Runtime;
+/// # Runtime::new().unwrap().block_on(async {
+/// let ankaios: AnkaiosHandle = Ankaios::new().await.unwrap();
+/// let ankaios_clone = ankaios.clone();
+/// let state_task = tokio::spawn(async move { ankaios_clone.get_state(Vec::default()).await });
+/// let agents = ankaios.get_agents().await.unwrap();
+/// let state = state_task.await.unwrap().unwrap();
+/// # })
+/// ```
+#[derive(Clone)]
 pub struct Ankaios {
-    /// The receiver end of the channel used to receive responses from the Control Interface.
-    response_receiver: mpsc::Receiver<Response>,
-    /// The control interface instance that is used to communicate with the Control Interface.
-    control_interface: ControlInterface,
+    /// The state shared between all the clones of the object.
+    inner: Arc<AnkaiosInner>,
     /// The timeout used for the requests.
     pub timeout: Duration,
+    /// The interval between two checks of the workload state while waiting for it.
+    pub state_check_interval: Duration,
+    /// An optional prefix added to the IDs of the requests sent by this object.
+    pub request_id_prefix: Option<String>,
+    /// An optional correlation ID added to the log lines of the requests sent by this object.
+    pub correlation_id: Option<String>,
+    /// Whether [`apply_workload`](Ankaios::apply_workload) and [`apply_manifest`](Ankaios::apply_manifest)
+    /// check that the agents of the workloads are connected before applying them.
+    /// Disabled by default, as it requires an additional request.
+    pub validate_agents: bool,
+    /// Whether [`delete_workload`](Ankaios::delete_workload), [`delete_workloads_by_tag`](Ankaios::delete_workloads_by_tag)
+    /// and [`delete_manifest`](Ankaios::delete_manifest) refuse to delete the workload using this object,
+    /// as determined by [`my_instance_name`](Ankaios::my_instance_name). Disabled by default.
+    pub protect_own_workload: bool,
+    /// The name of the workload using this object, used by [`my_instance_name`](Ankaios::my_instance_name).
+    /// If not set, it is read from the `ANKAIOS_WORKLOAD_NAME` environment variable.
+    pub own_workload_name: Option<String>,
+    /// The name of the agent running the workload using this object, used by
+    /// [`my_instance_name`](Ankaios::my_instance_name) to tell apart instances on several agents.
+    /// If not set, it is read from the `ANKAIOS_AGENT_NAME` environment variable.
+    pub own_agent_name: Option<String>,
+    /// The campaigns of the scope this object belongs to, if started with [`scope`](Ankaios::scope).
+    scope_campaigns: Option<SharedScopeCampaigns>,
+}
+
+/// A cheap handle to the connection to the Control Interface, to be passed to multiple tasks.
+///
+/// [Ankaios] objects share the connection behind an [Arc] and each request only waits
+/// for its own response, routed to it by the dispatcher, so a clone can be moved into
+/// every task instead of sharing an `Arc<Mutex<Ankaios>>`, which would serialize
+/// unrelated requests. Dropping the last clone closes the connection.
+pub type AnkaiosHandle = Ankaios;
+
+/// Builder for an [Ankaios] object, created with [`Ankaios::builder`].
+///
+/// Besides the timeout and the protocol version, it configures the sizes of the
+/// internal channels. The responses channel buffers the responses received from
+/// the Control Interface until they are dispatched to their requests, and the writer
+/// queue buffers the requests until they are written to the Control Interface.
+/// Smaller sizes save memory on constrained targets, while larger sizes absorb
+/// bursts, e.g. of log entries, without slowing down the reading from the Control Interface.
+///
+/// # Example
+///
+/// ## Create an Ankaios object with smaller buffers:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::Ankaios;
+/// # use tokio::runtime::Runtime;
+/// # use tokio::time::Duration;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// let ankaios = Ankaios::builder()
+///     .timeout(Duration::from_secs(10))
+///     .response_channel_size(16)
+///     .writer_queue_size(2)
+///     .build()
+///     .await
+///     .unwrap();
+/// # })
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnkaiosBuilder {
+    /// The maximum time to wait for the requests.
+    timeout: Duration,
+    /// The protocol version sent in the initial hello message, the supported one if not set.
+    protocol_version: Option<String>,
+    /// The number of responses buffered before they are dispatched.
+    response_channel_size: usize,
+    /// The number of requests queued before they are written.
+    writer_queue_size: usize,
+}
+
+impl Default for AnkaiosBuilder {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT),
+            protocol_version: None,
+            response_channel_size: CHANNEL_SIZE,
+            writer_queue_size: WRITER_QUEUE_SIZE,
+        }
+    }
+}
+
+impl AnkaiosBuilder {
+    /// Sets the maximum time to wait for the requests (default: 5 seconds).
+    ///
+    /// ## Arguments
+    ///
+    /// - `timeout`: The maximum time to wait for the requests.
+    ///
+    /// ## Returns
+    ///
+    /// The [`AnkaiosBuilder`] object.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the protocol version sent in the initial hello message, see
+    /// [`Ankaios::new_with_protocol_version`].
+    ///
+    /// ## Arguments
+    ///
+    /// - `protocol_version`: The protocol version to announce.
+    ///
+    /// ## Returns
+    ///
+    /// The [`AnkaiosBuilder`] object.
+    #[must_use]
+    pub fn protocol_version<T: Into<String>>(mut self, protocol_version: T) -> Self {
+        self.protocol_version = Some(protocol_version.into());
+        self
+    }
+
+    /// Sets the number of responses buffered before they are dispatched to their requests (default: 100).
+    /// When full, the reading from the Control Interface waits.
+    ///
+    /// ## Arguments
+    ///
+    /// - `response_channel_size`: The number of buffered responses, at least 1.
+    ///
+    /// ## Returns
+    ///
+    /// The [`AnkaiosBuilder`] object.
+    #[must_use]
+    pub fn response_channel_size(mut self, response_channel_size: usize) -> Self {
+        self.response_channel_size = response_channel_size.max(1);
+        self
+    }
+
+    /// Sets the number of requests queued before they are written to the Control Interface (default: 5).
+    /// When full, sending a request waits.
+    ///
+    /// ## Arguments
+    ///
+    /// - `writer_queue_size`: The number of queued requests, at least 1.
+    ///
+    /// ## Returns
+    ///
+    /// The [`AnkaiosBuilder`] object.
+    #[must_use]
+    pub fn writer_queue_size(mut self, writer_queue_size: usize) -> Self {
+        self.writer_queue_size = writer_queue_size.max(1);
+        self
+    }
+
+    /// Creates the [Ankaios] object and connects to the Control Interface.
+    ///
+    /// ## Returns
+    ///
+    /// A [Result] containing the [Ankaios] object if the connection was successful.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if an error occurred when connecting.
+    /// [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the workload has no control interface access.
+    /// [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) if the protocol version was rejected.
+    pub async fn build(self) -> Result<Ankaios, AnkaiosError> {
+        let (response_sender, response_receiver) =
+            mpsc::channel::<Response>(self.response_channel_size);
+        let mut control_interface = ControlInterface::new(response_sender);
+        if let Some(protocol_version) = self.protocol_version {
+            control_interface.set_protocol_version(protocol_version);
+        }
+        control_interface.set_writer_queue_size(self.writer_queue_size);
+        let object =
+            Ankaios::new_from_control_interface(control_interface, response_receiver, self.timeout);
+
+        object
+            .inner
+            .control_interface
+            .lock()
+            .await
+            .connect(self.timeout)
+            .await?;
+        Ok(object)
+    }
+}
+
+/// Request ID to pending response sender mapping.
+type PendingRequestsMap = Arc<StdMutex<HashMap<String, oneshot::Sender<Response>>>>;
+
+/// Registers a request as waiting for a response and removes it again when dropped.
+struct PendingRequestGuard<'a> {
+    /// The requests that are waiting for a response.
+    pending_requests: &'a PendingRequestsMap,
+    /// The ID of the guarded request.
+    request_id: String,
+}
+
+impl<'a> PendingRequestGuard<'a> {
+    /// Registers a new request as waiting for a response.
+    ///
+    /// ## Arguments
+    ///
+    /// - `pending_requests`: The requests that are waiting for a response;
+    /// - `request_id`: The ID of the request;
+    /// - `response_sender`: The sender used to forward the response of the request.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`PendingRequestGuard`] object.
+    fn new(
+        pending_requests: &'a PendingRequestsMap,
+        request_id: String,
+        response_sender: oneshot::Sender<Response>,
+    ) -> Self {
+        pending_requests
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(request_id.clone(), response_sender);
+        Self {
+            pending_requests,
+            request_id,
+        }
+    }
+}
+
+impl Drop for PendingRequestGuard<'_> {
+    fn drop(&mut self) {
+        self.pending_requests
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(&self.request_id);
+    }
+}
+
+/// Identifies identical state requests by their normalized field masks, request ID prefix, correlation ID
+/// and the generation of the state cache they were sent in.
+type InFlightStateKey = (Vec<String>, Option<String>, Option<String>, u64);
+
+/// Identical state requests to the sender of the state requested by them, while the request is in flight.
+type InFlightStatesMap =
+    StdMutex<HashMap<InFlightStateKey, broadcast::Sender<Option<CompleteState>>>>;
+
+/// Removes a state request from the requests in flight when dropped.
+struct InFlightStateGuard<'a> {
+    /// The state requests that are in flight.
+    in_flight_states: &'a InFlightStatesMap,
+    /// The key of the guarded request.
+    key: InFlightStateKey,
+}
+
+impl Drop for InFlightStateGuard<'_> {
+    fn drop(&mut self) {
+        self.in_flight_states
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(&self.key);
+    }
+}
+
+/// The state shared between all the clones of an [Ankaios] object.
+/// The connection is closed when the last clone is dropped.
+struct AnkaiosInner {
+    /// The control interface instance that is used to communicate with the Control Interface.
+    control_interface: Mutex<ControlInterface>,
+    /// The requests that are waiting for a response.
+    pending_requests: PendingRequestsMap,
+    /// Handler for the task dispatching the responses to the pending requests.
+    dispatcher_handler: JoinHandle<()>,
+    /// The log of the updates sent, if enabled.
+    audit_log: StdMutex<Option<AuditLog>>,
+    /// The cache of the requested states, if enabled.
+    state_cache: StdMutex<Option<StateCache>>,
+    /// The state requests that are in flight, shared with identical concurrent requests.
+    in_flight_states: InFlightStatesMap,
+    /// The log campaigns that are still active, by request id.
+    log_campaigns: StdMutex<HashMap<String, LogCampaignInfo>>,
+    /// Handler for the keepalive task, if enabled.
+    keepalive_handler: StdMutex<Option<JoinHandle<()>>>,
+    /// The index of the next agent selected with [`PlacementStrategy::RoundRobin`].
+    round_robin_index: AtomicUsize,
 }
 
 impl Ankaios {
+    /// Creates an [`AnkaiosBuilder`] to configure the `Ankaios` object before connecting.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`AnkaiosBuilder`] with the default configuration.
+    #[must_use]
+    pub fn builder() -> AnkaiosBuilder {
+        AnkaiosBuilder::default()
+    }
+
     /// Creates a new `Ankaios` object and connects to the Control Interface.
     ///
     /// ## Returns
@@ -268,6 +629,7 @@ impl Ankaios {
     /// ## Errors
     ///
     /// [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if an error occurred when connecting.
+    /// [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the workload has no control interface access.
     /// [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if a timeout occurred when testing the connection.
     pub async fn new() -> Result<Self, AnkaiosError> {
         Self::new_with_timeout(Duration::from_secs(DEFAULT_TIMEOUT)).await
@@ -286,215 +648,702 @@ impl Ankaios {
     /// ## Errors
     ///
     /// [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if an error occurred when connecting.
+    /// [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the workload has no control interface access.
     pub async fn new_with_timeout(timeout: Duration) -> Result<Self, AnkaiosError> {
         let (response_sender, response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
-        let mut object = Self {
+        let object = Self::new_from_control_interface(
+            ControlInterface::new(response_sender),
             response_receiver,
-            control_interface: ControlInterface::new(response_sender),
             timeout,
-        };
+        );
 
-        object.control_interface.connect(timeout).await?;
+        object
+            .inner
+            .control_interface
+            .lock()
+            .await
+            .connect(timeout)
+            .await?;
         Ok(object)
     }
 
-    /// Sends a request to the Control Interface and waits for the response.
+    /// Creates a new `Ankaios` object that announces a custom protocol version
+    /// and connects to the Control Interface.
+    ///
+    /// This is mainly intended for testing the compatibility with different
+    /// [Ankaios](https://eclipse-ankaios.github.io/ankaios) versions.
     ///
     /// ## Arguments
     ///
-    /// - `request`: The [`Request`] to be sent.
+    /// - `timeout`: The maximum time to wait for the requests;
+    /// - `protocol_version`: The protocol version sent in the initial hello message.
     ///
     /// ## Returns
     ///
-    /// - the [Response] if the request was successful.
+    /// A [Result] containing the [Ankaios] object if the connection was successful.
     ///
     /// ## Errors
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    async fn send_request(
-        &mut self,
-        request: impl Request + 'static,
-    ) -> Result<Response, AnkaiosError> {
-        let request_id = request.get_id();
-        self.control_interface.write_request(request).await?;
-        loop {
-            match tokio_timeout(self.timeout, self.response_receiver.recv()).await {
-                Ok(Some(response)) => {
-                    if let ResponseType::ConnectionClosedReason(reason) = response.content {
-                        log::error!("Connection closed: {reason}");
-                        return Err(AnkaiosError::ConnectionClosedError(reason));
-                    }
-                    if response.get_request_id() == request_id {
-                        return Ok(response);
-                    }
-                    log::warn!("Received response with wrong id.");
-                }
-                Ok(None) => {
-                    log::error!("Reading thread closed unexpectedly.");
-                    return Err(AnkaiosError::ControlInterfaceError(
-                        "Reading thread closed.".to_owned(),
-                    ));
-                }
-                Err(err) => {
-                    log::error!("Timeout while waiting for response.");
-                    return Err(AnkaiosError::TimeoutError(err));
-                }
-            }
-        }
+    /// [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if an error occurred when connecting.
+    /// [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the workload has no control interface access.
+    /// [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) if the protocol version was rejected.
+    pub async fn new_with_protocol_version<T: Into<String>>(
+        timeout: Duration,
+        protocol_version: T,
+    ) -> Result<Self, AnkaiosError> {
+        Self::builder()
+            .timeout(timeout)
+            .protocol_version(protocol_version)
+            .build()
+            .await
     }
 
-    /// Send a request to apply a [Manifest].
+    /// Creates a new `Ankaios` object around a not yet connected control interface
+    /// and starts dispatching the responses to the pending requests.
     ///
     /// ## Arguments
     ///
-    /// - `manifest`: The [Manifest] to be applied.
+    /// - `control_interface`: The [`ControlInterface`] used for the communication;
+    /// - `response_receiver`: The receiver end of the channel the control interface forwards the responses to;
+    /// - `timeout`: The maximum time to wait for the requests.
     ///
     /// ## Returns
     ///
-    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
+    /// A new [Ankaios] object.
+    fn new_from_control_interface(
+        control_interface: ControlInterface,
+        response_receiver: mpsc::Receiver<Response>,
+        timeout: Duration,
+    ) -> Self {
+        let pending_requests = PendingRequestsMap::default();
+        let dispatcher_handler = tokio::spawn(Self::dispatch_responses(
+            response_receiver,
+            Arc::clone(&pending_requests),
+        ));
+        Self::from_inner(
+            Arc::new(AnkaiosInner {
+                control_interface: Mutex::new(control_interface),
+                pending_requests,
+                dispatcher_handler,
+                audit_log: StdMutex::new(None),
+                state_cache: StdMutex::new(None),
+                in_flight_states: StdMutex::new(HashMap::new()),
+                log_campaigns: StdMutex::new(HashMap::new()),
+                keepalive_handler: StdMutex::new(None),
+                round_robin_index: AtomicUsize::new(0),
+            }),
+            timeout,
+        )
+    }
+
+    /// Creates a new `Ankaios` object with the default settings around the shared state.
     ///
-    /// ## Errors
+    /// ## Arguments
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn apply_manifest(
-        &mut self,
-        manifest: Manifest,
-    ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        // Create request
-        let masks = manifest.calculate_masks();
-        let request = UpdateStateRequest::new(&CompleteState::new_from_manifest(manifest), masks);
-
-        // Wait for the response
-        let response = self.send_request(request).await?;
+    /// - `inner`: The state shared between the clones of the [Ankaios] object;
+    /// - `timeout`: The maximum time to wait for the requests.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Ankaios] object.
+    fn from_inner(inner: Arc<AnkaiosInner>, timeout: Duration) -> Self {
+        Self {
+            inner,
+            timeout,
+            state_check_interval: Duration::from_millis(DEFAULT_STATE_CHECK_INTERVAL),
+            request_id_prefix: None,
+            correlation_id: None,
+            validate_agents: false,
+            protect_own_workload: false,
+            own_workload_name: None,
+            own_agent_name: None,
+            scope_campaigns: None,
+        }
+    }
 
-        match response.content {
-            ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
-                    "Update successful: {:?} added workloads, {:?} deleted workloads",
-                    update_state_success.added_workloads.len(),
-                    update_state_success.deleted_workloads.len()
-                );
-                Ok(*update_state_success)
-            }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to apply manifest: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
-                ))
+    /// Forwards each received response to the request waiting for it.
+    /// A connection closed response is forwarded to all the pending requests.
+    ///
+    /// ## Arguments
+    ///
+    /// - `response_receiver`: The receiver end of the channel used to receive responses from the Control Interface;
+    /// - `pending_requests`: The requests that are waiting for a response.
+    async fn dispatch_responses(
+        mut response_receiver: mpsc::Receiver<Response>,
+        pending_requests: PendingRequestsMap,
+    ) {
+        while let Some(response) = response_receiver.recv().await {
+            let mut pending_guard = pending_requests.lock().unwrap_or_else(|_| unreachable!());
+            if let ResponseType::ConnectionClosedReason(_) = response.content {
+                for (_, response_sender) in pending_guard.drain() {
+                    let _ = response_sender.send(response.clone());
+                }
+            } else if let Some(response_sender) = pending_guard.remove(&response.get_request_id()) {
+                let _ = response_sender.send(response);
+            } else {
+                sdk_log!(warn, "Received response with wrong id '{}'.", response.id);
             }
         }
+        sdk_log!(trace, "Response channel closed, stopping the dispatcher.");
+        pending_requests
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clear();
     }
 
-    /// Send a request to delete a [Manifest].
+    /// Returns the protocol version configured for the connection to the Control Interface.
+    /// It is the version sent in the hello message, [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+    /// does not report the version it supports and closes the connection if it rejects it.
     ///
-    /// ## Arguments
+    /// ## Returns
     ///
-    /// - `manifest`: The [Manifest] to be deleted.
+    /// A [String] containing the configured protocol version.
+    pub async fn get_protocol_version(&self) -> String {
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .get_protocol_version()
+    }
+
+    /// Returns information about the connection to the Control Interface, e.g. for a diagnostics page:
+    /// the paths of the FIFO pipes, the configured protocol version, the time the connection
+    /// was established, the number of agent reconnections and the last error.
     ///
     /// ## Returns
     ///
-    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
+    /// The [`ConnectionInfo`] of the connection.
+    pub async fn connection_info(&self) -> ConnectionInfo {
+        self.inner.control_interface.lock().await.connection_info()
+    }
+
+    /// Returns the features supported by the connected [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+    /// cluster, derived from the API version of the desired state reported by the server,
+    /// so that the features not supported by older versions can be avoided.
+    ///
+    /// The API version is always requested from the server, bypassing the state cache.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ServerCapabilities`] of the cluster.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for a response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn delete_manifest(
-        &mut self,
-        manifest: Manifest,
-    ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        // Create request
-        let request =
-            UpdateStateRequest::new(&CompleteState::default(), manifest.calculate_masks());
+    pub async fn server_capabilities(&self) -> Result<ServerCapabilities, AnkaiosError> {
+        let state = self
+            .get_state_uncached(vec![API_VERSION_FIELD_MASK.to_owned()])
+            .await?;
+        Ok(ServerCapabilities::from_api_version(
+            &state.get_api_version(),
+        ))
+    }
 
-        // Wait for the response
-        let response = self.send_request(request).await?;
+    /// Subscribes to copies of all the responses received from the Control Interface,
+    /// including log entries and events, without consuming them.
+    ///
+    /// This is intended for debugging, e.g. for diagnosing responses with unexpected ids or types.
+    /// Only the responses received after subscribing are yielded and lagging receivers miss responses.
+    ///
+    /// ## Returns
+    ///
+    /// A [`broadcast::Receiver`] of the [`TappedResponse`]s.
+    pub async fn debug_tap(&self) -> broadcast::Receiver<TappedResponse> {
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .subscribe_debug_tap()
+    }
 
-        match response.content {
-            ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
-                    "Update successful: {:?} added workloads, {:?} deleted workloads",
-                    update_state_success.added_workloads.len(),
-                    update_state_success.deleted_workloads.len()
-                );
-                Ok(*update_state_success)
-            }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to delete manifest: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
-                ))
-            }
-        }
+    /// Enables or disables the resubscription of the log campaigns after the agent reconnected.
+    ///
+    /// When enabled, which is the default, the logs requests of the active log campaigns are
+    /// re-issued once the connection to the agent is restored and a
+    /// [`LogResponse::Resubscribed`](crate::LogResponse::Resubscribed) marker is sent to each campaign, as log entries might
+    /// have been lost in the meantime.
+    ///
+    /// ## Arguments
+    ///
+    /// - `enabled`: Whether the log campaigns are resubscribed.
+    pub async fn set_logs_resubscription(&self, enabled: bool) {
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .set_logs_resubscription(enabled);
     }
 
-    /// Send a request to run a [Workload].
+    /// Sends a request to the Control Interface and waits for the response.
     ///
     /// ## Arguments
     ///
-    /// - `workload`: The [Workload] to be run.
+    /// - `request`: The [`Request`] to be sent.
     ///
     /// ## Returns
     ///
-    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
+    /// - the [Response] if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn apply_workload(
-        &mut self,
-        workload: Workload,
-    ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        let mut masks = workload.masks.clone();
-        if masks.is_empty() {
-            masks = vec![workload.main_mask.clone()];
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) if the connection was closed due to an incompatible protocol version;
+    /// - [`AnkaiosError`]::[`MessageTooLargeError`](AnkaiosError::MessageTooLargeError) if the request exceeds the control interface message limit.
+    async fn send_request(
+        &self,
+        mut request: impl Request + 'static,
+    ) -> Result<Response, AnkaiosError> {
+        self.apply_id_prefix(&mut request);
+        self.send_prefixed_request(request).await
+    }
+
+    /// Prepends the [`request_id_prefix`](Ankaios::request_id_prefix), if any, to the ID of a request.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`Request`] about to be sent.
+    fn apply_id_prefix(&self, request: &mut impl Request) {
+        if let Some(prefix) = self.request_id_prefix.as_deref() {
+            request.set_id_prefix(prefix);
         }
+    }
 
-        // Create CompleteState
-        let complete_state = CompleteState::new_from_workloads(vec![workload]);
+    /// Sends a request that already has its final ID to the Control Interface and waits for the response,
+    /// as described in [`send_request`](Ankaios::send_request).
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`Request`] to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// - the [Response] if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`send_request`](Ankaios::send_request).
+    async fn send_prefixed_request(
+        &self,
+        request: impl Request + 'static,
+    ) -> Result<Response, AnkaiosError> {
+        let audited_masks = self.update_masks_to_audit(&request);
+        let invalidates_cache = self.invalidates_state_cache(&request);
+        let request_id = request.get_id();
 
-        // Create request
-        let request = UpdateStateRequest::new(&complete_state, masks);
+        let result = self.exchange_request(request).await;
 
-        // Wait for the response
-        let response = self.send_request(request).await?;
+        if invalidates_cache {
+            self.invalidate_state_cache();
+        }
+        if let Some(masks) = audited_masks {
+            self.record_audit_entry(request_id, masks, &result);
+        }
+        result
+    }
 
-        match response.content {
-            ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
-                    "Update successful: {:?} added workloads, {:?} deleted workloads",
-                    update_state_success.added_workloads.len(),
-                    update_state_success.deleted_workloads.len()
+    /// Writes a request to the Control Interface and waits for the response,
+    /// as described in [`send_request`](Ankaios::send_request).
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`Request`] to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// - the [Response] if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`send_request`](Ankaios::send_request).
+    async fn exchange_request(
+        &self,
+        request: impl Request + 'static,
+    ) -> Result<Response, AnkaiosError> {
+        let request_id = request.get_id();
+        let request_type = request.get_type();
+        let request_description = self.describe_request(&request_id);
+        sdk_log!(debug, "Sending {request_description}.");
+
+        let (response_sender, response_receiver) = oneshot::channel();
+        // Removes the pending request also if this future is dropped before completion.
+        let _pending_guard = PendingRequestGuard::new(
+            &self.inner.pending_requests,
+            request_id.clone(),
+            response_sender,
+        );
+
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .write_request(request)
+            .await?;
+
+        match tokio_timeout(self.timeout, response_receiver).await {
+            Ok(Ok(response)) => {
+                if let ResponseType::ConnectionClosedReason(reason) = response.content {
+                    sdk_log!(
+                        error,
+                        "Connection closed while waiting for {request_description}: {reason}"
+                    );
+                    return Err(AnkaiosError::from_connection_closed_reason(reason));
+                }
+                sdk_log!(debug, "Received response for {request_description}.");
+                Ok(response)
+            }
+            Ok(Err(_)) => {
+                sdk_log!(error, "Reading thread closed unexpectedly.");
+                Err(AnkaiosError::ControlInterfaceError(
+                    "Reading thread closed.".to_owned(),
+                ))
+            }
+            Err(_) => {
+                sdk_log!(
+                    error,
+                    "Timeout while waiting for response to {request_description}."
                 );
-                Ok(*update_state_success)
+                Err(AnkaiosError::TimeoutError {
+                    request_id: Some(request_id),
+                    request_type,
+                    elapsed: self.timeout,
+                })
             }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to apply workload: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
+        }
+    }
+
+    /// Returns the update masks of a request if it updates the state and the audit log is enabled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`Request`] about to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// The update masks of the request, or [None] if it must not be audited.
+    fn update_masks_to_audit(&self, request: &impl Request) -> Option<Vec<String>> {
+        if self
+            .inner
+            .audit_log
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_none()
+        {
+            return None;
+        }
+        request.update_mask().map(<[String]>::to_vec)
+    }
+
+    /// Checks if a request updates the state while the state cache is enabled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`Request`] about to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the state cache must be invalidated after sending the request.
+    fn invalidates_state_cache(&self, request: &impl Request) -> bool {
+        if self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_none()
+        {
+            return false;
+        }
+        request.update_mask().is_some()
+    }
+
+    /// Records the result of an update in the audit log, if enabled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request_id`: The ID of the update request;
+    /// - `masks`: The update masks of the request;
+    /// - `result`: The result of sending the request.
+    fn record_audit_entry(
+        &self,
+        request_id: String,
+        masks: Vec<String>,
+        result: &Result<Response, AnkaiosError>,
+    ) {
+        let entry_result = match result {
+            Ok(Response {
+                content: ResponseType::UpdateStateSuccess(update_state_success),
+                ..
+            }) => Ok(*update_state_success.clone()),
+            Ok(Response {
+                content: ResponseType::Error(error),
+                ..
+            }) => Err(error.clone()),
+            Ok(_) => Err("Received unexpected response type.".to_owned()),
+            Err(err) => Err(err.to_string()),
+        };
+        if let Some(audit_log) = self
+            .inner
+            .audit_log
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_mut()
+        {
+            audit_log.record(AuditEntry {
+                timestamp: SystemTime::now(),
+                request_id,
+                masks,
+                result: entry_result,
+            });
+        }
+    }
+
+    /// Enables the audit log, recording every update of the state sent through this object
+    /// and its clones. If the audit log is already enabled, only its size is changed.
+    ///
+    /// ## Arguments
+    ///
+    /// - `max_entries`: The maximum number of entries kept, the oldest entries being dropped first.
+    pub fn enable_audit_log(&self, max_entries: usize) {
+        let mut audit_log = self
+            .inner
+            .audit_log
+            .lock()
+            .unwrap_or_else(|_| unreachable!());
+        match audit_log.as_mut() {
+            Some(log) => log.set_max_entries(max_entries),
+            None => *audit_log = Some(AuditLog::new(max_entries)),
+        }
+    }
+
+    /// Disables the audit log and drops the recorded entries.
+    pub fn disable_audit_log(&self) {
+        *self
+            .inner
+            .audit_log
+            .lock()
+            .unwrap_or_else(|_| unreachable!()) = None;
+    }
+
+    /// Returns the updates of the state recorded since the audit log was enabled.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [`AuditEntry`] objects, the oldest first. Empty if the audit log is disabled.
+    #[must_use]
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.inner
+            .audit_log
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_ref()
+            .map(AuditLog::entries)
+            .unwrap_or_default()
+    }
+
+    /// Enables the state cache, serving [`get_state`](Ankaios::get_state) and the getters built
+    /// on it from memory while the cached state is younger than the policy's `ttl`.
+    /// The cache is shared by all the clones of this object and is invalidated by every
+    /// update of the state sent through them. If the cache is already enabled, only its
+    /// policy is changed.
+    ///
+    /// ## Arguments
+    ///
+    /// - `policy`: The [`CachePolicy`] of the cache.
+    pub fn enable_state_cache(&self, policy: CachePolicy) {
+        let mut state_cache = self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!());
+        match state_cache.as_mut() {
+            Some(cache) => cache.set_policy(policy),
+            None => *state_cache = Some(StateCache::new(policy)),
+        }
+    }
+
+    /// Disables the state cache and drops the cached states.
+    pub fn disable_state_cache(&self) {
+        *self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!()) = None;
+    }
+
+    /// Drops the cached states, the next requests being sent to the Control Interface.
+    pub fn invalidate_state_cache(&self) {
+        if let Some(cache) = self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_mut()
+        {
+            cache.clear();
+        }
+    }
+
+    /// Enables the keepalive, periodically checking that the agent still answers,
+    /// as a hung agent otherwise looks the same as a healthy idle one.
+    ///
+    /// Every `interval` a small part of the state is requested. If no response arrives
+    /// within `interval` for `max_missed_responses` consecutive checks, the agent is considered
+    /// disconnected and a new handshake is started. If the keepalive is already enabled,
+    /// it is restarted with the new parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// - `interval`: The time between two checks, also used as timeout for each check;
+    /// - `max_missed_responses`: The number of consecutive checks without response after which the agent is considered disconnected.
+    pub fn enable_keepalive(&self, interval: Duration, max_missed_responses: u32) {
+        let handler = tokio::spawn(Self::run_keepalive(
+            Arc::downgrade(&self.inner),
+            interval,
+            max_missed_responses.max(1),
+        ));
+        if let Some(previous_handler) = self
+            .inner
+            .keepalive_handler
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .replace(handler)
+        {
+            previous_handler.abort();
+        }
+    }
+
+    /// Disables the keepalive.
+    pub fn disable_keepalive(&self) {
+        if let Some(handler) = self
+            .inner
+            .keepalive_handler
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .take()
+        {
+            handler.abort();
+        }
+    }
+
+    /// Checks periodically that the agent answers and marks it as unresponsive otherwise.
+    /// Only a weak reference to the shared state is kept, so that the keepalive does not
+    /// keep the connection open after the last [Ankaios] object was dropped.
+    ///
+    /// ## Arguments
+    ///
+    /// - `inner`: The state shared between the clones of the [Ankaios] object;
+    /// - `interval`: The time between two checks, also used as timeout for each check;
+    /// - `max_missed_responses`: The number of consecutive checks without response after which the agent is considered disconnected.
+    async fn run_keepalive(
+        inner: Weak<AnkaiosInner>,
+        interval: Duration,
+        max_missed_responses: u32,
+    ) {
+        let mut missed_responses = 0;
+        loop {
+            sleep(interval).await;
+            let Some(shared_inner) = inner.upgrade() else {
+                break;
+            };
+            // The check sends its own request, so that it is neither answered from the
+            // state cache nor joined to a request in flight with another timeout.
+            let probe = Self::from_inner(shared_inner, interval);
+            match probe
+                .get_state_uncached(vec![KEEPALIVE_FIELD_MASK.to_owned()])
+                .await
+            {
+                Ok(_)
+                | Err(AnkaiosError::AnkaiosResponseError(_) | AnkaiosError::ResponseError(_)) => {
+                    missed_responses = 0;
+                }
+                Err(AnkaiosError::TimeoutError { .. }) => {
+                    missed_responses += 1;
+                    sdk_log!(
+                        debug,
+                        "No response to the keepalive check ({missed_responses}/{max_missed_responses})."
+                    );
+                    if missed_responses >= max_missed_responses {
+                        missed_responses = 0;
+                        probe
+                            .inner
+                            .control_interface
+                            .lock()
+                            .await
+                            .mark_agent_unresponsive();
+                    }
+                }
+                Err(err) => sdk_log!(trace, "Skipped the keepalive check: {err}"),
+            }
+        }
+    }
+
+    /// Describes a request for the log lines, including the correlation ID if set.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request_id`: The ID of the request.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] describing the request.
+    fn describe_request(&self, request_id: &str) -> String {
+        match self.correlation_id.as_deref() {
+            Some(correlation_id) => {
+                format!("request '{request_id}' (correlation id '{correlation_id}')")
             }
+            None => format!("request '{request_id}'"),
+        }
+    }
+
+    /// Sends a raw proto request to the Control Interface and returns the raw proto response.
+    ///
+    /// This allows accessing proto fields that are not yet covered by the SDK types.
+    /// If the request has no ID, a new one is generated. The request is sent like the
+    /// requests of the SDK: the [`request_id_prefix`](Ankaios::request_id_prefix) is applied,
+    /// updates of the state are recorded in the audit log and invalidate the state cache.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: The [`ank_base::Request`] to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// - the [`ank_base::Response`] received for the request.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) if the connection was closed due to an incompatible protocol version;
+    /// - [`AnkaiosError`]::[`MessageTooLargeError`](AnkaiosError::MessageTooLargeError) if the request exceeds the control interface message limit.
+    #[cfg(feature = "unstable-proto")]
+    pub async fn send_raw_request(
+        &self,
+        request: ank_base::Request,
+    ) -> Result<ank_base::Response, AnkaiosError> {
+        let mut raw_request = RawRequest::new(request);
+        self.apply_id_prefix(&mut raw_request);
+
+        // Removes the raw request also if this future is dropped before completion.
+        let _raw_request_guard = self
+            .inner
+            .control_interface
+            .lock()
+            .await
+            .add_raw_request(raw_request.get_id());
+        let response = self.send_prefixed_request(raw_request).await?;
+
+        match response.content {
+            ResponseType::RawResponse(raw_response) => Ok(*raw_response),
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
@@ -502,42 +1351,48 @@ impl Ankaios {
         }
     }
 
-    /// Send a request to get the [Workload] that matches the given name.
+    /// Send a request to apply a [Manifest].
     ///
     /// ## Arguments
     ///
-    /// - `workload_name`: A [String] containing the name of the workload to get.
+    /// - `manifest`: The [Manifest] to be applied.
     ///
     /// ## Returns
     ///
-    /// - a [Workload] object if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_workload(
-        &mut self,
-        workload_name: String,
-    ) -> Result<Vec<Workload>, AnkaiosError> {
-        let complete_state = self
-            .get_state(vec![format!("{WORKLOADS_PREFIX}.{workload_name}")])
-            .await?;
-        Ok(complete_state.get_workloads())
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if [`validate_agents`](Ankaios::validate_agents)
+    ///   is enabled and some of the agents are not connected.
+    pub async fn apply_manifest(
+        &self,
+        manifest: Manifest,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let masks = manifest.calculate_masks();
+        self.apply_manifest_masks(manifest, masks).await
     }
 
-    /// Send a request to delete a workload.
+    /// Send a request to apply only a part of a [Manifest], e.g. only its configs.
+    ///
+    /// The included masks select the workloads and configs of the manifest under them,
+    /// so the parts of the state missing from the manifest are not deleted. More specific masks,
+    /// e.g. a single field of a workload of the manifest, are applied as they are.
     ///
     /// ## Arguments
     ///
-    /// - `workload_name`: A [String] containing the name of the workload to get.
+    /// - `manifest`: The [Manifest] to be partially applied;
+    /// - `include_masks`: The masks selecting the parts of the manifest to apply, e.g. `desiredState.configs`.
     ///
     /// ## Returns
     ///
-    /// - a [Workload] object if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
@@ -545,75 +1400,61 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn delete_workload(
-        &mut self,
-        workload_name: String,
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if [`validate_agents`](Ankaios::validate_agents)
+    ///   is enabled and some of the agents of the applied workloads are not connected.
+    pub async fn apply_manifest_partial(
+        &self,
+        manifest: Manifest,
+        include_masks: &[String],
     ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        // Create request
-        let request = UpdateStateRequest::new(
-            &CompleteState::default(),
-            vec![format!("{WORKLOADS_PREFIX}.{workload_name}")],
-        );
-
-        // Wait for the response
-        let response = self.send_request(request).await?;
-
-        match response.content {
-            ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
-                    "Update successful: {:?} added workloads, {:?} deleted workloads",
-                    update_state_success.added_workloads.len(),
-                    update_state_success.deleted_workloads.len()
-                );
-                Ok(*update_state_success)
-            }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to delete workload: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
-                ))
-            }
+        let masks = manifest.calculate_masks_including(include_masks);
+        if masks.is_empty() {
+            sdk_log!(
+                info,
+                "No part of the manifest matches the masks, nothing to apply."
+            );
+            return Ok(UpdateStateSuccess::default());
         }
+        self.apply_manifest_masks(manifest, masks).await
     }
 
-    /// Send a request to update the configs
+    /// Sends a request to apply the parts of a [Manifest] selected by the masks,
+    /// as described in [`apply_manifest`](Ankaios::apply_manifest).
     ///
     /// ## Arguments
     ///
-    /// - `configs`: A [`HashMap`] containing the configs to be updated.
+    /// - `manifest`: The [Manifest] to be applied;
+    /// - `masks`: The update masks of the request.
     ///
     /// ## Returns
     ///
-    /// - an [`UpdateStateSuccess`] object if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn update_configs(
-        &mut self,
-        configs: HashMap<String, serde_yaml::Value>,
+    /// The same as [`apply_manifest`](Ankaios::apply_manifest).
+    async fn apply_manifest_masks(
+        &self,
+        manifest: Manifest,
+        masks: Vec<String>,
     ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        // Create CompleteState
-        let complete_state = CompleteState::new_from_configs(configs);
+        manifest.check_mask_names()?;
+        let mask_set = MaskSet::try_from(masks.clone())?;
+        self.check_agents_connected(manifest.get_agent_names_for_masks(&masks))
+            .await?;
 
         // Create request
-        let request = UpdateStateRequest::new(&complete_state, vec![CONFIGS_PREFIX.to_owned()]);
+        let request =
+            UpdateStateRequest::new(&CompleteState::new_from_manifest(manifest), mask_set);
 
         // Wait for the response
         let response = self.send_request(request).await?;
 
         match response.content {
             ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
+                sdk_log!(
+                    info,
                     "Update successful: {:?} added workloads, {:?} deleted workloads",
                     update_state_success.added_workloads.len(),
                     update_state_success.deleted_workloads.len()
@@ -621,11 +1462,11 @@ impl Ankaios {
                 Ok(*update_state_success)
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to update configs: {error}");
+                sdk_log!(error, "Error while trying to apply manifest: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
@@ -633,44 +1474,42 @@ impl Ankaios {
         }
     }
 
-    /// Send a request to add a config with the provided name.
-    /// If the config exists, it will be replaced.
+    /// Send a request to delete a [Manifest].
     ///
     /// ## Arguments
     ///
-    /// - `name`: A [String] containing the name of the config to be added;
-    /// - `configs`: A [`serde_yaml::Value`] containing the configs to be added.
+    /// - `manifest`: The [Manifest] to be deleted.
     ///
     /// ## Returns
     ///
-    /// - an [`UpdateStateSuccess`] object if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn add_config(
-        &mut self,
-        name: String,
-        configs: serde_yaml::Value,
+    pub async fn delete_manifest(
+        &self,
+        manifest: Manifest,
     ) -> Result<UpdateStateSuccess, AnkaiosError> {
-        // Create CompleteState
-        let complete_state =
-            CompleteState::new_from_configs(HashMap::from([(name.clone(), configs)]));
+        manifest.check_mask_names()?;
+        let masks = manifest.calculate_masks();
+        self.check_self_delete(&masks)?;
 
         // Create request
-        let request =
-            UpdateStateRequest::new(&complete_state, vec![format!("{CONFIGS_PREFIX}.{name}")]);
+        let request = UpdateStateRequest::new(&CompleteState::default(), MaskSet::try_from(masks)?);
 
         // Wait for the response
         let response = self.send_request(request).await?;
 
         match response.content {
             ResponseType::UpdateStateSuccess(update_state_success) => {
-                log::info!(
+                sdk_log!(
+                    info,
                     "Update successful: {:?} added workloads, {:?} deleted workloads",
                     update_state_success.added_workloads.len(),
                     update_state_success.deleted_workloads.len()
@@ -678,11 +1517,11 @@ impl Ankaios {
                 Ok(*update_state_success)
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to add the config: {error}");
+                sdk_log!(error, "Error while trying to delete manifest: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
@@ -690,93 +1529,63 @@ impl Ankaios {
         }
     }
 
-    /// Send a request to get all the configs.
+    /// Loads a [Manifest] from a file, validates it and sends a request to apply it.
+    ///
+    /// ## Arguments
+    ///
+    /// - `path`: The [Path] of the manifest file, `-` for the standard input.
     ///
     /// ## Returns
     ///
-    /// - a [`HashMap`] containing the configs if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_configs(
-        &mut self,
-    ) -> Result<HashMap<String, serde_yaml::Value>, AnkaiosError> {
-        let complete_state = self.get_state(vec![CONFIGS_PREFIX.to_owned()]).await?;
-        Ok(complete_state.get_configs())
+    /// - any error returned by [`Manifest::from_file_validated`] if the manifest can't be loaded;
+    /// - any error returned by [`apply_manifest`](Ankaios::apply_manifest) if the manifest can't be applied.
+    pub async fn apply_manifest_from_file(
+        &self,
+        path: &Path,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let manifest = Manifest::from_file_validated(path).inspect_err(|err| {
+            sdk_log!(error, "Could not load manifest '{}': {err}", path.display());
+        })?;
+        self.apply_manifest(manifest).await
     }
 
-    /// Send a request to get the config with the provided name.
+    /// Loads a [Manifest] from a file, validates it and sends a request to delete it.
     ///
     /// ## Arguments
     ///
-    /// - `name`: A [String] containing the name of the config.
+    /// - `path`: The [Path] of the manifest file, `-` for the standard input.
     ///
     /// ## Returns
     ///
-    /// - a [`HashMap`] containing the config if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_config(
-        &mut self,
-        name: String,
-    ) -> Result<HashMap<String, serde_yaml::Value>, AnkaiosError> {
-        let complete_state = self
-            .get_state(vec![format!("{CONFIGS_PREFIX}.{name}")])
-            .await?;
-        Ok(complete_state.get_configs())
+    /// - any error returned by [`Manifest::from_file_validated`] if the manifest can't be loaded;
+    /// - any error returned by [`delete_manifest`](Ankaios::delete_manifest) if the manifest can't be deleted.
+    pub async fn delete_manifest_from_file(
+        &self,
+        path: &Path,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let manifest = Manifest::from_file_validated(path).inspect_err(|err| {
+            sdk_log!(error, "Could not load manifest '{}': {err}", path.display());
+        })?;
+        self.delete_manifest(manifest).await
     }
 
-    /// Send a request to delete all the configs.
+    /// Send a request to run a [Workload].
     ///
-    /// ## Errors
+    /// ## Arguments
     ///
-    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn delete_all_configs(&mut self) -> Result<(), AnkaiosError> {
-        // Create request
-        let request =
-            UpdateStateRequest::new(&CompleteState::default(), vec![CONFIGS_PREFIX.to_owned()]);
-
-        // Wait for the response
-        let response = self.send_request(request).await?;
-
-        match response.content {
-            ResponseType::UpdateStateSuccess(_) => {
-                log::info!("Update successful");
-                Ok(())
-            }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to delete all configs: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
-                ))
-            }
-        }
-    }
-
-    /// Send a request to delete the config with the provided name.
+    /// - `workload`: The [Workload] to be run.
     ///
-    /// ## Arguments
+    /// ## Returns
     ///
-    /// - `name`: A [String] containing the name of the config.
+    /// - an [`UpdateStateSuccess`] containing the number of added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
@@ -784,28 +1593,46 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn delete_config(&mut self, name: String) -> Result<(), AnkaiosError> {
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if [`validate_agents`](Ankaios::validate_agents)
+    ///   is enabled and the agent of the workload is not connected.
+    pub async fn apply_workload(
+        &self,
+        workload: Workload,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        self.check_agents_connected(workload.workload.agent.clone())
+            .await?;
+
+        let mut masks = workload.masks.clone();
+        if masks.is_empty() {
+            masks = MaskSet::from(Mask::new_unchecked(workload.main_mask.clone()));
+        }
+
+        // Create CompleteState
+        let complete_state = CompleteState::new_from_workloads(vec![workload]);
+
         // Create request
-        let request = UpdateStateRequest::new(
-            &CompleteState::default(),
-            vec![format!("{CONFIGS_PREFIX}.{name}")],
-        );
+        let request = UpdateStateRequest::new(&complete_state, masks);
 
         // Wait for the response
         let response = self.send_request(request).await?;
 
         match response.content {
-            ResponseType::UpdateStateSuccess(_) => {
-                log::info!("Update successful");
-                Ok(())
+            ResponseType::UpdateStateSuccess(update_state_success) => {
+                sdk_log!(
+                    info,
+                    "Update successful: {:?} added workloads, {:?} deleted workloads",
+                    update_state_success.added_workloads.len(),
+                    update_state_success.deleted_workloads.len()
+                );
+                Ok(*update_state_success)
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to delete config: {error}");
+                sdk_log!(error, "Error while trying to apply workload: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
@@ -813,15 +1640,19 @@ impl Ankaios {
         }
     }
 
-    /// Send a request to get the [complete state](CompleteState).
+    /// Send a request to get the [Workload] that matches the given name.
+    ///
+    /// The returned workload has no masks. The fields changed afterwards through its update
+    /// methods are tracked, so that the modified workload can be passed to
+    /// [`apply_workload`](Ankaios::apply_workload) to update only these fields.
     ///
     /// ## Arguments
     ///
-    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request.
+    /// - `workload_name`: A [String] containing the name of the workload to get.
     ///
     /// ## Returns
     ///
-    /// - a [`CompleteState`] object containing the state of the cluster.
+    /// - a [Workload] object if the request was successful.
     ///
     /// ## Errors
     ///
@@ -830,86 +1661,84 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_state(
-        &mut self,
-        field_masks: Vec<String>,
-    ) -> Result<CompleteState, AnkaiosError> {
-        // Create request
-        let request = GetStateRequest::new(field_masks);
-
-        // Wait for the response
-        let response = self.send_request(request).await?;
-
-        match response.content {
-            ResponseType::CompleteState(complete_state) => Ok(*complete_state),
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to get the state: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
-                ))
-            }
-        }
+    pub async fn get_workload(&self, workload_name: String) -> Result<Vec<Workload>, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().workload(workload_name).build())
+            .await?;
+        Ok(complete_state.get_workloads())
     }
 
-    /// Send a request to set tags for a specific agent.
+    /// Send a request to get the files of a workload, rendered with the configs
+    /// assigned to the workload, as they are mounted by [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+    ///
+    /// The rendering is done by the SDK and supports only a subset of the template syntax
+    /// of Ankaios: plain references to config values, e.g. `{{alias.key}}`, `{{alias.list.0}}`
+    /// or `{{{alias.key}}}`. Files using any other syntax, e.g. helpers like `{{#each}}`,
+    /// comments or partials, are not passed through but fail to render.
     ///
     /// ## Arguments
     ///
-    /// * `agent_name` - The name of the agent.
-    /// * `tags` - A [`HashMap`] containing the tags to set for the agent.
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// - a [Vec] of rendered [Files](File) if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
-    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error or the workload was not found;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn set_agent_tags(
-        &mut self,
-        agent_name: String,
-        tags: HashMap<String, String>,
-    ) -> Result<(), AnkaiosError> {
-        // Create CompleteState
-        let mut complete_state = CompleteState::new();
-        complete_state.set_agent_tags(&agent_name, tags);
-
-        // Create request
-        let request = UpdateStateRequest::new(
-            &complete_state,
-            vec![format!("{AGENTS_PREFIX}.{agent_name}.tags")],
-        );
-
-        // Wait for the response
-        let response = self.send_request(request).await?;
-
-        match response.content {
-            ResponseType::UpdateStateSuccess(_) => {
-                log::info!("Update successful");
-                Ok(())
-            }
-            ResponseType::Error(error) => {
-                log::error!("Error while trying to set agent tags: {error}");
-                Err(AnkaiosError::AnkaiosResponseError(error))
-            }
-            _ => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(
-                    "Received unexpected response type.".to_owned(),
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`FileRenderingError`](AnkaiosError::FileRenderingError) if a file uses unsupported template
+    ///   syntax or references unknown config values.
+    pub async fn get_rendered_workload_files(
+        &self,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<Vec<File>, AnkaiosError> {
+        let complete_state = self
+            .get_state(
+                StateQuery::new()
+                    .field(instance_name.desired_state_mask())
+                    .configs()
+                    .build(),
+            )
+            .await?;
+        let workload = complete_state
+            .get_workload(&instance_name.workload_name)
+            .ok_or_else(|| {
+                AnkaiosError::AnkaiosResponseError(format!(
+                    "Workload {} not found.",
+                    instance_name.workload_name
                 ))
-            }
-        }
+            })?;
+
+        let configs = complete_state.get_configs();
+        let workload_configs: HashMap<String, serde_yaml::Value> = workload
+            .get_configs()
+            .into_iter()
+            .filter_map(|(alias, config_name)| {
+                configs
+                    .get(&config_name)
+                    .map(|config| (alias, config.clone()))
+            })
+            .collect();
+
+        workload
+            .get_files()
+            .iter()
+            .map(|file| file.render(&workload_configs))
+            .collect()
     }
 
-    /// Send a request to get the agents.
+    /// Send a request to get the desired state, i.e. the workloads and configs
+    /// requested by the users. The observed execution states of the workloads
+    /// are not part of it, see [`Ankaios::get_workload_states`] for these.
     ///
     /// ## Returns
     ///
-    /// - a [`HashMap`] containing the agents if the request was successful.
+    /// - a [Manifest] containing the desired state if the request was successful.
     ///
     /// ## Errors
     ///
@@ -918,16 +1747,20 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_agents(&mut self) -> Result<HashMap<String, AgentAttributes>, AnkaiosError> {
-        let complete_state = self.get_state(vec![AGENTS_PREFIX.to_owned()]).await?;
-        Ok(complete_state.get_agents())
+    pub async fn get_desired_state(&self) -> Result<Manifest, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().desired_state().build())
+            .await?;
+        Ok(Manifest::from(complete_state))
     }
 
-    /// Send a request to get the agents.
+    /// Send a request to get all the workloads of the desired state.
+    /// The observed execution states of the workloads are not part of it,
+    /// see [`Ankaios::get_workload_states`] for these.
     ///
     /// ## Returns
     ///
-    /// - the [`AgentAttributes`] of the requested agent if the request was successful.
+    /// - a [Vec] of [Workloads](Workload) if the request was successful.
     ///
     /// ## Errors
     ///
@@ -936,22 +1769,23 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_agent(&mut self, agent_name: String) -> Result<AgentAttributes, AnkaiosError> {
-        let agents = self
-            .get_state(vec![format!("{AGENTS_PREFIX}.{agent_name}")])
-            .await?
-            .get_agents();
-
-        agents.get(&agent_name).cloned().ok_or_else(|| {
-            AnkaiosError::AnkaiosResponseError(format!("Agent {agent_name} not found."))
-        })
+    pub async fn get_desired_workloads(&self) -> Result<Vec<Workload>, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().workloads().build())
+            .await?;
+        Ok(complete_state.get_workloads())
     }
 
-    /// Send a request to get the workload states.
+    /// Send a request to get the workloads that have the given tag.
+    ///
+    /// ## Arguments
+    ///
+    /// - `key`: A [String] containing the key of the tag;
+    /// - `value`: A [String] containing the value the tag must have.
     ///
     /// ## Returns
     ///
-    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    /// - a [Vec] of workload names and [Workloads](Workload), sorted by name, if the request was successful.
     ///
     /// ## Errors
     ///
@@ -960,22 +1794,37 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_workload_states(&mut self) -> Result<WorkloadStateCollection, AnkaiosError> {
+    pub async fn get_workloads_by_tag(
+        &self,
+        key: String,
+        value: String,
+    ) -> Result<Vec<(String, Workload)>, AnkaiosError> {
         let complete_state = self
-            .get_state(vec![WORKLOAD_STATES_PREFIX.to_owned()])
+            .get_state(StateQuery::new().workloads().build())
             .await?;
-        Ok(complete_state.get_workload_states())
+        let mut workloads: Vec<(String, Workload)> = complete_state
+            .get_workloads()
+            .into_iter()
+            .filter(|workload| workload.get_tags().get(&key) == Some(&value))
+            .map(|workload| (workload.name.clone(), workload))
+            .collect();
+        workloads.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+        Ok(workloads)
     }
 
-    /// Send a request to get the execution state for an instance name.
+    /// Send a request to run a [Workload] only if it differs from the current state.
+    ///
+    /// The current workload is fetched first, bypassing the state cache, and compared
+    /// semantically with the provided one. If they are identical, no update request is sent.
     ///
     /// ## Arguments
     ///
-    /// - `instance_name`: The [`WorkloadInstanceName`] to get the execution state for.
+    /// - `workload`: The [Workload] to be run.
     ///
     /// ## Returns
     ///
-    /// - the requested [`WorkloadExecutionState`] for the provided instance name.
+    /// - [`ApplyWorkloadResult::Applied`] containing the [`UpdateStateSuccess`] if the workload was applied;
+    /// - [`ApplyWorkloadResult::Unchanged`] if the workload is already in the desired state.
     ///
     /// ## Errors
     ///
@@ -984,212 +1833,420 @@ impl Ankaios {
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_execution_state_for_instance_name(
-        &mut self,
-        instance_name: &WorkloadInstanceName,
-    ) -> Result<WorkloadExecutionState, AnkaiosError> {
-        let complete_state: CompleteState = self
-            .get_state(vec![instance_name.get_filter_mask()])
-            .await?;
-        let workload_states = Vec::from(complete_state.get_workload_states());
-        match workload_states.first() {
-            Some(workload_state) => Ok(workload_state.execution_state.clone()),
-            None => Err(AnkaiosError::AnkaiosResponseError(
-                "No workload states found.".to_owned(),
-            )),
+    pub async fn apply_workload_if_changed(
+        &self,
+        workload: Workload,
+    ) -> Result<ApplyWorkloadResult, AnkaiosError> {
+        let current_workloads = self
+            .get_state_uncached(StateQuery::new().workload(workload.name.clone()).build())
+            .await?
+            .get_workloads();
+        if current_workloads
+            .iter()
+            .any(|current| current.is_equivalent_to(&workload))
+        {
+            sdk_log!(
+                info,
+                "Workload '{}' is unchanged, skipping update.",
+                workload.name
+            );
+            return Ok(ApplyWorkloadResult::Unchanged);
         }
+
+        self.apply_workload(workload)
+            .await
+            .map(ApplyWorkloadResult::Applied)
     }
 
-    /// Send a request to get the workload states for the workloads running on a specific agent.
+    /// Applies configs and the workloads referencing them in a single call.
+    ///
+    /// The configs are applied first and the workloads only after Ankaios accepted the configs.
+    /// If the workloads could not be applied, the configs are rolled back: the existing configs
+    /// get their previous values back and the configs created by the bundle are deleted.
+    /// The previous values are read from the server, bypassing the state cache.
     ///
     /// ## Arguments
     ///
-    /// - `agent_name`: A [String] containing the name of the agent to get the workload states for.
+    /// - `configs`: A [`HashMap`] containing the configs to be applied, with values convertible into a [`ConfigValue`];
+    /// - `workloads`: The [Workload]s referencing the configs.
     ///
     /// ## Returns
     ///
-    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    /// - an [`UpdateStateSuccess`] containing the added and deleted workloads if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for a response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
-    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
-    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_workload_states_on_agent(
-        &mut self,
-        agent_name: String,
-    ) -> Result<WorkloadStateCollection, AnkaiosError> {
-        let complete_state = self
-            .get_state(vec![format!("{WORKLOAD_STATES_PREFIX}.{agent_name}")])
-            .await?;
-        Ok(complete_state.get_workload_states())
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if a response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if [`validate_agents`](Ankaios::validate_agents)
+    ///   is enabled and some of the agents of the workloads are not connected.
+    ///
+    /// If the workloads could not be applied, the error of the workloads request is returned after the rollback.
+    pub async fn apply_bundle<T: Into<ConfigValue>>(
+        &self,
+        configs: HashMap<String, T>,
+        workloads: Vec<Workload>,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        self.check_agents_connected(
+            workloads
+                .iter()
+                .filter_map(|workload| workload.workload.agent.clone()),
+        )
+        .await?;
+
+        let configs_mask = Mask::new(CONFIGS_PREFIX)?;
+        let config_masks: MaskSet = configs
+            .keys()
+            .map(|name| configs_mask.join_name(name))
+            .collect::<Result<_, _>>()?;
+        let previous_configs: HashMap<String, serde_yaml::Value> = self
+            .get_state_uncached(vec![CONFIGS_PREFIX.to_owned()])
+            .await?
+            .get_configs()
+            .into_iter()
+            .filter(|(name, _)| configs.contains_key(name))
+            .collect();
+        self.send_configs_update(
+            configs
+                .into_iter()
+                .map(|(name, config)| (name, config.into().into_inner()))
+                .collect(),
+            config_masks.clone(),
+        )
+        .await?;
+
+        let workload_masks: MaskSet = workloads
+            .iter()
+            .flat_map(|workload| {
+                if workload.masks.is_empty() {
+                    MaskSet::from(Mask::new_unchecked(workload.main_mask.clone()))
+                } else {
+                    workload.masks.clone()
+                }
+            })
+            .collect();
+        let request = UpdateStateRequest::new(
+            &CompleteState::new_from_workloads(workloads),
+            workload_masks,
+        );
+        let result = match self
+            .send_request(request)
+            .await
+            .map(|response| response.content)
+        {
+            Ok(ResponseType::UpdateStateSuccess(update_state_success)) => Ok(*update_state_success),
+            Ok(ResponseType::Error(error)) => Err(AnkaiosError::AnkaiosResponseError(error)),
+            Ok(_) => Err(AnkaiosError::ResponseError(
+                "Received unexpected response type.".to_owned(),
+            )),
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(update_state_success) => {
+                sdk_log!(
+                    info,
+                    "Bundle applied: {} configs, {:?} added workloads, {:?} deleted workloads",
+                    config_masks.len(),
+                    update_state_success.added_workloads.len(),
+                    update_state_success.deleted_workloads.len()
+                );
+                Ok(update_state_success)
+            }
+            Err(err) => {
+                sdk_log!(
+                    error,
+                    "Error while trying to apply the workloads of the bundle, rolling back the configs: {err}"
+                );
+                if let Err(rollback_err) = self
+                    .send_configs_update(previous_configs, config_masks)
+                    .await
+                {
+                    sdk_log!(error, "Could not roll back the configs: {rollback_err}");
+                }
+                Err(err)
+            }
+        }
     }
 
-    /// Send a request to get the workload states for the workloads with a specific name.
+    /// Send a request to delete a workload.
     ///
     /// ## Arguments
     ///
-    /// - `workload_name`: A [String] containing the name of the workloads to get the states for.
+    /// - `workload_name`: A [String] containing the name of the workload to get.
     ///
     /// ## Returns
     ///
-    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    /// - a [Workload] object if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
     /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn get_workload_states_for_name(
-        &mut self,
+    pub async fn delete_workload(
+        &self,
         workload_name: String,
-    ) -> Result<WorkloadStateCollection, AnkaiosError> {
-        let complete_state = self
-            .get_state(vec![WORKLOAD_STATES_PREFIX.to_owned()])
-            .await?;
-        let mut workload_states_for_name = WorkloadStateCollection::new();
-        for workload_state in Vec::from(complete_state.get_workload_states()) {
-            if workload_state.workload_instance_name.workload_name == workload_name {
-                workload_states_for_name.add_workload_state(workload_state.clone());
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let mask = Mask::new(WORKLOADS_PREFIX)?.join_name(&workload_name)?;
+        self.check_self_delete(&[mask.to_string()])?;
+
+        // Create request
+        let request = UpdateStateRequest::new(&CompleteState::default(), mask);
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
+
+        match response.content {
+            ResponseType::UpdateStateSuccess(update_state_success) => {
+                sdk_log!(
+                    info,
+                    "Update successful: {:?} added workloads, {:?} deleted workloads",
+                    update_state_success.added_workloads.len(),
+                    update_state_success.deleted_workloads.len()
+                );
+                Ok(*update_state_success)
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to delete workload: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
             }
         }
-        Ok(workload_states_for_name)
     }
 
-    /// Waits for the workload to reach the specified state.
+    /// Send a request to delete a workload, only if the given instance is its current instance.
+    ///
+    /// The workload states are requested first, bypassing the state cache, and the workload is deleted only if the
+    /// given instance is the only active instance of the workload. This prevents deleting
+    /// a workload that has been re-created with the same name in the meantime.
+    /// The check and the deletion are two requests, so a small window remains between them.
     ///
     /// ## Arguments
     ///
-    /// - `instance_name`: The [`WorkloadInstanceName`] to wait for;
-    /// - `state`: The [`WorkloadStateEnum`] to wait for.
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload to delete.
+    ///
+    /// ## Returns
+    ///
+    /// - an [`UpdateStateSuccess`] object if the request was successful.
     ///
     /// ## Errors
     ///
+    /// - [`AnkaiosError`]::[`InstanceMismatchError`](AnkaiosError::InstanceMismatchError) if the instance is not the current one;
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn wait_for_workload_to_reach_state(
-        &mut self,
-        instance_name: WorkloadInstanceName,
-        state: WorkloadStateEnum,
-    ) -> Result<(), AnkaiosError> {
-        const CHECK_INTERVAL: Duration = Duration::from_millis(100);
-        let timeout_clone = self.timeout;
-        let poll_future = async {
-            loop {
-                let workload_exec_state = self
-                    .get_execution_state_for_instance_name(&instance_name)
-                    .await?;
-                if workload_exec_state.state == state {
-                    return Ok(());
-                }
+    pub async fn delete_workload_instance(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let active_instances: Vec<WorkloadInstanceName> = self
+            .get_workload_states_for_name_uncached(&instance_name.workload_name)
+            .await?
+            .as_list()
+            .into_iter()
+            .filter(|workload_state| {
+                workload_state.execution_state.state != WorkloadStateEnum::Removed
+            })
+            .map(|workload_state| workload_state.workload_instance_name)
+            .collect();
+        if !active_instances.contains(instance_name)
+            || active_instances
+                .iter()
+                .any(|active| active != instance_name)
+        {
+            sdk_log!(
+                error,
+                "Workload instance '{instance_name}' is not the current instance of the workload."
+            );
+            return Err(AnkaiosError::InstanceMismatchError(
+                instance_name.to_string(),
+            ));
+        }
 
-                sleep(CHECK_INTERVAL).await;
-            }
-        };
+        self.delete_workload(instance_name.workload_name.clone())
+            .await
+    }
 
-        match tokio_timeout(timeout_clone, poll_future).await {
-            Ok(Ok(())) => Ok(()),
-            Ok(Err(err)) => {
-                log::error!("Error while waiting for workload to reach state: {err}");
-                Err(err)
-            }
-            Err(err) => {
-                log::error!("Timeout while waiting for workload to reach state: {err}");
-                Err(AnkaiosError::TimeoutError(err))
-            }
+    /// Returns a clone of this object that is allowed to delete its own workload,
+    /// e.g. for a single deletion while [`protect_own_workload`](Ankaios::protect_own_workload) is enabled.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Ankaios] object sharing the connection of this one.
+    #[must_use]
+    pub fn allow_self_delete(&self) -> Self {
+        Self {
+            protect_own_workload: false,
+            ..self.clone()
         }
     }
 
-    /// Request logs for the specified workloads.
+    /// Checks that the masks of a deletion do not cover the workload using this object,
+    /// if [`protect_own_workload`](Ankaios::protect_own_workload) is enabled.
     ///
     /// ## Arguments
     ///
-    /// - `logs_request`: A [`LogsRequest`] containing the details to request logs of workloads.
+    /// - `masks`: The update masks of the deletion.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SelfDeleteError`](AnkaiosError::SelfDeleteError) if the masks cover the own workload;
+    /// - [`AnkaiosError`]::[`SelfIntrospectionError`](AnkaiosError::SelfIntrospectionError) if the name of the own workload is not configured.
+    fn check_self_delete(&self, masks: &[String]) -> Result<(), AnkaiosError> {
+        if !self.protect_own_workload {
+            return Ok(());
+        }
+        let workload_name = self.own_workload_name()?;
+        let own_mask = format!("{WORKLOADS_PREFIX}.{workload_name}");
+        if masks
+            .iter()
+            .any(|mask| own_mask == *mask || own_mask.starts_with(&format!("{mask}.")))
+        {
+            sdk_log!(
+                error,
+                "Refusing to delete the own workload '{workload_name}'."
+            );
+            return Err(AnkaiosError::SelfDeleteError(workload_name));
+        }
+        Ok(())
+    }
+
+    /// Send a request to delete all the workloads that have the given tag.
+    ///
+    /// The matching workloads are deleted with a single update request.
+    /// If no workload matches, no update request is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// - `key`: A [String] containing the key of the tag;
+    /// - `value`: A [String] containing the value the tag must have.
+    ///
+    /// ## Returns
+    ///
+    /// - an [`UpdateStateSuccess`] object if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn request_logs(
-        &mut self,
-        logs_request: LogsRequest,
-    ) -> Result<LogCampaignResponse, AnkaiosError> {
-        let request = AnkaiosLogsRequest::from(logs_request);
-        let request_id = request.get_id();
+    pub async fn delete_workloads_by_tag(
+        &self,
+        key: String,
+        value: String,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let workloads_mask = Mask::new(WORKLOADS_PREFIX)?;
+        let masks: MaskSet = self
+            .get_workloads_by_tag(key, value)
+            .await?
+            .into_iter()
+            .map(|(workload_name, _)| workloads_mask.join_name(&workload_name))
+            .collect::<Result<_, _>>()?;
+        if masks.is_empty() {
+            sdk_log!(info, "No workloads match the tag, nothing to delete.");
+            return Ok(UpdateStateSuccess::default());
+        }
+        self.check_self_delete(&masks.clone().into_vec())?;
+
+        // Create request
+        let request = UpdateStateRequest::new(&CompleteState::default(), masks);
+
+        // Wait for the response
         let response = self.send_request(request).await?;
 
         match response.content {
-            ResponseType::LogsRequestAccepted(accepted_workload_names) => {
-                log::trace!(
-                    "Received LogsRequestAccepted: {accepted_workload_names:?} accepted workloads."
-                );
-
-                let (logs_sender, logs_receiver) = mpsc::channel(CHANNEL_SIZE);
-                let log_campaign_response = LogCampaignResponse::new(
-                    request_id.clone(),
-                    accepted_workload_names,
-                    logs_receiver,
+            ResponseType::UpdateStateSuccess(update_state_success) => {
+                sdk_log!(
+                    info,
+                    "Update successful: {:?} added workloads, {:?} deleted workloads",
+                    update_state_success.added_workloads.len(),
+                    update_state_success.deleted_workloads.len()
                 );
-                self.control_interface
-                    .add_log_campaign(request_id, logs_sender);
-                Ok(log_campaign_response)
+                Ok(*update_state_success)
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to request logs: {error}");
+                sdk_log!(error, "Error while trying to delete workloads: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
-            unexpected_response => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(format!(
-                    "Received unexpected response type: '{unexpected_response:?}'"
-                )))
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
             }
         }
     }
 
-    /// Stop receiving logs for a log campaign.
+    /// Send a request to update the configs
     ///
     /// ## Arguments
     ///
-    /// - `log_campaign_response`: A [`LogCampaignResponse`] to stop receiving logs for;
+    /// - `configs`: A [`HashMap`] containing the configs to be updated, with values convertible into a [`ConfigValue`].
+    ///
+    /// ## Returns
+    ///
+    /// - a [`ConfigUpdateResult`] object if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn stop_receiving_logs(
-        &mut self,
-        log_campaign_response: LogCampaignResponse,
-    ) -> Result<(), AnkaiosError> {
-        let logs_cancel_request = LogsCancelRequest::new(log_campaign_response.get_request_id());
-        self.control_interface
-            .remove_log_campaign(&logs_cancel_request.get_id());
-        let response = self.send_request(logs_cancel_request).await?;
+    pub async fn update_configs<T: Into<ConfigValue>>(
+        &self,
+        configs: HashMap<String, T>,
+    ) -> Result<ConfigUpdateResult, AnkaiosError> {
+        let config_names: Vec<String> = configs.keys().cloned().collect();
+
+        // Create CompleteState
+        let complete_state = CompleteState::new_from_configs(
+            configs
+                .into_iter()
+                .map(|(name, config)| (name, config.into().into_inner()))
+                .collect(),
+        );
+
+        // Create request
+        let request = UpdateStateRequest::new(&complete_state, Mask::new_unchecked(CONFIGS_PREFIX));
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
 
         match response.content {
-            ResponseType::LogsCancelAccepted => {
-                log::trace!("Received LogsCancelAccepted: log campaign canceled successfully.");
-                Ok(())
+            ResponseType::UpdateStateSuccess(_) => {
+                sdk_log!(
+                    info,
+                    "Update successful: {} configs updated",
+                    config_names.len()
+                );
+                Ok(ConfigUpdateResult::new(config_names))
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to cancel log campaign: {error}");
+                sdk_log!(error, "Error while trying to update configs: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
@@ -1197,343 +2254,3840 @@ impl Ankaios {
         }
     }
 
-    /// Register to an event campaign.
+    /// Sends a request updating the configs selected by the masks.
+    /// The masked configs missing from `configs` are deleted.
     ///
     /// ## Arguments
     ///
-    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request.
+    /// - `configs`: A [`HashMap`] containing the new values of the configs;
+    /// - `masks`: The update masks of the request, one per config.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`update_configs`](Ankaios::update_configs).
+    async fn send_configs_update(
+        &self,
+        configs: HashMap<String, serde_yaml::Value>,
+        masks: MaskSet,
+    ) -> Result<(), AnkaiosError> {
+        let request = UpdateStateRequest::new(&CompleteState::new_from_configs(configs), masks);
+        let response = self.send_request(request).await?;
+
+        match response.content {
+            ResponseType::UpdateStateSuccess(_) => Ok(()),
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to update configs: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Send a request to add a config with the provided name.
+    /// If the config exists, it will be replaced.
+    ///
+    /// ## Arguments
+    ///
+    /// - `name`: A [String] containing the name of the config to be added;
+    /// - `configs`: A value convertible into a [`ConfigValue`], e.g. a [`serde_yaml::Value`]
+    ///   or a [`serde_json::Value`], containing the configs to be added.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`ConfigUpdateResult`] object if the request was successful.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn register_event(
-        &mut self,
-        field_masks: Vec<String>,
-    ) -> Result<EventsCampaignResponse, AnkaiosError> {
-        let request = EventsRequest::new(field_masks);
-        let request_id = request.get_id();
-        let response = self.send_request(request).await?;
+    pub async fn add_config<T: Into<ConfigValue>>(
+        &self,
+        name: String,
+        configs: T,
+    ) -> Result<ConfigUpdateResult, AnkaiosError> {
+        let mask = Mask::new(CONFIGS_PREFIX)?.join_name(&name)?;
 
-        match response.content {
-            ResponseType::CompleteState(complete_state) => {
-                log::info!("Event registered successfully, state received.");
+        // Create CompleteState
+        let complete_state = CompleteState::new_from_configs(HashMap::from([(
+            name.clone(),
+            configs.into().into_inner(),
+        )]));
 
-                let (events_sender, events_receiver) = mpsc::channel(CHANNEL_SIZE);
-                let events_campaign_response =
-                    EventsCampaignResponse::new(request_id.clone(), events_receiver);
+        // Create request
+        let request = UpdateStateRequest::new(&complete_state, mask);
 
-                let event_entry = EventEntry {
-                    complete_state: *complete_state,
-                    ..Default::default()
-                };
-                events_sender.send(event_entry).await.unwrap_or_else(|err| {
-                    log::error!("Error while sending initial event: '{err}'");
-                });
+        // Wait for the response
+        let response = self.send_request(request).await?;
 
-                self.control_interface
-                    .add_events_campaign(request_id, events_sender);
-                Ok(events_campaign_response)
+        match response.content {
+            ResponseType::UpdateStateSuccess(_) => {
+                sdk_log!(info, "Update successful: config '{name}' added");
+                Ok(ConfigUpdateResult::new([name]))
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to request events: {error}");
+                sdk_log!(error, "Error while trying to add the config: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
-            unexpected_response => {
-                log::error!("Received unexpected response type.");
-                Err(AnkaiosError::ResponseError(format!(
-                    "Received unexpected response type: '{unexpected_response:?}'"
-                )))
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
             }
         }
     }
 
-    /// Unregister from an event campaign.
+    /// Ensures the config with the provided name has the given value.
+    ///
+    /// The current config is fetched first, bypassing the state cache, and an update
+    /// request is sent only if the config does not exist or has another value.
     ///
     /// ## Arguments
     ///
-    /// - `events_campaign_response`: The [`EventsCampaignResponse`] received when registering
+    /// - `name`: A [String] containing the name of the config;
+    /// - `value`: A value convertible into a [`ConfigValue`] the config must have.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Ensured::Unchanged`] if the config already had the value;
+    /// - [`Ensured::Updated`] if the config had another value and was updated;
+    /// - [`Ensured::Created`] if the config did not exist and was created.
     ///
     /// ## Errors
     ///
     /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
-    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
     /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
     /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
     /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
-    pub async fn unregister_event(
-        &mut self,
-        events_campaign_response: EventsCampaignResponse,
-    ) -> Result<(), AnkaiosError> {
-        let events_cancel_request =
-            EventsCancelRequest::new(events_campaign_response.get_request_id());
-        self.control_interface
-            .remove_events_campaign(&events_cancel_request.get_id());
-        let response = self.send_request(events_cancel_request).await?;
+    pub async fn ensure_config<T: Into<ConfigValue>>(
+        &self,
+        name: String,
+        value: T,
+    ) -> Result<Ensured, AnkaiosError> {
+        let config_value = value.into().into_inner();
+        let current_configs = self
+            .get_state_uncached(StateQuery::new().config(name.clone()).build())
+            .await?
+            .get_configs();
+        let ensured = match current_configs.get(&name) {
+            Some(current) if *current == config_value => {
+                sdk_log!(info, "Config '{name}' is unchanged, skipping update.");
+                return Ok(Ensured::Unchanged);
+            }
+            Some(_) => Ensured::Updated,
+            None => Ensured::Created,
+        };
+
+        self.add_config(name, config_value).await?;
+        Ok(ensured)
+    }
+
+    /// Send a request to get all the configs.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`HashMap`] containing the configs if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_configs(&self) -> Result<HashMap<String, serde_yaml::Value>, AnkaiosError> {
+        let complete_state = self.get_state(StateQuery::new().configs().build()).await?;
+        Ok(complete_state.get_configs())
+    }
+
+    /// Send a request to get the config with the provided name.
+    ///
+    /// ## Arguments
+    ///
+    /// - `name`: A [String] containing the name of the config.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`HashMap`] containing the config if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_config(
+        &self,
+        name: String,
+    ) -> Result<HashMap<String, serde_yaml::Value>, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().config(name).build())
+            .await?;
+        Ok(complete_state.get_configs())
+    }
+
+    /// Send a request to delete all the configs.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn delete_all_configs(&self) -> Result<(), AnkaiosError> {
+        // Create request
+        let request = UpdateStateRequest::new(
+            &CompleteState::default(),
+            Mask::new_unchecked(CONFIGS_PREFIX),
+        );
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
 
         match response.content {
-            ResponseType::EventsCancelAccepted => {
-                log::trace!("Received EventsCancelAccepted: unregistered successfully.");
+            ResponseType::UpdateStateSuccess(_) => {
+                sdk_log!(info, "Update successful");
                 Ok(())
             }
             ResponseType::Error(error) => {
-                log::error!("Error while trying to unregister from the campaign: {error}");
+                sdk_log!(error, "Error while trying to delete all configs: {error}");
                 Err(AnkaiosError::AnkaiosResponseError(error))
             }
             _ => {
-                log::error!("Received unexpected response type.");
+                sdk_log!(error, "Received unexpected response type.");
                 Err(AnkaiosError::ResponseError(
                     "Received unexpected response type.".to_owned(),
                 ))
             }
         }
     }
-}
 
-impl Drop for Ankaios {
+    /// Send a request to delete the config with the provided name.
+    ///
+    /// ## Arguments
+    ///
+    /// - `name`: A [String] containing the name of the config.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn delete_config(&self, name: String) -> Result<(), AnkaiosError> {
+        // Create request
+        let request = UpdateStateRequest::new(
+            &CompleteState::default(),
+            Mask::new(CONFIGS_PREFIX)?.join_name(&name)?,
+        );
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
+
+        match response.content {
+            ResponseType::UpdateStateSuccess(_) => {
+                sdk_log!(info, "Update successful");
+                Ok(())
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to delete config: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Send a request to get the [complete state](CompleteState).
+    ///
+    /// Served from the state cache if enabled with [`enable_state_cache`](Ankaios::enable_state_cache)
+    /// and a state requested with the same field masks has not expired yet. Concurrent calls with
+    /// the same field masks share a single request to the Control Interface. The keepalive and the
+    /// methods waiting for a state always use [`get_state_uncached`](Ankaios::get_state_uncached).
+    ///
+    /// ## Arguments
+    ///
+    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`CompleteState`] object containing the state of the cluster.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a field mask is not valid;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_state(&self, field_masks: Vec<String>) -> Result<CompleteState, AnkaiosError> {
+        let (cached_state, generation) = self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_ref()
+            .map_or((None, 0), |cache| {
+                (cache.get(&field_masks), cache.generation())
+            });
+        if let Some(state) = cached_state {
+            sdk_log!(trace, "Serving the state from the cache.");
+            return Ok(state);
+        }
+
+        let state = self
+            .get_state_coalesced(field_masks.clone(), generation)
+            .await?;
+        if let Some(cache) = self
+            .inner
+            .state_cache
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_mut()
+        {
+            cache.insert(generation, &field_masks, state.clone());
+        }
+        Ok(state)
+    }
+
+    /// Gets the [complete state](CompleteState), joining an identical request that is already
+    /// in flight instead of sending a new one. Only requests sent with the same request ID prefix
+    /// and correlation ID are joined, and a joining caller waits at most its own timeout.
+    /// Requests sent before the state cache was invalidated are not joined, as their state
+    /// might predate the update.
+    /// If the joined request fails, a new request is sent, so that the caller gets its own error.
+    ///
+    /// ## Arguments
+    ///
+    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request;
+    /// - `generation`: The generation of the state cache when the state was requested.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`CompleteState`] object containing the state of the cluster.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`get_state`](Ankaios::get_state).
+    async fn get_state_coalesced(
+        &self,
+        field_masks: Vec<String>,
+        generation: u64,
+    ) -> Result<CompleteState, AnkaiosError> {
+        // Requests are only joined if they are sent the same way
+        let key = (
+            normalize_field_masks(&field_masks),
+            self.request_id_prefix.clone(),
+            self.correlation_id.clone(),
+            generation,
+        );
+        let (state_sender, joined_receiver) = {
+            let mut in_flight_states = self
+                .inner
+                .in_flight_states
+                .lock()
+                .unwrap_or_else(|_| unreachable!());
+            if let Some(sender) = in_flight_states.get(&key) {
+                (None, Some(sender.subscribe()))
+            } else {
+                let (sender, _) = broadcast::channel(1);
+                in_flight_states.insert(key.clone(), sender.clone());
+                (Some(sender), None)
+            }
+        };
+
+        if let Some(mut state_receiver) = joined_receiver {
+            match tokio_timeout(self.timeout, state_receiver.recv()).await {
+                Ok(Ok(Some(state))) => {
+                    sdk_log!(trace, "Joined an identical state request in flight.");
+                    return Ok(state);
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    sdk_log!(
+                        error,
+                        "Timeout while waiting for an identical state request in flight."
+                    );
+                    return Err(AnkaiosError::TimeoutError {
+                        request_id: None,
+                        request_type: GET_STATE_REQUEST_TYPE.to_owned(),
+                        elapsed: self.timeout,
+                    });
+                }
+            }
+            sdk_log!(
+                debug,
+                "The identical state request in flight failed, sending a new one."
+            );
+            return self.get_state_uncached(field_masks).await;
+        }
+
+        let guard = InFlightStateGuard {
+            in_flight_states: &self.inner.in_flight_states,
+            key,
+        };
+        let result = self.get_state_uncached(field_masks).await;
+        drop(guard);
+        if let Some(sender) = state_sender {
+            // There might be no request waiting for the state.
+            let _ = sender.send(result.as_ref().ok().cloned());
+        }
+        result
+    }
+
+    /// Send a request to get the [complete state](CompleteState), bypassing the state cache
+    /// enabled with [`enable_state_cache`](Ankaios::enable_state_cache).
+    ///
+    /// ## Arguments
+    ///
+    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`CompleteState`] object containing the state of the cluster.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`get_state`](Ankaios::get_state).
+    pub async fn get_state_uncached(
+        &self,
+        field_masks: Vec<String>,
+    ) -> Result<CompleteState, AnkaiosError> {
+        // Create request
+        let request = GetStateRequest::new(MaskSet::try_from(field_masks)?);
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
+
+        match response.content {
+            ResponseType::CompleteState(complete_state) => Ok(*complete_state),
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to get the state: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Send several requests to get the [`CompleteState`] concurrently, one for each set of field masks.
+    ///
+    /// Useful to query several disjoint parts of the state without waiting for each response in turn.
+    ///
+    /// ## Arguments
+    ///
+    /// - `masks`: A [Vec] containing the field masks of each request.
+    ///
+    /// ## Returns
+    ///
+    /// - a [Vec] of [`CompleteState`] objects, in the same order as the given `masks`.
+    ///
+    /// ## Errors
+    ///
+    /// The first error of any of the requests, the remaining requests being aborted:
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for a response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if a response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_states_parallel(
+        &self,
+        masks: Vec<Vec<String>>,
+    ) -> Result<Vec<CompleteState>, AnkaiosError> {
+        let handles: Vec<JoinHandle<Result<CompleteState, AnkaiosError>>> = masks
+            .into_iter()
+            .map(|field_masks| {
+                let ankaios = self.clone();
+                tokio::spawn(async move { ankaios.get_state(field_masks).await })
+            })
+            .collect();
+
+        let mut states = Vec::with_capacity(handles.len());
+        let mut pending_handles = handles.into_iter();
+        while let Some(handle) = pending_handles.next() {
+            let result = match handle.await {
+                Ok(result) => result,
+                Err(err) => Err(AnkaiosError::ControlInterfaceError(format!(
+                    "State request task failed: {err}"
+                ))),
+            };
+            match result {
+                Ok(state) => states.push(state),
+                Err(err) => {
+                    pending_handles.for_each(|pending| pending.abort());
+                    return Err(err);
+                }
+            }
+        }
+        Ok(states)
+    }
+
+    /// Send a request to set tags for a specific agent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agent_name` - The name of the agent.
+    /// * `tags` - A [`HashMap`] containing the tags to set for the agent.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty, contains a dot or is the wildcard `*`;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn set_agent_tags(
+        &self,
+        agent_name: String,
+        tags: HashMap<String, String>,
+    ) -> Result<(), AnkaiosError> {
+        let mask = Mask::new(AGENTS_PREFIX)?
+            .join_name(&agent_name)?
+            .join("tags")?;
+
+        // Create CompleteState
+        let mut complete_state = CompleteState::new();
+        complete_state.set_agent_tags(&agent_name, tags);
+
+        // Create request
+        let request = UpdateStateRequest::new(&complete_state, mask);
+
+        // Wait for the response
+        let response = self.send_request(request).await?;
+
+        match response.content {
+            ResponseType::UpdateStateSuccess(_) => {
+                sdk_log!(info, "Update successful");
+                Ok(())
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to set agent tags: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Checks that the given agents are connected, if [`validate_agents`](Ankaios::validate_agents) is enabled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `agent_names`: The names of the agents to check.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if some of the agents are not connected;
+    /// - the errors of [`get_agents`](Ankaios::get_agents).
+    async fn check_agents_connected<T: IntoIterator<Item = String>>(
+        &self,
+        agent_names: T,
+    ) -> Result<(), AnkaiosError> {
+        if !self.validate_agents {
+            return Ok(());
+        }
+        let connected_agents = self.get_agents().await?;
+        let mut unknown_agents: Vec<String> = agent_names
+            .into_iter()
+            .filter(|agent_name| !connected_agents.contains_key(agent_name))
+            .collect();
+        if unknown_agents.is_empty() {
+            return Ok(());
+        }
+        unknown_agents.sort();
+        unknown_agents.dedup();
+        sdk_log!(error, "Agents not connected: {}", unknown_agents.join(", "));
+        Err(AnkaiosError::UnknownAgentsError(unknown_agents))
+    }
+
+    /// Send a request to get the agents.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`HashMap`] containing the agents if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_agents(&self) -> Result<HashMap<String, AgentAttributes>, AnkaiosError> {
+        let complete_state = self.get_state(StateQuery::new().agents().build()).await?;
+        Ok(complete_state.get_agents())
+    }
+
+    /// Send a request to get the agents, including the agents that have workloads
+    /// assigned but are not connected.
+    ///
+    /// ## Returns
+    ///
+    /// - a [Vec] of [`AgentInfo`] sorted by agent name if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_agents_detailed(&self) -> Result<Vec<AgentInfo>, AnkaiosError> {
+        let complete_state = self
+            .get_state(
+                StateQuery::new()
+                    .agents()
+                    .workload_states()
+                    .workloads()
+                    .build(),
+            )
+            .await?;
+        Ok(complete_state.get_agents_detailed())
+    }
+
+    /// Send a request to get the agents fulfilling a condition, e.g. the connected agents
+    /// having a tag, to choose where to place a workload.
+    ///
+    /// ## Arguments
+    ///
+    /// - `predicate`: The condition the [`AgentInfo`] has to fulfill.
+    ///
+    /// ## Returns
+    ///
+    /// - a [Vec] of the matching [`AgentInfo`] sorted by agent name if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_agents_matching<P: Fn(&AgentInfo) -> bool>(
+        &self,
+        predicate: P,
+    ) -> Result<Vec<AgentInfo>, AnkaiosError> {
+        Ok(self
+            .get_agents_detailed()
+            .await?
+            .into_iter()
+            .filter(|agent_info| predicate(agent_info))
+            .collect())
+    }
+
+    /// Chooses a connected agent to place a workload on, e.g. to pass it to
+    /// [`WorkloadBuilder::agent_name`](crate::WorkloadBuilder::agent_name).
+    ///
+    /// The agents not reporting the resource used by the strategy are skipped. The position of
+    /// [`RoundRobin`](PlacementStrategy::RoundRobin) is shared by all the clones of this object.
+    ///
+    /// ## Arguments
+    ///
+    /// - `strategy`: The [`PlacementStrategy`] used to choose the agent.
+    ///
+    /// ## Returns
+    ///
+    /// - the name of the chosen agent if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error
+    ///   or no connected agent is suitable;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn select_agent(&self, strategy: PlacementStrategy) -> Result<String, AnkaiosError> {
+        let agents = self.get_agents().await?;
+        placement::select_agent(&agents, strategy, &self.inner.round_robin_index).ok_or_else(|| {
+            sdk_log!(error, "No connected agent is suitable for {strategy:?}.");
+            AnkaiosError::AnkaiosResponseError(format!(
+                "No connected agent is suitable for {strategy:?}."
+            ))
+        })
+    }
+
+    /// Send a request to get the agents.
+    ///
+    /// ## Returns
+    ///
+    /// - the [`AgentAttributes`] of the requested agent if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_agent(&self, agent_name: String) -> Result<AgentAttributes, AnkaiosError> {
+        let agents = self
+            .get_state(StateQuery::new().agent(agent_name.clone()).build())
+            .await?
+            .get_agents();
+
+        agents.get(&agent_name).cloned().ok_or_else(|| {
+            AnkaiosError::AnkaiosResponseError(format!("Agent {agent_name} not found."))
+        })
+    }
+
+    /// Send a request to get the workload states.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_workload_states(&self) -> Result<WorkloadStateCollection, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().workload_states().build())
+            .await?;
+        Ok(complete_state.get_workload_states())
+    }
+
+    /// Send a request to get the execution state for an instance name.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] to get the execution state for.
+    ///
+    /// ## Returns
+    ///
+    /// - the requested [`WorkloadExecutionState`] for the provided instance name.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_execution_state_for_instance_name(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Result<WorkloadExecutionState, AnkaiosError> {
+        let complete_state: CompleteState = self
+            .get_state_uncached(StateQuery::new().workload_state(instance_name).build())
+            .await?;
+        let workload_states = Vec::from(complete_state.get_workload_states());
+        match workload_states.first() {
+            Some(workload_state) => Ok(workload_state.execution_state.clone()),
+            None => Err(AnkaiosError::AnkaiosResponseError(
+                "No workload states found.".to_owned(),
+            )),
+        }
+    }
+
+    /// Determines the instance name of the workload using this object.
+    ///
+    /// As the Control Interface does not tell a workload who it is, the workload name is taken
+    /// from [`own_workload_name`](Ankaios::own_workload_name) or the `ANKAIOS_WORKLOAD_NAME`
+    /// environment variable, and the agent name, if any, from [`own_agent_name`](Ankaios::own_agent_name)
+    /// or the `ANKAIOS_AGENT_NAME` environment variable. The variables can be set for example with
+    /// [`WorkloadBuilder::add_env`](crate::WorkloadBuilder::add_env). The workload id is then
+    /// looked up in the workload states.
+    ///
+    /// ## Returns
+    ///
+    /// - the [`WorkloadInstanceName`] of the workload using this object.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SelfIntrospectionError`](AnkaiosError::SelfIntrospectionError) if the workload name is not configured
+    ///   or not exactly one instance of the workload was found;
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn my_instance_name(&self) -> Result<WorkloadInstanceName, AnkaiosError> {
+        let workload_name = self.own_workload_name()?;
+        let agent_name = self
+            .own_agent_name
+            .clone()
+            .or_else(|| env::var(OWN_AGENT_NAME_ENV).ok());
+
+        let mut instance_names: Vec<WorkloadInstanceName> = self
+            .get_workload_states_for_name(workload_name.clone())
+            .await?
+            .as_list()
+            .into_iter()
+            .map(|workload_state| workload_state.workload_instance_name)
+            .filter(|instance_name| {
+                agent_name
+                    .as_ref()
+                    .is_none_or(|agent| *agent == instance_name.agent_name)
+            })
+            .collect();
+        match instance_names.len() {
+            0 => Err(AnkaiosError::SelfIntrospectionError(format!(
+                "No instance of workload '{workload_name}' found."
+            ))),
+            1 => Ok(instance_names.remove(0)),
+            _ => Err(AnkaiosError::SelfIntrospectionError(format!(
+                "Several instances of workload '{workload_name}' found, the agent name is needed."
+            ))),
+        }
+    }
+
+    /// Send a request to get the desired state of the workload using this object,
+    /// e.g. to read its own tags and configs. The workload name is determined as
+    /// described in [`my_instance_name`](Ankaios::my_instance_name).
+    ///
+    /// ## Returns
+    ///
+    /// - the [Workload] using this object.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SelfIntrospectionError`](AnkaiosError::SelfIntrospectionError) if the workload name is not configured
+    ///   or the workload is not in the desired state;
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn my_workload(&self) -> Result<Workload, AnkaiosError> {
+        let workload_name = self.own_workload_name()?;
+        self.get_workload(workload_name.clone())
+            .await?
+            .into_iter()
+            .find(|workload| workload.name == workload_name)
+            .ok_or_else(|| {
+                AnkaiosError::SelfIntrospectionError(format!(
+                    "Workload '{workload_name}' not found in the desired state."
+                ))
+            })
+    }
+
+    /// Returns the name of the workload using this object, as described in
+    /// [`my_instance_name`](Ankaios::my_instance_name).
+    ///
+    /// ## Returns
+    ///
+    /// - the name of the workload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SelfIntrospectionError`](AnkaiosError::SelfIntrospectionError) if the workload name is not configured.
+    fn own_workload_name(&self) -> Result<String, AnkaiosError> {
+        self.own_workload_name
+            .clone()
+            .or_else(|| env::var(OWN_WORKLOAD_NAME_ENV).ok())
+            .ok_or_else(|| {
+                AnkaiosError::SelfIntrospectionError(format!(
+                    "The workload name is neither set nor in the '{OWN_WORKLOAD_NAME_ENV}' environment variable."
+                ))
+            })
+    }
+
+    /// Send a request to summarize the workload states of the cluster: the number of workload
+    /// instances per state, in total and per agent, and the failing workload instances.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`ClusterSummary`] aggregating the workload states.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_cluster_summary(&self) -> Result<ClusterSummary, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().agents().workload_states().build())
+            .await?;
+        Ok(ClusterSummary::new(&complete_state))
+    }
+
+    /// Send a request to gather the information explaining why a workload is not running:
+    /// its execution state, the connectivity of its agent and the states of its dependencies.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload to diagnose.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`WorkloadDiagnosis`] containing the gathered information and the found issues.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error
+    ///   or the workload is neither in the desired state nor has a state;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn diagnose_workload(
+        &self,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<WorkloadDiagnosis, AnkaiosError> {
+        let complete_state = self
+            .get_state(
+                StateQuery::new()
+                    .field(instance_name.desired_state_mask())
+                    .agents()
+                    .workload_states()
+                    .build(),
+            )
+            .await?;
+        let workload_name = instance_name.workload_name.clone();
+        let diagnosis = WorkloadDiagnosis::new(instance_name, &complete_state);
+        if diagnosis.execution_state.is_none() && complete_state.get_workloads().is_empty() {
+            sdk_log!(error, "Workload '{workload_name}' not found.");
+            return Err(AnkaiosError::AnkaiosResponseError(format!(
+                "Workload {workload_name} not found."
+            )));
+        }
+        Ok(diagnosis)
+    }
+
+    /// Send a request to get the workload states for the workloads running on a specific agent.
+    ///
+    /// ## Arguments
+    ///
+    /// - `agent_name`: A [String] containing the name of the agent to get the workload states for.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_workload_states_on_agent(
+        &self,
+        agent_name: String,
+    ) -> Result<WorkloadStateCollection, AnkaiosError> {
+        let complete_state = self
+            .get_state(
+                StateQuery::new()
+                    .workload_states_on_agent(agent_name)
+                    .build(),
+            )
+            .await?;
+        Ok(complete_state.get_workload_states())
+    }
+
+    /// Send a request to get the workload states for the workloads with a specific name.
+    ///
+    /// ## Arguments
+    ///
+    /// - `workload_name`: A [String] containing the name of the workloads to get the states for.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn get_workload_states_for_name(
+        &self,
+        workload_name: String,
+    ) -> Result<WorkloadStateCollection, AnkaiosError> {
+        let complete_state = self
+            .get_state(StateQuery::new().workload_states().build())
+            .await?;
+        Ok(workload_states_for_name(&complete_state, &workload_name))
+    }
+
+    /// Send a request to get the workload states for the workloads with a specific name,
+    /// bypassing the state cache.
+    ///
+    /// ## Arguments
+    ///
+    /// - `workload_name`: The name of the workloads to get the states for.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`WorkloadStateCollection`] containing the workload states if the request was successful.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`get_workload_states_for_name`](Ankaios::get_workload_states_for_name).
+    pub(crate) async fn get_workload_states_for_name_uncached(
+        &self,
+        workload_name: &str,
+    ) -> Result<WorkloadStateCollection, AnkaiosError> {
+        let complete_state = self
+            .get_state_uncached(StateQuery::new().workload_states().build())
+            .await?;
+        Ok(workload_states_for_name(&complete_state, workload_name))
+    }
+
+    /// Waits for the workload to reach the specified state.
+    ///
+    /// The state is checked every [`state_check_interval`](Ankaios::state_check_interval)
+    /// and all the checks share the same [`timeout`](Ankaios::timeout). No new check is
+    /// started if it could not finish before the timeout is reached.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] to wait for;
+    /// - `state`: The [`WorkloadStateEnum`] to wait for.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) without request ID if the state was not reached in time,
+    ///   a single check timing out is retried until then;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn wait_for_workload_to_reach_state(
+        &self,
+        instance_name: WorkloadInstanceName,
+        state: WorkloadStateEnum,
+    ) -> Result<(), AnkaiosError> {
+        self.wait_for_execution_state(
+            &instance_name,
+            |execution_state| execution_state.state == state,
+            self.timeout,
+        )
+        .await
+    }
+
+    /// Waits for the workload to reach the specified substate.
+    ///
+    /// This allows distinguishing for example [`RunningOk`](WorkloadSubStateEnum::RunningOk)
+    /// from [`StoppingRequestedAtRuntime`](WorkloadSubStateEnum::StoppingRequestedAtRuntime) or waiting for a
+    /// specific failure.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] to wait for;
+    /// - `substate`: The [`WorkloadSubStateEnum`] to wait for;
+    /// - `timeout`: The maximum time to wait for the substate to be reached.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) without request ID if the substate was not reached in time,
+    ///   a single check timing out is retried until then;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn wait_for_workload_substate(
+        &self,
+        instance_name: WorkloadInstanceName,
+        substate: WorkloadSubStateEnum,
+        timeout: Duration,
+    ) -> Result<(), AnkaiosError> {
+        self.wait_for_execution_state(
+            &instance_name,
+            |execution_state| execution_state.substate == substate,
+            timeout,
+        )
+        .await
+    }
+
+    /// Polls the execution state of the workload until it fulfills the condition.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] to wait for;
+    /// - `condition`: The condition the [`WorkloadExecutionState`] has to fulfill;
+    /// - `timeout`: The maximum time shared by all the checks.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached;
+    /// - any error returned by [`get_execution_state_for_instance_name`](Ankaios::get_execution_state_for_instance_name).
+    async fn wait_for_execution_state(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        condition: impl Fn(&WorkloadExecutionState) -> bool,
+        timeout: Duration,
+    ) -> Result<(), AnkaiosError> {
+        let condition = &condition;
+        self.poll_until(
+            move || async move {
+                let workload_exec_state = self
+                    .get_execution_state_for_instance_name(instance_name)
+                    .await?;
+                Ok(condition(&workload_exec_state).then_some(()))
+            },
+            timeout,
+            WAIT_FOR_STATE_OPERATION,
+            &format!("the state of workload '{instance_name}'"),
+        )
+        .await
+    }
+
+    /// Waits until the [complete state](CompleteState) fulfills an arbitrary condition,
+    /// e.g. an agent being connected, a config being present or a number of workloads running.
+    ///
+    /// The state is requested with the given field masks every
+    /// [`state_check_interval`](Ankaios::state_check_interval) and all the checks share the same
+    /// timeout. No new check is started if it could not finish before the timeout is reached.
+    ///
+    /// ## Arguments
+    ///
+    /// - `field_masks`: A [Vec] of [String]s containing the field masks of the checked state;
+    /// - `predicate`: The condition the [`CompleteState`] has to fulfill;
+    /// - `timeout`: The maximum time to wait for the condition.
+    ///
+    /// ## Returns
+    ///
+    /// - the first [`CompleteState`] fulfilling the condition.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a field mask is not valid;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) without request ID if the condition was not fulfilled in time,
+    ///   a single check timing out is retried until then;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn wait_for<P: Fn(&CompleteState) -> bool>(
+        &self,
+        field_masks: Vec<String>,
+        predicate: P,
+        timeout: Duration,
+    ) -> Result<CompleteState, AnkaiosError> {
+        let field_masks = &field_masks;
+        let predicate = &predicate;
+        self.poll_until(
+            move || async move {
+                let complete_state = self.get_state_uncached(field_masks.clone()).await?;
+                Ok(predicate(&complete_state).then_some(complete_state))
+            },
+            timeout,
+            WAIT_FOR_CONDITION_OPERATION,
+            "the condition on the state",
+        )
+        .await
+    }
+
+    /// Repeats a check every [`state_check_interval`](Ankaios::state_check_interval)
+    /// until it returns a value.
+    ///
+    /// ## Arguments
+    ///
+    /// - `check`: The check, returning [None] while the awaited condition is not fulfilled;
+    /// - `timeout`: The maximum time shared by all the checks;
+    /// - `operation`: The operation reported in the [`TimeoutError`](AnkaiosError::TimeoutError);
+    /// - `description`: What is waited for, used in the log messages.
+    ///
+    /// ## Returns
+    ///
+    /// - the value returned by the first successful check.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached;
+    /// - any other error returned by the check.
+    async fn poll_until<T, F, Fut>(
+        &self,
+        mut check: F,
+        timeout: Duration,
+        operation: &str,
+        description: &str,
+    ) -> Result<T, AnkaiosError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<Option<T>, AnkaiosError>>,
+    {
+        let deadline = Instant::now() + timeout;
+        let poll_future = async {
+            loop {
+                match check().await {
+                    Ok(Some(value)) => return Ok(value),
+                    Ok(None) => {}
+                    // A single check timing out does not end the waiting before the deadline
+                    Err(AnkaiosError::TimeoutError { .. }) => {
+                        sdk_log!(warn, "Timeout while checking {description}, retrying.");
+                    }
+                    Err(err) => return Err(err),
+                }
+
+                let next_check = Instant::now() + self.state_check_interval;
+                if next_check >= deadline {
+                    // The next check cannot complete anymore, wait for the deadline instead
+                    pending::<()>().await;
+                }
+                sleep_until(next_check).await;
+            }
+        };
+
+        match timeout_at(deadline, poll_future).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(err)) => {
+                sdk_log!(error, "Error while waiting for {description}: {err}");
+                Err(err)
+            }
+            Err(_) => {
+                sdk_log!(error, "Timeout while waiting for {description}.");
+                Err(AnkaiosError::TimeoutError {
+                    request_id: None,
+                    request_type: operation.to_owned(),
+                    elapsed: timeout,
+                })
+            }
+        }
+    }
+
+    /// Waits for the workload to reach the specified state, unless cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload;
+    /// - `state`: The [`WorkloadStateEnum`] to wait for;
+    /// - `cancellation_token`: A [`CancellationToken`] that aborts the waiting when cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`CancelledError`](AnkaiosError::CancelledError) if the token was cancelled before the state was reached;
+    /// - any error returned by [`wait_for_workload_to_reach_state`](Ankaios::wait_for_workload_to_reach_state).
+    pub async fn wait_for_workload_to_reach_state_with_cancellation(
+        &self,
+        instance_name: WorkloadInstanceName,
+        state: WorkloadStateEnum,
+        cancellation_token: &CancellationToken,
+    ) -> Result<(), AnkaiosError> {
+        tokio::select! {
+            result = self.wait_for_workload_to_reach_state(instance_name, state) => result,
+            () = cancellation_token.cancelled() => {
+                sdk_log!(info, "Waiting for workload to reach state was cancelled.");
+                Err(AnkaiosError::CancelledError(
+                    "Waiting for workload to reach state was cancelled.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Runs a [Workload] until it finishes and deletes it afterwards, as done for ephemeral jobs.
+    ///
+    /// The workload is applied and its state is checked every
+    /// [`state_check_interval`](Ankaios::state_check_interval) until it is
+    /// [`Succeeded`](WorkloadStateEnum::Succeeded) or [`Failed`](WorkloadStateEnum::Failed).
+    /// Its logs are then optionally collected and it is deleted, also if it did not finish in time.
+    /// The workload should use the restart policy `NEVER`, otherwise it might be restarted
+    /// before its final state is observed.
+    ///
+    /// ## Arguments
+    ///
+    /// - `workload`: The [Workload] to run;
+    /// - `timeout`: The maximum time to wait for the workload to finish and for its logs;
+    /// - `collect_logs`: Whether to collect the logs of the workload before deleting it.
+    ///
+    /// ## Returns
+    ///
+    /// - a [`BatchResult`] containing the final state, the logs and the duration of the run.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) without request ID if the workload did not finish in time,
+    ///   or with request ID if the timeout was reached while waiting for a response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if a response has the wrong type or no instance of the workload was added;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - [`AnkaiosError`]::[`UnknownAgentsError`](AnkaiosError::UnknownAgentsError) if [`validate_agents`](Ankaios::validate_agents)
+    ///   is enabled and the agent of the workload is not connected.
+    pub async fn run_workload_to_completion(
+        &self,
+        workload: Workload,
+        timeout: Duration,
+        collect_logs: bool,
+    ) -> Result<BatchResult, AnkaiosError> {
+        let workload_name = workload.name.clone();
+        let start = Instant::now();
+        let deadline = start + timeout;
+        let update_state_success = self.apply_workload(workload).await?;
+        let Some(instance_name) = update_state_success.added_workloads.into_iter().next() else {
+            // Nothing was started, so the existing workload with this name is left untouched
+            sdk_log!(
+                error,
+                "No instance of workload '{workload_name}' was added."
+            );
+            return Err(AnkaiosError::ResponseError(format!(
+                "No instance of workload '{workload_name}' was added."
+            )));
+        };
+
+        let result = self
+            .wait_for_completion(&instance_name, start, deadline, collect_logs)
+            .await;
+        let deletion = self.delete_workload(workload_name).await;
+        match (result, deletion) {
+            (Ok(batch_result), Ok(_)) => Ok(batch_result),
+            (Ok(_), Err(err)) | (Err(err), Ok(_)) => Err(err),
+            (Err(err), Err(delete_err)) => {
+                sdk_log!(
+                    error,
+                    "Could not delete workload '{instance_name}' after the run: {delete_err}"
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// Waits for the workload to finish and collects its logs.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload;
+    /// - `start`: The [Instant] the workload was applied at;
+    /// - `deadline`: The [Instant] at which the waiting and the log collection end;
+    /// - `collect_logs`: Whether to collect the logs of the workload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the workload did not finish in time;
+    /// - any error returned by [`get_execution_state_for_instance_name`](Ankaios::get_execution_state_for_instance_name)
+    ///   or the log requests.
+    async fn wait_for_completion(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        start: Instant,
+        deadline: Instant,
+        collect_logs: bool,
+    ) -> Result<BatchResult, AnkaiosError> {
+        let state = self
+            .poll_until(
+                move || async move {
+                    let execution_state = self
+                        .get_execution_state_for_instance_name(instance_name)
+                        .await?;
+                    Ok(matches!(
+                        execution_state.state,
+                        WorkloadStateEnum::Succeeded | WorkloadStateEnum::Failed
+                    )
+                    .then_some(execution_state))
+                },
+                deadline.saturating_duration_since(Instant::now()),
+                WAIT_FOR_STATE_OPERATION,
+                &format!("the state of workload '{instance_name}'"),
+            )
+            .await?;
+        let duration = start.elapsed();
+
+        let logs = if collect_logs {
+            self.collect_finished_logs(instance_name, deadline).await?
+        } else {
+            Vec::new()
+        };
+        Ok(BatchResult {
+            state,
+            logs,
+            duration,
+        })
+    }
+
+    /// Collects the logs of a finished workload until [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+    /// stops sending them or the deadline is reached.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload;
+    /// - `deadline`: The [Instant] at which the collection ends with the entries received so far.
+    ///
+    /// ## Errors
+    ///
+    /// - any error returned by [`request_logs`](Ankaios::request_logs) or
+    ///   [`stop_receiving_logs`](Ankaios::stop_receiving_logs).
+    async fn collect_finished_logs(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        deadline: Instant,
+    ) -> Result<Vec<LogEntry>, AnkaiosError> {
+        let mut log_campaign = self
+            .request_logs(LogsRequest {
+                workload_names: vec![instance_name.clone()],
+                ..Default::default()
+            })
+            .await?;
+
+        let mut logs = Vec::new();
+        if log_campaign.accepted_workload_names.contains(instance_name) {
+            let receiver = &mut log_campaign.logs_receiver;
+            let collected = &mut logs;
+            let receive = async move {
+                while let Some(log_response) = receiver.recv().await {
+                    match log_response {
+                        LogResponse::LogEntries(entries) => collected.extend(entries),
+                        LogResponse::LogsStopResponse(_) => break,
+                        LogResponse::Resubscribed => {}
+                    }
+                }
+            };
+            if timeout_at(deadline, receive).await.is_err() {
+                sdk_log!(
+                    warn,
+                    "Timeout while collecting the logs of workload '{instance_name}', keeping the entries received so far."
+                );
+            }
+        } else {
+            sdk_log!(
+                warn,
+                "The logs of workload '{instance_name}' were not accepted, no logs are collected."
+            );
+        }
+
+        self.stop_receiving_logs(log_campaign).await?;
+        Ok(logs)
+    }
+
+    /// Executes the steps of a [Plan] in order, aborting at the first failing step.
+    ///
+    /// ## Arguments
+    ///
+    /// - `plan`: The [Plan] to execute.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`PlanError`](AnkaiosError::PlanError) containing the index and the error of the first failing step.
+    pub async fn execute_plan(&self, plan: Plan) -> Result<(), AnkaiosError> {
+        plan.execute(self).await
+    }
+
+    /// Runs a scope tying the SDK-driven background work to it, so that nothing outlives
+    /// the logic of the scope. When the future returned by `scope_fn` completes, the tasks
+    /// spawned with [`AnkaiosScope::spawn`] are aborted, the token returned by
+    /// [`AnkaiosScope::cancellation_token`] is cancelled and the log and events campaigns
+    /// started through [`AnkaiosScope::ankaios`] are stopped. If the scope is dropped before
+    /// completion, the tasks are still aborted and the token cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// - `scope_fn`: The function receiving the [`AnkaiosScope`] and returning the future to run.
+    ///
+    /// ## Returns
+    ///
+    /// The output of the future returned by `scope_fn`.
+    pub async fn scope<F, Fut, T>(&self, scope_fn: F) -> T
+    where
+        F: FnOnce(AnkaiosScope) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        AnkaiosScope::run(self, scope_fn).await
+    }
+
+    /// Creates a clone of this object whose campaigns are tied to a scope.
+    ///
+    /// ## Arguments
+    ///
+    /// - `scope_campaigns`: The campaigns of the scope.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Ankaios] object sharing the connection of this one.
+    pub(crate) fn scoped(&self, scope_campaigns: SharedScopeCampaigns) -> Self {
+        Self {
+            scope_campaigns: Some(scope_campaigns),
+            ..self.clone()
+        }
+    }
+
+    /// Request logs for the specified workloads.
+    ///
+    /// ## Arguments
+    ///
+    /// - `logs_request`: A [`LogsRequest`] containing the details to request logs of workloads.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn request_logs(
+        &self,
+        logs_request: LogsRequest,
+    ) -> Result<LogCampaignResponse, AnkaiosError> {
+        let buffer_size = logs_request.buffer_size.max(1);
+        let overflow_policy = logs_request.overflow_policy;
+        let request = AnkaiosLogsRequest::from(logs_request);
+        let mut request_proto = request.to_proto();
+        let response = self.send_request(request).await?;
+        // The ID might have been prefixed while sending the request
+        let request_id = response.id;
+        request_proto.request_id.clone_from(&request_id);
+
+        match response.content {
+            ResponseType::LogsRequestAccepted(accepted_workload_names) => {
+                sdk_log!(
+                    trace,
+                    "Received LogsRequestAccepted: {accepted_workload_names:?} accepted workloads."
+                );
+
+                let log_campaign_response;
+                let logs_sender;
+                if overflow_policy == LogOverflowPolicy::Block {
+                    let (sender, receiver) = mpsc::channel(buffer_size);
+                    logs_sender = sender;
+                    log_campaign_response = LogCampaignResponse::new(
+                        request_id.clone(),
+                        accepted_workload_names,
+                        receiver,
+                    );
+                } else {
+                    // The buffering is done by the forwarding task, so that the
+                    // control interface is never blocked by a slow consumer.
+                    let (sender, incoming_receiver) = mpsc::channel(CHANNEL_SIZE);
+                    let (outgoing_sender, receiver) = mpsc::channel(1);
+                    logs_sender = sender;
+                    log_campaign_response = LogCampaignResponse::new(
+                        request_id.clone(),
+                        accepted_workload_names,
+                        receiver,
+                    );
+                    tokio::spawn(forward_with_overflow_policy(
+                        incoming_receiver,
+                        outgoing_sender,
+                        buffer_size,
+                        overflow_policy,
+                        log_campaign_response.dropped_counter(),
+                    ));
+                }
+                let log_campaign_info = LogCampaignInfo::new(
+                    request_id.clone(),
+                    log_campaign_response.accepted_workload_names.clone(),
+                );
+                self.inner.control_interface.lock().await.add_log_campaign(
+                    request_id.clone(),
+                    request_proto,
+                    logs_sender,
+                    log_campaign_info.entries_counter(),
+                );
+                self.inner
+                    .log_campaigns
+                    .lock()
+                    .unwrap_or_else(|_| unreachable!())
+                    .insert(request_id.clone(), log_campaign_info);
+                if let Some(scope_campaigns) = &self.scope_campaigns {
+                    scope_campaigns
+                        .lock()
+                        .unwrap_or_else(|_| unreachable!())
+                        .log_campaigns
+                        .insert(request_id);
+                }
+                Ok(log_campaign_response)
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to request logs: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            unexpected_response => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(format!(
+                    "Received unexpected response type: '{unexpected_response:?}'"
+                )))
+            }
+        }
+    }
+
+    /// Stop receiving logs for a log campaign.
+    ///
+    /// ## Arguments
+    ///
+    /// - `log_campaign_response`: A [`LogCampaignResponse`] to stop receiving logs for;
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn stop_receiving_logs(
+        &self,
+        log_campaign_response: LogCampaignResponse,
+    ) -> Result<(), AnkaiosError> {
+        self.stop_log_campaign(log_campaign_response.get_request_id())
+            .await
+    }
+
+    /// Get the last lines of the logs of a workload and optionally follow the new ones.
+    ///
+    /// The entries of the requested last lines are received as
+    /// [`TailedLogEntry::Backlog`](crate::TailedLogEntry::Backlog), the following ones as
+    /// [`TailedLogEntry::Live`](crate::TailedLogEntry::Live). As the backlog is not marked by
+    /// [Ankaios](https://eclipse-ankaios.github.io/ankaios), it is considered complete after
+    /// `lines` entries or when no entry is received for a short time.
+    ///
+    /// ## Arguments
+    ///
+    /// - `instance_name`: The [`WorkloadInstanceName`] of the workload;
+    /// - `lines`: The number of last lines to get;
+    /// - `follow`: Whether to continue receiving the new log entries.
+    ///
+    /// ## Returns
+    ///
+    /// A [`LogTail`] receiving the log entries.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error or did not accept the workload;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn tail_logs(
+        &self,
+        instance_name: WorkloadInstanceName,
+        lines: usize,
+        follow: bool,
+    ) -> Result<LogTail, AnkaiosError> {
+        let log_campaign_response = self
+            .request_logs(LogsRequest {
+                workload_names: vec![instance_name.clone()],
+                follow,
+                tail: i32::try_from(lines).unwrap_or(i32::MAX),
+                ..Default::default()
+            })
+            .await?;
+        let request_id = log_campaign_response.get_request_id();
+        if !log_campaign_response
+            .accepted_workload_names
+            .contains(&instance_name)
+        {
+            self.stop_log_campaign(request_id).await?;
+            return Err(AnkaiosError::AnkaiosResponseError(format!(
+                "The logs of workload '{instance_name}' were not accepted."
+            )));
+        }
+
+        let (entries_sender, entries_receiver) = mpsc::channel(CHANNEL_SIZE);
+        tokio::spawn(forward_tailed_log_entries(
+            log_campaign_response.logs_receiver,
+            entries_sender,
+            lines,
+            Duration::from_millis(TAIL_BACKLOG_QUIET_PERIOD),
+        ));
+        Ok(LogTail::new(request_id, entries_receiver))
+    }
+
+    /// Stop receiving the log entries of a log tail.
+    ///
+    /// ## Arguments
+    ///
+    /// - `log_tail`: The [`LogTail`] received when starting to tail the logs.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Ankaios::stop_receiving_logs`].
+    pub async fn stop_tailing_logs(&self, log_tail: LogTail) -> Result<(), AnkaiosError> {
+        self.stop_log_campaign(log_tail.get_request_id()).await
+    }
+
+    /// Get the log campaigns that were requested through this object and not stopped yet.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [`LogCampaignInfo`] sorted by the start time of the log campaigns.
+    #[must_use]
+    pub fn active_log_campaigns(&self) -> Vec<LogCampaignInfo> {
+        let mut log_campaigns: Vec<LogCampaignInfo> = self
+            .inner
+            .log_campaigns
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .values()
+            .cloned()
+            .collect();
+        log_campaigns.sort_by(|first, second| {
+            first
+                .start_time
+                .cmp(&second.start_time)
+                .then_with(|| first.request_id.cmp(&second.request_id))
+        });
+        log_campaigns
+    }
+
+    /// Stop all the active log campaigns. All the log campaigns are stopped
+    /// even if stopping one of them fails.
+    ///
+    /// ## Errors
+    ///
+    /// The first error encountered while stopping the log campaigns:
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn stop_all_log_campaigns(&self) -> Result<(), AnkaiosError> {
+        let mut first_error = None;
+        for log_campaign in self.active_log_campaigns() {
+            if let Err(err) = self.stop_log_campaign(log_campaign.request_id).await {
+                sdk_log!(error, "Error while stopping log campaign: '{err}'");
+                first_error.get_or_insert(err);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Stop the log campaign with the given request id.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request_id`: The request id as a [String] of the initial logs request.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Ankaios::stop_receiving_logs`].
+    pub(crate) async fn stop_log_campaign(&self, request_id: String) -> Result<(), AnkaiosError> {
+        self.inner
+            .log_campaigns
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(&request_id);
+        if let Some(scope_campaigns) = &self.scope_campaigns {
+            scope_campaigns
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .log_campaigns
+                .remove(&request_id);
+        }
+        let logs_cancel_request = LogsCancelRequest::new(request_id);
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .remove_log_campaign(&logs_cancel_request.get_id());
+        let response = self.send_request(logs_cancel_request).await?;
+
+        match response.content {
+            ResponseType::LogsCancelAccepted => {
+                sdk_log!(
+                    trace,
+                    "Received LogsCancelAccepted: log campaign canceled successfully."
+                );
+                Ok(())
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to cancel log campaign: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Register to an event campaign.
+    ///
+    /// ## Arguments
+    ///
+    /// - `field_masks`: A [Vec] of [String]s containing the field masks to be used in the request.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a field mask is not valid;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn register_event(
+        &self,
+        field_masks: Vec<String>,
+    ) -> Result<EventsCampaignResponse, AnkaiosError> {
+        let request = EventsRequest::new(MaskSet::try_from(field_masks)?);
+        let response = self.send_request(request).await?;
+        // The ID might have been prefixed while sending the request
+        let request_id = response.id;
+
+        match response.content {
+            ResponseType::CompleteState(complete_state) => {
+                sdk_log!(info, "Event registered successfully, state received.");
+
+                let (events_sender, events_receiver) = mpsc::channel(CHANNEL_SIZE);
+                let events_campaign_response =
+                    EventsCampaignResponse::new(request_id.clone(), events_receiver);
+
+                let event_entry = EventEntry {
+                    complete_state: *complete_state,
+                    ..Default::default()
+                };
+                events_sender.send(event_entry).await.unwrap_or_else(|err| {
+                    sdk_log!(error, "Error while sending initial event: '{err}'");
+                });
+
+                if let Some(scope_campaigns) = &self.scope_campaigns {
+                    scope_campaigns
+                        .lock()
+                        .unwrap_or_else(|_| unreachable!())
+                        .events_campaigns
+                        .insert(request_id.clone());
+                }
+                self.inner
+                    .control_interface
+                    .lock()
+                    .await
+                    .add_events_campaign(request_id, events_sender);
+                Ok(events_campaign_response)
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(error, "Error while trying to request events: {error}");
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            unexpected_response => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(format!(
+                    "Received unexpected response type: '{unexpected_response:?}'"
+                )))
+            }
+        }
+    }
+
+    /// Unregister from an event campaign.
+    ///
+    /// ## Arguments
+    ///
+    /// - `events_campaign_response`: The [`EventsCampaignResponse`] received when registering
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response or waiting for the state to be reached.
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn unregister_event(
+        &self,
+        events_campaign_response: EventsCampaignResponse,
+    ) -> Result<(), AnkaiosError> {
+        self.unregister_events_campaign(events_campaign_response.get_request_id())
+            .await
+    }
+
+    /// Watch the workload states selected by a filter. The changes received within
+    /// the `coalesce_window` are merged, so that only the latest state of every workload
+    /// instance is received, and only if it differs from the one received before.
+    /// The current states are received first.
+    ///
+    /// ## Arguments
+    ///
+    /// - `filter`: The [`WorkloadStatesFilter`] selecting the workload states to watch;
+    /// - `coalesce_window`: The [Duration] for which the changes are merged. A zero duration
+    ///   only merges the changes received at once.
+    ///
+    /// ## Returns
+    ///
+    /// A [`WorkloadStatesWatch`] receiving the coalesced workload states.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected;
+    /// - [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if the timeout was reached while waiting for the response;
+    /// - [`AnkaiosError`]::[`AnkaiosResponseError`](AnkaiosError::AnkaiosResponseError) if [Ankaios](https://eclipse-ankaios.github.io/ankaios) returned an error;
+    /// - [`AnkaiosError`]::[`ResponseError`](AnkaiosError::ResponseError) if the response has the wrong type;
+    /// - [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed.
+    pub async fn watch_workload_states(
+        &self,
+        filter: WorkloadStatesFilter,
+        coalesce_window: Duration,
+    ) -> Result<WorkloadStatesWatch, AnkaiosError> {
+        let events_campaign_response = self.register_event(filter.field_masks()).await?;
+        let (states_sender, states_receiver) = mpsc::channel(CHANNEL_SIZE);
+        let watch =
+            WorkloadStatesWatch::new(events_campaign_response.get_request_id(), states_receiver);
+        tokio::spawn(coalesce_workload_states(
+            events_campaign_response.events_receiver,
+            states_sender,
+            filter,
+            coalesce_window,
+        ));
+        Ok(watch)
+    }
+
+    /// Stop watching the workload states.
+    ///
+    /// ## Arguments
+    ///
+    /// - `watch`: The [`WorkloadStatesWatch`] received when starting to watch.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Ankaios::unregister_event`].
+    pub async fn stop_watching_workload_states(
+        &self,
+        watch: WorkloadStatesWatch,
+    ) -> Result<(), AnkaiosError> {
+        self.unregister_events_campaign(watch.get_request_id())
+            .await
+    }
+
+    /// Unregister from the event campaign with the given request id.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request_id`: The request id as a [String] of the initial events request.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Ankaios::unregister_event`].
+    pub(crate) async fn unregister_events_campaign(
+        &self,
+        request_id: String,
+    ) -> Result<(), AnkaiosError> {
+        if let Some(scope_campaigns) = &self.scope_campaigns {
+            scope_campaigns
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .events_campaigns
+                .remove(&request_id);
+        }
+        let events_cancel_request = EventsCancelRequest::new(request_id);
+        self.inner
+            .control_interface
+            .lock()
+            .await
+            .remove_events_campaign(&events_cancel_request.get_id());
+        let response = self.send_request(events_cancel_request).await?;
+
+        match response.content {
+            ResponseType::EventsCancelAccepted => {
+                sdk_log!(
+                    trace,
+                    "Received EventsCancelAccepted: unregistered successfully."
+                );
+                Ok(())
+            }
+            ResponseType::Error(error) => {
+                sdk_log!(
+                    error,
+                    "Error while trying to unregister from the campaign: {error}"
+                );
+                Err(AnkaiosError::AnkaiosResponseError(error))
+            }
+            _ => {
+                sdk_log!(error, "Received unexpected response type.");
+                Err(AnkaiosError::ResponseError(
+                    "Received unexpected response type.".to_owned(),
+                ))
+            }
+        }
+    }
+}
+
+impl Drop for AnkaiosInner {
     fn drop(&mut self) {
-        log::trace!("Dropping Ankaios");
-        self.control_interface.disconnect().unwrap_or_else(|err| {
-            log::error!("Error while disconnecting: '{err}'");
+        sdk_log!(trace, "Dropping Ankaios");
+        self.dispatcher_handler.abort();
+        if let Some(handler) = self
+            .keepalive_handler
+            .get_mut()
+            .unwrap_or_else(|_| unreachable!())
+            .take()
+        {
+            handler.abort();
+        }
+        self.control_interface
+            .get_mut()
+            .disconnect()
+            .unwrap_or_else(|err| {
+                sdk_log!(error, "Error while disconnecting: '{err}'");
+            });
+    }
+}
+
+/// Collects the workload states of the workloads with a specific name.
+///
+/// ## Arguments
+///
+/// - `complete_state`: The [`CompleteState`] containing the workload states;
+/// - `workload_name`: The name of the workloads.
+///
+/// ## Returns
+///
+/// A [`WorkloadStateCollection`] containing the matching workload states.
+fn workload_states_for_name(
+    complete_state: &CompleteState,
+    workload_name: &str,
+) -> WorkloadStateCollection {
+    let mut workload_states_for_name = WorkloadStateCollection::new();
+    for workload_state in Vec::from(complete_state.get_workload_states()) {
+        if workload_state.workload_instance_name.workload_name == workload_name {
+            workload_states_for_name.add_workload_state(workload_state);
+        }
+    }
+    workload_states_for_name
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+pub(crate) fn generate_test_ankaios(
+    mock_control_interface: ControlInterface,
+) -> (Ankaios, mpsc::Sender<Response>) {
+    let (response_sender, response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+    (
+        Ankaios::new_from_control_interface(
+            mock_control_interface,
+            response_receiver,
+            Duration::from_millis(50),
+        ),
+        response_sender,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        path::Path,
+        sync::{
+            Arc, LazyLock,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+    use tokio::{
+        sync::{Mutex, mpsc},
+        time::Duration,
+    };
+
+    use super::{
+        AGENTS_PREFIX, AgentAttributes, Ankaios, AnkaiosError, AnkaiosHandle, ApplyWorkloadResult,
+        CONFIGS_PREFIX, CachePolicy, CancellationToken, CompleteState, ControlInterface,
+        DEFAULT_TIMEOUT, Ensured, EventsCampaignResponse, File, KEEPALIVE_FIELD_MASK,
+        PlacementStrategy, Response, WORKLOAD_STATES_PREFIX, WRITER_QUEUE_SIZE,
+        WorkloadInstanceName, WorkloadStateEnum, WorkloadStatesFilter, WorkloadSubStateEnum,
+        generate_test_ankaios,
+    };
+    use crate::components::{
+        complete_state::generate_complete_state_proto,
+        manifest::{MANIFEST_CONTENT, generate_test_manifest},
+        request::{
+            AnkaiosLogsRequest, EventsCancelRequest, EventsRequest, GetStateRequest,
+            LogsCancelRequest, Request, UpdateStateRequest,
+        },
+        response::generate_test_response_update_state_success,
+        workload_mod::{WORKLOADS_PREFIX, test_helpers::generate_test_workload},
+    };
+    use crate::{
+        AgentStatus, EventEntry, ankaios_api::ank_base, ankaios_api::ank_base::RequestContent,
+    };
+    use crate::{
+        LogCampaignResponse, LogEntry, LogResponse, LogsRequest as InputLogsRequest, TailedLogEntry,
+    };
+
+    // Used for synchronizing multiple tests that use the same mock.
+    pub static MOCKALL_SYNC: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+    const TEST_LOG_MESSAGE: &str = "some log message 1";
+    const REQUEST_ID: &str = "request_id";
+    const TEST_MASK: &str = "test.mask";
+
+    #[tokio::test]
+    async fn itest_create_ankaios() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let ci_new_context = ControlInterface::new_context();
+        let mut ci_mock = ControlInterface::default();
+
+        ci_mock
+            .expect_connect()
+            .times(1)
+            .with(mockall::predicate::eq(Duration::from_millis(50)))
+            .returning(|_| Ok(()));
+
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        ci_new_context.expect().return_once(move |_| ci_mock);
+
+        // Create Ankaios handle
+        let ankaios_handle = tokio::spawn(Ankaios::new_with_timeout(Duration::from_millis(50)));
+
+        // Create Ankaios fully and check the connection
+        let ankaios = ankaios_handle.await.unwrap();
+        assert!(ankaios.is_ok());
+    }
+
+    #[tokio::test]
+    async fn itest_create_ankaios_with_protocol_version() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let ci_new_context = ControlInterface::new_context();
+        let mut ci_mock = ControlInterface::default();
+
+        ci_mock
+            .expect_set_protocol_version()
+            .times(1)
+            .with(mockall::predicate::eq("0.0.1".to_owned()))
+            .returning(|_| ());
+        ci_mock
+            .expect_set_writer_queue_size()
+            .times(1)
+            .with(mockall::predicate::eq(WRITER_QUEUE_SIZE))
+            .returning(|_| ());
+        ci_mock
+            .expect_connect()
+            .times(1)
+            .with(mockall::predicate::eq(Duration::from_millis(50)))
+            .returning(|_| Ok(()));
+        ci_mock
+            .expect_get_protocol_version()
+            .times(1)
+            .returning(|| "0.0.1".to_owned());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        ci_new_context.expect().return_once(move |_| ci_mock);
+
+        // Create Ankaios handle
+        let ankaios_handle = tokio::spawn(Ankaios::new_with_protocol_version(
+            Duration::from_millis(50),
+            "0.0.1",
+        ));
+
+        // Create Ankaios fully and check the protocol version
+        let ankaios = ankaios_handle.await.unwrap().unwrap();
+        assert_eq!(ankaios.get_protocol_version().await, "0.0.1");
+    }
+
+    #[tokio::test]
+    async fn itest_create_ankaios_with_builder() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let ci_new_context = ControlInterface::new_context();
+        let mut ci_mock = ControlInterface::default();
+
+        ci_mock
+            .expect_set_protocol_version()
+            .times(1)
+            .with(mockall::predicate::eq("0.0.1".to_owned()))
+            .returning(|_| ());
+        ci_mock
+            .expect_set_writer_queue_size()
+            .times(1)
+            .with(mockall::predicate::eq(1))
+            .returning(|_| ());
+        ci_mock
+            .expect_connect()
+            .times(1)
+            .with(mockall::predicate::eq(Duration::from_millis(50)))
+            .returning(|_| Ok(()));
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        ci_new_context.expect().return_once(move |_| ci_mock);
+
+        // Create Ankaios handle
+        let ankaios_handle = tokio::spawn(
+            Ankaios::builder()
+                .timeout(Duration::from_millis(50))
+                .protocol_version("0.0.1")
+                .response_channel_size(0)
+                .writer_queue_size(0)
+                .build(),
+        );
+
+        // Create Ankaios fully and check the connection
+        let ankaios = ankaios_handle.await.unwrap();
+        assert!(ankaios.is_ok());
+    }
+
+    #[test]
+    fn utest_ankaios_handle_is_shareable() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<AnkaiosHandle>();
+    }
+
+    #[tokio::test]
+    async fn itest_server_capabilities() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock.expect_get_protocol_version().never();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![super::API_VERSION_FIELD_MASK.to_owned()]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let method_handle = tokio::spawn(async move { ank.server_capabilities().await });
+
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_proto(ank_base::CompleteState {
+            desired_state: Some(ank_base::State {
+                api_version: "v0.1".to_owned(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        let capabilities = method_handle.await.unwrap().unwrap();
+        assert_eq!(capabilities.api_version, "v0.1");
+        assert!(!capabilities.logs);
+        assert!(!capabilities.events);
+    }
+
+    #[tokio::test]
+    async fn itest_connection_info() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_connection_info()
+            .times(1)
+            .returning(|| super::ConnectionInfo {
+                base_path: "/run/ankaios/control_interface".into(),
+                input_fifo: "/run/ankaios/control_interface/input".into(),
+                output_fifo: "/run/ankaios/control_interface/output".into(),
+                control_socket: None,
+                protocol_version: "1.0.0".to_owned(),
+                state: crate::ControlInterfaceState::Connected,
+                connected_at: Some(std::time::SystemTime::UNIX_EPOCH),
+                reconnect_count: 2,
+                last_error: None,
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+        let info = ank.connection_info().await;
+        assert_eq!(info.protocol_version, "1.0.0");
+        assert_eq!(info.reconnect_count, 2);
+        assert_eq!(info.state, crate::ControlInterfaceState::Connected);
+    }
+
+    #[tokio::test]
+    async fn itest_version_mismatch_on_connection_closed() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a connection closed response
+        let response = Response {
+            content: super::ResponseType::ConnectionClosedReason(
+                "Unsupported protocol version '0.1.0'. Currently supported '1.0.0'".to_owned(),
+            ),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        let result = method_handle.await.unwrap();
+        assert!(matches!(result, Err(AnkaiosError::VersionMismatch(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_timeout_while_connecting() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let ci_new_context = ControlInterface::new_context();
+        let mut ci_mock = ControlInterface::default();
+
+        ci_mock
+            .expect_connect()
+            .with(mockall::predicate::eq(Duration::from_secs(DEFAULT_TIMEOUT)))
+            .times(1)
+            .returning(|_| Err(AnkaiosError::ControlInterfaceError(String::default())));
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        ci_new_context.expect().return_once(move |_| ci_mock);
+
+        // Create Ankaios handle
+        let ankaios_handle = tokio::spawn(Ankaios::new());
+
+        // Create Ankaios fully and check the connection
+        let result = ankaios_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(AnkaiosError::ControlInterfaceError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_ok() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let complete_state = CompleteState::default();
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the state
+        let state = method_handle.await.unwrap().unwrap();
+
+        assert_eq!(state.get_api_version(), complete_state.get_api_version());
+    }
+
+    #[tokio::test]
+    async fn itest_concurrent_requests_from_clones() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the requests that are being sent
+        let (request_sender, mut request_receiver) = mpsc::channel(2);
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.try_send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let ank_clone = ank.clone();
+
+        // Send two requests in parallel from different clones
+        let first_handle =
+            tokio::spawn(async move { ank.get_state(vec!["first".to_owned()]).await });
+        let second_handle =
+            tokio::spawn(async move { ank_clone.get_state(vec!["second".to_owned()]).await });
+
+        let first_request = request_receiver.recv().await.unwrap();
+        let second_request = request_receiver.recv().await.unwrap();
+
+        // Answer the requests in reverse order
+        for request in [second_request, first_request] {
+            let response = Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            };
+            response_sender.send(response).await.unwrap();
+        }
+
+        assert!(first_handle.await.unwrap().is_ok());
+        assert!(second_handle.await.unwrap().is_ok());
+    }
+
+    #[test]
+    fn utest_ankaios_is_clone_send_sync() {
+        fn assert_clone_send_sync<T: Clone + Send + Sync>() {}
+        assert_clone_send_sync::<Ankaios>();
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_incorrect_id_and_timeout() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::default()),
+            id: "incorrect_id".to_owned(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the state
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(AnkaiosError::TimeoutError { request_id: Some(request_id), request_type, elapsed })
+                if request_id == request.get_id()
+                    && request_type == "GetStateRequest"
+                    && elapsed == Duration::from_millis(50)
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_err() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::Error("test".to_owned()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_mismatch_response_type() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::UpdateStateSuccess(Box::default()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_invalid_mask() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock.expect_write_request::<GetStateRequest>().never();
+        ci_mock.expect_write_request::<EventsRequest>().never();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+
+        assert!(matches!(
+            ank.get_state(vec!["desiredState..workloads".to_owned()])
+                .await,
+            Err(AnkaiosError::MaskError(_))
+        ));
+        assert!(matches!(
+            ank.register_event(vec!["workload States".to_owned()]).await,
+            Err(AnkaiosError::MaskError(_))
+        ));
+    }
+
+    #[cfg(feature = "unstable-proto")]
+    #[tokio::test]
+    async fn itest_send_raw_request() {
+        use super::ank_base;
+        use crate::components::control_interface::RawRequestIds;
+        use crate::components::request::RawRequest;
+
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let prefixed_id = format!("app-{REQUEST_ID}");
+        let raw_request_ids = RawRequestIds::default();
+        let raw_request_ids_clone = raw_request_ids.clone();
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        let expected_id = prefixed_id.clone();
+        ci_mock
+            .expect_add_raw_request()
+            .times(1)
+            .withf(move |request_id| *request_id == expected_id)
+            .return_once(move |request_id| raw_request_ids_clone.insert(request_id));
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: RawRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.request_id_prefix = Some("app".to_owned());
+
+        // Prepare handle for sending the raw request
+        let method_handle = tokio::spawn(async move {
+            ank.send_raw_request(ank_base::Request {
+                request_id: REQUEST_ID.to_owned(),
+                request_content: None,
+            })
+            .await
+        });
+
+        // The raw request is sent with the prefix
+        let request = request_receiver.await.unwrap();
+        assert_eq!(request.get_id(), prefixed_id);
+
+        // Send the raw response
+        response_sender
+            .send(Response {
+                content: super::ResponseType::RawResponse(Box::new(ank_base::Response {
+                    request_id: prefixed_id.clone(),
+                    response_content: None,
+                })),
+                id: prefixed_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let response = method_handle.await.unwrap().unwrap();
+        assert_eq!(response.request_id, prefixed_id);
+        assert!(!raw_request_ids.contains(&prefixed_id));
+    }
+
+    #[cfg(feature = "unstable-proto")]
+    #[tokio::test]
+    async fn itest_send_raw_request_connection_closed() {
+        use super::ank_base;
+        use crate::components::control_interface::RawRequestIds;
+        use crate::components::request::RawRequest;
+
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let raw_request_ids = RawRequestIds::default();
+        let raw_request_ids_clone = raw_request_ids.clone();
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_add_raw_request()
+            .times(1)
+            .return_once(move |request_id| raw_request_ids_clone.insert(request_id));
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: RawRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move {
+            ank.send_raw_request(ank_base::Request {
+                request_id: REQUEST_ID.to_owned(),
+                request_content: None,
+            })
+            .await
+        });
+        let request = request_receiver.await.unwrap();
+        assert_eq!(request.get_id(), REQUEST_ID);
+
+        // The closed connection is reported to the raw request
+        response_sender
+            .send(Response {
+                content: super::ResponseType::ConnectionClosedReason("closed".to_owned()),
+                id: String::default(),
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::ConnectionClosedError(_))
+        ));
+        // The raw request was removed by its guard
+        assert!(!raw_request_ids.contains(REQUEST_ID));
+    }
+
+    #[tokio::test]
+    async fn itest_apply_manifest_ok() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for applying the manifest
+        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = generate_test_response_update_state_success(request.get_id());
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.len() == 1);
+        assert!(ret.deleted_workloads.is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_apply_manifest_partial() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask
+                            == vec![
+                                format!("{CONFIGS_PREFIX}.config1"),
+                                format!("{CONFIGS_PREFIX}.config2"),
+                                format!("{CONFIGS_PREFIX}.config3"),
+                            ]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Nothing is sent if no part of the manifest is selected
+        let ret = ank
+            .apply_manifest_partial(
+                generate_test_manifest(),
+                &[format!("{WORKLOADS_PREFIX}.other")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(ret, super::UpdateStateSuccess::default());
+
+        // Prepare handle for applying the configs of the manifest
+        let method_handle = tokio::spawn(async move {
+            ank.apply_manifest_partial(generate_test_manifest(), &[CONFIGS_PREFIX.to_owned()])
+                .await
+        });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn itest_apply_and_delete_manifest_from_file() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+        let masks = generate_test_manifest().calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .returning(move |request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // The mocked file reading returns the path as content
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .apply_manifest_from_file(Path::new(MANIFEST_CONTENT))
+                .await
+        });
+        let request = request_receiver.recv().await.unwrap();
+        let Some(RequestContent::UpdateStateRequest(content)) = request.to_proto().request_content
+        else {
+            panic!("Expected an update state request");
+        };
+        assert!(content.new_state.unwrap().desired_state.is_some());
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .delete_manifest_from_file(Path::new(MANIFEST_CONTENT))
+                .await
+        });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        // An invalid manifest is not sent
+        assert!(matches!(
+            ank.apply_manifest_from_file(Path::new("apiVersion: v1\nworkloads: []"))
+                .await,
+            Err(AnkaiosError::ManifestSchemaError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_apply_manifest_err() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for applying the manifest
+        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::Error("test".to_owned()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_apply_manifest_mismatch_response_type() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for applying the manifest
+        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::default()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_delete_manifest_ok() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for deleting the manifest
+        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = generate_test_response_update_state_success(request.get_id());
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.len() == 1);
+        assert!(ret.deleted_workloads.is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_delete_manifest_err() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for deleting the manifest
+        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::Error("test".to_owned()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_delete_manifest_mismatch_response_type() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare manifest
+        let manifest = generate_test_manifest();
+        let masks = manifest.calculate_masks();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for deleting the manifest
+        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::default()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_apply_workload_ok() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare workload
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let masks = workload.masks.clone();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for applying the workload
+        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = generate_test_response_update_state_success(request.get_id());
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.len() == 1);
+        assert!(ret.deleted_workloads.is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_audit_log() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the requests that are being sent
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let masks = workload.masks.clone();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        assert!(ank.audit_log().is_empty());
+        ank.enable_audit_log(10);
+
+        // A successful update
+        let ank_clone = ank.clone();
+        let workload_clone = workload.clone();
+        let method_handle =
+            tokio::spawn(async move { ank_clone.apply_workload(workload_clone).await });
+        let request = request_receiver.recv().await.unwrap();
+        let successful_id = request.get_id();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                successful_id.clone(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        // A failed update
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.apply_workload(workload).await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::Error("error".to_owned()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_err());
+
+        let audit_log = ank.audit_log();
+        assert_eq!(audit_log.len(), 2);
+        assert_eq!(audit_log[0].request_id, successful_id);
+        assert_eq!(audit_log[0].masks, masks);
+        assert!(audit_log[0].result.is_ok());
+        assert_eq!(audit_log[1].result, Err("error".to_owned()));
+
+        ank.disable_audit_log();
+        assert!(ank.audit_log().is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_state_cache() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channels to intercept the requests that are being sent
+        let (get_sender, mut get_receiver) = mpsc::unbounded_channel();
+        let (update_sender, mut update_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(3)
+            .returning(move |request: GetStateRequest| {
+                get_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: UpdateStateRequest| {
+                update_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
         });
+        let masks = vec!["workloadStates".to_owned()];
+
+        // The first request reaches the control interface
+        let ank_clone = ank.clone();
+        let masks_clone = masks.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.get_state(masks_clone).await });
+        let request = get_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        // The second one is served from the cache
+        assert!(ank.get_state(masks.clone()).await.is_ok());
+
+        // An update invalidates the cache
+        let ank_clone = ank.clone();
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let method_handle = tokio::spawn(async move { ank_clone.apply_workload(workload).await });
+        let request = update_receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        let ank_clone = ank.clone();
+        let masks_clone = masks.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.get_state(masks_clone).await });
+        let request = get_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        // The bypass always reaches the control interface
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.get_state_uncached(masks).await });
+        let request = get_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+
+        ank.invalidate_state_cache();
+        ank.disable_state_cache();
     }
-}
 
-//////////////////////////////////////////////////////////////////////////////
-//                 ########  #######    #########  #########                //
-//                    ##     ##        ##             ##                    //
-//                    ##     #####     #########      ##                    //
-//                    ##     ##                ##     ##                    //
-//                    ##     #######   #########      ##                    //
-//////////////////////////////////////////////////////////////////////////////
+    #[tokio::test]
+    async fn itest_state_cache_fetch_during_update() {
+        let _guard = MOCKALL_SYNC.lock().await;
 
-#[cfg(test)]
-fn generate_test_ankaios(
-    mock_control_interface: ControlInterface,
-) -> (Ankaios, mpsc::Sender<Response>) {
-    let (response_sender, response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
-    (
-        Ankaios {
-            response_receiver,
-            control_interface: mock_control_interface,
-            timeout: Duration::from_millis(50),
-        },
-        response_sender,
-    )
-}
+        // Prepare channels to intercept the requests that are being sent
+        let (get_sender, mut get_receiver) = mpsc::unbounded_channel();
+        let (update_sender, mut update_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                get_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: UpdateStateRequest| {
+                update_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
+        });
+        let masks = vec!["workloadStates".to_owned()];
+
+        // A fetch is started before the update
+        let ank_clone = ank.clone();
+        let masks_clone = masks.clone();
+        let fetch_handle = tokio::spawn(async move { ank_clone.get_state(masks_clone).await });
+        let fetch_request = get_receiver.recv().await.unwrap();
+
+        // The update completes and invalidates the cache
+        let ank_clone = ank.clone();
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let update_handle = tokio::spawn(async move { ank_clone.apply_workload(workload).await });
+        let update_request = update_receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(update_handle.await.unwrap().is_ok());
+
+        // The older fetch completes with the state before the update
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: fetch_request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(fetch_handle.await.unwrap().is_ok());
+
+        // Its state was not cached, so the next request reaches the control interface
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.get_state(masks).await });
+        let request = get_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_coalesced() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being sent
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let ank_clone = ank.clone();
+        let first_handle = tokio::spawn(async move {
+            ank_clone
+                .get_state(vec!["workloadStates".to_owned(), "agents".to_owned()])
+                .await
+        });
+        let request = request_receiver.recv().await.unwrap();
+
+        // An identical request joins the one in flight
+        let ank_clone = ank.clone();
+        let second_handle = tokio::spawn(async move {
+            ank_clone
+                .get_state(vec!["agents".to_owned(), "workloadStates".to_owned()])
+                .await
+        });
+        while ank
+            .inner
+            .in_flight_states
+            .lock()
+            .unwrap()
+            .values()
+            .all(|sender| sender.receiver_count() == 0)
+        {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(first_handle.await.unwrap().is_ok());
+        assert!(second_handle.await.unwrap().is_ok());
+        assert!(ank.inner.in_flight_states.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_get_state_coalesced_by_prefix_and_timeout() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the requests that are being sent
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, _response_sender) = generate_test_ankaios(ci_mock);
+        ank.timeout = Duration::from_secs(1);
+        let masks = vec!["workloadStates".to_owned()];
+
+        let ank_clone = ank.clone();
+        let masks_clone = masks.clone();
+        let first_handle = tokio::spawn(async move { ank_clone.get_state(masks_clone).await });
+        let first_request = request_receiver.recv().await.unwrap();
+
+        // A request with another prefix is sent on its own
+        let mut prefixed_ank = ank.clone();
+        prefixed_ank.request_id_prefix = Some("my_app".to_owned());
+        let masks_clone = masks.clone();
+        let prefixed_handle =
+            tokio::spawn(async move { prefixed_ank.get_state(masks_clone).await });
+        let prefixed_request = request_receiver.recv().await.unwrap();
+        assert_ne!(prefixed_request.get_id(), first_request.get_id());
+        assert!(prefixed_request.get_id().starts_with("my_app-"));
+
+        // A joining request waits at most its own timeout
+        let mut joining_ank = ank.clone();
+        joining_ank.timeout = Duration::from_millis(10);
+        assert!(matches!(
+            joining_ank.get_state(masks).await,
+            Err(AnkaiosError::TimeoutError {
+                request_id: None,
+                ..
+            })
+        ));
 
-#[cfg(test)]
-mod tests {
-    use std::{collections::HashMap, sync::LazyLock};
-    use tokio::{
-        sync::{Mutex, mpsc},
-        time::Duration,
-    };
+        first_handle.abort();
+        prefixed_handle.abort();
+    }
 
-    use super::{
-        AGENTS_PREFIX, AgentAttributes, Ankaios, AnkaiosError, CONFIGS_PREFIX, CompleteState,
-        ControlInterface, DEFAULT_TIMEOUT, EventsCampaignResponse, Response,
-        WORKLOAD_STATES_PREFIX, WorkloadInstanceName, WorkloadStateEnum, generate_test_ankaios,
-    };
-    use crate::components::{
-        complete_state::generate_complete_state_proto,
-        manifest::generate_test_manifest,
-        request::{
-            AnkaiosLogsRequest, EventsCancelRequest, EventsRequest, GetStateRequest,
-            LogsCancelRequest, Request, UpdateStateRequest,
-        },
-        response::generate_test_response_update_state_success,
-        workload_mod::{WORKLOADS_PREFIX, test_helpers::generate_test_workload},
-    };
-    use crate::{EventEntry, ankaios_api::ank_base::RequestContent};
-    use crate::{LogCampaignResponse, LogEntry, LogResponse, LogsRequest as InputLogsRequest};
+    #[tokio::test]
+    async fn itest_keepalive() {
+        let _guard = MOCKALL_SYNC.lock().await;
 
-    // Used for synchronizing multiple tests that use the same mock.
-    pub static MOCKALL_SYNC: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+        let (unresponsive_sender, mut unresponsive_receiver) = mpsc::unbounded_channel();
 
-    const TEST_LOG_MESSAGE: &str = "some log message 1";
-    const REQUEST_ID: &str = "request_id";
-    const TEST_MASK: &str = "test.mask";
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_mark_agent_unresponsive()
+            .times(1..)
+            .returning(move || {
+                let _ = unresponsive_sender.send(());
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_keepalive(Duration::from_millis(20), 2);
+
+        // The agent answers the first checks
+        for _ in 0..3 {
+            let request = request_receiver.recv().await.unwrap();
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::default()),
+                    id: request.get_id(),
+                })
+                .await
+                .unwrap();
+        }
+        assert!(unresponsive_receiver.try_recv().is_err());
+
+        // The agent stops answering
+        tokio::time::timeout(Duration::from_secs(1), unresponsive_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        ank.disable_keepalive();
+    }
 
     #[tokio::test]
-    async fn itest_create_ankaios() {
+    async fn itest_keepalive_with_state_cache() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        let ci_new_context = ControlInterface::new_context();
-        let mut ci_mock = ControlInterface::default();
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+        let (unresponsive_sender, mut unresponsive_receiver) = mpsc::unbounded_channel();
 
+        let mut ci_mock = ControlInterface::default();
         ci_mock
-            .expect_connect()
-            .times(1)
-            .with(mockall::predicate::eq(Duration::from_millis(50)))
-            .returning(|_| Ok(()));
-
+            .expect_write_request()
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_mark_agent_unresponsive()
+            .times(1..)
+            .returning(move || {
+                let _ = unresponsive_sender.send(());
+            });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        ci_new_context.expect().return_once(move |_| ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
+        });
 
-        // Create Ankaios handle
-        let ankaios_handle = tokio::spawn(Ankaios::new_with_timeout(Duration::from_millis(50)));
+        // The state requested by the keepalive is cached by a user call
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .get_state(vec![KEEPALIVE_FIELD_MASK.to_owned()])
+                .await
+        });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
 
-        // Create Ankaios fully and check the connection
-        let ankaios = ankaios_handle.await.unwrap();
-        assert!(ankaios.is_ok());
+        // The checks still reach the agent, which does not answer them
+        ank.enable_keepalive(Duration::from_millis(20), 2);
+        tokio::time::timeout(Duration::from_secs(1), request_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(1), unresponsive_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        ank.disable_keepalive();
     }
 
     #[tokio::test]
-    async fn itest_timeout_while_connecting() {
+    async fn itest_get_modify_apply_workload() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        let ci_new_context = ControlInterface::new_context();
-        let mut ci_mock = ControlInterface::default();
+        // Prepare channels to intercept the requests that are being sent
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
 
+        let mut ci_mock = ControlInterface::default();
         ci_mock
-            .expect_connect()
-            .with(mockall::predicate::eq(Duration::from_secs(DEFAULT_TIMEOUT)))
+            .expect_write_request()
             .times(1)
-            .returning(|_| Err(AnkaiosError::ControlInterfaceError(String::default())));
+            .return_once(|request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask
+                            == vec!["desiredState.workloads.workload_Test.agent".to_owned()]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        ci_new_context.expect().return_once(move |_| ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Create Ankaios handle
-        let ankaios_handle = tokio::spawn(Ankaios::new());
+        let method_handle = tokio::spawn(async move {
+            let mut workload = ank
+                .get_workload("workload_Test".to_owned())
+                .await?
+                .remove(0);
+            workload.update_agent_name("agent_B");
+            ank.apply_workload(workload).await
+        });
 
-        // Create Ankaios fully and check the connection
-        let result = ankaios_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(
-            result,
-            Err(AnkaiosError::ControlInterfaceError(_))
-        ));
+        // Answer the get request with the current workload
+        let request = get_request_receiver.await.unwrap();
+        let workload = generate_test_workload("agent_A", "workload_Test", "podman");
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_workloads(vec![workload]),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // Only the modified field is updated
+        let request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
     }
 
     #[tokio::test]
-    async fn itest_get_state_ok() {
+    async fn itest_apply_workload_err() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
+        // Prepare workload
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let masks = workload.masks.clone();
+
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .return_once(move |request: GetStateRequest| {
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for getting the state
-        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+        // Prepare handle for applying the workload
+        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
         // Fabricate a response
-        let complete_state = CompleteState::default();
         let response = Response {
-            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
+            content: super::ResponseType::Error("test".to_owned()),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the state
-        let state = method_handle.await.unwrap().unwrap();
-
-        assert_eq!(state.get_api_version(), complete_state.get_api_version());
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
     }
 
     #[tokio::test]
-    async fn itest_get_state_incorrect_id_and_timeout() {
+    async fn itest_apply_workload_mismatch_response_type() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
+        // Prepare workload
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let masks = workload.masks.clone();
+
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .return_once(move |request: GetStateRequest| {
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for getting the state
-        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+        // Prepare handle for applying the workload
+        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
 
         // Get the request from the ControlInterface
-        let _request = request_receiver.await.unwrap();
+        let request = request_receiver.await.unwrap();
 
         // Fabricate a response
         let response = Response {
             content: super::ResponseType::CompleteState(Box::default()),
-            id: "incorrect_id".to_owned(),
+            id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the state
+        // Get the result
         let result = method_handle.await.unwrap();
         assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::TimeoutError(_))));
+        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
     }
 
     #[tokio::test]
-    async fn itest_get_state_err() {
+    async fn itest_apply_workload_empty_masks_uses_main_mask() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
+        // Prepare workload with no masks (e.g. created via from_proto)
+        let mut workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        workload.masks.clear();
+        let main_mask = workload.main_mask.clone();
+
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .return_once(move |request: GetStateRequest| {
+            .withf(
+                move |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == vec![main_mask.clone()]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for getting the state
-        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
 
-        // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let response = Response {
-            content: super::ResponseType::Error("test".to_owned()),
-            id: request.get_id(),
-        };
-
-        // Send the response
+        let response = generate_test_response_update_state_success(request.get_id());
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.len() == 1);
+        assert!(ret.deleted_workloads.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_get_state_mismatch_response_type() {
+    async fn itest_get_workload() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
@@ -1543,329 +6097,423 @@ mod tests {
         ci_mock
             .expect_write_request()
             .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![format!("{WORKLOADS_PREFIX}.workload_Test")]
+                    }
+                    _ => false,
+                },
+            )
             .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for getting the state
-        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
+        // Prepare handle for getting the workload
+        let method_handle =
+            tokio::spawn(async move { ank.get_workload("workload_Test".to_owned()).await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
         // Fabricate a response
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let complete_state = CompleteState::new_from_workloads(vec![workload.clone()]);
         let response = Response {
-            content: super::ResponseType::UpdateStateSuccess(Box::default()),
+            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+        // Get the workload
+        let ret_workloads = method_handle.await.unwrap().unwrap();
+
+        assert_eq!(ret_workloads.len(), 1);
+        assert_eq!(workload.workload, ret_workloads[0].workload);
     }
 
     #[tokio::test]
-    async fn itest_apply_manifest_ok() {
+    async fn itest_request_id_prefix() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
-                    }
-                    _ => false,
-                },
-            )
-            .return_once(|request: UpdateStateRequest| {
+            .withf(|request: &GetStateRequest| {
+                request.get_id().starts_with("my_app-")
+                    && request.to_proto().request_id == request.get_id()
+            })
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
         let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.request_id_prefix = Some("my_app".to_owned());
+        ank.correlation_id = Some("trace_1".to_owned());
 
-        // Prepare handle for applying the manifest
-        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
-
-        // Get the request from the ControlInterface
-        let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let response = generate_test_response_update_state_success(request.get_id());
-
-        // Send the response
-        response_sender.send(response).await.unwrap();
+        // Prepare handle for getting the state
+        let method_handle = tokio::spawn(async move { ank.get_state(Vec::default()).await });
 
-        // Get the result
-        let ret = method_handle.await.unwrap().unwrap();
-        assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
+        // Answer with the prefixed id
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(method_handle.await.unwrap().unwrap(), complete_state);
     }
 
     #[tokio::test]
-    async fn itest_apply_manifest_err() {
+    async fn itest_get_rendered_workload_files() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask
+                            == vec![
+                                format!("{WORKLOADS_PREFIX}.workload_A"),
+                                CONFIGS_PREFIX.to_owned(),
+                            ]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for applying the manifest
-        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
+        // Prepare handle for getting the files
+        let method_handle = tokio::spawn(async move {
+            ank.get_rendered_workload_files(WorkloadInstanceName::new(
+                "agent_Test".to_owned(),
+                "workload_A".to_owned(),
+                "1234".to_owned(),
+            ))
+            .await
+        });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
-        // Fabricate a response
+        // Fabricate a response with a templated file
+        let mut workload = generate_test_workload("agent_Test", "workload_A", "podman");
+        workload.update_configs(HashMap::from([("alias".to_owned(), "config_A".to_owned())]));
+        workload.update_files(vec![File::from_data(
+            "/etc/config.txt",
+            "port={{alias.port}}",
+        )]);
+        let mut complete_state_proto = CompleteState::new_from_workloads(vec![workload]).to_proto();
+        if let Some(desired_state) = complete_state_proto.desired_state.as_mut() {
+            desired_state.configs = CompleteState::new_from_configs(HashMap::from([(
+                "config_A".to_owned(),
+                serde_yaml::from_str("{port: \"8080\"}").unwrap(),
+            )]))
+            .to_proto()
+            .desired_state
+            .unwrap()
+            .configs;
+        }
         let response = Response {
-            content: super::ResponseType::Error("test".to_owned()),
+            content: super::ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                complete_state_proto,
+            ))),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        // Get the rendered files
+        let ret_files = method_handle.await.unwrap().unwrap();
+        assert_eq!(
+            ret_files,
+            vec![File::from_data("/etc/config.txt", "port=8080")]
+        );
     }
 
     #[tokio::test]
-    async fn itest_apply_manifest_mismatch_response_type() {
+    async fn itest_get_desired_state() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![super::DESIRED_STATE_PREFIX.to_owned()]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for applying the manifest
-        let method_handle = tokio::spawn(async move { ank.apply_manifest(manifest).await });
+        let method_handle = tokio::spawn(async move { ank.get_desired_state().await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
         // Fabricate a response
+        let workload = generate_test_workload("agent_Test", "workload_A", "podman");
         let response = Response {
-            content: super::ResponseType::CompleteState(Box::default()),
+            content: super::ResponseType::CompleteState(Box::new(
+                CompleteState::new_from_workloads(vec![workload.clone()]),
+            )),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+        // Get the desired state
+        let desired_state = method_handle.await.unwrap().unwrap();
+        let workloads = CompleteState::new_from_manifest(desired_state).get_workloads();
+        assert_eq!(workloads.len(), 1);
+        assert_eq!(workloads[0].workload, workload.workload);
     }
 
     #[tokio::test]
-    async fn itest_delete_manifest_ok() {
+    async fn itest_get_states_parallel() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the requests that are being sent
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move {
+            ank.get_states_parallel(vec![
+                vec!["desiredState.workloads.workload_A".to_owned()],
+                vec!["desiredState.workloads.workload_B".to_owned()],
+            ])
+            .await
+        });
+
+        // Answer both requests, the second one first
+        let first_request = request_receiver.recv().await.unwrap();
+        let second_request = request_receiver.recv().await.unwrap();
+        for request in [second_request, first_request] {
+            let workload_name = match &request.request.request_content {
+                Some(RequestContent::CompleteStateRequest(content)) => content.field_mask[0]
+                    .trim_start_matches("desiredState.workloads.")
+                    .to_owned(),
+                _ => panic!("Unexpected request content"),
+            };
+            let workload = generate_test_workload("agent_Test", workload_name.as_str(), "podman");
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::new(
+                        CompleteState::new_from_workloads(vec![workload]),
+                    )),
+                    id: request.get_id(),
+                })
+                .await
+                .unwrap();
+        }
+
+        // The states are returned in the order of the masks
+        let states = method_handle.await.unwrap().unwrap();
+        assert_eq!(states.len(), 2);
+        assert_eq!(states[0].get_workloads()[0].name, "workload_A");
+        assert_eq!(states[1].get_workloads()[0].name, "workload_B");
+    }
+
+    #[tokio::test]
+    async fn itest_get_desired_workloads() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![WORKLOADS_PREFIX.to_owned()]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for deleting the manifest
-        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+        let method_handle = tokio::spawn(async move { ank.get_desired_workloads().await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
         // Fabricate a response
-        let response = generate_test_response_update_state_success(request.get_id());
+        let workload = generate_test_workload("agent_Test", "workload_A", "podman");
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(
+                CompleteState::new_from_workloads(vec![workload.clone()]),
+            )),
+            id: request.get_id(),
+        };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let ret = method_handle.await.unwrap().unwrap();
-        assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
+        // Get the workloads
+        let workloads = method_handle.await.unwrap().unwrap();
+        assert_eq!(workloads.len(), 1);
+        assert_eq!(workloads[0].workload, workload.workload);
     }
 
     #[tokio::test]
-    async fn itest_delete_manifest_err() {
+    async fn itest_get_workloads_by_tag() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![WORKLOADS_PREFIX.to_owned()]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for deleting the manifest
-        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+        // Prepare handle for getting the workloads
+        let method_handle = tokio::spawn(async move {
+            ank.get_workloads_by_tag("key_test".to_owned(), "val_test".to_owned())
+                .await
+        });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
-        // Fabricate a response
+        // Fabricate a response with one untagged workload
+        let workload_b = generate_test_workload("agent_Test", "workload_B", "podman");
+        let workload_a = generate_test_workload("agent_Test", "workload_A", "podman");
+        let mut untagged_workload = generate_test_workload("agent_Test", "workload_C", "podman");
+        untagged_workload.update_tags(&HashMap::new());
+        let complete_state = CompleteState::new_from_workloads(vec![
+            workload_b.clone(),
+            untagged_workload,
+            workload_a.clone(),
+        ]);
         let response = Response {
-            content: super::ResponseType::Error("test".to_owned()),
+            content: super::ResponseType::CompleteState(Box::new(complete_state)),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        // Get the workloads
+        let ret_workloads = method_handle.await.unwrap().unwrap();
+
+        assert_eq!(ret_workloads.len(), 2);
+        assert_eq!(ret_workloads[0].0, "workload_A");
+        assert_eq!(ret_workloads[0].1.workload, workload_a.workload);
+        assert_eq!(ret_workloads[1].0, "workload_B");
+        assert_eq!(ret_workloads[1].1.workload, workload_b.workload);
     }
 
     #[tokio::test]
-    async fn itest_delete_manifest_mismatch_response_type() {
+    async fn itest_apply_workload_if_changed_unchanged() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare manifest
-        let manifest = generate_test_manifest();
-        let masks = manifest.calculate_masks();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
-                    }
-                    _ => false,
-                },
-            )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
+        ci_mock.expect_write_request::<UpdateStateRequest>().never();
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for deleting the manifest
-        let method_handle = tokio::spawn(async move { ank.delete_manifest(manifest).await });
+        // Prepare handle for applying the workload
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let workload_clone = workload.clone();
+        let method_handle =
+            tokio::spawn(async move { ank.apply_workload_if_changed(workload_clone).await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
-        // Fabricate a response
+        // Fabricate a response containing the same workload
+        let complete_state = CompleteState::new_from_workloads(vec![workload]);
         let response = Response {
-            content: super::ResponseType::CompleteState(Box::default()),
+            content: super::ResponseType::CompleteState(Box::new(complete_state)),
             id: request.get_id(),
         };
 
@@ -1873,131 +6521,251 @@ mod tests {
         response_sender.send(response).await.unwrap();
 
         // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+        let ret = method_handle.await.unwrap().unwrap();
+        assert_eq!(ret, ApplyWorkloadResult::Unchanged);
     }
 
     #[tokio::test]
-    async fn itest_apply_workload_ok() {
+    async fn itest_apply_workload_if_changed_with_state_cache() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        // Prepare channel to intercept the request that is being
-        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
 
-        // Prepare workload
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_write_request::<UpdateStateRequest>().never();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
+        });
+
+        // Every comparison reads the workload from the server
         let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
-        let masks = workload.masks.clone();
+        for _ in 0..2 {
+            let ank_clone = ank.clone();
+            let workload_clone = workload.clone();
+            let method_handle =
+                tokio::spawn(
+                    async move { ank_clone.apply_workload_if_changed(workload_clone).await },
+                );
+            let request = request_receiver.recv().await.unwrap();
+            let complete_state = CompleteState::new_from_workloads(vec![workload.clone()]);
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                    id: request.get_id(),
+                })
+                .await
+                .unwrap();
+            assert_eq!(
+                method_handle.await.unwrap().unwrap(),
+                ApplyWorkloadResult::Unchanged
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn itest_apply_workload_if_changed_applied() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channels to intercept the requests that are being sent
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for applying the workload
+        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
+        let mut current_workload = workload.clone();
+        current_workload.update_agent_name("agent_Other");
+        let method_handle =
+            tokio::spawn(async move { ank.apply_workload_if_changed(workload).await });
+
+        // Answer the get state request with a different workload
+        let get_request = get_request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_workloads(vec![current_workload]);
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: get_request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // Answer the update state request
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
+
+        // Get the result
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(matches!(ret, ApplyWorkloadResult::Applied(_)));
+    }
+
+    #[tokio::test]
+    async fn itest_delete_workloads_by_tag() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channels to intercept the requests that are being sent
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
 
         let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
+                |request: &UpdateStateRequest| match &request.request.request_content {
                     Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                        content.update_mask
+                            == vec![
+                                format!("{WORKLOADS_PREFIX}.workload_A"),
+                                format!("{WORKLOADS_PREFIX}.workload_B"),
+                            ]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
-                request_sender.send(request).unwrap();
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
-
-        // Prepare handle for applying the workload
-        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
-
-        // Get the request from the ControlInterface
-        let request = request_receiver.await.unwrap();
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Fabricate a response
-        let response = generate_test_response_update_state_success(request.get_id());
+        // Prepare handle for deleting the workloads
+        let method_handle = tokio::spawn(async move {
+            ank.delete_workloads_by_tag("key_test".to_owned(), "val_test".to_owned())
+                .await
+        });
 
-        // Send the response
-        response_sender.send(response).await.unwrap();
+        // Answer the get state request
+        let get_request = get_request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_workloads(vec![
+            generate_test_workload("agent_Test", "workload_B", "podman"),
+            generate_test_workload("agent_Test", "workload_A", "podman"),
+        ]);
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: get_request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // Answer the update state request
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
 
         // Get the result
         let ret = method_handle.await.unwrap().unwrap();
         assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_apply_workload_err() {
+    async fn itest_delete_workloads_by_tag_no_match() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare workload
-        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
-        let masks = workload.masks.clone();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
-                    }
-                    _ => false,
-                },
-            )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
-
-        // Prepare handle for applying the workload
-        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
-
-        // Get the request from the ControlInterface
-        let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let response = Response {
-            content: super::ResponseType::Error("test".to_owned()),
-            id: request.get_id(),
-        };
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Send the response
-        response_sender.send(response).await.unwrap();
+        // Prepare handle for deleting the workloads
+        let method_handle = tokio::spawn(async move {
+            ank.delete_workloads_by_tag("key_test".to_owned(), "other_value".to_owned())
+                .await
+        });
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        // Answer the get state request
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_workloads(vec![generate_test_workload(
+            "agent_Test",
+            "workload_A",
+            "podman",
+        )]);
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // No update request is sent
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.is_empty());
+        assert!(ret.deleted_workloads.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_apply_workload_mismatch_response_type() {
+    async fn itest_delete_workload_ok() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare workload
-        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
-        let masks = workload.masks.clone();
-
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
+                |request: &UpdateStateRequest| match &request.request.request_content {
                     Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == masks
+                        content.update_mask == vec![format!("{WORKLOADS_PREFIX}.workload_Test")]
                     }
                     _ => false,
                 },
@@ -2008,125 +6776,181 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for applying the workload
-        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
+        // Prepare handle for deleting the workload
+        let method_handle =
+            tokio::spawn(async move { ank.delete_workload("workload_Test".to_owned()).await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
         // Fabricate a response
-        let response = Response {
-            content: super::ResponseType::CompleteState(Box::default()),
-            id: request.get_id(),
-        };
+        let response = generate_test_response_update_state_success(request.get_id());
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
         // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+        let ret = method_handle.await.unwrap().unwrap();
+        assert!(ret.added_workloads.len() == 1);
+        assert!(ret.deleted_workloads.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_apply_workload_empty_masks_uses_main_mask() {
+    async fn itest_delete_workload_invalid_name() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+        let mut ci_mock = ControlInterface::default();
+        ci_mock.expect_write_request::<UpdateStateRequest>().never();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        // Prepare workload with no masks (e.g. created via from_proto)
-        let mut workload = generate_test_workload("agent_Test", "workload_Test", "podman");
-        workload.masks.clear();
-        let main_mask = workload.main_mask.clone();
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+
+        for invalid in ["", "nginx.files", "*"] {
+            assert!(matches!(
+                ank.delete_workload(invalid.to_owned()).await,
+                Err(AnkaiosError::MaskError(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn itest_delete_workload_instance() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channels to intercept the requests that are being sent
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
 
         let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
         ci_mock
             .expect_write_request()
             .times(1)
             .withf(
-                move |request: &UpdateStateRequest| match &request.request.request_content {
+                |request: &UpdateStateRequest| match &request.request.request_content {
                     Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == vec![main_mask.clone()]
+                        content.update_mask == vec![format!("{WORKLOADS_PREFIX}.dyn_nginx")]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
-                request_sender.send(request).unwrap();
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
+        // Prepare handle for deleting the workload instance
+        let instance_name = WorkloadInstanceName::new(
+            "agent_B".to_owned(),
+            "dyn_nginx".to_owned(),
+            "9012".to_owned(),
+        );
+        let method_handle =
+            tokio::spawn(async move { ank.delete_workload_instance(&instance_name).await });
 
-        let request = request_receiver.await.unwrap();
-        let response = generate_test_response_update_state_success(request.get_id());
-        response_sender.send(response).await.unwrap();
+        // Answer the get state request
+        let get_request = get_request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: get_request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // Answer the update state request
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
 
+        // Get the result
         let ret = method_handle.await.unwrap().unwrap();
         assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_get_workload() {
+    async fn itest_delete_workload_instance_with_state_cache() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        // Prepare channel to intercept the request that is being
-        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
 
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
-            .times(1)
-            .withf(
-                move |request: &GetStateRequest| match &request.request.request_content {
-                    Some(RequestContent::CompleteStateRequest(content)) => {
-                        content.field_mask == vec![format!("{WORKLOADS_PREFIX}.workload_Test")]
-                    }
-                    _ => false,
-                },
-            )
-            .return_once(move |request: GetStateRequest| {
+            .times(2)
+            .returning(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
+        ci_mock.expect_write_request::<UpdateStateRequest>().never();
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
-
-        // Prepare handle for getting the workload
-        let method_handle =
-            tokio::spawn(async move { ank.get_workload("workload_Test".to_owned()).await });
-
-        // Get the request from the ControlInterface
-        let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let workload = generate_test_workload("agent_Test", "workload_Test", "podman");
-        let complete_state = CompleteState::new_from_workloads(vec![workload.clone()]);
-        let response = Response {
-            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
-            id: request.get_id(),
-        };
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
+        });
 
-        // Send the response
-        response_sender.send(response).await.unwrap();
+        // The workload states containing the instance are cached
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .get_workload_states_for_name("dyn_nginx".to_owned())
+                .await
+        });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_proto(generate_complete_state_proto()),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
 
-        // Get the workload
-        let ret_workloads = method_handle.await.unwrap().unwrap();
+        // The instance was deleted in the meantime, which only the server knows
+        let instance_name = WorkloadInstanceName::new(
+            "agent_B".to_owned(),
+            "dyn_nginx".to_owned(),
+            "9012".to_owned(),
+        );
+        let method_handle =
+            tokio::spawn(async move { ank.delete_workload_instance(&instance_name).await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
 
-        assert_eq!(ret_workloads.len(), 1);
-        assert_eq!(workload.workload, ret_workloads[0].workload);
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::InstanceMismatchError(_))
+        ));
     }
 
     #[tokio::test]
-    async fn itest_delete_workload_ok() {
+    async fn itest_delete_workload_instance_mismatch() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
@@ -2136,39 +6960,37 @@ mod tests {
         ci_mock
             .expect_write_request()
             .times(1)
-            .withf(
-                |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == vec![format!("{WORKLOADS_PREFIX}.workload_Test")]
-                    }
-                    _ => false,
-                },
-            )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for deleting the workload
+        // The workload was re-created with another id
+        let instance_name = WorkloadInstanceName::new(
+            "agent_B".to_owned(),
+            "dyn_nginx".to_owned(),
+            "1111".to_owned(),
+        );
         let method_handle =
-            tokio::spawn(async move { ank.delete_workload("workload_Test".to_owned()).await });
+            tokio::spawn(async move { ank.delete_workload_instance(&instance_name).await });
 
-        // Get the request from the ControlInterface
+        // Answer the get state request
         let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let response = generate_test_response_update_state_success(request.get_id());
-
-        // Send the response
-        response_sender.send(response).await.unwrap();
-
-        // Get the result
-        let ret = method_handle.await.unwrap().unwrap();
-        assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // No update request is sent
+        let ret = method_handle.await.unwrap();
+        assert!(matches!(ret, Err(AnkaiosError::InstanceMismatchError(_))));
     }
 
     #[tokio::test]
@@ -2196,7 +7018,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting the workload
         let method_handle =
@@ -2245,7 +7067,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting the workload
         let method_handle =
@@ -2294,10 +7116,13 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare configs
-        let configs = HashMap::new();
+        let configs: HashMap<String, serde_yaml::Value> = HashMap::from([
+            ("config_B".to_owned(), serde_yaml::Value::default()),
+            ("config_A".to_owned(), serde_yaml::Value::default()),
+        ]);
 
         // Prepare handle for updating the configs
         let method_handle = tokio::spawn(async move { ank.update_configs(configs).await });
@@ -2313,8 +7138,11 @@ mod tests {
 
         // Get the result
         let ret = method_handle.await.unwrap().unwrap();
-        assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
+        assert!(ret.is_ok());
+        assert_eq!(
+            ret.updated_configs,
+            vec!["config_A".to_owned(), "config_B".to_owned()]
+        );
     }
 
     #[tokio::test]
@@ -2342,10 +7170,10 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare configs
-        let configs = HashMap::new();
+        let configs: HashMap<String, serde_yaml::Value> = HashMap::new();
 
         // Prepare handle for updating the configs
         let method_handle = tokio::spawn(async move { ank.update_configs(configs).await });
@@ -2393,10 +7221,10 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare configs
-        let configs = HashMap::new();
+        let configs: HashMap<String, serde_yaml::Value> = HashMap::new();
 
         // Prepare handle for updating the configs
         let method_handle = tokio::spawn(async move { ank.update_configs(configs).await });
@@ -2444,7 +7272,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare config
         let config = serde_yaml::Value::default();
@@ -2464,8 +7292,8 @@ mod tests {
 
         // Get the result
         let ret = method_handle.await.unwrap().unwrap();
-        assert!(ret.added_workloads.len() == 1);
-        assert!(ret.deleted_workloads.is_empty());
+        assert!(ret.is_ok());
+        assert_eq!(ret.updated_configs, vec!["Test".to_owned()]);
     }
 
     #[tokio::test]
@@ -2493,7 +7321,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare config
         let config = serde_yaml::Value::default();
@@ -2545,7 +7373,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare config
         let config = serde_yaml::Value::default();
@@ -2597,7 +7425,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the configs
         let method_handle = tokio::spawn(async move { ank.get_configs().await });
@@ -2647,7 +7475,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the configs
         let method_handle = tokio::spawn(async move { ank.get_config("Test".to_owned()).await });
@@ -2672,7 +7500,320 @@ mod tests {
         // Get the config
         let ret_config = method_handle.await.unwrap().unwrap();
 
-        assert_eq!(ret_config, configs);
+        assert_eq!(ret_config, configs);
+    }
+
+    #[tokio::test]
+    async fn itest_ensure_config_unchanged() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for ensuring the config
+        let method_handle = tokio::spawn(async move {
+            ank.ensure_config(
+                "Test".to_owned(),
+                serde_yaml::Value::String("test".to_owned()),
+            )
+            .await
+        });
+
+        // Answer the get state request
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_configs(HashMap::from([(
+            "Test".to_owned(),
+            serde_yaml::Value::String("test".to_owned()),
+        )]));
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // No update request is sent
+        let ret = method_handle.await.unwrap().unwrap();
+        assert_eq!(ret, Ensured::Unchanged);
+    }
+
+    #[tokio::test]
+    async fn itest_ensure_config_with_state_cache() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.enable_state_cache(CachePolicy {
+            ttl: Duration::from_secs(60),
+            max_entries: 4,
+        });
+
+        // Every comparison reads the config from the server
+        for _ in 0..2 {
+            let ank_clone = ank.clone();
+            let method_handle = tokio::spawn(async move {
+                ank_clone
+                    .ensure_config(
+                        "Test".to_owned(),
+                        serde_yaml::Value::String("test".to_owned()),
+                    )
+                    .await
+            });
+            let request = request_receiver.recv().await.unwrap();
+            let complete_state = CompleteState::new_from_configs(HashMap::from([(
+                "Test".to_owned(),
+                serde_yaml::Value::String("test".to_owned()),
+            )]));
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                    id: request.get_id(),
+                })
+                .await
+                .unwrap();
+            assert_eq!(method_handle.await.unwrap().unwrap(), Ensured::Unchanged);
+        }
+    }
+
+    #[tokio::test]
+    async fn itest_ensure_config_updated_and_created() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        for (current_configs, expected) in [
+            (
+                HashMap::from([(
+                    "Test".to_owned(),
+                    serde_yaml::Value::String("old".to_owned()),
+                )]),
+                Ensured::Updated,
+            ),
+            (HashMap::new(), Ensured::Created),
+        ] {
+            // Prepare channels to intercept the requests that are being sent
+            let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+            let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+
+            let mut ci_mock = ControlInterface::default();
+            ci_mock
+                .expect_write_request()
+                .times(1)
+                .return_once(move |request: GetStateRequest| {
+                    get_request_sender.send(request).unwrap();
+                    Ok(())
+                });
+            ci_mock
+                .expect_write_request()
+                .times(1)
+                .withf(
+                    |request: &UpdateStateRequest| match &request.request.request_content {
+                        Some(RequestContent::UpdateStateRequest(content)) => {
+                            content.update_mask == vec![format!("{CONFIGS_PREFIX}.Test")]
+                        }
+                        _ => false,
+                    },
+                )
+                .return_once(move |request: UpdateStateRequest| {
+                    update_request_sender.send(request).unwrap();
+                    Ok(())
+                });
+            ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+            let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+            // Prepare handle for ensuring the config
+            let method_handle = tokio::spawn(async move {
+                ank.ensure_config(
+                    "Test".to_owned(),
+                    serde_yaml::Value::String("test".to_owned()),
+                )
+                .await
+            });
+
+            // Answer the get state request
+            let get_request = get_request_receiver.await.unwrap();
+            let complete_state = CompleteState::new_from_configs(current_configs);
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                    id: get_request.get_id(),
+                })
+                .await
+                .unwrap();
+
+            // Answer the update state request
+            let update_request = update_request_receiver.await.unwrap();
+            response_sender
+                .send(generate_test_response_update_state_success(
+                    update_request.get_id(),
+                ))
+                .await
+                .unwrap();
+
+            let ret = method_handle.await.unwrap().unwrap();
+            assert_eq!(ret, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn itest_apply_bundle() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        for workloads_accepted in [true, false] {
+            // Prepare channels to intercept the requests that are being sent
+            let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+            let (config_request_sender, mut config_request_receiver) = mpsc::unbounded_channel();
+            let (workload_request_sender, workload_request_receiver) =
+                tokio::sync::oneshot::channel();
+
+            let mut ci_mock = ControlInterface::default();
+            ci_mock
+                .expect_write_request()
+                .times(1)
+                .return_once(move |request: GetStateRequest| {
+                    get_request_sender.send(request).unwrap();
+                    Ok(())
+                });
+            ci_mock
+                .expect_write_request()
+                .times(if workloads_accepted { 1 } else { 2 })
+                .withf(
+                    |request: &UpdateStateRequest| match &request.request.request_content {
+                        Some(RequestContent::UpdateStateRequest(content)) => {
+                            content.update_mask == vec![format!("{CONFIGS_PREFIX}.Test")]
+                        }
+                        _ => false,
+                    },
+                )
+                .returning(move |request: UpdateStateRequest| {
+                    config_request_sender.send(request).unwrap();
+                    Ok(())
+                });
+            ci_mock
+                .expect_write_request()
+                .times(1)
+                .withf(
+                    |request: &UpdateStateRequest| match &request.request.request_content {
+                        Some(RequestContent::UpdateStateRequest(content)) => {
+                            content.update_mask == vec![format!("{WORKLOADS_PREFIX}.nginx_test")]
+                        }
+                        _ => false,
+                    },
+                )
+                .return_once(move |request: UpdateStateRequest| {
+                    workload_request_sender.send(request).unwrap();
+                    Ok(())
+                });
+            ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+            let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+            // Prepare handle for applying the bundle
+            let method_handle = tokio::spawn(async move {
+                ank.apply_bundle(
+                    HashMap::from([(
+                        "Test".to_owned(),
+                        serde_yaml::Value::String("new".to_owned()),
+                    )]),
+                    vec![generate_test_workload("agent_A", "nginx_test", "podman")],
+                )
+                .await
+            });
+
+            // Answer the get state request with the previous config
+            let get_request = get_request_receiver.await.unwrap();
+            let complete_state = CompleteState::new_from_configs(HashMap::from([(
+                "Test".to_owned(),
+                serde_yaml::Value::String("old".to_owned()),
+            )]));
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                    id: get_request.get_id(),
+                })
+                .await
+                .unwrap();
+
+            // Answer the configs request
+            let config_request = config_request_receiver.recv().await.unwrap();
+            response_sender
+                .send(generate_test_response_update_state_success(
+                    config_request.get_id(),
+                ))
+                .await
+                .unwrap();
+
+            // Answer the workloads request
+            let workload_request = workload_request_receiver.await.unwrap();
+            if workloads_accepted {
+                response_sender
+                    .send(generate_test_response_update_state_success(
+                        workload_request.get_id(),
+                    ))
+                    .await
+                    .unwrap();
+
+                assert!(method_handle.await.unwrap().is_ok());
+                continue;
+            }
+            response_sender
+                .send(Response {
+                    content: super::ResponseType::Error("test".to_owned()),
+                    id: workload_request.get_id(),
+                })
+                .await
+                .unwrap();
+
+            // Answer the rollback request, restoring the previous config
+            let rollback_request = config_request_receiver.recv().await.unwrap();
+            match &rollback_request.request.request_content {
+                Some(RequestContent::UpdateStateRequest(content)) => {
+                    let configs = CompleteState::new_from_proto(
+                        content.new_state.clone().unwrap_or_default(),
+                    )
+                    .get_configs();
+                    assert_eq!(
+                        configs.get("Test"),
+                        Some(&serde_yaml::Value::String("old".to_owned()))
+                    );
+                }
+                _ => panic!("Expected an update state request"),
+            }
+            response_sender
+                .send(generate_test_response_update_state_success(
+                    rollback_request.get_id(),
+                ))
+                .await
+                .unwrap();
+
+            assert!(matches!(
+                method_handle.await.unwrap(),
+                Err(AnkaiosError::AnkaiosResponseError(_))
+            ));
+        }
     }
 
     #[tokio::test]
@@ -2700,7 +7841,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting the workload
         let method_handle = tokio::spawn(async move { ank.delete_all_configs().await });
@@ -2743,7 +7884,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting the workload
         let method_handle = tokio::spawn(async move { ank.delete_all_configs().await });
@@ -2791,7 +7932,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting the workload
         let method_handle = tokio::spawn(async move { ank.delete_all_configs().await });
@@ -2839,7 +7980,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting a config
         let method_handle = tokio::spawn(async move { ank.delete_config("Test".to_owned()).await });
@@ -2857,6 +7998,34 @@ mod tests {
         assert!(method_handle.await.unwrap().is_ok());
     }
 
+    #[tokio::test]
+    async fn itest_config_invalid_name() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock.expect_write_request::<UpdateStateRequest>().never();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+
+        for invalid in ["", "a.b"] {
+            assert!(matches!(
+                ank.delete_config(invalid.to_owned()).await,
+                Err(AnkaiosError::MaskError(_))
+            ));
+            assert!(matches!(
+                ank.add_config(invalid.to_owned(), serde_yaml::Value::Null)
+                    .await,
+                Err(AnkaiosError::MaskError(_))
+            ));
+        }
+        assert!(matches!(
+            ank.set_agent_tags("agent.A".to_owned(), HashMap::new())
+                .await,
+            Err(AnkaiosError::MaskError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn itest_delete_config_err() {
         let _guard = MOCKALL_SYNC.lock().await;
@@ -2882,7 +8051,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting a config
         let method_handle = tokio::spawn(async move { ank.delete_config("Test".to_owned()).await });
@@ -2930,7 +8099,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for deleting a config
         let method_handle = tokio::spawn(async move { ank.delete_config("Test".to_owned()).await });
@@ -2993,7 +8162,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare tags
         let tags = HashMap::from([
@@ -3043,7 +8212,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare tags
         let tags = HashMap::from([
@@ -3067,17 +8236,361 @@ mod tests {
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_set_agent_tags_mismatch_response_type() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                |request: &UpdateStateRequest| match &request.request.request_content {
+                    Some(RequestContent::UpdateStateRequest(content)) => {
+                        content.update_mask == vec![format!("{AGENTS_PREFIX}.agent_A.tags")]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare tags
+        let tags = HashMap::from([
+            ("environment".to_owned(), "production".to_owned()),
+            ("region".to_owned(), "us-west".to_owned()),
+        ]);
+
+        // Prepare handle for setting agent tags
+        let method_handle =
+            tokio::spawn(async move { ank.set_agent_tags("agent_A".to_owned(), tags).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response with wrong type
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::default()),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // Get the result
+        let result = method_handle.await.unwrap();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_apply_workload_validate_agents() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Only the agents are requested, the workload is not applied
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![AGENTS_PREFIX]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.validate_agents = true;
+
+        let workload = generate_test_workload("agent_B", "workload_test", "podman");
+        let method_handle = tokio::spawn(async move { ank.apply_workload(workload).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response with only agent_A connected
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(complete_state)),
+            id: request.get_id(),
+        };
+        response_sender.send(response).await.unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::UnknownAgentsError(agents)) if agents == vec!["agent_B".to_owned()]
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_diagnose_workload() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask
+                            == vec![
+                                format!("{WORKLOADS_PREFIX}.nginx_test"),
+                                AGENTS_PREFIX.to_owned(),
+                                WORKLOAD_STATES_PREFIX.to_owned(),
+                            ]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "nginx_test".to_owned(),
+            "1234".to_owned(),
+        );
+        let method_handle = tokio::spawn(async move { ank.diagnose_workload(instance_name).await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                generate_complete_state_proto(),
+            ))),
+            id: request.get_id(),
+        };
+        response_sender.send(response).await.unwrap();
+
+        // The workload has no state yet and its dependencies are not fulfilled
+        let diagnosis = method_handle.await.unwrap().unwrap();
+        assert!(diagnosis.agent_connected);
+        assert!(diagnosis.execution_state.is_none());
+        assert_eq!(diagnosis.dependencies.len(), 2);
+        assert_eq!(diagnosis.issues.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn itest_my_instance_name() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the requests that are being sent
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(3)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.own_workload_name = Some("nginx".to_owned());
+
+        // The workload has instances on several agents
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.my_instance_name().await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_proto(generate_complete_state_proto()),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::SelfIntrospectionError(_))
+        ));
+
+        ank.own_agent_name = Some("agent_B".to_owned());
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.my_instance_name().await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_proto(generate_complete_state_proto()),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            method_handle.await.unwrap().unwrap(),
+            WorkloadInstanceName::new("agent_B".to_owned(), "nginx".to_owned(), "5678".to_owned())
+        );
+
+        // The desired state of the own workload
+        ank.own_workload_name = Some("nginx_test".to_owned());
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.my_workload().await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_proto(generate_complete_state_proto()),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(method_handle.await.unwrap().unwrap().name, "nginx_test");
+
+        // Without a workload name nothing is requested
+        ank.own_workload_name = None;
+        if std::env::var("ANKAIOS_WORKLOAD_NAME").is_err() {
+            assert!(matches!(
+                ank.my_workload().await,
+                Err(AnkaiosError::SelfIntrospectionError(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn itest_delete_own_workload_protected() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(|request: UpdateStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.protect_own_workload = true;
+        ank.own_workload_name = Some("workload_Test".to_owned());
+
+        // Masks covering the own workload are refused without sending a request
+        assert!(matches!(
+            ank.delete_workload("workload_Test".to_owned()).await,
+            Err(AnkaiosError::SelfDeleteError(name)) if name == "workload_Test"
+        ));
+        assert!(matches!(
+            ank.check_self_delete(&[WORKLOADS_PREFIX.to_owned()]),
+            Err(AnkaiosError::SelfDeleteError(_))
+        ));
+        assert!(
+            ank.check_self_delete(&[format!("{WORKLOADS_PREFIX}.workload_Test2")])
+                .is_ok()
+        );
+
+        // The deletion goes through once explicitly allowed
+        let ank_clone = ank.allow_self_delete();
+        let method_handle =
+            tokio::spawn(
+                async move { ank_clone.delete_workload("workload_Test".to_owned()).await },
+            );
+        let request = request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn itest_get_cluster_summary() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask
+                            == vec![AGENTS_PREFIX.to_owned(), WORKLOAD_STATES_PREFIX.to_owned()]
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move { ank.get_cluster_summary().await });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                generate_complete_state_proto(),
+            ))),
+            id: request.get_id(),
+        };
+        response_sender.send(response).await.unwrap();
+
+        let summary = method_handle.await.unwrap().unwrap();
+        assert_eq!(summary.totals.total(), 3);
+        assert_eq!(summary.agents.len(), 2);
+        assert!(summary.failing_instances.is_empty());
     }
 
     #[tokio::test]
-    async fn itest_set_agent_tags_mismatch_response_type() {
+    async fn itest_get_agents() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        // Prepare channel to intercept the request
+        // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
         let mut ci_mock = ControlInterface::default();
@@ -3085,51 +8598,106 @@ mod tests {
             .expect_write_request()
             .times(1)
             .withf(
-                |request: &UpdateStateRequest| match &request.request.request_content {
-                    Some(RequestContent::UpdateStateRequest(content)) => {
-                        content.update_mask == vec![format!("{AGENTS_PREFIX}.agent_A.tags")]
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == vec![AGENTS_PREFIX]
                     }
                     _ => false,
                 },
             )
-            .return_once(|request: UpdateStateRequest| {
+            .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
-
-        // Prepare tags
-        let tags = HashMap::from([
-            ("environment".to_owned(), "production".to_owned()),
-            ("region".to_owned(), "us-west".to_owned()),
-        ]);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
-        // Prepare handle for setting agent tags
-        let method_handle =
-            tokio::spawn(async move { ank.set_agent_tags("agent_A".to_owned(), tags).await });
+        // Prepare handle for getting the agents
+        let method_handle = tokio::spawn(async move { ank.get_agents().await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
 
-        // Fabricate a response with wrong type
+        // Fabricate a response
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
         let response = Response {
-            content: super::ResponseType::CompleteState(Box::default()),
+            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
             id: request.get_id(),
         };
 
         // Send the response
         response_sender.send(response).await.unwrap();
 
-        // Get the result
-        let result = method_handle.await.unwrap();
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AnkaiosError::ResponseError(_))));
+        // Get the agents
+        let ret_agents = method_handle.await.unwrap().unwrap();
+
+        let expected_agent_attributes = AgentAttributes {
+            tags: HashMap::from([("tag_key".to_owned(), "tag_value".to_owned())]),
+            status: HashMap::from([
+                ("free_memory".to_owned(), "1024".to_owned()),
+                ("cpu_usage".to_owned(), "50".to_owned()),
+            ]),
+        };
+
+        assert_eq!(
+            ret_agents,
+            HashMap::from([("agent_A".to_owned(), expected_agent_attributes)])
+        );
     }
 
     #[tokio::test]
-    async fn itest_get_agents() {
+    async fn itest_select_agent() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let ank_clone = ank.clone();
+        let method_handle =
+            tokio::spawn(async move { ank_clone.select_agent(PlacementStrategy::LeastCpu).await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(
+                    CompleteState::new_from_proto(generate_complete_state_proto()),
+                )),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(method_handle.await.unwrap().unwrap(), "agent_A");
+
+        // No agent is connected
+        let method_handle =
+            tokio::spawn(async move { ank.select_agent(PlacementStrategy::RoundRobin).await });
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::default()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::AnkaiosResponseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_get_agents_detailed() {
         let _guard = MOCKALL_SYNC.lock().await;
 
         // Prepare channel to intercept the request that is being
@@ -3142,7 +8710,8 @@ mod tests {
             .withf(
                 move |request: &GetStateRequest| match &request.request.request_content {
                     Some(RequestContent::CompleteStateRequest(content)) => {
-                        content.field_mask == vec![AGENTS_PREFIX]
+                        content.field_mask
+                            == vec![AGENTS_PREFIX, WORKLOAD_STATES_PREFIX, WORKLOADS_PREFIX]
                     }
                     _ => false,
                 },
@@ -3153,10 +8722,10 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the agents
-        let method_handle = tokio::spawn(async move { ank.get_agents().await });
+        let method_handle = tokio::spawn(async move { ank.get_agents_detailed().await });
 
         // Get the request from the ControlInterface
         let request = request_receiver.await.unwrap();
@@ -3174,17 +8743,16 @@ mod tests {
         // Get the agents
         let ret_agents = method_handle.await.unwrap().unwrap();
 
-        let expected_agent_attributes = AgentAttributes {
-            tags: HashMap::from([("tag_key".to_owned(), "tag_value".to_owned())]),
-            status: HashMap::from([
-                ("free_memory".to_owned(), "1024".to_owned()),
-                ("cpu_usage".to_owned(), "50".to_owned()),
-            ]),
-        };
-
+        assert_eq!(ret_agents, complete_state.get_agents_detailed());
         assert_eq!(
-            ret_agents,
-            HashMap::from([("agent_A".to_owned(), expected_agent_attributes)])
+            ret_agents
+                .iter()
+                .map(|agent| (agent.name.as_str(), agent.status))
+                .collect::<Vec<_>>(),
+            vec![
+                ("agent_A", AgentStatus::Connected),
+                ("agent_B", AgentStatus::NotReporting)
+            ]
         );
     }
 
@@ -3213,7 +8781,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the agents
         let method_handle =
@@ -3271,7 +8839,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting non-existing agent
         let method_handle =
@@ -3321,7 +8889,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the workload states
         let method_handle = tokio::spawn(async move { ank.get_workload_states().await });
@@ -3358,7 +8926,7 @@ mod tests {
             "workload_A".to_owned(),
             "workload_id".to_owned(),
         );
-        let masks = vec![wl_instance_name.get_filter_mask()];
+        let masks = vec![wl_instance_name.state_mask()];
 
         let mut ci_mock = ControlInterface::default();
         ci_mock
@@ -3378,7 +8946,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the workload execution state
         let method_handle = tokio::spawn(async move {
@@ -3433,7 +9001,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the workload states on agent
         let method_handle =
@@ -3485,7 +9053,7 @@ mod tests {
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         // Prepare handle for getting the workload states for name
         let method_handle =
@@ -3507,68 +9075,476 @@ mod tests {
         // Get the workload states for name
         let ret_wl_states = method_handle.await.unwrap().unwrap();
 
-        assert_eq!(Vec::from(ret_wl_states).len(), 2);
+        assert_eq!(Vec::from(ret_wl_states).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn itest_wait_for_workload_to_reach_state_timeout() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        // Prepare channel to intercept the request that is being
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        // Prepare instance name
+        let wl_instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "workload_id".to_owned(),
+        );
+        let masks = vec![wl_instance_name.state_mask()];
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        // Prepare handle for getting the workload states for name
+        let method_handle = tokio::spawn(async move {
+            ank.wait_for_workload_to_reach_state(wl_instance_name, WorkloadStateEnum::Failed)
+                .await
+        });
+
+        // Get the request from the ControlInterface
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        let response = Response {
+            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
+            id: request.get_id(),
+        };
+
+        // Send the response
+        response_sender.send(response).await.unwrap();
+
+        // The deadline expired, not a single check
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::TimeoutError { request_id: None, request_type, .. })
+                if request_type == super::WAIT_FOR_STATE_OPERATION
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_wait_for_workload_substate() {
+        use crate::ankaios_api::ank_base;
+
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let wl_instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "workload_id".to_owned(),
+        );
+        let masks = vec![wl_instance_name.state_mask()];
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == masks
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move {
+            ank.wait_for_workload_substate(
+                wl_instance_name,
+                WorkloadSubStateEnum::RunningOk,
+                Duration::from_secs(1),
+            )
+            .await
+        });
+
+        let request = request_receiver.await.unwrap();
+
+        // Fabricate a response containing only the running workload
+        let complete_state = CompleteState::new_from_proto(ank_base::CompleteState {
+            workload_states: Some(ank_base::WorkloadStatesMap {
+                agent_state_map: HashMap::from([(
+                    "agent_A".to_owned(),
+                    ank_base::ExecutionsStatesOfWorkload {
+                        wl_name_state_map: HashMap::from([(
+                            "workload_A".to_owned(),
+                            ank_base::ExecutionsStatesForId {
+                                id_state_map: HashMap::from([(
+                                    "workload_id".to_owned(),
+                                    ank_base::ExecutionState {
+                                        execution_state_enum: Some(
+                                            ank_base::ExecutionStateEnum::Running(
+                                                ank_base::Running::Ok as i32,
+                                            ),
+                                        ),
+                                        additional_info: None,
+                                    },
+                                )]),
+                            },
+                        )]),
+                    },
+                )]),
+            }),
+            ..Default::default()
+        });
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn itest_wait_for() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+        let masks = vec!["workloadStates".to_owned()];
+        let masks_clone = masks.clone();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .withf(
+                move |request: &GetStateRequest| match &request.request.request_content {
+                    Some(RequestContent::CompleteStateRequest(content)) => {
+                        content.field_mask == masks_clone
+                    }
+                    _ => false,
+                },
+            )
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move {
+            ank.wait_for(
+                masks,
+                |complete_state| !complete_state.get_workload_states().as_list().is_empty(),
+                Duration::from_secs(1),
+            )
+            .await
+        });
+
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(method_handle.await.unwrap().unwrap(), complete_state);
+    }
+
+    #[tokio::test]
+    async fn itest_wait_for_workload_to_reach_state_no_check_after_deadline() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let wl_instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "workload_id".to_owned(),
+        );
+
+        // Only one check fits in the timeout
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        ank.timeout = Duration::from_millis(200);
+        ank.state_check_interval = Duration::from_secs(10);
+
+        let start = tokio::time::Instant::now();
+        let method_handle = tokio::spawn(async move {
+            ank.wait_for_workload_to_reach_state(wl_instance_name, WorkloadStateEnum::Failed)
+                .await
+        });
+
+        let request = request_receiver.await.unwrap();
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::TimeoutError { .. })
+        ));
+        assert!(start.elapsed() < Duration::from_secs(10));
     }
 
     #[tokio::test]
-    async fn itest_wait_for_workload_to_reach_state_timeout() {
+    async fn itest_wait_for_workload_to_reach_state_cancelled() {
         let _guard = MOCKALL_SYNC.lock().await;
 
-        // Prepare channel to intercept the request that is being
         let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
 
-        // Prepare instance name
         let wl_instance_name = WorkloadInstanceName::new(
             "agent_A".to_owned(),
             "workload_A".to_owned(),
             "workload_id".to_owned(),
         );
-        let masks = vec![wl_instance_name.get_filter_mask()];
 
         let mut ci_mock = ControlInterface::default();
         ci_mock
             .expect_write_request()
             .times(1)
-            .withf(
-                move |request: &GetStateRequest| match &request.request.request_content {
-                    Some(RequestContent::CompleteStateRequest(content)) => {
-                        content.field_mask == masks
-                    }
-                    _ => false,
-                },
-            )
             .return_once(move |request: GetStateRequest| {
                 request_sender.send(request).unwrap();
                 Ok(())
             });
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+        let cancellation_token = CancellationToken::new();
 
-        // Prepare handle for getting the workload states for name
+        let ank_clone = ank.clone();
+        let token_clone = cancellation_token.clone();
         let method_handle = tokio::spawn(async move {
-            ank.wait_for_workload_to_reach_state(wl_instance_name, WorkloadStateEnum::Failed)
+            ank_clone
+                .wait_for_workload_to_reach_state_with_cancellation(
+                    wl_instance_name,
+                    WorkloadStateEnum::Running,
+                    &token_clone,
+                )
                 .await
         });
 
-        // Get the request from the ControlInterface
-        let request = request_receiver.await.unwrap();
-
-        // Fabricate a response
-        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
-        let response = Response {
-            content: super::ResponseType::CompleteState(Box::new(complete_state.clone())),
-            id: request.get_id(),
-        };
-
-        // Send the response
-        response_sender.send(response).await.unwrap();
+        // Cancel while the request is waiting for a response
+        let _request = request_receiver.await.unwrap();
+        cancellation_token.cancel();
 
-        // Get the workload states for name
         assert!(matches!(
             method_handle.await.unwrap(),
-            Err(AnkaiosError::TimeoutError(_))
+            Err(AnkaiosError::CancelledError(_))
         ));
+        assert!(
+            ank.inner
+                .pending_requests
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn itest_run_workload_to_completion() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (update_request_sender, mut update_request_receiver) = mpsc::unbounded_channel();
+        let (state_request_sender, mut state_request_receiver) = mpsc::unbounded_channel();
+        let (logs_request_sender, mut logs_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        let instance_name = WorkloadInstanceName::new(
+            "agent_Test".to_owned(),
+            "workload_test".to_owned(),
+            "1234".to_owned(),
+        );
+        let log_entry = LogEntry {
+            workload_name: instance_name.clone(),
+            message: TEST_LOG_MESSAGE.to_owned(),
+        };
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: GetStateRequest| {
+                state_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        let sent_log_entry = log_entry.clone();
+        let stopped_instance_name = instance_name.clone();
+        ci_mock.expect_add_log_campaign().times(1).return_once(
+            move |_request_id: String,
+                  _logs_request: ank_base::Request,
+                  logs_sender: mpsc::Sender<LogResponse>,
+                  _entries_counter: Arc<AtomicUsize>| {
+                logs_sender
+                    .try_send(LogResponse::LogEntries(vec![sent_log_entry]))
+                    .unwrap();
+                logs_sender
+                    .try_send(LogResponse::LogsStopResponse(stopped_instance_name))
+                    .unwrap();
+            },
+        );
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let method_handle = tokio::spawn(async move {
+            ank.run_workload_to_completion(workload, Duration::from_secs(1), true)
+                .await
+        });
+
+        // Apply
+        let request = update_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+
+        // Wait for the final state
+        let request = state_request_receiver.recv().await.unwrap();
+        let complete_state = CompleteState::new_from_proto(ank_base::CompleteState {
+            workload_states: Some(ank_base::WorkloadStatesMap {
+                agent_state_map: HashMap::from([(
+                    "agent_Test".to_owned(),
+                    ank_base::ExecutionsStatesOfWorkload {
+                        wl_name_state_map: HashMap::from([(
+                            "workload_test".to_owned(),
+                            ank_base::ExecutionsStatesForId {
+                                id_state_map: HashMap::from([(
+                                    "1234".to_owned(),
+                                    ank_base::ExecutionState {
+                                        execution_state_enum: Some(
+                                            ank_base::ExecutionStateEnum::Succeeded(
+                                                ank_base::Succeeded::Ok as i32,
+                                            ),
+                                        ),
+                                        additional_info: None,
+                                    },
+                                )]),
+                            },
+                        )]),
+                    },
+                )]),
+            }),
+            ..Default::default()
+        });
+        response_sender
+            .send(Response {
+                content: super::ResponseType::CompleteState(Box::new(complete_state)),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // Collect the logs
+        let request = logs_request_receiver.recv().await.unwrap();
+        let Some(RequestContent::LogsRequest(proto_logs_request)) =
+            request.to_proto().request_content
+        else {
+            panic!("Expected a logs request");
+        };
+        assert_eq!(proto_logs_request.follow, Some(false));
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsRequestAccepted(vec![instance_name.clone()]),
+            })
+            .await
+            .unwrap();
+        let request = cancel_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsCancelAccepted,
+            })
+            .await
+            .unwrap();
+
+        // Delete
+        let request = update_request_receiver.recv().await.unwrap();
+        let Some(RequestContent::UpdateStateRequest(content)) = request.to_proto().request_content
+        else {
+            panic!("Expected an update state request");
+        };
+        assert_eq!(
+            content.update_mask,
+            vec![format!("{WORKLOADS_PREFIX}.workload_test")]
+        );
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+
+        let batch_result = method_handle.await.unwrap().unwrap();
+        assert_eq!(batch_result.state.state, WorkloadStateEnum::Succeeded);
+        assert!(batch_result.is_success());
+        assert_eq!(batch_result.logs, vec![log_entry]);
     }
 
     #[tokio::test]
@@ -3605,7 +9581,9 @@ mod tests {
             .in_sequence(&mut call_sequence)
             .return_once(
                 move |_request_id: String,
-                 incoming_logs_sender: tokio::sync::mpsc::Sender<LogResponse>| {
+                      _logs_request: ank_base::Request,
+                      incoming_logs_sender: tokio::sync::mpsc::Sender<LogResponse>,
+                      _entries_counter: Arc<AtomicUsize>| {
                     incoming_logs_sender
                         .try_send(LogResponse::LogEntries(cloned_log_entries))
                         .unwrap();
@@ -3618,7 +9596,7 @@ mod tests {
             .in_sequence(&mut call_sequence)
             .returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let logs_request = InputLogsRequest {
             workload_names: vec![instance_name.clone()],
@@ -3681,7 +9659,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let logs_request = InputLogsRequest {
             workload_names: vec![instance_name.clone()],
@@ -3732,7 +9710,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let logs_request = InputLogsRequest {
             workload_names: vec![instance_name.clone()],
@@ -3758,6 +9736,288 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn itest_active_log_campaigns_and_stop_all() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (logs_request_sender, mut logs_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+
+        let instance_name_a = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "1234".to_owned(),
+        );
+        let instance_name_b = WorkloadInstanceName::new(
+            "agent_B".to_owned(),
+            "workload_B".to_owned(),
+            "5678".to_owned(),
+        );
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_log_campaign().times(2).returning(
+            |_request_id: String,
+             _logs_request: ank_base::Request,
+             _logs_sender: mpsc::Sender<LogResponse>,
+             entries_counter: Arc<AtomicUsize>| {
+                entries_counter.fetch_add(2, Ordering::Relaxed);
+            },
+        );
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(2)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let mut request_ids = Vec::new();
+        for instance_name in [instance_name_a.clone(), instance_name_b.clone()] {
+            let ank_clone = ank.clone();
+            let logs_request = InputLogsRequest {
+                workload_names: vec![instance_name.clone()],
+                ..Default::default()
+            };
+            let method_handle =
+                tokio::spawn(async move { ank_clone.request_logs(logs_request).await });
+
+            let request = logs_request_receiver.recv().await.unwrap();
+            assert!(
+                response_sender
+                    .send(Response {
+                        id: request.get_id(),
+                        content: super::ResponseType::LogsRequestAccepted(vec![instance_name]),
+                    })
+                    .await
+                    .is_ok()
+            );
+            method_handle.await.unwrap().unwrap();
+            request_ids.push(request.get_id());
+        }
+
+        let active_log_campaigns = ank.active_log_campaigns();
+        assert_eq!(active_log_campaigns.len(), 2);
+        let mut active_request_ids: Vec<String> = active_log_campaigns
+            .iter()
+            .map(|log_campaign| log_campaign.request_id.clone())
+            .collect();
+        active_request_ids.sort();
+        request_ids.sort();
+        assert_eq!(active_request_ids, request_ids);
+        let log_campaign_b = active_log_campaigns
+            .iter()
+            .find(|log_campaign| log_campaign.workload_names == vec![instance_name_b.clone()])
+            .unwrap();
+        assert_eq!(log_campaign_b.entries_received(), 2);
+        assert!(active_log_campaigns[0].start_time <= active_log_campaigns[1].start_time);
+
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move { ank_clone.stop_all_log_campaigns().await });
+
+        let request = cancel_request_receiver.recv().await.unwrap();
+        assert!(
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: super::ResponseType::Error("cancel failed".to_owned()),
+                })
+                .await
+                .is_ok()
+        );
+        let request = cancel_request_receiver.recv().await.unwrap();
+        assert!(
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: super::ResponseType::LogsCancelAccepted,
+                })
+                .await
+                .is_ok()
+        );
+
+        let result = method_handle.await.unwrap();
+        assert!(matches!(result, Err(AnkaiosError::AnkaiosResponseError(_))));
+        assert!(ank.active_log_campaigns().is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_tail_logs() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (logs_request_sender, mut logs_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+        let (logs_sender_sender, mut logs_sender_receiver) = mpsc::unbounded_channel();
+
+        let instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "1234".to_owned(),
+        );
+        let log_entry = LogEntry {
+            workload_name: instance_name.clone(),
+            message: TEST_LOG_MESSAGE.to_owned(),
+        };
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_log_campaign().times(1).returning(
+            move |_request_id: String,
+                  _logs_request: ank_base::Request,
+                  logs_sender: mpsc::Sender<LogResponse>,
+                  _entries_counter: Arc<AtomicUsize>| {
+                logs_sender_sender.send(logs_sender).unwrap();
+            },
+        );
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let ank_clone = ank.clone();
+        let instance_name_clone = instance_name.clone();
+        let method_handle =
+            tokio::spawn(async move { ank_clone.tail_logs(instance_name_clone, 2, true).await });
+
+        let request = logs_request_receiver.recv().await.unwrap();
+        let Some(RequestContent::LogsRequest(proto_logs_request)) =
+            request.to_proto().request_content
+        else {
+            panic!("Expected a logs request");
+        };
+        assert_eq!(proto_logs_request.tail, Some(2));
+        assert_eq!(proto_logs_request.follow, Some(true));
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsRequestAccepted(vec![instance_name.clone()]),
+            })
+            .await
+            .unwrap();
+        let mut log_tail = method_handle.await.unwrap().unwrap();
+        assert_eq!(log_tail.get_request_id(), request.get_id());
+
+        let logs_sender = logs_sender_receiver.recv().await.unwrap();
+        logs_sender
+            .send(LogResponse::LogEntries(vec![log_entry.clone(); 3]))
+            .await
+            .unwrap();
+        for expected_entry in [
+            TailedLogEntry::Backlog(log_entry.clone()),
+            TailedLogEntry::Backlog(log_entry.clone()),
+            TailedLogEntry::Live(log_entry),
+        ] {
+            assert_eq!(
+                log_tail.entries_receiver.recv().await.unwrap(),
+                expected_entry
+            );
+        }
+
+        let ank_clone = ank.clone();
+        let method_handle =
+            tokio::spawn(async move { ank_clone.stop_tailing_logs(log_tail).await });
+        let request = cancel_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsCancelAccepted,
+            })
+            .await
+            .unwrap();
+        method_handle.await.unwrap().unwrap();
+        assert!(ank.active_log_campaigns().is_empty());
+    }
+
+    #[tokio::test]
+    async fn itest_tail_logs_not_accepted() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (logs_request_sender, mut logs_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_log_campaign().times(1).return_const(());
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .tail_logs(WorkloadInstanceName::default(), 10, false)
+                .await
+        });
+
+        let request = logs_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsRequestAccepted(Vec::new()),
+            })
+            .await
+            .unwrap();
+        let request = cancel_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: super::ResponseType::LogsCancelAccepted,
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::AnkaiosResponseError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn itest_stop_receiving_logs_ok() {
         let _guard = MOCKALL_SYNC.lock().await;
@@ -3786,7 +10046,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let accepted_workload_names = vec![instance_name.clone()];
         let (logs_sender, logs_receiver) = mpsc::channel(1);
@@ -3842,7 +10102,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let accepted_workload_names = vec![instance_name.clone()];
         let (logs_sender, logs_receiver) = mpsc::channel(1);
@@ -3902,7 +10162,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let accepted_workload_names = vec![instance_name.clone()];
         let (logs_sender, logs_receiver) = mpsc::channel(1);
@@ -3972,7 +10232,7 @@ mod tests {
             .in_sequence(&mut call_sequence)
             .returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let method_handle =
             tokio::spawn(async move { ank.register_event(vec![TEST_MASK.to_owned()]).await });
@@ -4005,6 +10265,110 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn itest_watch_workload_states() {
+        let _guard = MOCKALL_SYNC.lock().await;
+
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+        let (cancel_request_sender, cancel_request_receiver) = tokio::sync::oneshot::channel();
+        let (events_sender_sender, events_sender_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = ControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: EventsRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: EventsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_events_campaign().times(1).return_once(
+            move |_request_id: String, events_sender: mpsc::Sender<EventEntry>| {
+                events_sender_sender.send(events_sender).unwrap();
+            },
+        );
+        ci_mock
+            .expect_remove_events_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let filter = WorkloadStatesFilter {
+            agent_name: Some("agent_B".to_owned()),
+            ..Default::default()
+        };
+        let ank_clone = ank.clone();
+        let method_handle = tokio::spawn(async move {
+            ank_clone
+                .watch_workload_states(filter, Duration::from_millis(20))
+                .await
+        });
+
+        let request = request_receiver.await.unwrap();
+        assert_eq!(
+            request.to_proto().request_content,
+            Some(RequestContent::CompleteStateRequest(
+                ank_base::CompleteStateRequest {
+                    field_mask: vec!["workloadStates.agent_B".to_owned()],
+                    subscribe_for_events: true,
+                }
+            ))
+        );
+        assert!(
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: super::ResponseType::CompleteState(Box::new(
+                        CompleteState::new_from_proto(generate_complete_state_proto())
+                    )),
+                })
+                .await
+                .is_ok()
+        );
+        let mut watch = method_handle.await.unwrap().unwrap();
+
+        let mut workload_names = vec![
+            watch.states_receiver.recv().await.unwrap(),
+            watch.states_receiver.recv().await.unwrap(),
+        ]
+        .into_iter()
+        .map(|workload_state| {
+            assert_eq!(workload_state.workload_instance_name.agent_name, "agent_B");
+            workload_state.workload_instance_name.workload_name
+        })
+        .collect::<Vec<String>>();
+        workload_names.sort();
+        assert_eq!(
+            workload_names,
+            vec!["dyn_nginx".to_owned(), "nginx".to_owned()]
+        );
+
+        let events_sender = events_sender_receiver.await.unwrap();
+        let ank_clone = ank.clone();
+        let method_handle =
+            tokio::spawn(async move { ank_clone.stop_watching_workload_states(watch).await });
+        let request = cancel_request_receiver.await.unwrap();
+        assert!(
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: super::ResponseType::EventsCancelAccepted,
+                })
+                .await
+                .is_ok()
+        );
+        assert!(method_handle.await.unwrap().is_ok());
+        drop(events_sender);
+    }
+
     #[tokio::test]
     async fn itest_register_events_error() {
         let _guard = MOCKALL_SYNC.lock().await;
@@ -4024,7 +10388,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let method_handle =
             tokio::spawn(async move { ank.register_event(vec![TEST_MASK.to_owned()]).await });
@@ -4065,7 +10429,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let method_handle =
             tokio::spawn(async move { ank.register_event(vec![TEST_MASK.to_owned()]).await });
@@ -4109,7 +10473,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let (events_sender, events_receiver) = mpsc::channel(1);
         let events_campaign_response =
@@ -4155,7 +10519,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let (events_sender, events_receiver) = mpsc::channel(1);
         let events_campaign_response =
@@ -4205,7 +10569,7 @@ mod tests {
 
         ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
 
-        let (mut ank, response_sender) = generate_test_ankaios(ci_mock);
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
 
         let (events_sender, events_receiver) = mpsc::channel(1);
         let events_campaign_response =
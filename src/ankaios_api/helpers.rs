@@ -15,6 +15,11 @@
 use serde::{Serialize, Serializer};
 use std::collections::{BTreeMap, HashMap};
 
+/// Serializes a [`HashMap`] with its keys in sorted order.
+///
+/// # Errors
+///
+/// Returns the serializer's error if the serialization of the map fails.
 pub fn serialize_to_ordered_map<S, T: Serialize>(
     value: &HashMap<String, T>,
     serializer: S,
@@ -19,7 +19,8 @@
     clippy::needless_pass_by_value,
     clippy::str_to_string,
     clippy::absolute_paths,
-    clippy::shadow_reuse
+    clippy::shadow_reuse,
+    missing_docs
 )]
 
 pub mod control_api {
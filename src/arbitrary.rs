@@ -0,0 +1,234 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`proptest`] [`Arbitrary`] implementations of
+//! [Workload], [Manifest] and [`CompleteState`], to be reused in property tests
+//! of crates built on the SDK.
+//!
+//! The module is only available with the `proptest` feature, which is meant
+//! to be enabled for the `dev-dependencies`. The generated objects are always
+//! valid, so that they can be sent to [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+//! or converted without errors.
+//!
+//! # Example
+//!
+//! ## Check a property of arbitrary workloads:
+//!
+//! ```rust
+//! use ankaios_sdk::Workload;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(workload in any::<Workload>())| {
+//!     prop_assert!(workload.get_allow_rules().is_ok());
+//! });
+//! ```
+
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::{Arbitrary, BoxedStrategy, Strategy, any};
+use proptest::sample::select;
+use serde_yaml::{Mapping, Value};
+
+use crate::{CompleteState, File, Manifest, Workload};
+
+/// The pattern of the generated names.
+const NAME_PATTERN: &str = "[a-z][a-z0-9_]{0,11}";
+/// The pattern of the generated file contents.
+const FILE_DATA_PATTERN: &str = "[a-zA-Z0-9 ]{0,32}";
+/// The maximum number of entries of the generated collections.
+const MAX_COLLECTION_SIZE: usize = 3;
+/// The runtimes of the generated workloads.
+const RUNTIMES: [&str; 2] = ["podman", "podman-kube"];
+/// The restart policies of the generated workloads.
+const RESTART_POLICIES: [&str; 3] = ["NEVER", "ON_FAILURE", "ALWAYS"];
+/// The conditions of the generated dependencies.
+const ADD_CONDITIONS: [&str; 3] = ["ADD_COND_RUNNING", "ADD_COND_SUCCEEDED", "ADD_COND_FAILED"];
+/// The operations of the generated access rules.
+const RULE_OPERATIONS: [&str; 4] = ["Nothing", "Read", "Write", "ReadWrite"];
+
+/// Generates names valid for workloads, agents, tags and configs.
+fn name() -> impl Strategy<Value = String> {
+    NAME_PATTERN
+}
+
+/// Generates access rules made of an operation and its filter masks.
+fn access_rules() -> impl Strategy<Value = Vec<(String, Vec<String>)>> {
+    vec(
+        (
+            select(RULE_OPERATIONS.as_slice()),
+            vec(
+                name().prop_map(|name| format!("desiredState.workloads.{name}")),
+                1..=MAX_COLLECTION_SIZE,
+            ),
+        )
+            .prop_map(|(operation, filter_masks)| (operation.to_owned(), filter_masks)),
+        0..=MAX_COLLECTION_SIZE,
+    )
+}
+
+impl Arbitrary for Workload {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (
+            (name(), name(), select(RUNTIMES.as_slice()), name()),
+            select(RESTART_POLICIES.as_slice()),
+            btree_map(
+                name(),
+                select(ADD_CONDITIONS.as_slice()),
+                0..=MAX_COLLECTION_SIZE,
+            ),
+            btree_map(name(), name(), 0..=MAX_COLLECTION_SIZE),
+            (access_rules(), access_rules()),
+            btree_map(name(), name(), 0..=MAX_COLLECTION_SIZE),
+            btree_map(name(), FILE_DATA_PATTERN, 0..=MAX_COLLECTION_SIZE),
+        )
+            .prop_map(
+                |(
+                    (workload_name, agent_name, runtime, image),
+                    restart_policy,
+                    dependencies,
+                    tags,
+                    (allow_rules, deny_rules),
+                    configs,
+                    files,
+                )| {
+                    let mut builder = Workload::builder()
+                        .workload_name(workload_name)
+                        .agent_name(agent_name)
+                        .runtime(runtime)
+                        .runtime_config(format!("image: {image}"))
+                        .restart_policy(restart_policy);
+                    for (dependency, condition) in dependencies {
+                        builder = builder.add_dependency(dependency, condition.to_owned());
+                    }
+                    for (key, value) in tags {
+                        builder = builder.add_tag(key, value);
+                    }
+                    for (operation, filter_masks) in allow_rules {
+                        builder = builder.add_allow_rule(operation, filter_masks);
+                    }
+                    for (operation, filter_masks) in deny_rules {
+                        builder = builder.add_deny_rule(operation, filter_masks);
+                    }
+                    for (alias, config_name) in configs {
+                        builder = builder.add_config(alias, config_name);
+                    }
+                    for (mount_point, data) in files {
+                        builder =
+                            builder.add_file(File::from_data(format!("/{mount_point}"), data));
+                    }
+                    builder
+                        .build()
+                        .unwrap_or_else(|_| unreachable!("The generated workload is valid."))
+                },
+            )
+            .boxed()
+    }
+}
+
+impl Arbitrary for Manifest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (
+            vec(any::<Workload>(), 0..=MAX_COLLECTION_SIZE),
+            btree_map(name(), name(), 0..=MAX_COLLECTION_SIZE),
+        )
+            .prop_map(|(workloads, configs)| {
+                let mut workloads_dict = Mapping::new();
+                for workload in workloads {
+                    workloads_dict.insert(
+                        Value::String(workload.name.clone()),
+                        Value::Mapping(workload.to_dict()),
+                    );
+                }
+                let mut configs_dict = Mapping::new();
+                for (config_name, config_value) in configs {
+                    configs_dict.insert(Value::String(config_name), Value::String(config_value));
+                }
+                let mut manifest_dict = Mapping::new();
+                manifest_dict.insert(
+                    Value::String("apiVersion".to_owned()),
+                    Value::String("v1".to_owned()),
+                );
+                manifest_dict.insert(
+                    Value::String("workloads".to_owned()),
+                    Value::Mapping(workloads_dict),
+                );
+                manifest_dict.insert(
+                    Value::String("configs".to_owned()),
+                    Value::Mapping(configs_dict),
+                );
+                Manifest::from_dict(Value::Mapping(manifest_dict))
+                    .unwrap_or_else(|_| unreachable!("The generated manifest is valid."))
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for CompleteState {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<Manifest>()
+            .prop_map(CompleteState::new_from_manifest)
+            .boxed()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{any, prop_assert_eq, proptest};
+
+    use crate::{CompleteState, Manifest, Workload};
+
+    proptest! {
+        #[test]
+        fn utest_workload_proto_round_trip(workload in any::<Workload>()) {
+            let proto = workload.clone().to_proto();
+            let workload_from_proto = Workload::new_from_proto(workload.name.clone(), proto.clone());
+            prop_assert_eq!(workload_from_proto.to_proto(), proto);
+        }
+
+        #[test]
+        fn utest_workload_dict_round_trip(workload in any::<Workload>()) {
+            let workload_from_dict = Workload::new_from_dict(workload.name.clone(), &workload.to_dict()).unwrap();
+            prop_assert_eq!(workload_from_dict.to_proto(), workload.to_proto());
+        }
+
+        #[test]
+        fn utest_manifest_round_trip(manifest in any::<Manifest>()) {
+            let complete_state = CompleteState::new_from_manifest(manifest.clone());
+            let manifest_from_state = Manifest::from(complete_state);
+            prop_assert_eq!(manifest_from_state.to_desired_state(), manifest.to_desired_state());
+        }
+
+        #[test]
+        fn utest_complete_state_proto_round_trip(complete_state in any::<CompleteState>()) {
+            let proto = complete_state.to_proto();
+            prop_assert_eq!(CompleteState::new_from_proto(proto.clone()).to_proto(), proto);
+        }
+    }
+}
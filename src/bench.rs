@@ -0,0 +1,473 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the helpers of the benchmarks of the SDK: a [`FakeAgent`]
+//! answering the requests on a loopback control socket and the encoded messages
+//! used to measure the parsing.
+//!
+//! The module is only available with the `bench` feature and is not part of the public API.
+//! The benchmarks are run with `cargo bench --features bench`.
+
+use prost::Message;
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::{AsyncWriteExt, BufReader, BufWriter},
+    net::{UnixListener, UnixStream},
+    sync::mpsc,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::ankaios::CHANNEL_SIZE;
+use crate::ankaios_api;
+use crate::components::complete_state::SUPPORTED_API_VERSION;
+use crate::components::control_interface::read_protobuf_data;
+use crate::components::response::{Response, ResponseType};
+use crate::components::workload_mod::test_helpers::generate_test_workload_proto;
+use crate::{CompleteState, ControlSocketAddress};
+use ankaios_api::ank_base::{self, RequestContent, response::ResponseContent};
+use ankaios_api::control_api::{
+    ControlInterfaceAccepted, FromAnkaios, ToAnkaios, from_ankaios::FromAnkaiosEnum,
+    to_ankaios::ToAnkaiosEnum,
+};
+
+/// The name of the agent of the generated workloads.
+const AGENT_NAME: &str = "agent_A";
+
+/// Generates a complete state containing the given number of workloads, all running on [`AGENT_NAME`].
+///
+/// ## Arguments
+///
+/// * `workload_count` - The number of workloads.
+///
+/// ## Returns
+///
+/// The generated [`CompleteState`](ank_base::CompleteState) proto.
+fn generate_complete_state_proto(workload_count: usize) -> ank_base::CompleteState {
+    let workload_names: Vec<String> = (0..workload_count)
+        .map(|index| format!("workload_{index}"))
+        .collect();
+    ank_base::CompleteState {
+        desired_state: Some(ank_base::State {
+            api_version: SUPPORTED_API_VERSION.to_owned(),
+            workloads: Some(ank_base::WorkloadMap {
+                workloads: workload_names
+                    .iter()
+                    .map(|name| {
+                        (
+                            name.clone(),
+                            generate_test_workload_proto(AGENT_NAME, "podman"),
+                        )
+                    })
+                    .collect(),
+            }),
+            configs: None,
+        }),
+        workload_states: Some(ank_base::WorkloadStatesMap {
+            agent_state_map: HashMap::from([(
+                AGENT_NAME.to_owned(),
+                ank_base::ExecutionsStatesOfWorkload {
+                    wl_name_state_map: workload_names
+                        .iter()
+                        .map(|name| {
+                            (
+                                name.clone(),
+                                ank_base::ExecutionsStatesForId {
+                                    id_state_map: HashMap::from([(
+                                        format!("{name}_id"),
+                                        ank_base::ExecutionState {
+                                            execution_state_enum: Some(
+                                                ank_base::ExecutionStateEnum::Running(
+                                                    ank_base::Running::Ok as i32,
+                                                ),
+                                            ),
+                                            additional_info: None,
+                                        },
+                                    )]),
+                                },
+                            )
+                        })
+                        .collect(),
+                },
+            )]),
+        }),
+        agents: Some(ank_base::AgentMap {
+            agents: HashMap::from([(AGENT_NAME.to_owned(), ank_base::AgentAttributes::default())]),
+        }),
+    }
+}
+
+/// Wraps the content of a response into a message sent by the agent.
+///
+/// ## Arguments
+///
+/// * `request_id` - The ID of the request the response belongs to;
+/// * `response_content` - The content of the response.
+///
+/// ## Returns
+///
+/// The [`FromAnkaios`] message.
+fn response_message(request_id: String, response_content: ResponseContent) -> FromAnkaios {
+    FromAnkaios {
+        from_ankaios_enum: Some(FromAnkaiosEnum::Response(Box::new(ank_base::Response {
+            request_id,
+            response_content: Some(response_content),
+        }))),
+    }
+}
+
+/// Encodes the response to a complete state request, as written by the agent.
+///
+/// ## Arguments
+///
+/// * `workload_count` - The number of workloads in the complete state.
+///
+/// ## Returns
+///
+/// The encoded message, without the length prefix.
+#[must_use]
+pub fn encoded_complete_state_response(workload_count: usize) -> Vec<u8> {
+    response_message(
+        "bench".to_owned(),
+        ResponseContent::CompleteStateResponse(Box::new(ank_base::CompleteStateResponse {
+            complete_state: Some(generate_complete_state_proto(workload_count)),
+            altered_fields: None,
+        })),
+    )
+    .encode_to_vec()
+}
+
+/// Decodes a response to a complete state request, as done when reading from the control interface.
+///
+/// ## Arguments
+///
+/// * `encoded` - The encoded message, as returned by [`encoded_complete_state_response`].
+///
+/// ## Returns
+///
+/// The parsed [`CompleteState`], [None] if the message is not a complete state response.
+#[must_use]
+pub fn decode_complete_state_response(encoded: &[u8]) -> Option<CompleteState> {
+    let from_ankaios = FromAnkaios::decode(encoded).ok()?;
+    match Response::new(from_ankaios).content {
+        ResponseType::CompleteState(complete_state) => Some(*complete_state),
+        _ => None,
+    }
+}
+
+/// A fake agent answering the requests of the SDK on a Unix control socket, without
+/// connecting to [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+///
+/// The agent accepts the hello message, answers the complete state requests with a
+/// generated state, accepts all updates and log requests and streams log entries on demand.
+/// The socket is removed and the agent stopped when it is dropped.
+#[derive(Debug)]
+pub struct FakeAgent {
+    /// The path of the Unix socket.
+    path: PathBuf,
+    /// The sender of the messages to the connected SDK, if any.
+    outgoing: Arc<Mutex<Option<mpsc::Sender<FromAnkaios>>>>,
+    /// The ID of the last log request accepted.
+    log_request_id: Arc<Mutex<Option<String>>>,
+    /// Token cancelled to stop the agent.
+    shutdown: CancellationToken,
+}
+
+impl FakeAgent {
+    /// Starts a fake agent listening on the given Unix socket path, serving the connections
+    /// on the current tokio runtime.
+    ///
+    /// ## Arguments
+    ///
+    /// * `socket_path` - The path of the Unix socket, replaced if it already exists;
+    /// * `workload_count` - The number of workloads of the complete state returned to the requests.
+    ///
+    /// ## Returns
+    ///
+    /// The started [`FakeAgent`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`io::Error`] if the socket could not be bound.
+    pub async fn start<T: Into<PathBuf>>(
+        socket_path: T,
+        workload_count: usize,
+    ) -> io::Result<FakeAgent> {
+        let path = socket_path.into();
+        match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        let listener = UnixListener::bind(&path)?;
+        let agent = FakeAgent {
+            path,
+            outgoing: Arc::new(Mutex::new(None)),
+            log_request_id: Arc::new(Mutex::new(None)),
+            shutdown: CancellationToken::new(),
+        };
+
+        let complete_state = Arc::new(generate_complete_state_proto(workload_count));
+        let outgoing = Arc::clone(&agent.outgoing);
+        let log_request_id = Arc::clone(&agent.log_request_id);
+        let shutdown = agent.shutdown.clone();
+        tokio::spawn(async move {
+            loop {
+                let stream = tokio::select! {
+                    () = shutdown.cancelled() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => stream,
+                        Err(_) => break,
+                    },
+                };
+                tokio::spawn(Self::serve(
+                    stream,
+                    Arc::clone(&complete_state),
+                    Arc::clone(&outgoing),
+                    Arc::clone(&log_request_id),
+                    shutdown.clone(),
+                ));
+            }
+        });
+        Ok(agent)
+    }
+
+    /// Returns the address of the control socket the agent listens on.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ControlSocketAddress`] of the agent.
+    #[must_use]
+    pub fn address(&self) -> ControlSocketAddress {
+        ControlSocketAddress::Unix(self.path.clone())
+    }
+
+    /// Streams log entries to the last log request accepted, one entry per message.
+    ///
+    /// ## Arguments
+    ///
+    /// * `count` - The number of log entries to send.
+    ///
+    /// ## Panics
+    ///
+    /// If no SDK is connected or no log request was accepted.
+    pub async fn send_log_entries(&self, count: usize) {
+        let outgoing = self
+            .outgoing
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clone()
+            .expect("no SDK connected");
+        let request_id = self
+            .log_request_id
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clone()
+            .expect("no log request accepted");
+        for index in 0..count {
+            let log_entries = ank_base::LogEntriesResponse {
+                log_entries: vec![ank_base::LogEntry {
+                    workload_name: Some(ank_base::WorkloadInstanceName {
+                        agent_name: AGENT_NAME.to_owned(),
+                        workload_name: "workload_0".to_owned(),
+                        id: "workload_0_id".to_owned(),
+                    }),
+                    message: format!("log message {index}"),
+                }],
+            };
+            outgoing
+                .send(response_message(
+                    request_id.clone(),
+                    ResponseContent::LogEntriesResponse(log_entries),
+                ))
+                .await
+                .expect("SDK disconnected");
+        }
+    }
+
+    /// Serves a connection of the SDK until it is closed or the agent is stopped.
+    ///
+    /// ## Arguments
+    ///
+    /// * `stream` - The accepted connection;
+    /// * `complete_state` - The complete state returned to the requests;
+    /// * `outgoing` - The sender of the messages to the connected SDK, set while serving;
+    /// * `log_request_id` - The ID of the last log request accepted;
+    /// * `shutdown` - Token cancelled when the agent is stopped.
+    async fn serve(
+        stream: UnixStream,
+        complete_state: Arc<ank_base::CompleteState>,
+        outgoing: Arc<Mutex<Option<mpsc::Sender<FromAnkaios>>>>,
+        log_request_id: Arc<Mutex<Option<String>>>,
+        shutdown: CancellationToken,
+    ) {
+        let (reader, writer) = stream.into_split();
+        let (sender, mut receiver) = mpsc::channel::<FromAnkaios>(CHANNEL_SIZE);
+        *outgoing.lock().unwrap_or_else(|_| unreachable!()) = Some(sender.clone());
+
+        let writer_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            let mut buffered_writer = BufWriter::new(writer);
+            loop {
+                let message = tokio::select! {
+                    () = writer_shutdown.cancelled() => break,
+                    received = receiver.recv() => match received {
+                        Some(message) => message,
+                        None => break,
+                    },
+                };
+                if buffered_writer
+                    .write_all(&message.encode_length_delimited_to_vec())
+                    .await
+                    .is_err()
+                    || (receiver.is_empty() && buffered_writer.flush().await.is_err())
+                {
+                    break;
+                }
+            }
+        });
+
+        let mut buffered_reader = BufReader::new(reader);
+        loop {
+            let binary = tokio::select! {
+                () = shutdown.cancelled() => break,
+                read = read_protobuf_data(&mut buffered_reader) => match read {
+                    Ok(binary) => binary,
+                    Err(_) => break,
+                },
+            };
+            let Ok(to_ankaios) = ToAnkaios::decode(binary.as_slice()) else {
+                break;
+            };
+            let message = match to_ankaios.to_ankaios_enum {
+                Some(ToAnkaiosEnum::Hello(_)) => FromAnkaios {
+                    from_ankaios_enum: Some(FromAnkaiosEnum::ControlInterfaceAccepted(
+                        ControlInterfaceAccepted::default(),
+                    )),
+                },
+                Some(ToAnkaiosEnum::Request(request)) => {
+                    let response_content = match request.request_content {
+                        Some(RequestContent::CompleteStateRequest(_)) => {
+                            ResponseContent::CompleteStateResponse(Box::new(
+                                ank_base::CompleteStateResponse {
+                                    complete_state: Some(complete_state.as_ref().clone()),
+                                    altered_fields: None,
+                                },
+                            ))
+                        }
+                        Some(RequestContent::LogsRequest(logs_request)) => {
+                            *log_request_id.lock().unwrap_or_else(|_| unreachable!()) =
+                                Some(request.request_id.clone());
+                            ResponseContent::LogsRequestAccepted(ank_base::LogsRequestAccepted {
+                                workload_names: logs_request.workload_names,
+                            })
+                        }
+                        Some(RequestContent::LogsCancelRequest(_)) => {
+                            ResponseContent::LogsCancelAccepted(
+                                ank_base::LogsCancelAccepted::default(),
+                            )
+                        }
+                        Some(RequestContent::EventsCancelRequest(_)) => {
+                            ResponseContent::EventsCancelAccepted(
+                                ank_base::EventsCancelAccepted::default(),
+                            )
+                        }
+                        Some(RequestContent::UpdateStateRequest(_)) | None => {
+                            ResponseContent::UpdateStateSuccess(
+                                ank_base::UpdateStateSuccess::default(),
+                            )
+                        }
+                    };
+                    response_message(request.request_id, response_content)
+                }
+                None => continue,
+            };
+            if sender.send(message).await.is_err() {
+                break;
+            }
+        }
+        outgoing.lock().unwrap_or_else(|_| unreachable!()).take();
+    }
+}
+
+impl Drop for FakeAgent {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use tokio::io::{AsyncWriteExt, BufReader};
+
+    use super::{FakeAgent, decode_complete_state_response, encoded_complete_state_response};
+    use crate::ankaios_api::control_api::{
+        FromAnkaios, Hello, ToAnkaios, from_ankaios::FromAnkaiosEnum, to_ankaios::ToAnkaiosEnum,
+    };
+    use crate::components::control_interface::read_protobuf_data;
+
+    #[test]
+    fn utest_complete_state_response_round_trip() {
+        let complete_state =
+            decode_complete_state_response(&encoded_complete_state_response(25)).unwrap();
+        assert_eq!(complete_state.get_workloads().len(), 25);
+        assert_eq!(complete_state.get_workload_states().len(), 25);
+        assert!(decode_complete_state_response(&[0xff]).is_none());
+    }
+
+    #[tokio::test]
+    async fn utest_fake_agent_accepts_hello() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let agent = FakeAgent::start(tmpdir.path().join("control.sock"), 1)
+            .await
+            .unwrap();
+
+        let (reader, mut writer) = agent.address().connect().await.unwrap();
+        let hello = ToAnkaios {
+            to_ankaios_enum: Some(ToAnkaiosEnum::Hello(Hello {
+                protocol_version: "1.0.0".to_owned(),
+            })),
+        };
+        writer
+            .write_all(&hello.encode_length_delimited_to_vec())
+            .await
+            .unwrap();
+
+        let binary = read_protobuf_data(&mut BufReader::new(reader))
+            .await
+            .unwrap();
+        assert!(matches!(
+            FromAnkaios::decode(binary.as_slice())
+                .unwrap()
+                .from_ankaios_enum,
+            Some(FromAnkaiosEnum::ControlInterfaceAccepted(_))
+        ));
+
+        drop(agent);
+        assert!(!tmpdir.path().join("control.sock").exists());
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains bridges forwarding the state of
+//! [Ankaios](https://eclipse-ankaios.github.io/ankaios) to other systems.
+//! Custom bridges implement the [`StateBridge`] trait and are fed by a [`StateBridgeRunner`].
+//! The bundled bridges are available with the feature of the same name.
+
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+mod state_bridge;
+
+pub use state_bridge::{StateBridge, StateBridgeConfig, StateBridgeRunner};
+
+/// Re-export of the attribute needed to implement the [`StateBridge`] trait.
+pub use async_trait::async_trait;
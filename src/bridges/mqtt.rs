@@ -0,0 +1,535 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`MqttBridge`], which publishes the workload states and the
+//! connected agents to MQTT topics and applies the manifests received on a command topic.
+//!
+//! # Topics
+//!
+//! - `<states_topic>/<agent>/<workload>/<id>`: the execution state of a workload instance, retained;
+//! - `<agents_topic>`: the connected agents and their attributes, retained;
+//! - `<command_topic>`: the manifests to apply, as YAML;
+//! - `<command_topic>/result`: the result of applying a manifest.
+//!
+//! All the published payloads are JSON objects.
+//!
+//! # Example
+//!
+//! ## Run the bridge until the application shuts down:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::{Ankaios, CancellationToken};
+//! use ankaios_sdk::bridges::mqtt::{MqttBridge, MqttBridgeConfig};
+//! #
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! # let ankaios = Ankaios::new().await.unwrap();
+//!
+//! let config = MqttBridgeConfig {
+//!     host: "broker.local".to_owned(),
+//!     ..Default::default()
+//! };
+//! let bridge = MqttBridge::new(ankaios, config);
+//! let cancellation_token = CancellationToken::new();
+//! bridge.run(&cancellation_token).await.unwrap();
+//! # })
+//! ```
+
+use rumqttc::{AsyncClient, Event, EventLoop, MqttOptions, Packet, QoS};
+use serde_yaml::{Mapping, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+use tokio_util::sync::CancellationToken;
+
+use crate::ankaios::{AGENTS_PREFIX, CHANNEL_SIZE};
+use crate::logging::sdk_log;
+use crate::{
+    AgentAttributes, Ankaios, AnkaiosError, Manifest, UpdateStateSuccess, WorkloadExecutionState,
+    WorkloadInstanceName, WorkloadStatesFilter,
+};
+
+/// The default port of the MQTT broker.
+const DEFAULT_PORT: u16 = 1883;
+/// The default client id used to connect to the MQTT broker.
+const DEFAULT_CLIENT_ID: &str = "ankaios-sdk-bridge";
+/// The default topic under which the workload states are published.
+const DEFAULT_STATES_TOPIC: &str = "ankaios/workloadStates";
+/// The default topic on which the connected agents are published.
+const DEFAULT_AGENTS_TOPIC: &str = "ankaios/agents";
+/// The default topic from which the manifests to apply are received.
+const DEFAULT_COMMAND_TOPIC: &str = "ankaios/commands/apply";
+/// The default window in which workload state changes are merged.
+const DEFAULT_COALESCE_WINDOW: u64 = 500; // milliseconds
+/// The keep alive interval of the MQTT connection.
+const KEEP_ALIVE_INTERVAL: u64 = 30; // seconds
+/// The time to wait before polling the MQTT connection again after an error.
+const RECONNECT_DELAY: u64 = 1; // seconds
+/// The suffix of the topic on which the results of the commands are published.
+const COMMAND_RESULT_SUFFIX: &str = "result";
+/// The MQTT wildcards, which are not allowed in the command topic.
+const TOPIC_WILDCARDS: [char; 2] = ['+', '#'];
+
+/// The configuration of the [`MqttBridge`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttBridgeConfig {
+    /// The host of the MQTT broker.
+    pub host: String,
+    /// The port of the MQTT broker.
+    pub port: u16,
+    /// The client id used to connect to the MQTT broker.
+    pub client_id: String,
+    /// The topic under which the workload states are published.
+    pub states_topic: String,
+    /// The topic on which the connected agents are published.
+    pub agents_topic: String,
+    /// The topic from which the manifests to apply are received. It must not contain wildcards.
+    pub command_topic: String,
+    /// The window in which workload state changes are merged before being published.
+    pub coalesce_window: Duration,
+}
+
+impl Default for MqttBridgeConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_owned(),
+            port: DEFAULT_PORT,
+            client_id: DEFAULT_CLIENT_ID.to_owned(),
+            states_topic: DEFAULT_STATES_TOPIC.to_owned(),
+            agents_topic: DEFAULT_AGENTS_TOPIC.to_owned(),
+            command_topic: DEFAULT_COMMAND_TOPIC.to_owned(),
+            coalesce_window: Duration::from_millis(DEFAULT_COALESCE_WINDOW),
+        }
+    }
+}
+
+impl MqttBridgeConfig {
+    /// Gets the topic of the state of a workload instance.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The topic as a [String].
+    #[must_use]
+    pub fn state_topic(&self, instance_name: &WorkloadInstanceName) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            self.states_topic,
+            instance_name.agent_name,
+            instance_name.workload_name,
+            instance_name.workload_id
+        )
+    }
+
+    /// Gets the topic on which the results of the commands are published.
+    ///
+    /// ## Returns
+    ///
+    /// The topic as a [String].
+    #[must_use]
+    pub fn command_result_topic(&self) -> String {
+        format!("{}/{COMMAND_RESULT_SUFFIX}", self.command_topic)
+    }
+
+    /// Checks that the command topic can be subscribed to and matched exactly.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`MqttError`](AnkaiosError::MqttError) if the command topic is empty or contains wildcards.
+    fn check_command_topic(&self) -> Result<(), AnkaiosError> {
+        if self.command_topic.is_empty() || self.command_topic.contains(TOPIC_WILDCARDS) {
+            return Err(AnkaiosError::MqttError(format!(
+                "Invalid command topic '{}': it must not be empty or contain wildcards.",
+                self.command_topic
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Guard that aborts the task polling the MQTT event loop when dropped.
+struct PollTaskGuard(JoinHandle<()>);
+
+impl Drop for PollTaskGuard {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Helper struct that bridges [Ankaios](https://eclipse-ankaios.github.io/ankaios) and an MQTT broker.
+pub struct MqttBridge {
+    /// The [Ankaios] object used to watch the state and apply the manifests.
+    ankaios: Ankaios,
+    /// The configuration of the bridge.
+    config: MqttBridgeConfig,
+}
+
+impl MqttBridge {
+    /// Creates a new `MqttBridge`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to watch the state and apply the manifests;
+    /// * `config` - The [`MqttBridgeConfig`] of the bridge.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`MqttBridge`] object.
+    #[must_use]
+    pub fn new(ankaios: Ankaios, config: MqttBridgeConfig) -> Self {
+        Self { ankaios, config }
+    }
+
+    /// Runs the bridge until cancelled. The connection to the MQTT broker is
+    /// re-established automatically when lost and the command topic is
+    /// subscribed again on every new connection.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the bridge when cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`MqttError`](AnkaiosError::MqttError) if the command topic is invalid or the MQTT client failed;
+    /// - any error returned while registering to the state of [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+    pub async fn run(&self, cancellation_token: &CancellationToken) -> Result<(), AnkaiosError> {
+        self.config.check_command_topic()?;
+        let mut mqtt_options =
+            MqttOptions::new(&self.config.client_id, &self.config.host, self.config.port);
+        mqtt_options.set_keep_alive(Duration::from_secs(KEEP_ALIVE_INTERVAL));
+        let (client, event_loop) = AsyncClient::new(mqtt_options, CHANNEL_SIZE);
+
+        let (commands_sender, mut commands_receiver) = mpsc::channel(CHANNEL_SIZE);
+        let _poll_task_guard = PollTaskGuard(tokio::spawn(poll_event_loop(
+            event_loop,
+            client.clone(),
+            self.config.command_topic.clone(),
+            commands_sender,
+        )));
+
+        let mut states_watch = self
+            .ankaios
+            .watch_workload_states(WorkloadStatesFilter::default(), self.config.coalesce_window)
+            .await?;
+        let mut agents_events = self
+            .ankaios
+            .register_event(vec![AGENTS_PREFIX.to_owned()])
+            .await?;
+
+        let result = loop {
+            let published = tokio::select! {
+                () = cancellation_token.cancelled() => break Ok(()),
+                received_state = states_watch.states_receiver.recv() => {
+                    let Some(workload_state) = received_state else {
+                        break Err(AnkaiosError::MqttError("The workload states watch was closed.".to_owned()));
+                    };
+                    client.publish(
+                        self.config.state_topic(&workload_state.workload_instance_name),
+                        QoS::AtLeastOnce,
+                        true,
+                        state_payload(&workload_state.execution_state),
+                    ).await
+                }
+                agents_event = agents_events.events_receiver.recv() => {
+                    let Some(event) = agents_event else {
+                        break Err(AnkaiosError::MqttError("The agents events campaign was closed.".to_owned()));
+                    };
+                    client.publish(
+                        &self.config.agents_topic,
+                        QoS::AtLeastOnce,
+                        true,
+                        agents_payload(&event.complete_state.get_agents()),
+                    ).await
+                }
+                received_command = commands_receiver.recv() => {
+                    let Some(command) = received_command else {
+                        break Err(AnkaiosError::MqttError("The MQTT connection was closed.".to_owned()));
+                    };
+                    let apply_result = self.apply_command(&command).await;
+                    client.publish(
+                        self.config.command_result_topic(),
+                        QoS::AtLeastOnce,
+                        false,
+                        command_result_payload(&apply_result),
+                    ).await
+                }
+            };
+            if let Err(err) = published {
+                break Err(AnkaiosError::MqttError(err.to_string()));
+            }
+        };
+
+        self.ankaios
+            .stop_watching_workload_states(states_watch)
+            .await
+            .unwrap_or_else(|err| sdk_log!(warn, "Error while stopping the states watch: '{err}'"));
+        self.ankaios
+            .unregister_event(agents_events)
+            .await
+            .unwrap_or_else(|err| {
+                sdk_log!(warn, "Error while unregistering the agents events: '{err}'")
+            });
+        client
+            .disconnect()
+            .await
+            .unwrap_or_else(|err| sdk_log!(warn, "Error while disconnecting from MQTT: '{err}'"));
+        result
+    }
+
+    /// Applies a manifest received on the command topic.
+    ///
+    /// ## Arguments
+    ///
+    /// * `command` - The payload of the command, containing the manifest as YAML.
+    ///
+    /// ## Returns
+    ///
+    /// The [`UpdateStateSuccess`] containing the changed workloads.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the payload is not a valid manifest;
+    /// - any error returned while applying the manifest.
+    async fn apply_command(&self, command: &[u8]) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let manifest = Manifest::from_string(String::from_utf8_lossy(command))?;
+        sdk_log!(info, "Applying the manifest received over MQTT.");
+        self.ankaios.apply_manifest(manifest).await
+    }
+}
+
+/// Polls the MQTT event loop, forwarding the payloads received on the command topic.
+/// The command topic is subscribed on every connection acknowledged by the broker,
+/// as the subscriptions of a clean session do not survive a reconnection.
+///
+/// ## Arguments
+///
+/// * `event_loop` - The [`EventLoop`] of the MQTT client;
+/// * `client` - The [`AsyncClient`] used to subscribe to the command topic;
+/// * `command_topic` - The topic from which the commands are received;
+/// * `commands_sender` - The [`mpsc::Sender`] towards the bridge.
+async fn poll_event_loop(
+    mut event_loop: EventLoop,
+    client: AsyncClient,
+    command_topic: String,
+    commands_sender: mpsc::Sender<Vec<u8>>,
+) {
+    loop {
+        match event_loop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                // The event loop is only driven by this task, so the subscription
+                // must be queued without waiting for room in the request channel.
+                if let Err(err) = client.try_subscribe(&command_topic, QoS::AtLeastOnce) {
+                    sdk_log!(warn, "Could not subscribe to the command topic: '{err}'");
+                }
+            }
+            Ok(Event::Incoming(Packet::Publish(publish))) if publish.topic == command_topic => {
+                if commands_sender
+                    .send(publish.payload.to_vec())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                sdk_log!(warn, "MQTT connection error: '{err}', reconnecting.");
+                sleep(Duration::from_secs(RECONNECT_DELAY)).await;
+            }
+        }
+    }
+}
+
+/// Converts a YAML value to the JSON payload of an MQTT message.
+///
+/// ## Arguments
+///
+/// * `value` - The [Value] to convert.
+///
+/// ## Returns
+///
+/// The JSON payload as bytes.
+fn to_json_payload(value: &Value) -> Vec<u8> {
+    serde_json::to_vec(value).unwrap_or_else(|err| {
+        sdk_log!(error, "Could not serialize the MQTT payload: '{err}'");
+        Vec::new()
+    })
+}
+
+/// Builds the payload of a workload state.
+///
+/// ## Arguments
+///
+/// * `execution_state` - The [`WorkloadExecutionState`] of the workload.
+///
+/// ## Returns
+///
+/// The JSON payload as bytes.
+fn state_payload(execution_state: &WorkloadExecutionState) -> Vec<u8> {
+    to_json_payload(&Value::Mapping(execution_state.to_dict()))
+}
+
+/// Builds the payload of the connected agents.
+///
+/// ## Arguments
+///
+/// * `agents` - The connected agents and their [`AgentAttributes`].
+///
+/// ## Returns
+///
+/// The JSON payload as bytes.
+fn agents_payload<S: BuildHasher>(agents: &HashMap<String, AgentAttributes, S>) -> Vec<u8> {
+    let sorted_agents: BTreeMap<&String, &AgentAttributes> = agents.iter().collect();
+    let mut mapping = Mapping::new();
+    for (agent_name, attributes) in sorted_agents {
+        mapping.insert(
+            Value::String(agent_name.clone()),
+            Value::Mapping(attributes.to_dict()),
+        );
+    }
+    to_json_payload(&Value::Mapping(mapping))
+}
+
+/// Builds the payload of the result of a command.
+///
+/// ## Arguments
+///
+/// * `result` - The result of applying the manifest.
+///
+/// ## Returns
+///
+/// The JSON payload as bytes.
+fn command_result_payload(result: &Result<UpdateStateSuccess, AnkaiosError>) -> Vec<u8> {
+    let mut mapping = Mapping::new();
+    match result {
+        Ok(update_state_success) => {
+            let sorted_result: BTreeMap<String, Vec<Mapping>> =
+                update_state_success.to_dict().into_iter().collect();
+            for (key, workloads) in sorted_result {
+                mapping.insert(
+                    Value::String(key),
+                    Value::Sequence(workloads.into_iter().map(Value::Mapping).collect()),
+                );
+            }
+        }
+        Err(err) => {
+            mapping.insert(
+                Value::String("error".to_owned()),
+                Value::String(err.to_string()),
+            );
+        }
+    }
+    to_json_payload(&Value::Mapping(mapping))
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{MqttBridgeConfig, agents_payload, command_result_payload, state_payload};
+    use crate::{
+        AgentAttributes, AnkaiosError, UpdateStateSuccess, WorkloadExecutionState,
+        WorkloadInstanceName, ankaios_api::ank_base,
+    };
+
+    fn generate_instance_name() -> WorkloadInstanceName {
+        WorkloadInstanceName::new("agent_A".to_owned(), "nginx".to_owned(), "1234".to_owned())
+    }
+
+    #[test]
+    fn utest_mqtt_bridge_config_topics() {
+        let config = MqttBridgeConfig::default();
+        assert_eq!(
+            config.state_topic(&generate_instance_name()),
+            "ankaios/workloadStates/agent_A/nginx/1234"
+        );
+        assert_eq!(
+            config.command_result_topic(),
+            "ankaios/commands/apply/result"
+        );
+    }
+
+    #[test]
+    fn utest_mqtt_bridge_config_check_command_topic() {
+        assert!(MqttBridgeConfig::default().check_command_topic().is_ok());
+        for command_topic in ["", "ankaios/+/apply", "ankaios/commands/#"] {
+            let config = MqttBridgeConfig {
+                command_topic: command_topic.to_owned(),
+                ..Default::default()
+            };
+            assert!(matches!(
+                config.check_command_topic(),
+                Err(AnkaiosError::MqttError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn utest_state_payload() {
+        let payload = state_payload(&WorkloadExecutionState::new(ank_base::ExecutionState {
+            execution_state_enum: None,
+            additional_info: None,
+        }));
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(json["state"], "NotScheduled");
+    }
+
+    #[test]
+    fn utest_agents_payload() {
+        let agents = HashMap::from([
+            (
+                "agent_B".to_owned(),
+                AgentAttributes::new_from_proto(ank_base::AgentAttributes::default()),
+            ),
+            (
+                "agent_A".to_owned(),
+                AgentAttributes::new_from_proto(ank_base::AgentAttributes::default()),
+            ),
+        ]);
+        let payload = agents_payload(&agents);
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        let agent_names: Vec<&String> = json.as_object().unwrap().keys().collect();
+        assert_eq!(agent_names, vec!["agent_A", "agent_B"]);
+    }
+
+    #[test]
+    fn utest_command_result_payload() {
+        let update_state_success = UpdateStateSuccess {
+            added_workloads: vec![generate_instance_name()],
+            deleted_workloads: Vec::new(),
+        };
+        let payload = command_result_payload(&Ok(update_state_success));
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(json["added_workloads"][0]["agent_name"], "agent_A");
+        assert_eq!(json["deleted_workloads"], serde_json::json!([]));
+
+        let payload = command_result_payload(&Err(AnkaiosError::ManifestParsingError(
+            "invalid".to_owned(),
+        )));
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(json["error"], "Manifest parsing error: invalid");
+    }
+}
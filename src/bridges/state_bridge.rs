@@ -0,0 +1,470 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`StateBridge`] trait, implemented by the publishers
+//! of the state of [Ankaios](https://eclipse-ankaios.github.io/ankaios) to other
+//! systems, and the [`StateBridgeRunner`] feeding them.
+//!
+//! # Example
+//!
+//! ## Print the workload states:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::{Ankaios, AnkaiosError, CancellationToken, WorkloadState};
+//! use ankaios_sdk::bridges::{StateBridge, StateBridgeConfig, StateBridgeRunner, async_trait};
+//!
+//! struct PrintBridge;
+//!
+//! #[async_trait]
+//! impl StateBridge for PrintBridge {
+//!     async fn on_state_change(&mut self, workload_state: WorkloadState) -> Result<(), AnkaiosError> {
+//!         println!("{}: {:?}", workload_state.workload_instance_name, workload_state.execution_state);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! # let ankaios = Ankaios::new().await.unwrap();
+//! let runner = StateBridgeRunner::new(ankaios, StateBridgeConfig::default());
+//! let cancellation_token = CancellationToken::new();
+//! runner.run(&mut PrintBridge, &cancellation_token).await.unwrap();
+//! # })
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::future::pending;
+use tokio::sync::mpsc::Receiver;
+use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+use crate::ankaios::AGENTS_PREFIX;
+use crate::logging::sdk_log;
+use crate::{
+    AgentAttributes, Ankaios, AnkaiosError, LogEntry, LogResponse, LogsRequest, WorkloadState,
+    WorkloadStatesFilter,
+};
+
+/// The default window in which workload state changes are merged.
+const DEFAULT_COALESCE_WINDOW: u64 = 500; // milliseconds
+
+/// Trait implemented by the publishers of the state of
+/// [Ankaios](https://eclipse-ankaios.github.io/ankaios) to other systems.
+/// All the methods do nothing by default, so that only the needed ones are implemented.
+#[async_trait]
+pub trait StateBridge: Send {
+    /// Called when the execution state of a workload instance changed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload_state` - The new [`WorkloadState`] of the workload instance.
+    ///
+    /// ## Errors
+    ///
+    /// Any [`AnkaiosError`] while publishing, logged by the [`StateBridgeRunner`].
+    async fn on_state_change(&mut self, workload_state: WorkloadState) -> Result<(), AnkaiosError> {
+        let _ = workload_state;
+        Ok(())
+    }
+
+    /// Called when the connected agents or their attributes changed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agents` - The connected agents and their [`AgentAttributes`].
+    ///
+    /// ## Errors
+    ///
+    /// Any [`AnkaiosError`] while publishing, logged by the [`StateBridgeRunner`].
+    async fn on_agent_event(
+        &mut self,
+        agents: HashMap<String, AgentAttributes>,
+    ) -> Result<(), AnkaiosError> {
+        let _ = agents;
+        Ok(())
+    }
+
+    /// Called for every log entry received for the [`StateBridgeConfig::logs_request`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `log_entry` - The received [`LogEntry`].
+    ///
+    /// ## Errors
+    ///
+    /// Any [`AnkaiosError`] while publishing, logged by the [`StateBridgeRunner`].
+    async fn on_log_entry(&mut self, log_entry: LogEntry) -> Result<(), AnkaiosError> {
+        let _ = log_entry;
+        Ok(())
+    }
+}
+
+/// The configuration of the [`StateBridgeRunner`].
+#[derive(Debug, Clone)]
+pub struct StateBridgeConfig {
+    /// The workload states forwarded to the bridge.
+    pub states_filter: WorkloadStatesFilter,
+    /// The window in which workload state changes are merged before being forwarded.
+    pub coalesce_window: Duration,
+    /// Whether the changes of the connected agents are forwarded to the bridge.
+    pub watch_agents: bool,
+    /// The logs request whose log entries are forwarded to the bridge, if any.
+    pub logs_request: Option<LogsRequest>,
+}
+
+impl Default for StateBridgeConfig {
+    fn default() -> Self {
+        Self {
+            states_filter: WorkloadStatesFilter::default(),
+            coalesce_window: Duration::from_millis(DEFAULT_COALESCE_WINDOW),
+            watch_agents: true,
+            logs_request: None,
+        }
+    }
+}
+
+/// Helper struct that feeds a [`StateBridge`] with the state of
+/// [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+pub struct StateBridgeRunner {
+    /// The [Ankaios] object used to watch the state.
+    ankaios: Ankaios,
+    /// The configuration of the runner.
+    config: StateBridgeConfig,
+}
+
+impl StateBridgeRunner {
+    /// Creates a new `StateBridgeRunner`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to watch the state;
+    /// * `config` - The [`StateBridgeConfig`] of the runner.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`StateBridgeRunner`] object.
+    #[must_use]
+    pub fn new(ankaios: Ankaios, config: StateBridgeConfig) -> Self {
+        Self { ankaios, config }
+    }
+
+    /// Feeds the bridge until cancelled. The errors returned by the bridge
+    /// are logged and do not stop the runner.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bridge` - The [`StateBridge`] to feed;
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the runner when cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if a subscription was closed;
+    /// - any error returned while subscribing to the state or the logs.
+    pub async fn run<B: StateBridge>(
+        &self,
+        bridge: &mut B,
+        cancellation_token: &CancellationToken,
+    ) -> Result<(), AnkaiosError> {
+        let mut states_watch = self
+            .ankaios
+            .watch_workload_states(
+                self.config.states_filter.clone(),
+                self.config.coalesce_window,
+            )
+            .await?;
+        let mut agents_events = if self.config.watch_agents {
+            Some(
+                self.ankaios
+                    .register_event(vec![AGENTS_PREFIX.to_owned()])
+                    .await?,
+            )
+        } else {
+            None
+        };
+        let mut log_campaign = match self.config.logs_request.clone() {
+            Some(logs_request) => Some(self.ankaios.request_logs(logs_request).await?),
+            None => None,
+        };
+
+        let result = loop {
+            let bridge_result = tokio::select! {
+                () = cancellation_token.cancelled() => break Ok(()),
+                received_state = states_watch.states_receiver.recv() => {
+                    let Some(workload_state) = received_state else {
+                        break Err(closed_error("workload states watch"));
+                    };
+                    bridge.on_state_change(workload_state).await
+                }
+                agents_event = recv_if_some(agents_events.as_mut().map(|events| &mut events.events_receiver)) => {
+                    let Some(event) = agents_event else {
+                        break Err(closed_error("agents events campaign"));
+                    };
+                    bridge.on_agent_event(event.complete_state.get_agents()).await
+                }
+                log_response = recv_if_some(log_campaign.as_mut().map(|campaign| &mut campaign.logs_receiver)) => {
+                    match log_response {
+                        Some(LogResponse::LogEntries(log_entries)) => {
+                            forward_log_entries(bridge, log_entries).await
+                        }
+                        Some(other_response) => {
+                            sdk_log!(debug, "Log response not forwarded to the bridge: {other_response:?}");
+                            Ok(())
+                        }
+                        None => break Err(closed_error("log campaign")),
+                    }
+                }
+            };
+            if let Err(err) = bridge_result {
+                sdk_log!(warn, "Error returned by the bridge: '{err}'");
+            }
+        };
+
+        self.ankaios
+            .stop_watching_workload_states(states_watch)
+            .await
+            .unwrap_or_else(|err| sdk_log!(warn, "Error while stopping the states watch: '{err}'"));
+        if let Some(events) = agents_events {
+            self.ankaios
+                .unregister_event(events)
+                .await
+                .unwrap_or_else(|err| {
+                    sdk_log!(warn, "Error while unregistering the agents events: '{err}'");
+                });
+        }
+        if let Some(campaign) = log_campaign {
+            self.ankaios
+                .stop_receiving_logs(campaign)
+                .await
+                .unwrap_or_else(|err| {
+                    sdk_log!(warn, "Error while stopping the log campaign: '{err}'")
+                });
+        }
+        result
+    }
+}
+
+/// Forwards the log entries to the bridge, one by one.
+///
+/// ## Arguments
+///
+/// * `bridge` - The [`StateBridge`] to forward the log entries to;
+/// * `log_entries` - The received [`LogEntry`]s.
+///
+/// ## Errors
+///
+/// The first error returned by the bridge, after all the log entries were forwarded.
+async fn forward_log_entries<B: StateBridge>(
+    bridge: &mut B,
+    log_entries: Vec<LogEntry>,
+) -> Result<(), AnkaiosError> {
+    let mut first_error = None;
+    for log_entry in log_entries {
+        if let Err(err) = bridge.on_log_entry(log_entry).await {
+            first_error.get_or_insert(err);
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// Receives from the receiver, if any, or waits forever otherwise.
+///
+/// ## Arguments
+///
+/// * `receiver` - The optional [Receiver] to receive from.
+///
+/// ## Returns
+///
+/// The received value, or `None` if the channel was closed.
+async fn recv_if_some<T>(receiver: Option<&mut Receiver<T>>) -> Option<T> {
+    match receiver {
+        Some(receiver_ref) => receiver_ref.recv().await,
+        None => pending().await,
+    }
+}
+
+/// Creates the error returned when a subscription was closed.
+///
+/// ## Arguments
+///
+/// * `subscription` - The name of the closed subscription.
+///
+/// ## Returns
+///
+/// An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError).
+fn closed_error(subscription: &str) -> AnkaiosError {
+    AnkaiosError::ControlInterfaceError(format!("The {subscription} was closed."))
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use tokio::sync::mpsc;
+    use tokio::time::Duration;
+
+    use super::{StateBridge, StateBridgeConfig, StateBridgeRunner, forward_log_entries};
+    use crate::ankaios::generate_test_ankaios;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{EventsCancelRequest, EventsRequest, Request};
+    use crate::components::response::{Response, ResponseType};
+    use crate::{
+        AgentAttributes, AnkaiosError, CancellationToken, CompleteState, EventEntry, LogEntry,
+        WorkloadState,
+    };
+
+    struct TestBridge {
+        calls_sender: mpsc::UnboundedSender<String>,
+    }
+
+    #[async_trait]
+    impl StateBridge for TestBridge {
+        async fn on_state_change(
+            &mut self,
+            workload_state: WorkloadState,
+        ) -> Result<(), AnkaiosError> {
+            self.calls_sender
+                .send(format!(
+                    "state:{}",
+                    workload_state.workload_instance_name.workload_name
+                ))
+                .unwrap();
+            Ok(())
+        }
+
+        async fn on_agent_event(
+            &mut self,
+            agents: HashMap<String, AgentAttributes>,
+        ) -> Result<(), AnkaiosError> {
+            self.calls_sender
+                .send(format!("agents:{}", agents.len()))
+                .unwrap();
+            Err(AnkaiosError::ResponseError("publishing failed".to_owned()))
+        }
+    }
+
+    #[tokio::test]
+    async fn utest_forward_log_entries() {
+        let (calls_sender, mut calls_receiver) = mpsc::unbounded_channel();
+        let mut bridge = TestBridge { calls_sender };
+
+        assert!(
+            forward_log_entries(&mut bridge, vec![LogEntry::default(), LogEntry::default()])
+                .await
+                .is_ok()
+        );
+        assert!(calls_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn itest_state_bridge_runner() {
+        let (events_request_sender, mut events_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+        // Keeps the events campaigns open until the end of the test
+        let (events_senders_sender, _events_senders_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: EventsRequest| {
+                events_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: EventsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_events_campaign().times(2).returning(
+            move |_request_id: String, events_sender: mpsc::Sender<EventEntry>| {
+                events_senders_sender.send(events_sender).unwrap();
+            },
+        );
+        ci_mock
+            .expect_remove_events_campaign()
+            .times(2)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let runner = StateBridgeRunner::new(
+            ank,
+            StateBridgeConfig {
+                coalesce_window: Duration::ZERO,
+                ..Default::default()
+            },
+        );
+        let (calls_sender, mut calls_receiver) = mpsc::unbounded_channel();
+        let mut bridge = TestBridge { calls_sender };
+        let cancellation_token = CancellationToken::new();
+
+        let token_clone = cancellation_token.clone();
+        let method_handle =
+            tokio::spawn(async move { runner.run(&mut bridge, &token_clone).await });
+
+        // Answer the workload states and the agents events requests
+        for _ in 0..2 {
+            let request = events_request_receiver.recv().await.unwrap();
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                        generate_complete_state_proto(),
+                    ))),
+                })
+                .await
+                .unwrap();
+        }
+
+        let mut calls = Vec::new();
+        for _ in 0..4 {
+            calls.push(calls_receiver.recv().await.unwrap());
+        }
+        calls.sort();
+        assert_eq!(
+            calls,
+            vec![
+                "agents:1".to_owned(),
+                "state:dyn_nginx".to_owned(),
+                "state:nginx".to_owned(),
+                "state:nginx".to_owned(),
+            ]
+        );
+
+        // The error of the bridge is not fatal, cancel the runner
+        cancellation_token.cancel();
+        for _ in 0..2 {
+            let request = cancel_request_receiver.recv().await.unwrap();
+            response_sender
+                .send(Response {
+                    id: request.get_id(),
+                    content: ResponseType::EventsCancelAccepted,
+                })
+                .await
+                .unwrap();
+        }
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`AuditEntry`] struct, which records an update
+//! of the state issued through the SDK.
+
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+use crate::UpdateStateSuccess;
+
+/// Struct representing an update of the state issued through the SDK.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditEntry {
+    /// The time the update was sent.
+    pub timestamp: SystemTime,
+    /// The ID of the update request.
+    pub request_id: String,
+    /// The update masks of the request.
+    pub masks: Vec<String>,
+    /// The result of the update, containing the error message if it failed.
+    pub result: Result<UpdateStateSuccess, String>,
+}
+
+#[doc(hidden)]
+/// Bounded in-memory log of the [`AuditEntry`] objects, dropping the oldest entries when full.
+#[derive(Debug)]
+pub(crate) struct AuditLog {
+    /// The recorded entries, the oldest first.
+    entries: VecDeque<AuditEntry>,
+    /// The maximum number of entries kept.
+    max_entries: usize,
+}
+
+impl AuditLog {
+    /// Creates a new empty `AuditLog`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_entries` - The maximum number of entries kept.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`AuditLog`] instance.
+    pub(crate) fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries,
+        }
+    }
+
+    /// Changes the maximum number of entries, dropping the oldest entries if needed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_entries` - The maximum number of entries kept.
+    pub(crate) fn set_max_entries(&mut self, max_entries: usize) {
+        self.max_entries = max_entries;
+        self.truncate();
+    }
+
+    /// Records a new entry, dropping the oldest entry if the log is full.
+    ///
+    /// ## Arguments
+    ///
+    /// * `entry` - The [`AuditEntry`] to record.
+    pub(crate) fn record(&mut self, entry: AuditEntry) {
+        self.entries.push_back(entry);
+        self.truncate();
+    }
+
+    /// Returns a copy of the recorded entries, the oldest first.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [`AuditEntry`] objects.
+    pub(crate) fn entries(&self) -> Vec<AuditEntry> {
+        self.entries.iter().cloned().collect()
+    }
+
+    /// Drops the oldest entries exceeding the maximum number of entries.
+    fn truncate(&mut self) {
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditEntry, AuditLog};
+    use std::time::SystemTime;
+
+    fn generate_test_entry(request_id: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: SystemTime::now(),
+            request_id: request_id.to_owned(),
+            masks: vec!["desiredState.workloads.nginx".to_owned()],
+            result: Err("error".to_owned()),
+        }
+    }
+
+    #[test]
+    fn utest_audit_log() {
+        let mut audit_log = AuditLog::new(2);
+        audit_log.record(generate_test_entry("1"));
+        audit_log.record(generate_test_entry("2"));
+        audit_log.record(generate_test_entry("3"));
+        let ids: Vec<String> = audit_log
+            .entries()
+            .into_iter()
+            .map(|entry| entry.request_id)
+            .collect();
+        assert_eq!(ids, vec!["2", "3"]);
+
+        audit_log.set_max_entries(1);
+        assert_eq!(audit_log.entries().len(), 1);
+        assert_eq!(audit_log.entries()[0].request_id, "3");
+    }
+}
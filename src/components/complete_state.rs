@@ -15,17 +15,22 @@
 //! This module contains the [`CompleteState`] and [`AgentAttributes`] structs.
 
 use serde_yaml::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::ankaios_api;
 use crate::components::manifest::Manifest;
 use crate::components::workload_mod::Workload;
-use crate::components::workload_state_mod::WorkloadStateCollection;
+use crate::components::workload_state_mod::{
+    WorkloadExecutionState, WorkloadInstanceName, WorkloadStateCollection,
+};
 use crate::extensions::UnreachableOption;
+use crate::logging::sdk_log;
 use ankaios_api::ank_base;
 
 /// The API version supported by Ankaios.
-const SUPPORTED_API_VERSION: &str = "v1";
+pub(crate) const SUPPORTED_API_VERSION: &str = "v1";
+/// All the API versions of the desired state supported by the SDK.
+pub const SUPPORTED_API_VERSIONS: &[&str] = &[SUPPORTED_API_VERSION];
 
 /// Struct encapsulating the complete state of the [Ankaios] system.
 ///
@@ -111,7 +116,7 @@ pub struct CompleteState {
 /// Struct containing the attributes of an agent of the [Ankaios] system.
 ///
 /// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct AgentAttributes {
     /// A map of custom tags as key-value pairs.
     pub tags: HashMap<String, String>,
@@ -119,6 +124,31 @@ pub struct AgentAttributes {
     pub status: HashMap<String, String>,
 }
 
+/// The connectivity of an agent, see [`AgentInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentStatus {
+    /// The agent is connected and reports its attributes.
+    Connected,
+    /// The agent has workloads assigned, but is not connected.
+    NotReporting,
+}
+
+/// Struct describing an agent of the [Ankaios] system, including agents that
+/// have workloads assigned but are not connected.
+///
+/// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentInfo {
+    /// The name of the agent.
+    pub name: String,
+    /// The connectivity of the agent.
+    pub status: AgentStatus,
+    /// The attributes of the agent, empty if it is not connected.
+    pub attributes: AgentAttributes,
+    /// The number of workload instances assigned to the agent.
+    pub workload_count: usize,
+}
+
 impl CompleteState {
     /// Creates a new `CompleteState` object.
     ///
@@ -150,9 +180,13 @@ impl CompleteState {
         obj
     }
 
-    #[doc(hidden)]
+    #[cfg_attr(not(feature = "proto-types"), doc(hidden))]
     /// Creates a new `CompleteState` object from a [ank_base::CompleteState].
     ///
+    /// Together with [`to_proto`](CompleteState::to_proto), this allows persisting
+    /// state snapshots in protobuf form. The proto types are available with the
+    /// `proto-types` feature.
+    ///
     /// ## Arguments
     ///
     /// * `proto` - The [ank_base::CompleteState] to create the [`CompleteState`] from.
@@ -160,7 +194,8 @@ impl CompleteState {
     /// ## Returns
     ///
     /// A new [`CompleteState`] instance.
-    pub(crate) fn new_from_proto(proto: ank_base::CompleteState) -> Self {
+    #[must_use]
+    pub fn new_from_proto(proto: ank_base::CompleteState) -> Self {
         let mut obj = Self::new();
         obj.complete_state = proto;
         obj
@@ -212,6 +247,7 @@ impl CompleteState {
     }
 
     /// Converts the `CompleteState` to a [`serde_yaml::Mapping`].
+    /// The entries of the maps are sorted by key, so the output is deterministic.
     ///
     /// ## Returns
     ///
@@ -224,7 +260,9 @@ impl CompleteState {
             Value::String(self.get_api_version()),
         );
         let mut workloads = serde_yaml::Mapping::new();
-        for workload in self.get_workloads() {
+        let mut sorted_workloads = self.get_workloads();
+        sorted_workloads.sort_by(|first, second| first.name.cmp(&second.name));
+        for workload in sorted_workloads {
             workloads.insert(
                 Value::String(workload.name.clone()),
                 Value::Mapping(workload.to_dict()),
@@ -235,12 +273,14 @@ impl CompleteState {
             Value::Mapping(workloads),
         );
         let mut configs = serde_yaml::Mapping::new();
-        for (k, v) in self.get_configs() {
+        for (k, v) in self.get_configs().into_iter().collect::<BTreeMap<_, _>>() {
             configs.insert(Value::String(k), v);
         }
         dict.insert(Value::String("configs".to_owned()), Value::Mapping(configs));
         let mut agents = serde_yaml::Mapping::new();
-        for (agent_name, agent_attributes) in self.get_agents() {
+        for (agent_name, agent_attributes) in
+            self.get_agents().into_iter().collect::<BTreeMap<_, _>>()
+        {
             agents.insert(
                 Value::String(agent_name),
                 Value::Mapping(agent_attributes.to_dict()),
@@ -254,13 +294,14 @@ impl CompleteState {
         dict
     }
 
-    #[doc(hidden)]
+    #[cfg_attr(not(feature = "proto-types"), doc(hidden))]
     /// Converts the `CompleteState` to a [ank_base::CompleteState].
     ///
     /// ## Returns
     ///
     /// A [ank_base::CompleteState] containing the `CompleteState` information.
-    pub(crate) fn to_proto(&self) -> ank_base::CompleteState {
+    #[must_use]
+    pub fn to_proto(&self) -> ank_base::CompleteState {
         self.complete_state.clone()
     }
 
@@ -292,7 +333,7 @@ impl CompleteState {
         if let Some(state) = self.complete_state.desired_state.as_ref() {
             state.api_version.clone()
         } else {
-            log::error!("Error: desired_state is None");
+            sdk_log!(error, "Error: desired_state is None");
             String::new()
         }
     }
@@ -351,6 +392,61 @@ impl CompleteState {
         WorkloadStateCollection::new()
     }
 
+    /// Gets the execution state of a workload instance from the `CompleteState`.
+    /// Unlike [`get_workload_states`](CompleteState::get_workload_states), only the requested
+    /// state is converted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agent_name` - The name of the agent of the workload;
+    /// * `workload_name` - The name of the workload;
+    /// * `workload_id` - The id of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadExecutionState`] of the workload instance, [None] if it is not found.
+    #[must_use]
+    pub fn get_workload_state(
+        &self,
+        agent_name: &str,
+        workload_name: &str,
+        workload_id: &str,
+    ) -> Option<WorkloadExecutionState> {
+        let execution_state = self
+            .complete_state
+            .workload_states
+            .as_ref()?
+            .agent_state_map
+            .get(agent_name)?
+            .wl_name_state_map
+            .get(workload_name)?
+            .id_state_map
+            .get(workload_id)?;
+        Some(WorkloadExecutionState::new(execution_state.clone()))
+    }
+
+    /// Gets the execution state of a workload instance from the `CompleteState`,
+    /// see [`get_workload_state`](CompleteState::get_workload_state).
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadExecutionState`] of the workload instance, [None] if it is not found.
+    #[must_use]
+    pub fn get_workload_state_for_instance_name(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Option<WorkloadExecutionState> {
+        self.get_workload_state(
+            &instance_name.agent_name,
+            &instance_name.workload_name,
+            &instance_name.workload_id,
+        )
+    }
+
     /// Gets the connected agents from the `CompleteState`.
     ///
     /// ## Returns
@@ -367,6 +463,69 @@ impl CompleteState {
         agents
     }
 
+    /// Gets all the agents known from the `CompleteState`: the connected agents and
+    /// the agents having workloads in the desired state or the workload states.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [`AgentInfo`] sorted by agent name.
+    #[must_use]
+    pub fn get_agents_detailed(&self) -> Vec<AgentInfo> {
+        let mut agents: BTreeMap<String, AgentInfo> = self
+            .get_agents()
+            .into_iter()
+            .map(|(name, attributes)| {
+                let agent_info = AgentInfo {
+                    name: name.clone(),
+                    status: AgentStatus::Connected,
+                    attributes,
+                    workload_count: 0,
+                };
+                (name, agent_info)
+            })
+            .collect();
+        let not_reporting = |agent_name: &str| AgentInfo {
+            name: agent_name.to_owned(),
+            status: AgentStatus::NotReporting,
+            attributes: AgentAttributes::default(),
+            workload_count: 0,
+        };
+
+        for workload_state in self.get_workload_states().as_list() {
+            let agent_name = workload_state.workload_instance_name.agent_name;
+            agents
+                .entry(agent_name.clone())
+                .or_insert_with(|| not_reporting(&agent_name))
+                .workload_count += 1;
+        }
+        for workload in self.get_workloads() {
+            if let Some(agent_name) = workload.workload.agent.filter(|name| !name.is_empty()) {
+                agents
+                    .entry(agent_name.clone())
+                    .or_insert_with(|| not_reporting(&agent_name));
+            }
+        }
+        agents.into_values().collect()
+    }
+
+    /// Gets the agents known from the `CompleteState` that fulfill a condition,
+    /// e.g. having a tag or enough free memory to place a workload on them.
+    ///
+    /// ## Arguments
+    ///
+    /// * `predicate` - The condition the [`AgentInfo`] has to fulfill.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of the matching [`AgentInfo`] sorted by agent name, see [`get_agents_detailed`](CompleteState::get_agents_detailed).
+    #[must_use]
+    pub fn get_agents_matching<P: Fn(&AgentInfo) -> bool>(&self, predicate: P) -> Vec<AgentInfo> {
+        self.get_agents_detailed()
+            .into_iter()
+            .filter(|agent_info| predicate(agent_info))
+            .collect()
+    }
+
     /// Sets the tags for a specific agent in the `CompleteState`.
     ///
     /// ## Arguments
@@ -398,39 +557,6 @@ impl CompleteState {
     ///
     /// * `configs` - A [`HashMap`] containing the configurations.
     fn set_configs(&mut self, configs: HashMap<String, Value>) {
-        fn to_config_item(value: &Value) -> ank_base::ConfigItem {
-            match value {
-                Value::String(val) => ank_base::ConfigItem {
-                    config_item_enum: Some(ank_base::ConfigItemEnum::String(val.clone())),
-                },
-                Value::Sequence(val) => ank_base::ConfigItem {
-                    config_item_enum: Some(ank_base::ConfigItemEnum::Array(
-                        ank_base::ConfigArray {
-                            values: val.iter().map(to_config_item).collect(),
-                        },
-                    )),
-                },
-                Value::Mapping(val) => ank_base::ConfigItem {
-                    config_item_enum: Some(ank_base::ConfigItemEnum::Object(
-                        ank_base::ConfigObject {
-                            fields: val
-                                .iter()
-                                .map(|(k, v)| {
-                                    (
-                                        k.as_str().unwrap_or_unreachable().to_owned(),
-                                        to_config_item(v),
-                                    )
-                                })
-                                .collect(),
-                        },
-                    )),
-                },
-                _ => ank_base::ConfigItem {
-                    config_item_enum: None,
-                },
-            }
-        }
-
         if let Some(desired_state) = self.complete_state.desired_state.as_mut() {
             if desired_state.configs.is_none() {
                 desired_state.configs = Some(ank_base::ConfigMap {
@@ -440,7 +566,7 @@ impl CompleteState {
             if let Some(state_configs) = desired_state.configs.as_mut() {
                 state_configs.configs = configs
                     .iter()
-                    .map(|(k, v)| (k.clone(), to_config_item(v)))
+                    .map(|(k, v)| (k.clone(), config_item_from_value(v)))
                     .collect();
                 drop(configs); // Consume configs
             }
@@ -454,27 +580,12 @@ impl CompleteState {
     /// A [`HashMap`] containing the configurations.
     #[must_use]
     pub fn get_configs(&self) -> HashMap<String, Value> {
-        fn from_config_item(config_item: &ank_base::ConfigItem) -> Value {
-            match &config_item.config_item_enum {
-                Some(ank_base::ConfigItemEnum::String(val)) => Value::String(val.clone()),
-                Some(ank_base::ConfigItemEnum::Array(val)) => {
-                    Value::Sequence(val.values.iter().map(from_config_item).collect())
-                }
-                Some(ank_base::ConfigItemEnum::Object(val)) => Value::Mapping(
-                    val.fields
-                        .iter()
-                        .map(|(k, v)| (Value::String(k.clone()), from_config_item(v)))
-                        .collect(),
-                ),
-                None => Value::Null,
-            }
-        }
         if let Some(desired_state) = self.complete_state.desired_state.as_ref() {
             if let Some(configs) = desired_state.configs.as_ref() {
                 return configs
                     .configs
                     .iter()
-                    .map(|(k, v)| (k.clone(), from_config_item(v)))
+                    .map(|(k, v)| (k.clone(), config_item_to_value(v)))
                     .collect();
             }
         }
@@ -482,6 +593,72 @@ impl CompleteState {
     }
 }
 
+/// Converts a config value to a [`ank_base::ConfigItem`].
+/// Values other than strings, sequences and mappings are converted to empty items.
+///
+/// ## Arguments
+///
+/// * `value` - The [Value] of the config.
+///
+/// ## Returns
+///
+/// The [`ank_base::ConfigItem`] representing the value.
+pub(crate) fn config_item_from_value(value: &Value) -> ank_base::ConfigItem {
+    match value {
+        Value::String(val) => ank_base::ConfigItem {
+            config_item_enum: Some(ank_base::ConfigItemEnum::String(val.clone())),
+        },
+        Value::Sequence(val) => ank_base::ConfigItem {
+            config_item_enum: Some(ank_base::ConfigItemEnum::Array(ank_base::ConfigArray {
+                values: val.iter().map(config_item_from_value).collect(),
+            })),
+        },
+        Value::Mapping(val) => ank_base::ConfigItem {
+            config_item_enum: Some(ank_base::ConfigItemEnum::Object(ank_base::ConfigObject {
+                fields: val
+                    .iter()
+                    .map(|(k, v)| {
+                        (
+                            k.as_str().unwrap_or_unreachable().to_owned(),
+                            config_item_from_value(v),
+                        )
+                    })
+                    .collect(),
+            })),
+        },
+        _ => ank_base::ConfigItem {
+            config_item_enum: None,
+        },
+    }
+}
+
+/// Converts a [`ank_base::ConfigItem`] to a config value.
+///
+/// ## Arguments
+///
+/// * `config_item` - The [`ank_base::ConfigItem`] of the config.
+///
+/// ## Returns
+///
+/// The [Value] representing the config item.
+pub(crate) fn config_item_to_value(config_item: &ank_base::ConfigItem) -> Value {
+    match &config_item.config_item_enum {
+        Some(ank_base::ConfigItemEnum::String(val)) => Value::String(val.clone()),
+        Some(ank_base::ConfigItemEnum::Array(val)) => {
+            Value::Sequence(val.values.iter().map(config_item_to_value).collect())
+        }
+        Some(ank_base::ConfigItemEnum::Object(val)) => Value::Mapping(
+            val.fields
+                .iter()
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .map(|(k, v)| (Value::String(k.clone()), config_item_to_value(v)))
+                .collect(),
+        ),
+        None => Value::Null,
+    }
+}
+
 impl AgentAttributes {
     #[doc(hidden)]
     /// Creates a new `AgentAttributes` object from a [ank_base::AgentAttributes].
@@ -523,6 +700,40 @@ impl AgentAttributes {
         obj
     }
 
+    /// Gets the value of a tag of the agent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` - The key of the tag.
+    ///
+    /// ## Returns
+    ///
+    /// The value of the tag, [None] if the agent does not have it.
+    #[must_use]
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+
+    /// Gets the CPU usage reported by the agent.
+    ///
+    /// ## Returns
+    ///
+    /// The CPU usage in percent, [None] if the agent did not report it.
+    #[must_use]
+    pub fn cpu_usage(&self) -> Option<u32> {
+        self.status.get("cpu_usage")?.parse().ok()
+    }
+
+    /// Gets the free memory reported by the agent.
+    ///
+    /// ## Returns
+    ///
+    /// The free memory in bytes, [None] if the agent did not report it.
+    #[must_use]
+    pub fn free_memory(&self) -> Option<u64> {
+        self.status.get("free_memory")?.parse().ok()
+    }
+
     /// Converts the `AgentAttributes` to a [`serde_yaml::Mapping`].
     ///
     /// ## Returns
@@ -533,13 +744,13 @@ impl AgentAttributes {
         let mut dict = serde_yaml::Mapping::new();
 
         let mut tags_dict = serde_yaml::Mapping::new();
-        for (k, v) in &self.tags {
+        for (k, v) in self.tags.iter().collect::<BTreeMap<_, _>>() {
             tags_dict.insert(Value::String(k.clone()), Value::String(v.clone()));
         }
         dict.insert(Value::String("tags".to_owned()), Value::Mapping(tags_dict));
 
         let mut status_dict = serde_yaml::Mapping::new();
-        for (k, v) in &self.status {
+        for (k, v) in self.status.iter().collect::<BTreeMap<_, _>>() {
             status_dict.insert(Value::String(k.clone()), Value::String(v.clone()));
         }
         dict.insert(
@@ -583,13 +794,13 @@ impl From<ank_base::AgentAttributes> for AgentAttributes {
 //                    ##     #######   #########      ##                    //
 //////////////////////////////////////////////////////////////////////////////
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 use crate::components::workload_mod::test_helpers::generate_test_workload_proto;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 use crate::components::workload_state_mod::generate_test_workload_states_proto;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub fn generate_test_configs_proto() -> ank_base::ConfigMap {
     ank_base::ConfigMap {
         configs: HashMap::from([
@@ -651,7 +862,7 @@ pub fn generate_test_configs_proto() -> ank_base::ConfigMap {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 fn generate_agents_proto() -> ank_base::AgentMap {
     ank_base::AgentMap {
         agents: HashMap::from([(
@@ -669,7 +880,7 @@ fn generate_agents_proto() -> ank_base::AgentMap {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub fn generate_complete_state_proto() -> ank_base::CompleteState {
     ank_base::CompleteState {
         desired_state: Some(ank_base::State {
@@ -692,7 +903,10 @@ mod tests {
     use serde_yaml::Value;
     use std::collections::HashMap;
 
-    use super::{CompleteState, SUPPORTED_API_VERSION, generate_complete_state_proto};
+    use super::{
+        AgentAttributes, AgentStatus, CompleteState, SUPPORTED_API_VERSION,
+        generate_complete_state_proto,
+    };
     use crate::components::manifest::generate_test_manifest;
     use crate::components::workload_mod::test_helpers::generate_test_workload;
     use crate::components::workload_state_mod::WorkloadInstanceName;
@@ -712,6 +926,19 @@ mod tests {
         assert_eq!(complete_state, other_complete_state);
     }
 
+    #[cfg(feature = "proto-types")]
+    #[test]
+    fn utest_proto_encoding() {
+        use prost::Message;
+
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+        let encoded = complete_state.to_proto().encode_to_vec();
+        let decoded = CompleteState::new_from_proto(
+            crate::proto::ank_base::CompleteState::decode(encoded.as_slice()).unwrap(),
+        );
+        assert_eq!(complete_state, decoded);
+    }
+
     #[test]
     fn utest_from_manifest() {
         let manifest = generate_test_manifest();
@@ -844,6 +1071,21 @@ mod tests {
             .as_mapping()
             .unwrap();
         assert_eq!(workload_states_agent_b.len(), 2);
+
+        // The entries are sorted by key
+        assert_eq!(
+            configs.keys().collect::<Vec<_>>(),
+            vec!["config1", "config2", "config3"]
+        );
+        assert_eq!(
+            workload_states.keys().collect::<Vec<_>>(),
+            vec!["agent_A", "agent_B"]
+        );
+        assert_eq!(
+            agent_a_status.keys().collect::<Vec<_>>(),
+            vec!["cpu_usage", "free_memory"]
+        );
+        assert_eq!(complete_state.to_dict(), complete_state_dict);
     }
 
     #[test]
@@ -864,6 +1106,23 @@ mod tests {
                 .get_for_instance_name(&workload_instance_name)
                 .is_some()
         );
+
+        assert_eq!(
+            complete_state.get_workload_state_for_instance_name(&workload_instance_name),
+            workload_states
+                .get_for_instance_name(&workload_instance_name)
+                .cloned()
+        );
+        assert!(
+            complete_state
+                .get_workload_state("agent_A", "nginx", "5678")
+                .is_none()
+        );
+        assert!(
+            CompleteState::new()
+                .get_workload_state("agent_A", "nginx", "1234")
+                .is_none()
+        );
     }
 
     #[test]
@@ -893,4 +1152,53 @@ mod tests {
         assert_eq!(agent_a.status.get("cpu_usage"), Some(&"50".to_owned()));
         assert_eq!(agent_a.status.get("free_memory"), Some(&"1024".to_owned()));
     }
+
+    #[test]
+    fn utest_get_agents_matching() {
+        let complete_state = CompleteState::from(generate_complete_state_proto());
+
+        let agent_a = &complete_state.get_agents()["agent_A"];
+        assert_eq!(agent_a.tag("tag_key"), Some("tag_value"));
+        assert_eq!(agent_a.tag("missing"), None);
+        assert_eq!(agent_a.cpu_usage(), Some(50));
+        assert_eq!(agent_a.free_memory(), Some(1024));
+        assert_eq!(AgentAttributes::default().cpu_usage(), None);
+
+        let tagged = complete_state
+            .get_agents_matching(|agent| agent.attributes.tag("tag_key") == Some("tag_value"));
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].name, "agent_A");
+
+        let not_reporting =
+            complete_state.get_agents_matching(|agent| agent.status == AgentStatus::NotReporting);
+        assert_eq!(not_reporting.len(), 1);
+        assert_eq!(not_reporting[0].name, "agent_B");
+
+        assert!(
+            complete_state
+                .get_agents_matching(|agent| agent
+                    .attributes
+                    .free_memory()
+                    .is_some_and(|free_memory| free_memory > 2048))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn utest_get_agents_detailed() {
+        let complete_state = CompleteState::from(generate_complete_state_proto());
+
+        let agents = complete_state.get_agents_detailed();
+        assert_eq!(agents.len(), 2);
+
+        assert_eq!(agents[0].name, "agent_A");
+        assert_eq!(agents[0].status, AgentStatus::Connected);
+        assert_eq!(agents[0].attributes, complete_state.get_agents()["agent_A"]);
+        assert_eq!(agents[0].workload_count, 1);
+
+        assert_eq!(agents[1].name, "agent_B");
+        assert_eq!(agents[1].status, AgentStatus::NotReporting);
+        assert_eq!(agents[1].attributes, AgentAttributes::default());
+        assert_eq!(agents[1].workload_count, 2);
+    }
 }
@@ -0,0 +1,207 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`ConfigValue`] struct, which is used to
+//! provide the values of the configs from different sources.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ankaios_sdk::ConfigValue;
+//! use std::collections::HashMap;
+//!
+//! let from_json = ConfigValue::from(serde_json::json!({"port": 8080, "debug": true}));
+//! let from_map = ConfigValue::from(HashMap::from([
+//!     ("port".to_owned(), ConfigValue::from(8080)),
+//!     ("debug".to_owned(), ConfigValue::from(true)),
+//! ]));
+//! assert_eq!(from_json, from_map);
+//! ```
+
+use serde_yaml::{Mapping, Number, Value};
+use std::collections::HashMap;
+
+/// A value of a config, convertible from [`serde_yaml::Value`], [`serde_json::Value`]
+/// and primitive, [Vec] and [`HashMap`] types.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigValue(Value);
+
+impl ConfigValue {
+    /// Returns the config value as a [`serde_yaml::Value`].
+    ///
+    /// ## Returns
+    ///
+    /// The underlying [`serde_yaml::Value`].
+    #[must_use]
+    pub fn into_inner(self) -> Value {
+        self.0
+    }
+}
+
+impl From<ConfigValue> for Value {
+    fn from(config_value: ConfigValue) -> Self {
+        config_value.0
+    }
+}
+
+impl From<Value> for ConfigValue {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+impl From<serde_json::Value> for ConfigValue {
+    fn from(value: serde_json::Value) -> Self {
+        Self(match value {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(boolean) => Value::Bool(boolean),
+            serde_json::Value::Number(number) => {
+                if let Some(unsigned) = number.as_u64() {
+                    Value::Number(Number::from(unsigned))
+                } else if let Some(signed) = number.as_i64() {
+                    Value::Number(Number::from(signed))
+                } else {
+                    Value::Number(Number::from(number.as_f64().unwrap_or(f64::NAN)))
+                }
+            }
+            serde_json::Value::String(string) => Value::String(string),
+            serde_json::Value::Array(array) => {
+                Value::Sequence(array.into_iter().map(|item| Self::from(item).0).collect())
+            }
+            serde_json::Value::Object(object) => Value::Mapping(
+                object
+                    .into_iter()
+                    .map(|(key, item)| (Value::String(key), Self::from(item).0))
+                    .collect::<Mapping>(),
+            ),
+        })
+    }
+}
+
+impl From<String> for ConfigValue {
+    fn from(value: String) -> Self {
+        Self(Value::String(value))
+    }
+}
+
+impl From<&str> for ConfigValue {
+    fn from(value: &str) -> Self {
+        Self(Value::String(value.to_owned()))
+    }
+}
+
+impl From<bool> for ConfigValue {
+    fn from(value: bool) -> Self {
+        Self(Value::Bool(value))
+    }
+}
+
+impl From<i32> for ConfigValue {
+    fn from(value: i32) -> Self {
+        Self(Value::Number(Number::from(value)))
+    }
+}
+
+impl From<i64> for ConfigValue {
+    fn from(value: i64) -> Self {
+        Self(Value::Number(Number::from(value)))
+    }
+}
+
+impl From<u32> for ConfigValue {
+    fn from(value: u32) -> Self {
+        Self(Value::Number(Number::from(value)))
+    }
+}
+
+impl From<u64> for ConfigValue {
+    fn from(value: u64) -> Self {
+        Self(Value::Number(Number::from(value)))
+    }
+}
+
+impl From<f64> for ConfigValue {
+    fn from(value: f64) -> Self {
+        Self(Value::Number(Number::from(value)))
+    }
+}
+
+impl<T: Into<ConfigValue>> From<Vec<T>> for ConfigValue {
+    fn from(values: Vec<T>) -> Self {
+        Self(Value::Sequence(
+            values.into_iter().map(|item| item.into().0).collect(),
+        ))
+    }
+}
+
+impl<T: Into<ConfigValue>> From<HashMap<String, T>> for ConfigValue {
+    fn from(values: HashMap<String, T>) -> Self {
+        Self(Value::Mapping(
+            values
+                .into_iter()
+                .map(|(key, item)| (Value::String(key), item.into().0))
+                .collect::<Mapping>(),
+        ))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigValue;
+    use serde_yaml::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn utest_config_value_from_json() {
+        let config_value = ConfigValue::from(serde_json::json!({
+            "name": "test",
+            "enabled": true,
+            "count": 3,
+            "offset": -2,
+            "ratio": 0.5,
+            "items": ["a", null],
+        }));
+        let expected: Value = serde_yaml::from_str(
+            "name: test\nenabled: true\ncount: 3\noffset: -2\nratio: 0.5\nitems: [a, null]",
+        )
+        .unwrap();
+        assert_eq!(config_value.into_inner(), expected);
+    }
+
+    #[test]
+    fn utest_config_value_from_primitives() {
+        assert_eq!(
+            Value::from(ConfigValue::from("value")),
+            Value::String("value".to_owned())
+        );
+        assert_eq!(ConfigValue::from(true).into_inner(), Value::Bool(true));
+        assert_eq!(ConfigValue::from(42_u32).into_inner(), Value::from(42));
+        assert_eq!(
+            ConfigValue::from(vec![1, 2]).into_inner(),
+            Value::Sequence(vec![Value::from(1), Value::from(2)])
+        );
+        assert_eq!(
+            ConfigValue::from(HashMap::from([("key".to_owned(), "value")])).into_inner(),
+            serde_yaml::from_str::<Value>("key: value").unwrap()
+        );
+    }
+}
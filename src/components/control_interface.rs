@@ -12,31 +12,46 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-//! This module contains the [`ControlInterface`] struct and the [`ControlInterfaceState`] enum.
+//! This module contains the [`ControlInterface`] struct, the [`ControlInterfaceState`] enum
+//! and the [`ConnectionInfo`] struct.
 
 use prost::{Message, encoding::decode_varint};
 use std::{
     collections::HashMap,
     fs::metadata,
-    path::Path,
-    sync::{Arc, Mutex},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::SystemTime,
 };
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter, Error, ErrorKind},
+    io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter, Error, ErrorKind},
     net::unix::pipe,
     spawn,
-    sync::mpsc,
+    sync::{broadcast, mpsc},
     task::JoinHandle,
     time::{Duration, sleep, timeout as tokio_timeout},
 };
+use tokio_util::sync::CancellationToken;
 
+use crate::ankaios::CHANNEL_SIZE;
+use crate::components::control_socket::{ConnectionReader, ConnectionWriter, ControlSocketAddress};
 use crate::components::event_types::EventEntry;
-use crate::components::log_types::{LogEntry, LogResponse};
+use crate::components::log_types::{LogEntry, LogResponse, rfc3339_before_now};
 use crate::components::request::Request;
-use crate::components::response::{Response, ResponseType};
+use crate::components::response::{Response, ResponseType, TappedResponse};
 use crate::components::workload_state_mod::WorkloadInstanceName;
+use crate::logging::sdk_log;
 use crate::{AnkaiosError, ankaios_api};
-use ankaios_api::control_api::{FromAnkaios, Hello, ToAnkaios, to_ankaios::ToAnkaiosEnum};
+use ankaios_api::ank_base::{self, request::RequestContent};
+use ankaios_api::control_api::{
+    FromAnkaios, Hello, ToAnkaios, from_ankaios::FromAnkaiosEnum, to_ankaios::ToAnkaiosEnum,
+};
+
+#[cfg(feature = "unstable-proto")]
+use std::collections::HashSet;
 
 #[cfg(test)]
 use mockall::automock;
@@ -52,6 +67,47 @@ const ANKAIOS_OUTPUT_FIFO_PATH: &str = "output";
 const ANKAIOS_VERSION: &str = "1.0.0";
 /// Maximum size of a varint in bytes.
 const MAX_VARINT_SIZE: usize = 19;
+/// Maximum size in bytes of a single message sent through the control interface.
+pub const MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+/// Size in bytes above which a warning is logged before sending a message.
+const MESSAGE_SIZE_WARNING_THRESHOLD: usize = MAX_MESSAGE_SIZE / 10 * 8;
+/// Maximum time for writing the queued messages when the writer task is stopped.
+const WRITER_FLUSH_TIMEOUT: Duration = Duration::from_millis(500);
+/// The default number of messages queued for the writer task.
+pub(crate) const WRITER_QUEUE_SIZE: usize = 5;
+
+/// Checks that a message fits into the control interface message limits.
+///
+/// A warning is logged if the message is close to the limit.
+///
+/// ## Arguments
+///
+/// * `request_id` - The ID of the request contained in the message;
+/// * `size` - The encoded size of the message in bytes.
+///
+/// ## Returns
+///
+/// An [`AnkaiosError`]::[`MessageTooLargeError`](AnkaiosError::MessageTooLargeError) if the message exceeds [`MAX_MESSAGE_SIZE`].
+fn check_message_size(request_id: &str, size: usize) -> Result<(), AnkaiosError> {
+    if size > MAX_MESSAGE_SIZE {
+        sdk_log!(
+            error,
+            "Request '{request_id}' has {size} bytes, exceeding the maximum of {MAX_MESSAGE_SIZE} bytes."
+        );
+        return Err(AnkaiosError::MessageTooLargeError(
+            request_id.to_owned(),
+            size,
+            MAX_MESSAGE_SIZE,
+        ));
+    }
+    if size > MESSAGE_SIZE_WARNING_THRESHOLD {
+        sdk_log!(
+            warn,
+            "Request '{request_id}' has {size} bytes, close to the maximum of {MAX_MESSAGE_SIZE} bytes."
+        );
+    }
+    Ok(())
+}
 
 /// Enum representing the state of the control interface.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -69,6 +125,29 @@ pub enum ControlInterfaceState {
     ConnectionClosed = 5,
 }
 
+/// Struct describing the connection to the control interface, intended for diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// The path to the FIFO pipes directory.
+    pub base_path: PathBuf,
+    /// The path to the input FIFO pipe, read by the SDK.
+    pub input_fifo: PathBuf,
+    /// The path to the output FIFO pipe, written by the SDK.
+    pub output_fifo: PathBuf,
+    /// The address of the socket used when the FIFO pipes do not exist.
+    pub control_socket: Option<ControlSocketAddress>,
+    /// The protocol version sent in the initial hello message.
+    pub protocol_version: String,
+    /// The current state of the control interface.
+    pub state: ControlInterfaceState,
+    /// The time the connection was established, [None] if not connected.
+    pub connected_at: Option<SystemTime>,
+    /// The number of times the agent reconnected since the connection was established.
+    pub reconnect_count: usize,
+    /// The reason of the last failed connection attempt or of the closed connection.
+    pub last_error: Option<String>,
+}
+
 #[doc(hidden)]
 #[derive(Debug, Clone)]
 struct SynchronizedSenderMap<T> {
@@ -133,14 +212,109 @@ impl<T> Default for SynchronizedSenderMap<T> {
     }
 }
 
+#[doc(hidden)]
+/// The IDs of the raw requests waiting for a response, which is forwarded without being converted.
+#[cfg(feature = "unstable-proto")]
+#[derive(Clone, Debug, Default)]
+pub struct RawRequestIds {
+    /// The IDs of the raw requests.
+    request_ids: Arc<Mutex<HashSet<String>>>,
+}
+
+#[cfg(feature = "unstable-proto")]
+impl RawRequestIds {
+    /// Adds the ID of a raw request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - A [String] representing the request ID of the raw request.
+    ///
+    /// ## Returns
+    ///
+    /// A [`RawRequestGuard`] removing the ID again when dropped.
+    pub fn insert(&self, request_id: String) -> RawRequestGuard {
+        self.request_ids
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(request_id.clone());
+        RawRequestGuard {
+            raw_request_ids: self.clone(),
+            request_id,
+        }
+    }
+
+    /// Checks whether an ID belongs to a raw request waiting for a response.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - A [&str] representing the request ID.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the ID belongs to a raw request.
+    #[cfg(test)]
+    pub fn contains(&self, request_id: &str) -> bool {
+        self.request_ids
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .contains(request_id)
+    }
+
+    /// Removes the ID of a raw request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - A [&str] representing the request ID.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the ID belonged to a raw request.
+    fn remove(&self, request_id: &str) -> bool {
+        self.request_ids
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(request_id)
+    }
+}
+
+#[doc(hidden)]
+/// Removes a raw request from the raw requests of the control interface when dropped,
+/// also if the request is cancelled before its response arrives.
+#[cfg(feature = "unstable-proto")]
+#[derive(Debug)]
+pub struct RawRequestGuard {
+    /// The IDs of the raw requests.
+    raw_request_ids: RawRequestIds,
+    /// The ID of the guarded raw request.
+    request_id: String,
+}
+
+#[cfg(feature = "unstable-proto")]
+impl Drop for RawRequestGuard {
+    fn drop(&mut self) {
+        if self.raw_request_ids.remove(&self.request_id) {
+            sdk_log!(
+                trace,
+                "Removed raw request with request id: '{}'",
+                self.request_id
+            );
+        }
+    }
+}
+
 /// This struct handles the interaction with the control interface.
 /// It provides means to send and receive messages through the FIFO pipes.
 ///
 /// It uses two [tokio] tasks, one for reading from the input FIFO and one for
 /// writing to the output FIFO.
+///
+/// If the FIFO pipes do not exist but a [control socket](ControlSocketAddress) is configured,
+/// the same messages are exchanged through the socket instead.
 pub struct ControlInterface {
     /// Path to the FIFO pipes directory.
     pub(crate) path: String,
+    /// Address of the socket used when the FIFO pipes do not exist.
+    pub(crate) control_socket: Option<ControlSocketAddress>,
     /// Output file for writing to the control interface.
     output_file: Option<pipe::Sender>,
     /// Handler for the read thread.
@@ -151,12 +325,43 @@ pub struct ControlInterface {
     state: Arc<Mutex<ControlInterfaceState>>,
     /// Sender for the response channel.
     response_sender: mpsc::Sender<Response>,
+    /// Protocol version sent in the initial hello message.
+    protocol_version: String,
+    /// Reason received from Ankaios when the connection was closed.
+    connection_closed_reason: Arc<Mutex<Option<String>>>,
+    /// The time the connection was established.
+    connected_at: Option<SystemTime>,
+    /// The number of times the agent reconnected since the connection was established.
+    reconnect_count: Arc<AtomicUsize>,
+    /// The error of the last failed connection attempt.
+    last_error: Option<String>,
     /// Sender for the writer channel.
     writer_ch_sender: Option<mpsc::Sender<ToAnkaios>>,
+    /// Sender for the priority channel of the writer, used for the hellos and the resubscriptions.
+    /// It is unbounded, so the writer task can feed it without waiting for itself.
+    priority_ch_sender: Option<mpsc::UnboundedSender<ToAnkaios>>,
+    /// The number of messages queued for the writer task.
+    writer_queue_size: usize,
     /// Request ID to logs sender mapping
     log_senders_map: SynchronizedSenderMap<LogResponse>,
+    /// Request ID to logs request mapping, used to resubscribe after the agent reconnected.
+    /// The requests resume after the last received log entries.
+    log_requests_map: Arc<Mutex<HashMap<String, ank_base::Request>>>,
+    /// Whether the agent was disconnected, so the log campaigns are resubscribed once connected again.
+    agent_disconnected: Arc<AtomicBool>,
+    /// Whether the log campaigns are resubscribed after the agent reconnected.
+    logs_resubscription: Arc<Mutex<bool>>,
+    /// Request ID to the counter of log entries received for the log campaign
+    log_entries_counters: Arc<Mutex<HashMap<String, Arc<AtomicUsize>>>>,
     /// Request ID to events sender mapping
     events_senders_map: SynchronizedSenderMap<EventEntry>,
+    /// The IDs of the raw requests waiting for a response
+    #[cfg(feature = "unstable-proto")]
+    raw_request_ids: RawRequestIds,
+    /// Token stopping the reader and writer tasks when the connection is closed.
+    tasks_shutdown: CancellationToken,
+    /// Sender for the copies of the received responses, used for debugging.
+    debug_tap: broadcast::Sender<TappedResponse>,
 }
 
 /// Helper function that reads varint data from the input pipe.
@@ -168,8 +373,8 @@ pub struct ControlInterface {
 /// ## Returns
 ///
 /// A result containing the varint data as a byte array or an [Error].
-async fn read_varint_data(
-    file: &mut BufReader<pipe::Receiver>,
+async fn read_varint_data<R: AsyncRead + Unpin>(
+    file: &mut BufReader<R>,
 ) -> Result<[u8; MAX_VARINT_SIZE], Error> {
     let mut res = [0u8; MAX_VARINT_SIZE];
     for item in &mut res {
@@ -190,7 +395,9 @@ async fn read_varint_data(
 /// ## Returns
 ///
 /// A result containing the protobuf data as a byte array or an [Error].
-async fn read_protobuf_data(file: &mut BufReader<pipe::Receiver>) -> Result<Vec<u8>, Error> {
+pub(crate) async fn read_protobuf_data<R: AsyncRead + Unpin>(
+    file: &mut BufReader<R>,
+) -> Result<Vec<u8>, Error> {
     let varint_data = read_varint_data(file).await?;
     let mut boxed_varint_data = Box::new(&varint_data[..]);
 
@@ -216,22 +423,118 @@ impl ControlInterface {
     pub fn new(response_sender: mpsc::Sender<Response>) -> Self {
         Self {
             path: ANKAIOS_CONTROL_INTERFACE_BASE_PATH.to_owned(),
+            control_socket: ControlSocketAddress::from_env(),
             output_file: None,
             read_thread_handler: None,
             writer_thread_handler: None,
             state: Arc::new(Mutex::new(ControlInterfaceState::Terminated)),
             response_sender,
+            protocol_version: ANKAIOS_VERSION.to_owned(),
+            connection_closed_reason: Arc::new(Mutex::new(None)),
+            connected_at: None,
+            reconnect_count: Arc::new(AtomicUsize::new(0)),
+            last_error: None,
             writer_ch_sender: None,
+            priority_ch_sender: None,
+            writer_queue_size: WRITER_QUEUE_SIZE,
             log_senders_map: SynchronizedSenderMap::default(),
+            log_requests_map: Arc::new(Mutex::new(HashMap::new())),
+            agent_disconnected: Arc::new(AtomicBool::new(false)),
+            logs_resubscription: Arc::new(Mutex::new(true)),
+            log_entries_counters: Arc::new(Mutex::new(HashMap::new())),
             events_senders_map: SynchronizedSenderMap::default(),
+            #[cfg(feature = "unstable-proto")]
+            raw_request_ids: RawRequestIds::default(),
+            tasks_shutdown: CancellationToken::new(),
+            debug_tap: broadcast::channel(CHANNEL_SIZE).0,
         }
     }
 
+    /// Subscribes to copies of all the responses received from the control interface.
+    ///
+    /// ## Returns
+    ///
+    /// A [`broadcast::Receiver`] of the [`TappedResponse`]s received after subscribing.
+    pub fn subscribe_debug_tap(&self) -> broadcast::Receiver<TappedResponse> {
+        self.debug_tap.subscribe()
+    }
+
+    /// Returns the protocol version used in the initial hello message.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] containing the protocol version.
+    pub fn get_protocol_version(&self) -> String {
+        self.protocol_version.clone()
+    }
+
+    /// Returns information about the connection to the control interface.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ConnectionInfo`] of the current connection.
+    pub fn connection_info(&self) -> ConnectionInfo {
+        let base_path = PathBuf::from(&self.path);
+        let closed_reason = self
+            .connection_closed_reason
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clone();
+        ConnectionInfo {
+            input_fifo: base_path.join(ANKAIOS_INPUT_FIFO_PATH),
+            output_fifo: base_path.join(ANKAIOS_OUTPUT_FIFO_PATH),
+            base_path,
+            control_socket: self.control_socket.clone(),
+            protocol_version: self.protocol_version.clone(),
+            state: *self.state.lock().unwrap_or_else(|_| unreachable!()),
+            connected_at: self.connected_at,
+            reconnect_count: self.reconnect_count.load(Ordering::Relaxed),
+            last_error: closed_reason.or_else(|| self.last_error.clone()),
+        }
+    }
+
+    /// Overrides the protocol version used in the initial hello message.
+    /// It takes effect on the next call to [`connect`](ControlInterface::connect).
+    ///
+    /// ## Arguments
+    ///
+    /// * `protocol_version` - The protocol version to be sent.
+    pub fn set_protocol_version(&mut self, protocol_version: String) {
+        self.protocol_version = protocol_version;
+    }
+
+    /// Overrides the number of messages queued for the writer task.
+    /// It takes effect on the next call to [`connect`](ControlInterface::connect).
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer_queue_size` - The number of queued messages, at least 1.
+    pub fn set_writer_queue_size(&mut self, writer_queue_size: usize) {
+        self.writer_queue_size = writer_queue_size.max(1);
+    }
+
+    /// Enables or disables the resubscription of the log campaigns after the agent reconnected.
+    ///
+    /// It is enabled by default.
+    ///
+    /// ## Arguments
+    ///
+    /// * `enabled` - Whether the logs requests are re-issued after a reconnection.
+    pub fn set_logs_resubscription(&mut self, enabled: bool) {
+        *self
+            .logs_resubscription
+            .lock()
+            .unwrap_or_else(|_| unreachable!()) = enabled;
+    }
+
     /// Connects to the control interface.
     ///
     /// ## Returns
     ///
-    /// An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if the connection fails.
+    /// An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if the connection fails,
+    /// [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the control interface is not enabled for the workload,
+    /// [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) if Ankaios rejected the protocol version or
+    /// [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if Ankaios closed the connection.
     pub async fn connect(&mut self, timeout: Duration) -> Result<(), AnkaiosError> {
         if matches!(
             *self.state.lock().unwrap_or_else(|_| unreachable!()),
@@ -241,6 +544,92 @@ impl ControlInterface {
                 "Already connected.".to_owned(),
             ));
         }
+        match self.open_connection(timeout).await {
+            Ok(()) => {
+                self.connected_at = Some(SystemTime::now());
+                Ok(())
+            }
+            Err(err) => {
+                self.last_error = Some(err.to_string());
+                Err(err)
+            }
+        }
+    }
+
+    /// Opens the FIFO pipes or the control socket and performs the handshake.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` - The maximum time to wait for the handshake.
+    ///
+    /// ## Returns
+    ///
+    /// The same errors as [`connect`](ControlInterface::connect).
+    async fn open_connection(&mut self, timeout: Duration) -> Result<(), AnkaiosError> {
+        let fifos_exist = [ANKAIOS_INPUT_FIFO_PATH, ANKAIOS_OUTPUT_FIFO_PATH]
+            .iter()
+            .all(|fifo| metadata(Path::new(&self.path).join(fifo)).is_ok());
+        let socket_connection = match self.control_socket.as_ref() {
+            Some(address) if !fifos_exist => {
+                sdk_log!(
+                    info,
+                    "Control interface fifos not found, using the control socket '{address}'."
+                );
+                Some(address.connect().await.map_err(|err| {
+                    AnkaiosError::ControlInterfaceError(format!(
+                        "Could not connect to the control socket '{address}': '{err}'"
+                    ))
+                })?)
+            }
+            _ => {
+                self.check_fifos()?;
+                None
+            }
+        };
+        let (socket_reader, socket_writer) = socket_connection.unzip();
+
+        self.connection_closed_reason
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .take();
+        self.reconnect_count.store(0, Ordering::Relaxed);
+        self.tasks_shutdown = CancellationToken::new();
+        self.prepare_writer(socket_writer);
+        self.read_from_control_interface(socket_reader);
+        ControlInterface::change_state(&self.state, ControlInterfaceState::Initialized);
+        ControlInterface::send_initial_hello(
+            self.priority_ch_sender
+                .as_ref()
+                .unwrap_or_else(|| unreachable!()),
+            &self.protocol_version,
+        );
+
+        self.wait_until_connected(timeout).await?;
+
+        sdk_log!(trace, "Connected to the control interface.");
+        Ok(())
+    }
+
+    /// Checks that the directory and the FIFO pipes of the control interface exist.
+    ///
+    /// ## Returns
+    ///
+    /// An [`AnkaiosError`]::[`ControlInterfaceNotEnabled`](AnkaiosError::ControlInterfaceNotEnabled) if the directory does not exist or
+    /// [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if a FIFO pipe does not exist.
+    fn check_fifos(&self) -> Result<(), AnkaiosError> {
+        if metadata(&self.path).is_err() {
+            sdk_log!(
+                error,
+                "Control interface directory '{}' does not exist.",
+                self.path
+            );
+            return Err(AnkaiosError::ControlInterfaceNotEnabled(format!(
+                "The control interface directory '{}' does not exist. \
+                Add 'controlInterfaceAccess' rules to the configuration of the workload \
+                to enable the control interface.",
+                self.path
+            )));
+        }
         if metadata(&(self.path.clone() + "/" + ANKAIOS_INPUT_FIFO_PATH)).is_err() {
             return Err(AnkaiosError::ControlInterfaceError(
                 "Control interface input fifo does not exist.".to_owned(),
@@ -251,37 +640,62 @@ impl ControlInterface {
                 "Control interface output fifo does not exist.".to_owned(),
             ));
         }
+        Ok(())
+    }
 
-        self.prepare_writer();
-        self.read_from_control_interface();
-        ControlInterface::change_state(&self.state, ControlInterfaceState::Initialized);
-        ControlInterface::send_initial_hello(
-            self.writer_ch_sender
-                .as_ref()
-                .unwrap_or_else(|| unreachable!()),
-        )
-        .await;
-
-        // Wait for the connection to be established
+    /// Waits until the handshake with the control interface is completed.
+    ///
+    /// The handshake is completed when the `ControlInterfaceAccepted` response
+    /// is received, which moves the state to [`ControlInterfaceState::Connected`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` - The maximum time to wait for the handshake.
+    ///
+    /// ## Returns
+    ///
+    /// An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if the handshake timed out,
+    /// [`AnkaiosError`]::[`VersionMismatch`](AnkaiosError::VersionMismatch) or
+    /// [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if Ankaios closed the connection.
+    async fn wait_until_connected(&self, timeout: Duration) -> Result<(), AnkaiosError> {
         let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&self.state);
-        if (tokio_timeout(timeout, async {
-            while *state_clone.lock().unwrap_or_else(|_| unreachable!())
-                != ControlInterfaceState::Connected
-            {
+        let closed_reason_clone =
+            Arc::<Mutex<Option<String>>>::clone(&self.connection_closed_reason);
+        match tokio_timeout(timeout, async {
+            loop {
+                match *state_clone.lock().unwrap_or_else(|_| unreachable!()) {
+                    ControlInterfaceState::Connected => return None,
+                    ControlInterfaceState::ConnectionClosed => {
+                        return Some(
+                            closed_reason_clone
+                                .lock()
+                                .unwrap_or_else(|_| unreachable!())
+                                .clone()
+                                .unwrap_or_default(),
+                        );
+                    }
+                    _ => {}
+                }
                 sleep(Duration::from_millis(100)).await;
             }
         })
-        .await)
-            .is_err()
+        .await
         {
-            log::error!("Connection to the control interface timed out.");
-            return Err(AnkaiosError::ControlInterfaceError(
-                "Connection to the control interface timed out.".to_owned(),
-            ));
+            Ok(None) => Ok(()),
+            Ok(Some(reason)) => {
+                sdk_log!(
+                    error,
+                    "Connection to the control interface was closed: {reason}"
+                );
+                Err(AnkaiosError::from_connection_closed_reason(reason))
+            }
+            Err(_) => {
+                sdk_log!(error, "Connection to the control interface timed out.");
+                Err(AnkaiosError::ControlInterfaceError(
+                    "Connection to the control interface timed out.".to_owned(),
+                ))
+            }
         }
-
-        log::trace!("Connected to the control interface.");
-        Ok(())
     }
 
     /// Disconnects from the control interface.
@@ -301,14 +715,32 @@ impl ControlInterface {
         if let Some(handler) = self.read_thread_handler.take() {
             handler.abort();
         }
+        self.tasks_shutdown.cancel();
         self.state
             .lock()
             .unwrap_or_else(|_| unreachable!())
             .clone_from(&ControlInterfaceState::Terminated);
         self.output_file = None;
+        self.connected_at = None;
         Ok(())
     }
 
+    /// Marks the agent as unresponsive after it stopped answering while connected.
+    /// The state changes to `AgentDisconnected` and a new hello is sent,
+    /// so the connection is established again once the agent answers.
+    pub fn mark_agent_unresponsive(&mut self) {
+        if *self.state.lock().unwrap_or_else(|_| unreachable!()) != ControlInterfaceState::Connected
+        {
+            return;
+        }
+        sdk_log!(warn, "The agent stopped responding.");
+        self.agent_disconnected.store(true, Ordering::Relaxed);
+        Self::change_state(&self.state, ControlInterfaceState::AgentDisconnected);
+        if let Some(sender) = self.priority_ch_sender.as_ref() {
+            Self::send_initial_hello(sender, &self.protocol_version);
+        }
+    }
+
     /// Changes the state of the control interface.
     /// This method should be used for all state changes inside the control interface.
     ///
@@ -324,38 +756,119 @@ impl ControlInterface {
             .lock()
             .unwrap_or_else(|_| unreachable!())
             .clone_from(&new_state);
-        log::info!("State changed: {new_state:?}");
+        sdk_log!(info, "State changed: {new_state:?}");
+    }
+
+    /// Closes the connection after a fatal error of the reader or writer task.
+    /// Both tasks are stopped and the pending requests are failed through
+    /// a connection closed response instead of waiting for their timeout.
+    ///
+    /// ## Arguments
+    ///
+    /// * `state` - A reference to the current state;
+    /// * `closed_reason` - The reason of the closed connection, set to `reason`;
+    /// * `response_sender` - A [`Sender<Response>`](mpsc::Sender) to forward the connection closed response;
+    /// * `tasks_shutdown` - The [`CancellationToken`] stopping the reader and writer tasks;
+    /// * `reason` - The reason why the connection is closed.
+    async fn close_connection(
+        state: &Arc<Mutex<ControlInterfaceState>>,
+        closed_reason: &Arc<Mutex<Option<String>>>,
+        response_sender: &mpsc::Sender<Response>,
+        tasks_shutdown: &CancellationToken,
+        reason: String,
+    ) {
+        sdk_log!(
+            error,
+            "Closing the connection to the control interface: {reason}"
+        );
+        closed_reason
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .replace(reason.clone());
+        Self::change_state(state, ControlInterfaceState::ConnectionClosed);
+        tasks_shutdown.cancel();
+        response_sender
+            .send(Response {
+                id: String::default(),
+                content: ResponseType::ConnectionClosedReason(reason),
+            })
+            .await
+            .unwrap_or_else(|err| {
+                sdk_log!(
+                    error,
+                    "Error while sending the connection closed response: '{err}'"
+                );
+            });
     }
 
     /// Prepares the writer thread for the control interface.
     /// It uses a [tokio] task that waits for messages and sends them to the output FIFO.
-    fn prepare_writer(&mut self) {
-        let (writer_ch_sender, mut writer_ch_receiver) = mpsc::channel::<ToAnkaios>(5);
-        self.writer_ch_sender = Some(writer_ch_sender.clone());
+    /// The messages of the priority channel are written before the queued requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `socket_writer` - The writing half of the control socket, [None] to use the output FIFO.
+    ///   Unlike the FIFO, a closed socket is not waited for but closes the connection.
+    fn prepare_writer(&mut self, socket_writer: Option<ConnectionWriter>) {
+        let (writer_ch_sender, mut writer_ch_receiver) =
+            mpsc::channel::<ToAnkaios>(self.writer_queue_size);
+        self.writer_ch_sender = Some(writer_ch_sender);
+        let (priority_ch_sender, mut priority_ch_receiver) = mpsc::unbounded_channel::<ToAnkaios>();
+        self.priority_ch_sender = Some(priority_ch_sender.clone());
         let output_path = Path::new(&self.path)
             .to_path_buf()
             .join(ANKAIOS_OUTPUT_FIFO_PATH);
         let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&self.state);
+        let closed_reason_clone =
+            Arc::<Mutex<Option<String>>>::clone(&self.connection_closed_reason);
+        let response_sender_clone = self.response_sender.clone();
+        let tasks_shutdown_clone = self.tasks_shutdown.clone();
+        let protocol_version = self.protocol_version.clone();
+        let agent_disconnected_clone = Arc::clone(&self.agent_disconnected);
         self.writer_thread_handler = Some(spawn(async move {
             const AGENT_RECONNECT_INTERVAL: u64 = 1;
-            let sender = pipe::OpenOptions::new()
-                .open_sender(output_path)
-                .map_err(|_| {
-                    AnkaiosError::ControlInterfaceError("Could not open output fifo.".to_owned())
-                })?;
+            let reconnectable = socket_writer.is_none();
+            let sender: ConnectionWriter = match socket_writer {
+                Some(writer) => writer,
+                None => Box::new(pipe::OpenOptions::new().open_sender(output_path).map_err(
+                    |_| {
+                        AnkaiosError::ControlInterfaceError(
+                            "Could not open output fifo.".to_owned(),
+                        )
+                    },
+                )?),
+            };
             let mut output_file = BufWriter::new(sender);
 
-            while let Some(message) = writer_ch_receiver.recv().await {
-                output_file
+            loop {
+                let message = tokio::select! {
+                    biased;
+                    () = tasks_shutdown_clone.cancelled() => {
+                        ControlInterface::write_queued_messages(
+                            &mut writer_ch_receiver,
+                            &mut output_file,
+                        )
+                        .await;
+                        return Ok(());
+                    }
+                    Some(message) = priority_ch_receiver.recv() => message,
+                    received_message = writer_ch_receiver.recv() => {
+                        let Some(message) = received_message else {
+                            return Ok(());
+                        };
+                        message
+                    }
+                };
+                let write_result = match output_file
                     .write_all(&message.encode_length_delimited_to_vec())
                     .await
-                    .unwrap_or_else(|err| {
-                        log::error!("Error while writing to output fifo: '{err}'");
-                        // let _ = self.disconnect();
-                    });
-                #[allow(clippy::else_if_without_else)]
-                if let Err(err) = output_file.flush().await {
-                    if err.kind() == ErrorKind::BrokenPipe {
+                {
+                    Ok(()) => output_file.flush().await,
+                    Err(err) => Err(err),
+                };
+                match write_result {
+                    Err(err) if err.kind() == ErrorKind::BrokenPipe && reconnectable => {
+                        agent_disconnected_clone.store(true, Ordering::Relaxed);
                         if *state_clone.lock().unwrap_or_else(|_| unreachable!())
                             == ControlInterfaceState::Connected
                         {
@@ -364,29 +877,75 @@ impl ControlInterface {
                                 ControlInterfaceState::AgentDisconnected,
                             );
                         }
-                        log::warn!("Waiting for the agent..");
+                        sdk_log!(warn, "Waiting for the agent..");
                         sleep(Duration::from_secs(AGENT_RECONNECT_INTERVAL)).await;
-                        ControlInterface::send_initial_hello(&writer_ch_sender).await;
-                    } else {
-                        log::error!("Error while flushing to output fifo: '{err}'");
-                        // let _ = self.disconnect();
+                        ControlInterface::send_initial_hello(
+                            &priority_ch_sender,
+                            &protocol_version,
+                        );
+                    }
+                    Err(err) => {
+                        let reason =
+                            format!("Error while writing to the control interface: '{err}'");
+                        ControlInterface::close_connection(
+                            &state_clone,
+                            &closed_reason_clone,
+                            &response_sender_clone,
+                            &tasks_shutdown_clone,
+                            reason.clone(),
+                        )
+                        .await;
+                        return Err(AnkaiosError::ControlInterfaceError(reason));
+                    }
+                    Ok(()) => {
+                        if *state_clone.lock().unwrap_or_else(|_| unreachable!())
+                            == ControlInterfaceState::AgentDisconnected
+                        {
+                            ControlInterface::change_state(
+                                &state_clone,
+                                ControlInterfaceState::Initialized,
+                            );
+                        }
                     }
-                } else if *state_clone.lock().unwrap_or_else(|_| unreachable!())
-                    == ControlInterfaceState::AgentDisconnected
-                {
-                    ControlInterface::change_state(
-                        &state_clone,
-                        ControlInterfaceState::Initialized,
-                    );
                 }
             }
-            Ok(())
         }));
     }
 
+    /// Writes the messages still queued for the writer task when it is stopped,
+    /// giving up after [`WRITER_FLUSH_TIMEOUT`] so that stopping never blocks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer_ch_receiver` - The receiver of the messages queued for the writer task;
+    /// * `output_file` - The output FIFO or socket the messages are written to.
+    async fn write_queued_messages(
+        writer_ch_receiver: &mut mpsc::Receiver<ToAnkaios>,
+        output_file: &mut BufWriter<ConnectionWriter>,
+    ) {
+        let write_all_queued = async {
+            while let Ok(message) = writer_ch_receiver.try_recv() {
+                output_file
+                    .write_all(&message.encode_length_delimited_to_vec())
+                    .await?;
+            }
+            output_file.flush().await
+        };
+        match tokio_timeout(WRITER_FLUSH_TIMEOUT, write_all_queued).await {
+            Ok(Ok(())) => sdk_log!(trace, "Queued messages written, writer stopped."),
+            Ok(Err(err)) => sdk_log!(warn, "Could not write the queued messages: '{err}'"),
+            Err(_) => sdk_log!(warn, "Timeout while writing the queued messages."),
+        }
+    }
+
     /// Prepares the reader thread for the control interface.
     /// It uses a [tokio] task that reads continuously from the FIFO input pipe.
-    fn read_from_control_interface(&mut self) {
+    ///
+    /// ## Arguments
+    ///
+    /// * `socket_reader` - The reading half of the control socket, [None] to use the input FIFO.
+    ///   Unlike the FIFO, a closed socket is not waited for but closes the connection.
+    fn read_from_control_interface(&mut self, socket_reader: Option<ConnectionReader>) {
         #[cfg(not(test))]
         const SLEEP_DURATION: u64 = 500; // ms
         #[cfg(test)]
@@ -395,42 +954,83 @@ impl ControlInterface {
             .to_path_buf()
             .join(ANKAIOS_INPUT_FIFO_PATH);
         let response_sender_clone = self.response_sender.clone();
-        let writer_ch_sender_clone = self
-            .writer_ch_sender
+        let priority_ch_sender_clone = self
+            .priority_ch_sender
             .as_ref()
             .unwrap_or_else(|| unreachable!())
             .clone();
         let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&self.state);
+        let closed_reason_clone =
+            Arc::<Mutex<Option<String>>>::clone(&self.connection_closed_reason);
+        let protocol_version = self.protocol_version.clone();
         let mut logs_sender_shared_map = self.log_senders_map.clone();
+        let log_requests_shared_map =
+            Arc::<Mutex<HashMap<String, ank_base::Request>>>::clone(&self.log_requests_map);
+        let logs_resubscription_clone = Arc::<Mutex<bool>>::clone(&self.logs_resubscription);
+        let agent_disconnected_clone = Arc::clone(&self.agent_disconnected);
+        agent_disconnected_clone.store(false, Ordering::Relaxed);
+        let log_entries_counters_clone = Arc::clone(&self.log_entries_counters);
         let mut event_sender_shared_map = self.events_senders_map.clone();
+        #[cfg(feature = "unstable-proto")]
+        let raw_request_ids_clone = self.raw_request_ids.clone();
+        let tasks_shutdown_clone = self.tasks_shutdown.clone();
+        let debug_tap_clone = self.debug_tap.clone();
+        let reconnect_count_clone = Arc::clone(&self.reconnect_count);
         self.read_thread_handler = Some(spawn(async move {
-            let receiver = pipe::OpenOptions::new()
-                .open_receiver(input_path)
-                .map_err(|_| {
-                    AnkaiosError::ControlInterfaceError("Could not open input fifo.".to_owned())
-                })?;
+            let reconnectable = socket_reader.is_none();
+            let receiver: ConnectionReader = match socket_reader {
+                Some(reader) => reader,
+                None => Box::new(pipe::OpenOptions::new().open_receiver(input_path).map_err(
+                    |_| {
+                        AnkaiosError::ControlInterfaceError("Could not open input fifo.".to_owned())
+                    },
+                )?),
+            };
             let mut input_file = BufReader::new(receiver);
 
             loop {
-                match read_protobuf_data(&mut input_file).await {
+                let read_result = tokio::select! {
+                    read_data = read_protobuf_data(&mut input_file) => read_data,
+                    () = tasks_shutdown_clone.cancelled() => break,
+                };
+                match read_result {
                     Ok(binary) => {
                         if *state_clone.lock().unwrap_or_else(|_| unreachable!())
                             == ControlInterfaceState::AgentDisconnected
                         {
-                            log::info!("Agent reconnected successfully.");
+                            sdk_log!(info, "Agent reconnected successfully.");
+                            reconnect_count_clone.fetch_add(1, Ordering::Relaxed);
                             Self::change_state(&state_clone, ControlInterfaceState::Initialized);
                         }
 
                         let decoded_response = FromAnkaios::decode(&mut Box::new(binary.as_ref()));
 
                         match decoded_response {
-                            Ok(from_ankaios) => {
-                                let received_response = Response::new(from_ankaios);
+                            Ok(decoded_from_ankaios) => {
+                                Self::tap_response(
+                                    &debug_tap_clone,
+                                    &decoded_from_ankaios,
+                                    binary.len(),
+                                );
+                                #[cfg(feature = "unstable-proto")]
+                                let received_response = Self::convert_response(
+                                    decoded_from_ankaios,
+                                    &raw_request_ids_clone,
+                                );
+                                #[cfg(not(feature = "unstable-proto"))]
+                                let received_response = Response::new(decoded_from_ankaios);
                                 let con_closed_reason: Option<String> =
                                     match &received_response.content {
                                         ResponseType::ConnectionClosedReason(reason) => {
                                             Some(reason.clone())
                                         }
+                                        ResponseType::LogEntriesResponse(_) => {
+                                            Self::advance_log_request(
+                                                &log_requests_shared_map,
+                                                &received_response.id,
+                                            );
+                                            None
+                                        }
                                         _ => None,
                                     };
 
@@ -439,12 +1039,37 @@ impl ControlInterface {
                                     received_response,
                                     &response_sender_clone,
                                     &mut logs_sender_shared_map,
+                                    &log_entries_counters_clone,
                                     &mut event_sender_shared_map,
                                 )
                                 .await;
 
+                                if *state_clone.lock().unwrap_or_else(|_| unreachable!())
+                                    == ControlInterfaceState::Connected
+                                    && agent_disconnected_clone.swap(false, Ordering::Relaxed)
+                                {
+                                    if *logs_resubscription_clone
+                                        .lock()
+                                        .unwrap_or_else(|_| unreachable!())
+                                    {
+                                        Self::resubscribe_log_campaigns(
+                                            &priority_ch_sender_clone,
+                                            &log_requests_shared_map,
+                                            &logs_sender_shared_map,
+                                        )
+                                        .await;
+                                    }
+                                }
+
                                 if let Some(reason) = con_closed_reason {
-                                    log::error!("Connection closed by the agent. Reason {reason}.");
+                                    sdk_log!(
+                                        error,
+                                        "Connection closed by the agent. Reason {reason}."
+                                    );
+                                    closed_reason_clone
+                                        .lock()
+                                        .unwrap_or_else(|_| unreachable!())
+                                        .replace(reason);
                                     Self::change_state(
                                         &state_clone,
                                         ControlInterfaceState::ConnectionClosed,
@@ -452,10 +1077,11 @@ impl ControlInterface {
                                     break;
                                 }
                             }
-                            Err(err) => log::error!("Invalid response, parsing error: '{err}'"),
+                            Err(err) => sdk_log!(error, "Invalid response, parsing error: '{err}'"),
                         }
                     }
-                    Err(err) if err.kind() == ErrorKind::UnexpectedEof => {
+                    Err(err) if err.kind() == ErrorKind::UnexpectedEof && reconnectable => {
+                        agent_disconnected_clone.store(true, Ordering::Relaxed);
                         if *state_clone.lock().unwrap_or_else(|_| unreachable!())
                             == ControlInterfaceState::Connected
                         {
@@ -463,14 +1089,22 @@ impl ControlInterface {
                                 &state_clone,
                                 ControlInterfaceState::AgentDisconnected,
                             );
-                            Self::send_initial_hello(&writer_ch_sender_clone).await;
+                            Self::send_initial_hello(&priority_ch_sender_clone, &protocol_version);
                         }
                         sleep(Duration::from_millis(SLEEP_DURATION)).await;
                     }
                     Err(err) => {
-                        log::error!("Error while reading from input fifo: '{err}'");
-                        Self::change_state(&state_clone, ControlInterfaceState::Terminated);
-                        break;
+                        let reason =
+                            format!("Error while reading from the control interface: '{err}'");
+                        Self::close_connection(
+                            &state_clone,
+                            &closed_reason_clone,
+                            &response_sender_clone,
+                            &tasks_shutdown_clone,
+                            reason.clone(),
+                        )
+                        .await;
+                        return Err(AnkaiosError::ControlInterfaceError(reason));
                     }
                 }
             }
@@ -488,6 +1122,7 @@ impl ControlInterface {
     /// * `received_response` - A decoded [`Response`] object from the control interface;
     /// * `response_sender` - A [`Sender<Response>`] to forward the response;
     /// * `logs_sender_map` - A [`SynchronizedSenderMap<LogResponse>`] to forward log entries and stop responses for a log campaign;
+    /// * `log_entries_counters` - The counters of the log entries received per log campaign;
     /// * `event_sender_map` - A [`SynchronizedSenderMap<EventEntry>`] to forward events for an event campaign
     ///
     async fn handle_decoded_response(
@@ -495,22 +1130,34 @@ impl ControlInterface {
         received_response: Response,
         response_sender: &mpsc::Sender<Response>,
         logs_sender_map: &mut SynchronizedSenderMap<LogResponse>,
+        log_entries_counters: &Arc<Mutex<HashMap<String, Arc<AtomicUsize>>>>,
         event_sender_map: &mut SynchronizedSenderMap<EventEntry>,
     ) {
         // The state needs to be locked outside of the match because otherwise the temporary created guard
         // will be dropped only at the end, repetitive locking inside the match not being allowed.
         let state_value = *state.lock().unwrap_or_else(|_| unreachable!());
         match state_value {
-            ControlInterfaceState::Initialized => {
-                if received_response.content == ResponseType::ControlInterfaceAccepted {
-                    log::debug!("Received control interface accepted response.");
+            ControlInterfaceState::Initialized => match received_response.content {
+                ResponseType::ControlInterfaceAccepted => {
+                    sdk_log!(debug, "Received control interface accepted response.");
                     ControlInterface::change_state(state, ControlInterfaceState::Connected);
                 }
-            }
+                _ => {
+                    sdk_log!(
+                        debug,
+                        "Received response {received_response:?} before the handshake completed. Ignoring.."
+                    );
+                }
+            },
             ControlInterfaceState::Connected => match received_response.content {
                 ResponseType::LogEntriesResponse(log_entries) => {
-                    Self::forward_log_entries(received_response.id, log_entries, logs_sender_map)
-                        .await;
+                    Self::forward_log_entries(
+                        received_response.id,
+                        log_entries,
+                        logs_sender_map,
+                        log_entries_counters,
+                    )
+                    .await;
                 }
                 ResponseType::LogsStopResponse(instance_name) => {
                     Self::forward_logs_stop_response(
@@ -529,19 +1176,32 @@ impl ControlInterface {
                     .await;
                 }
                 ResponseType::ControlInterfaceAccepted => {
-                    log::warn!("Received unexpected control interface accepted response.");
+                    sdk_log!(
+                        debug,
+                        "Received duplicate control interface accepted response. Ignoring.."
+                    );
+                }
+                ResponseType::LogsRequestAccepted(_)
+                    if logs_sender_map.get_cloned(&received_response.id).is_some() =>
+                {
+                    sdk_log!(
+                        debug,
+                        "Log campaign with request id '{}' resubscribed.",
+                        received_response.id
+                    );
                 }
                 _ => {
                     response_sender
                         .send(received_response)
                         .await
                         .unwrap_or_else(|err| {
-                            log::error!("Error while sending response: '{err}'");
+                            sdk_log!(error, "Error while sending response: '{err}'");
                         });
                 }
             },
             _ => {
-                log::warn!(
+                sdk_log!(
+                    warn,
                     "Received response {received_response:?}, but not in a valid state. Ignoring.."
                 );
             }
@@ -556,14 +1216,29 @@ impl ControlInterface {
     ///
     /// ## Returns
     ///
-    /// An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected.
+    /// - An [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if not connected or the writer task stopped;
+    /// - An [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the connection was closed;
+    /// - An [`AnkaiosError`]::[`MessageTooLargeError`](AnkaiosError::MessageTooLargeError) if the request exceeds [`MAX_MESSAGE_SIZE`].
     pub async fn write_request<T: Request + 'static>(
         &mut self,
         request: T,
     ) -> Result<(), AnkaiosError> {
-        if *self.state.lock().unwrap_or_else(|_| unreachable!()) != ControlInterfaceState::Connected
-        {
-            log::error!("Could not write to pipe, not connected.");
+        let current_state = *self.state.lock().unwrap_or_else(|_| unreachable!());
+        if current_state == ControlInterfaceState::ConnectionClosed {
+            let reason = self
+                .connection_closed_reason
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .clone()
+                .unwrap_or_default();
+            sdk_log!(
+                error,
+                "Could not write to pipe, the connection was closed: {reason}"
+            );
+            return Err(AnkaiosError::from_connection_closed_reason(reason));
+        }
+        if current_state != ControlInterfaceState::Connected {
+            sdk_log!(error, "Could not write to pipe, not connected.");
             return Err(AnkaiosError::ControlInterfaceError(
                 "Could not write to pipe, not connected.".to_owned(),
             ));
@@ -571,10 +1246,12 @@ impl ControlInterface {
         let message = ToAnkaios {
             to_ankaios_enum: Some(ToAnkaiosEnum::Request(request.to_proto())),
         };
+        check_message_size(&request.get_id(), message.encoded_len())?;
         if let Some(sender) = self.writer_ch_sender.as_ref() {
-            sender.send(message).await.unwrap_or_else(|err| {
-                log::error!("Error while sending request: '{err}'");
-            });
+            sender.send(message).await.map_err(|err| {
+                sdk_log!(error, "Error while sending request: '{err}'");
+                AnkaiosError::ControlInterfaceError("The writer task stopped.".to_owned())
+            })?;
         }
         Ok(())
     }
@@ -585,11 +1262,27 @@ impl ControlInterface {
     /// ## Arguments
     ///
     /// * `request_id` - A [String] representing the request ID of the initial logs request of the log campaign;
-    /// * `logs_sender` - A [`mpsc::Sender<LogResponse>`] to forward log responses for the log campaign.
+    /// * `logs_request` - The [`ank_base::Request`] of the log campaign, re-issued after the agent reconnected;
+    /// * `logs_sender` - A [`mpsc::Sender<LogResponse>`] to forward log responses for the log campaign;
+    /// * `entries_counter` - A counter incremented with the number of log entries received for the log campaign.
     ///
-    pub fn add_log_campaign(&mut self, request_id: String, logs_sender: mpsc::Sender<LogResponse>) {
-        log::trace!("Add log campaign with request id: '{request_id}'");
+    pub fn add_log_campaign(
+        &mut self,
+        request_id: String,
+        logs_request: ank_base::Request,
+        logs_sender: mpsc::Sender<LogResponse>,
+        entries_counter: Arc<AtomicUsize>,
+    ) {
+        sdk_log!(trace, "Add log campaign with request id: '{request_id}'");
 
+        self.log_entries_counters
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(request_id.clone(), entries_counter);
+        self.log_requests_map
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(request_id.clone(), logs_request);
         self.log_senders_map.insert(request_id, logs_sender);
     }
 
@@ -601,8 +1294,19 @@ impl ControlInterface {
     /// * `request_id` - A [&str] representing the request ID of the initial logs request of the log campaign;
     ///
     pub fn remove_log_campaign(&mut self, request_id: &str) {
+        self.log_requests_map
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(request_id);
+        self.log_entries_counters
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(request_id);
         if self.log_senders_map.remove(request_id).is_some() {
-            log::trace!("Removed log campaign with request id: '{request_id}'");
+            sdk_log!(
+                trace,
+                "Removed log campaign with request id: '{request_id}'"
+            );
         }
     }
 
@@ -619,7 +1323,7 @@ impl ControlInterface {
         request_id: String,
         events_sender: mpsc::Sender<EventEntry>,
     ) {
-        log::trace!("Add event campaign with request id: '{request_id}'");
+        sdk_log!(trace, "Add event campaign with request id: '{request_id}'");
 
         self.events_senders_map.insert(request_id, events_sender);
     }
@@ -633,7 +1337,10 @@ impl ControlInterface {
     ///
     pub fn remove_events_campaign(&mut self, request_id: &str) {
         if self.events_senders_map.remove(request_id).is_some() {
-            log::trace!("Removed events campaign with request id: '{request_id}'");
+            sdk_log!(
+                trace,
+                "Removed events campaign with request id: '{request_id}'"
+            );
         }
     }
 
@@ -644,27 +1351,38 @@ impl ControlInterface {
     ///
     /// * `request_id` - A [String] representing the request ID of the initial logs request of the log campaign;
     /// * `log_entries` - A [`Vec<LogEntry>`] containing the log entries of workload to be forwarded;
-    /// * `logs_sender_map` - A [`SynchronizedSenderMap<LogResponse>`] to forward log entries and stop responses for a log campaign.
+    /// * `logs_sender_map` - A [`SynchronizedSenderMap<LogResponse>`] to forward log entries and stop responses for a log campaign;
+    /// * `log_entries_counters` - The counters of the log entries received per log campaign.
     ///
     async fn forward_log_entries(
         request_id: String,
         log_entries: Vec<LogEntry>,
         logs_sender_map: &SynchronizedSenderMap<LogResponse>,
+        log_entries_counters: &Arc<Mutex<HashMap<String, Arc<AtomicUsize>>>>,
     ) {
         let log_entries_sender = logs_sender_map.get_cloned(&request_id);
 
         if let Some(sender) = log_entries_sender {
-            log::trace!(
+            if let Some(counter) = log_entries_counters
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .get(&request_id)
+            {
+                counter.fetch_add(log_entries.len(), Ordering::Relaxed);
+            }
+            sdk_log!(
+                trace,
                 "Forwarding log entries for request id '{request_id}' to log campaign receiver."
             );
             sender
                 .send(LogResponse::LogEntries(log_entries))
                 .await
                 .unwrap_or_else(|err| {
-                    log::error!("Error while sending log entries: '{err}'");
+                    sdk_log!(error, "Error while sending log entries: '{err}'");
                 });
         } else {
-            log::debug!(
+            sdk_log!(
+                debug,
                 "Received log entries response for request id '{request_id}', but no log campaign found."
             );
         }
@@ -686,22 +1404,169 @@ impl ControlInterface {
     ) {
         let log_entries_sender = logs_sender_map.get_cloned(&request_id);
         if let Some(sender) = log_entries_sender {
-            log::trace!(
+            sdk_log!(
+                trace,
                 "Forwarding logs stop response for workload '{instance_name:?}' of request id '{request_id}' to log campaign receiver."
             );
             sender
                 .send(LogResponse::LogsStopResponse(instance_name))
                 .await
                 .unwrap_or_else(|err| {
-                    log::error!("Error while sending log stop message: '{err}'");
+                    sdk_log!(error, "Error while sending log stop message: '{err}'");
                 });
         } else {
-            log::debug!(
+            sdk_log!(
+                debug,
                 "Received logs stop response for request id '{request_id}', but no log campaign found."
             );
         }
     }
 
+    #[doc(hidden)]
+    /// Moves the logs request of a log campaign after the log entries just received:
+    /// the `since` is set to now and the `tail` is dropped, so a resubscription
+    /// does not replay the entries already received. With a precision of one second,
+    /// the entries of the last second might still be received twice.
+    ///
+    /// ## Arguments
+    ///
+    /// * `log_requests_map` - The logs requests of the active log campaigns;
+    /// * `request_id` - A [&str] representing the request ID of the log campaign.
+    ///
+    fn advance_log_request(
+        log_requests_map: &Mutex<HashMap<String, ank_base::Request>>,
+        request_id: &str,
+    ) {
+        if let Some(ank_base::Request {
+            request_content: Some(RequestContent::LogsRequest(logs_request)),
+            ..
+        }) = log_requests_map
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .get_mut(request_id)
+        {
+            logs_request.since = Some(rfc3339_before_now(Duration::ZERO));
+            logs_request.tail = None;
+        }
+    }
+
+    #[doc(hidden)]
+    /// Re-issues the logs requests of the active log campaigns after the agent reconnected.
+    /// A [`LogResponse::Resubscribed`] is forwarded to each log campaign receiver, as log entries
+    /// might have been lost while the agent was disconnected.
+    ///
+    /// ## Arguments
+    ///
+    /// * `priority_ch_sender` - A [`mpsc::UnboundedSender<ToAnkaios>`] to send the logs requests;
+    /// * `log_requests_map` - The logs requests of the active log campaigns;
+    /// * `logs_sender_map` - A [`SynchronizedSenderMap<LogResponse>`] to notify the log campaigns.
+    ///
+    async fn resubscribe_log_campaigns(
+        priority_ch_sender: &mpsc::UnboundedSender<ToAnkaios>,
+        log_requests_map: &Mutex<HashMap<String, ank_base::Request>>,
+        logs_sender_map: &SynchronizedSenderMap<LogResponse>,
+    ) {
+        let log_requests: Vec<(String, ank_base::Request)> = log_requests_map
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .map(|(request_id, request)| (request_id.clone(), request.clone()))
+            .collect();
+
+        for (request_id, request) in log_requests {
+            sdk_log!(
+                info,
+                "Resubscribing log campaign with request id '{request_id}'."
+            );
+            priority_ch_sender
+                .send(ToAnkaios {
+                    to_ankaios_enum: Some(ToAnkaiosEnum::Request(request)),
+                })
+                .unwrap_or_else(|err| {
+                    sdk_log!(error, "Error while resubscribing log campaign: '{err}'");
+                });
+            if let Some(sender) = logs_sender_map.get_cloned(&request_id) {
+                sender
+                    .send(LogResponse::Resubscribed)
+                    .await
+                    .unwrap_or_else(|err| {
+                        sdk_log!(error, "Error while sending resubscribed message: '{err}'");
+                    });
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Adds a raw request to the control interface.
+    /// The response with the same request ID is forwarded as is, without being converted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - A [String] representing the request ID of the raw request.
+    ///
+    /// ## Returns
+    ///
+    /// A [`RawRequestGuard`] removing the raw request when dropped.
+    #[cfg(feature = "unstable-proto")]
+    pub fn add_raw_request(&mut self, request_id: String) -> RawRequestGuard {
+        sdk_log!(trace, "Add raw request with request id: '{request_id}'");
+        self.raw_request_ids.insert(request_id)
+    }
+
+    #[doc(hidden)]
+    /// Converts a received message into a [`Response`]. The response to a raw request
+    /// is kept as is in a [`ResponseType::RawResponse`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `from_ankaios` - The decoded [`FromAnkaios`] message;
+    /// * `raw_request_ids` - The IDs of the raw requests waiting for a response.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Response`] to dispatch.
+    #[cfg(feature = "unstable-proto")]
+    fn convert_response(from_ankaios: FromAnkaios, raw_request_ids: &RawRequestIds) -> Response {
+        match from_ankaios.from_ankaios_enum {
+            Some(FromAnkaiosEnum::Response(response))
+                if raw_request_ids.remove(&response.request_id) =>
+            {
+                sdk_log!(
+                    trace,
+                    "Forwarding raw response for request id '{}'.",
+                    response.request_id
+                );
+                Response {
+                    id: response.request_id.clone(),
+                    content: ResponseType::RawResponse(response),
+                }
+            }
+            from_ankaios_enum => Response::new(FromAnkaios { from_ankaios_enum }),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Sends a copy of a received message to the subscribers of the debug tap, if any.
+    ///
+    /// ## Arguments
+    ///
+    /// * `debug_tap` - The [`broadcast::Sender`] of the debug tap;
+    /// * `from_ankaios` - The decoded [`FromAnkaios`] message;
+    /// * `size` - The size in bytes of the encoded message.
+    fn tap_response(
+        debug_tap: &broadcast::Sender<TappedResponse>,
+        from_ankaios: &FromAnkaios,
+        size: usize,
+    ) {
+        if debug_tap.receiver_count() == 0 {
+            return;
+        }
+        let _ = debug_tap.send(TappedResponse {
+            response: Response::new(from_ankaios.clone()),
+            size,
+        });
+    }
+
     #[doc(hidden)]
     /// Forwards the event entries to the appropriate receiver.
     ///
@@ -719,12 +1584,16 @@ impl ControlInterface {
         let event_sender = event_sender_map.get_cloned(&request_id);
 
         if let Some(sender) = event_sender {
-            log::trace!("Forwarding event entry for request id '{request_id}' to receiver.");
+            sdk_log!(
+                trace,
+                "Forwarding event entry for request id '{request_id}' to receiver."
+            );
             sender.send(*event_entry).await.unwrap_or_else(|err| {
-                log::error!("Error while sending event entry: '{err}'");
+                sdk_log!(error, "Error while sending event entry: '{err}'");
             });
         } else {
-            log::debug!(
+            sdk_log!(
+                debug,
                 "Received event entry for request id '{request_id}', but no event campaign found."
             );
         }
@@ -734,20 +1603,24 @@ impl ControlInterface {
     ///
     /// ## Arguments
     ///
-    /// * `writer_ch_sender` - A sender for the writer channel.
-    async fn send_initial_hello(writer_ch_sender: &mpsc::Sender<ToAnkaios>) {
-        log::trace!("Sending initial hello message to the control interface.");
+    /// * `priority_ch_sender` - A sender for the priority channel of the writer;
+    /// * `protocol_version` - The protocol version to be sent.
+    fn send_initial_hello(
+        priority_ch_sender: &mpsc::UnboundedSender<ToAnkaios>,
+        protocol_version: &str,
+    ) {
+        sdk_log!(
+            trace,
+            "Sending initial hello message to the control interface."
+        );
         let hello_msg = ToAnkaios {
             to_ankaios_enum: Some(ToAnkaiosEnum::Hello(Hello {
-                protocol_version: ANKAIOS_VERSION.to_owned(),
+                protocol_version: protocol_version.to_owned(),
             })),
         };
-        writer_ch_sender
-            .send(hello_msg)
-            .await
-            .unwrap_or_else(|err| {
-                log::error!("Error while sending initial hello message: '{err}'");
-            });
+        priority_ch_sender.send(hello_msg).unwrap_or_else(|err| {
+            sdk_log!(error, "Error while sending initial hello message: '{err}'");
+        });
     }
 }
 
@@ -764,13 +1637,17 @@ mod tests {
     use nix::{sys::stat::Mode, unistd::mkfifo};
     use prost::Message;
     use std::{
-        sync::{Arc, Mutex},
+        collections::HashMap,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicUsize, Ordering},
+        },
         time::Duration,
     };
     use tokio::{
         fs::OpenOptions,
         io::{AsyncWriteExt, BufReader, BufWriter},
-        net::unix::pipe,
+        net::{TcpListener, unix::pipe},
         spawn,
         sync::{Barrier, mpsc},
         time::{sleep, timeout as tokio_timeout},
@@ -778,13 +1655,14 @@ mod tests {
 
     use super::{
         ANKAIOS_INPUT_FIFO_PATH, ANKAIOS_OUTPUT_FIFO_PATH, ANKAIOS_VERSION, ControlInterface,
-        ControlInterfaceState, read_protobuf_data,
+        ControlInterfaceState, MAX_MESSAGE_SIZE, check_message_size, read_protobuf_data,
     };
     use crate::{
         AnkaiosError, EventEntry, LogResponse,
         ankaios::CHANNEL_SIZE,
         ankaios_api,
         components::{
+            control_socket::ControlSocketAddress,
             request::{Request, generate_test_request},
             response::{
                 Response, ResponseType, generate_test_control_interface_accepted_response,
@@ -796,6 +1674,7 @@ mod tests {
             workload_state_mod::WorkloadInstanceName,
         },
     };
+    use ankaios_api::ank_base;
     use ankaios_api::control_api::{Hello, ToAnkaios, to_ankaios::ToAnkaiosEnum};
 
     const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
@@ -837,6 +1716,17 @@ mod tests {
         jh.await.unwrap();
     }
 
+    #[test]
+    fn utest_check_message_size() {
+        assert!(check_message_size("id", 1024).is_ok());
+        assert!(check_message_size("id", MAX_MESSAGE_SIZE).is_ok());
+        assert!(matches!(
+            check_message_size("id", MAX_MESSAGE_SIZE + 1),
+            Err(AnkaiosError::MessageTooLargeError(id, size, max))
+                if id == "id" && size == MAX_MESSAGE_SIZE + 1 && max == MAX_MESSAGE_SIZE
+        ));
+    }
+
     #[test]
     fn utest_control_interface_state() {
         let mut cis = ControlInterfaceState::Initialized;
@@ -867,12 +1757,28 @@ mod tests {
         assert_eq!(get_state(&ci), ControlInterfaceState::Terminated);
 
         // Try to connect - should fail because the input fifo is not yet created
-        assert!(ci.connect(CONNECT_TIMEOUT).await.is_err());
+        assert!(matches!(
+            ci.connect(CONNECT_TIMEOUT).await,
+            Err(AnkaiosError::ControlInterfaceError(_))
+        ));
         mkfifo(&fifo_input, Mode::S_IRWXU).unwrap();
 
         // Try to connect - should fail because the output fifo is not yet created
         assert!(ci.connect(CONNECT_TIMEOUT).await.is_err());
         mkfifo(&fifo_output, Mode::S_IRWXU).unwrap();
+        let info = ci.connection_info();
+        assert_eq!(info.base_path, tmpdir.path());
+        assert_eq!(info.input_fifo, fifo_input);
+        assert_eq!(info.output_fifo, fifo_output);
+        assert_eq!(info.protocol_version, ANKAIOS_VERSION);
+        assert_eq!(info.state, ControlInterfaceState::Terminated);
+        assert!(info.connected_at.is_none());
+        assert_eq!(
+            info.last_error,
+            Some(
+                "Control interface error: Control interface output fifo does not exist.".to_owned()
+            )
+        );
 
         // Open the output file for reading
         let mut file_output = tokio::io::BufReader::new(
@@ -899,6 +1805,10 @@ mod tests {
         // Connect to the control interface - success
         ci.connect(CONNECT_TIMEOUT).await.unwrap();
         assert_eq!(get_state(&ci), ControlInterfaceState::Connected);
+        let info = ci.connection_info();
+        assert_eq!(info.state, ControlInterfaceState::Connected);
+        assert!(info.connected_at.is_some());
+        assert_eq!(info.reconnect_count, 0);
 
         // Check that the initial hello was received
         #[allow(clippy::match_wild_err_arm)]
@@ -924,11 +1834,73 @@ mod tests {
         // Disconnect from the control interface
         ci.disconnect().unwrap();
         assert_eq!(get_state(&ci), ControlInterfaceState::Terminated);
+        assert!(ci.connection_info().connected_at.is_none());
 
         // Try to disconnect again - should fail
         assert!(ci.disconnect().is_err());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn utest_control_interface_disconnect_stops_writer() {
+        let (response_sender, _response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+
+        // Prepare fifo pipes
+        let tmpdir = tempfile::tempdir().unwrap();
+        let fifo_input = tmpdir.path().join(ANKAIOS_INPUT_FIFO_PATH);
+        let fifo_output = tmpdir.path().join(ANKAIOS_OUTPUT_FIFO_PATH);
+        mkfifo(&fifo_input, Mode::S_IRWXU).unwrap();
+        mkfifo(&fifo_output, Mode::S_IRWXU).unwrap();
+        let mut file_output = tokio::io::BufReader::new(
+            pipe::OpenOptions::new()
+                .open_receiver(&fifo_output)
+                .unwrap(),
+        );
+
+        let mut ci = ControlInterface::new(response_sender);
+        tmpdir.path().to_str().unwrap().clone_into(&mut ci.path);
+
+        // Simulate the established connection
+        let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&ci.state);
+        let _handle = spawn(async move {
+            while *state_clone.lock().unwrap() != ControlInterfaceState::Initialized {
+                sleep(Duration::from_millis(10)).await;
+            }
+            *state_clone.lock().unwrap() = ControlInterfaceState::Connected;
+        });
+        ci.connect(CONNECT_TIMEOUT).await.unwrap();
+
+        // A request queued right before disconnecting is still written
+        let request = generate_test_request();
+        let request_id = request.get_id();
+        ci.write_request(request).await.unwrap();
+        ci.disconnect().unwrap();
+
+        let writer_handler = ci.writer_thread_handler.take().unwrap();
+        tokio_timeout(Duration::from_secs(1), writer_handler)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        let mut received_messages = Vec::new();
+        while let Ok(Ok(binary)) = tokio_timeout(
+            Duration::from_millis(100),
+            read_protobuf_data(&mut file_output),
+        )
+        .await
+        {
+            received_messages.push(ToAnkaios::decode(&mut Box::new(binary.as_ref())).unwrap());
+        }
+        assert!(matches!(
+            received_messages.first().unwrap().to_ankaios_enum,
+            Some(ToAnkaiosEnum::Hello(_))
+        ));
+        assert!(matches!(
+            &received_messages.last().unwrap().to_ankaios_enum,
+            Some(ToAnkaiosEnum::Request(request)) if request.request_id == request_id
+        ));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn utest_control_interface_connect_timeout() {
         // Crate mpsc channel
@@ -959,6 +1931,170 @@ mod tests {
         assert!(ci.disconnect().is_ok());
     }
 
+    #[tokio::test]
+    async fn utest_control_interface_connect_not_enabled() {
+        let (response_sender, _response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let mut ci = ControlInterface::new(response_sender);
+        tmpdir
+            .path()
+            .join("missing")
+            .to_str()
+            .unwrap()
+            .clone_into(&mut ci.path);
+
+        assert!(matches!(
+            ci.connect(CONNECT_TIMEOUT).await,
+            Err(AnkaiosError::ControlInterfaceNotEnabled(hint)) if hint.contains("controlInterfaceAccess")
+        ));
+        assert_eq!(get_state(&ci), ControlInterfaceState::Terminated);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn utest_control_interface_connect_version_mismatch() {
+        // Crate mpsc channel
+        let (response_sender, _response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+
+        // Prepare fifo pipes
+        let tmpdir = tempfile::tempdir().unwrap();
+        let fifo_input = tmpdir.path().join(ANKAIOS_INPUT_FIFO_PATH);
+        let fifo_output = tmpdir.path().join(ANKAIOS_OUTPUT_FIFO_PATH);
+        mkfifo(&fifo_input, Mode::S_IRWXU).unwrap();
+        mkfifo(&fifo_output, Mode::S_IRWXU).unwrap();
+
+        // Open the output file for reading
+        let mut file_output = tokio::io::BufReader::new(
+            pipe::OpenOptions::new()
+                .open_receiver(&fifo_output)
+                .unwrap(),
+        );
+
+        // Create control interface with a custom protocol version
+        let mut ci = ControlInterface::new(response_sender);
+        tmpdir.path().to_str().unwrap().clone_into(&mut ci.path);
+        assert_eq!(ci.get_protocol_version(), ANKAIOS_VERSION);
+        ci.set_protocol_version("0.0.1".to_owned());
+        assert_eq!(ci.get_protocol_version(), "0.0.1");
+
+        // Create task to simulate the rejection of the protocol version
+        let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&ci.state);
+        let reason_clone = Arc::<Mutex<Option<String>>>::clone(&ci.connection_closed_reason);
+        let _handle = spawn(async move {
+            loop {
+                if *state_clone.lock().unwrap_or_else(|_| unreachable!())
+                    == ControlInterfaceState::Initialized
+                {
+                    *reason_clone.lock().unwrap_or_else(|_| unreachable!()) =
+                        Some("Unsupported protocol version '0.0.1'".to_owned());
+                    *state_clone.lock().unwrap_or_else(|_| unreachable!()) =
+                        ControlInterfaceState::ConnectionClosed;
+                    break;
+                }
+                sleep(Duration::from_millis(50)).await;
+            }
+        });
+
+        // Connect to the control interface - rejected
+        let ret = ci.connect(CONNECT_TIMEOUT).await;
+        assert!(matches!(ret, Err(AnkaiosError::VersionMismatch(_))));
+
+        // Check that the custom protocol version was sent
+        #[allow(clippy::match_wild_err_arm)]
+        match tokio_timeout(Duration::from_secs(1), read_protobuf_data(&mut file_output)).await {
+            Ok(Ok(binary)) => {
+                let to_ankaios = ToAnkaios::decode(&mut Box::new(binary.as_ref())).unwrap();
+                assert_eq!(
+                    to_ankaios.to_ankaios_enum,
+                    Some(ToAnkaiosEnum::Hello(Hello {
+                        protocol_version: "0.0.1".to_owned(),
+                    }))
+                );
+            }
+            Err(_) => panic!("Hello message was not sent"),
+            _ => panic!("Error while reading pipe"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn utest_control_interface_connect_socket() {
+        let (response_sender, _response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let tmpdir = tempfile::tempdir().unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        // Create control interface without fifo pipes, but with a control socket
+        let mut ci = ControlInterface::new(response_sender);
+        tmpdir
+            .path()
+            .join("missing")
+            .to_str()
+            .unwrap()
+            .clone_into(&mut ci.path);
+        ci.control_socket = Some(ControlSocketAddress::Tcp(
+            listener.local_addr().unwrap().to_string(),
+        ));
+
+        // Create task to simulate the established connection
+        let state_clone = Arc::<Mutex<ControlInterfaceState>>::clone(&ci.state);
+        let _handle = spawn(async move {
+            loop {
+                if *state_clone.lock().unwrap_or_else(|_| unreachable!())
+                    == ControlInterfaceState::Initialized
+                {
+                    *state_clone.lock().unwrap_or_else(|_| unreachable!()) =
+                        ControlInterfaceState::Connected;
+                    break;
+                }
+                sleep(Duration::from_millis(50)).await;
+            }
+        });
+        let accept_handle = spawn(async move { listener.accept().await.unwrap().0 });
+
+        // Connect to the control interface through the socket
+        ci.connect(CONNECT_TIMEOUT).await.unwrap();
+        assert_eq!(get_state(&ci), ControlInterfaceState::Connected);
+
+        // Check that the initial hello was received through the socket
+        let mut socket = BufReader::new(accept_handle.await.unwrap());
+        let binary = tokio_timeout(Duration::from_secs(1), read_protobuf_data(&mut socket))
+            .await
+            .unwrap()
+            .unwrap();
+        let to_ankaios = ToAnkaios::decode(&mut Box::new(binary.as_ref())).unwrap();
+        assert_eq!(
+            to_ankaios.to_ankaios_enum,
+            Some(ToAnkaiosEnum::Hello(Hello {
+                protocol_version: ANKAIOS_VERSION.to_owned(),
+            }))
+        );
+
+        // Closing the socket closes the connection instead of waiting for the agent
+        drop(socket);
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(get_state(&ci), ControlInterfaceState::ConnectionClosed);
+    }
+
+    #[test]
+    fn utest_control_interface_debug_tap() {
+        let (response_sender, _response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let ci = ControlInterface::new(response_sender);
+        let from_ankaios = generate_test_proto_update_state_success("1234".to_owned());
+
+        // Without subscribers nothing is sent
+        ControlInterface::tap_response(&ci.debug_tap, &from_ankaios, 42);
+
+        let mut tap_receiver = ci.subscribe_debug_tap();
+        ControlInterface::tap_response(&ci.debug_tap, &from_ankaios, 42);
+        let tapped_response = tap_receiver.try_recv().unwrap();
+        assert_eq!(tapped_response.response.id, "1234");
+        assert!(matches!(
+            tapped_response.response.content,
+            ResponseType::UpdateStateSuccess(_)
+        ));
+        assert_eq!(tapped_response.size, 42);
+        assert!(tap_receiver.try_recv().is_err());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn utest_control_interface_send_request() {
         // Crate mpsc channel
@@ -1202,18 +2338,33 @@ mod tests {
             update_state_response.clone(),
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
         response_receiver.try_recv().unwrap_err(); // No response should be sent
 
-        // Test initialized state - received control interface accepted response
+        // Test initialized state - received response before the handshake
         *state.lock().unwrap() = ControlInterfaceState::Initialized;
+        ControlInterface::handle_decoded_response(
+            &state,
+            update_state_response.clone(),
+            &ci.response_sender,
+            &mut ci.log_senders_map,
+            &ci.log_entries_counters,
+            &mut ci.events_senders_map,
+        )
+        .await;
+        response_receiver.try_recv().unwrap_err(); // No response should be sent
+        assert!(matches!(get_state(&ci), ControlInterfaceState::Initialized));
+
+        // Test initialized state - received control interface accepted response
         ControlInterface::handle_decoded_response(
             &state,
             ci_accepted_response.clone(),
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1225,9 +2376,11 @@ mod tests {
             ci_accepted_response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
+        assert!(matches!(get_state(&ci), ControlInterfaceState::Connected));
 
         // Test connected state - received valid response
         response_receiver.try_recv().unwrap_err(); // No response should be sent
@@ -1236,6 +2389,7 @@ mod tests {
             update_state_response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1262,12 +2416,17 @@ mod tests {
         let mut ci = ControlInterface::new(response_sender);
         tmpdir.path().to_str().unwrap().clone_into(&mut ci.path);
         let (logs_sender, mut logs_receiver) = mpsc::channel::<LogResponse>(CHANNEL_SIZE);
-        ci.log_senders_map
-            .insert(REQUEST_ID_1.to_owned(), logs_sender);
+        let entries_counter = Arc::new(AtomicUsize::new(0));
+        ci.add_log_campaign(
+            REQUEST_ID_1.to_owned(),
+            ank_base::Request::default(),
+            logs_sender,
+            Arc::clone(&entries_counter),
+        );
 
         // Simulate connecting to the control interface
-        ci.prepare_writer();
-        ci.read_from_control_interface();
+        ci.prepare_writer(None);
+        ci.read_from_control_interface(None);
         ci.state
             .lock()
             .unwrap_or_else(|_| unreachable!())
@@ -1306,6 +2465,7 @@ mod tests {
         );
 
         assert_eq!(log_response, expected_log_entries);
+        assert_eq!(entries_counter.load(Ordering::Relaxed), 2);
 
         // Disconnect from the control interface
         ci.remove_log_campaign(REQUEST_ID_1);
@@ -1339,6 +2499,7 @@ mod tests {
             not_existing_log_request_id,
             Vec::default(),
             &ci.log_senders_map,
+            &ci.log_entries_counters,
         )
         .await;
 
@@ -1386,6 +2547,7 @@ mod tests {
             response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1406,6 +2568,7 @@ mod tests {
             response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1492,6 +2655,7 @@ mod tests {
             event_entry_response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1529,6 +2693,7 @@ mod tests {
             event_entry_response,
             &ci.response_sender,
             &mut ci.log_senders_map,
+            &ci.log_entries_counters,
             &mut ci.events_senders_map,
         )
         .await;
@@ -1543,7 +2708,12 @@ mod tests {
         let mut ci = ControlInterface::new(response_sender);
 
         let (logs_sender_1, _) = mpsc::channel::<LogResponse>(CHANNEL_SIZE);
-        ci.add_log_campaign(REQUEST_ID_1.to_owned(), logs_sender_1);
+        ci.add_log_campaign(
+            REQUEST_ID_1.to_owned(),
+            ank_base::Request::default(),
+            logs_sender_1,
+            Arc::default(),
+        );
 
         {
             let map_guard = ci.log_senders_map.senders_map.lock().unwrap();
@@ -1552,13 +2722,98 @@ mod tests {
         }
 
         let (logs_sender_2, _) = mpsc::channel::<LogResponse>(CHANNEL_SIZE);
-        ci.add_log_campaign(REQUEST_ID_2.to_owned(), logs_sender_2);
+        ci.add_log_campaign(
+            REQUEST_ID_2.to_owned(),
+            ank_base::Request::default(),
+            logs_sender_2,
+            Arc::default(),
+        );
 
         {
             let map_guard = ci.log_senders_map.senders_map.lock().unwrap();
             assert_eq!(map_guard.len(), 2);
             assert!(map_guard.get(REQUEST_ID_2).is_some());
         }
+        assert_eq!(ci.log_requests_map.lock().unwrap().len(), 2);
+        assert_eq!(ci.log_entries_counters.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn utest_control_interface_resubscribe_log_campaigns() {
+        let (response_sender, _) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let mut ci = ControlInterface::new(response_sender);
+        let (priority_ch_sender, mut priority_ch_receiver) = mpsc::unbounded_channel::<ToAnkaios>();
+        assert!(*ci.logs_resubscription.lock().unwrap());
+        ci.set_logs_resubscription(false);
+        assert!(!*ci.logs_resubscription.lock().unwrap());
+
+        let logs_request = ank_base::Request {
+            request_id: REQUEST_ID_1.to_owned(),
+            ..Default::default()
+        };
+        let (logs_sender, mut logs_receiver) = mpsc::channel::<LogResponse>(CHANNEL_SIZE);
+        ci.add_log_campaign(
+            REQUEST_ID_1.to_owned(),
+            logs_request.clone(),
+            logs_sender,
+            Arc::default(),
+        );
+
+        ControlInterface::resubscribe_log_campaigns(
+            &priority_ch_sender,
+            &ci.log_requests_map,
+            &ci.log_senders_map,
+        )
+        .await;
+
+        assert_eq!(
+            priority_ch_receiver.try_recv().unwrap(),
+            ToAnkaios {
+                to_ankaios_enum: Some(ToAnkaiosEnum::Request(logs_request)),
+            }
+        );
+        assert_eq!(logs_receiver.try_recv().unwrap(), LogResponse::Resubscribed);
+
+        ci.remove_log_campaign(REQUEST_ID_1);
+        assert!(ci.log_requests_map.lock().unwrap().is_empty());
+        ControlInterface::resubscribe_log_campaigns(
+            &priority_ch_sender,
+            &ci.log_requests_map,
+            &ci.log_senders_map,
+        )
+        .await;
+        assert!(priority_ch_receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn utest_control_interface_advance_log_request() {
+        let log_requests_map = Mutex::new(HashMap::from([(
+            REQUEST_ID_1.to_owned(),
+            ank_base::Request {
+                request_id: REQUEST_ID_1.to_owned(),
+                request_content: Some(ank_base::request::RequestContent::LogsRequest(
+                    ank_base::LogsRequest {
+                        follow: Some(true),
+                        tail: Some(10),
+                        since: Some("2025-01-01T00:00:00Z".to_owned()),
+                        ..Default::default()
+                    },
+                )),
+                ..Default::default()
+            },
+        )]));
+
+        ControlInterface::advance_log_request(&log_requests_map, REQUEST_ID_1);
+
+        let log_requests = log_requests_map.lock().unwrap();
+        let Some(ank_base::request::RequestContent::LogsRequest(logs_request)) =
+            &log_requests[REQUEST_ID_1].request_content
+        else {
+            panic!("Expected a logs request");
+        };
+        assert_eq!(logs_request.tail, None);
+        assert_ne!(logs_request.since.as_deref(), Some("2025-01-01T00:00:00Z"));
+        assert_eq!(logs_request.follow, Some(true));
     }
 
     #[tokio::test]
@@ -1662,4 +2917,99 @@ mod tests {
             assert!(map_guard.get(REQUEST_ID_2).is_none());
         }
     }
+
+    #[cfg(feature = "unstable-proto")]
+    #[tokio::test]
+    async fn utest_control_interface_add_remove_raw_request() {
+        let (response_sender, _) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let mut ci = ControlInterface::new(response_sender);
+
+        let raw_request_guard = ci.add_raw_request(REQUEST_ID_1.to_owned());
+        assert!(ci.raw_request_ids.contains(REQUEST_ID_1));
+
+        drop(raw_request_guard);
+        assert!(!ci.raw_request_ids.contains(REQUEST_ID_1));
+    }
+
+    #[cfg(feature = "unstable-proto")]
+    #[tokio::test]
+    async fn utest_control_interface_convert_raw_response() {
+        let (response_sender, _) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let mut ci = ControlInterface::new(response_sender);
+        let _raw_request_guard = ci.add_raw_request(REQUEST_ID_1.to_owned());
+
+        // Response for a raw request is kept as is
+        let response = ControlInterface::convert_response(
+            generate_test_proto_update_state_success(REQUEST_ID_1.to_owned()),
+            &ci.raw_request_ids,
+        );
+        assert_eq!(response.id, REQUEST_ID_1);
+        assert!(matches!(response.content, ResponseType::RawResponse(_)));
+        assert!(!ci.raw_request_ids.contains(REQUEST_ID_1));
+
+        // Response for any other request is converted
+        let response = ControlInterface::convert_response(
+            generate_test_proto_update_state_success(REQUEST_ID_2.to_owned()),
+            &ci.raw_request_ids,
+        );
+        assert!(matches!(
+            response.content,
+            ResponseType::UpdateStateSuccess(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn utest_control_interface_close_connection() {
+        let (response_sender, mut response_receiver) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let mut ci = ControlInterface::new(response_sender.clone());
+        ControlInterface::change_state(&ci.state, ControlInterfaceState::Connected);
+
+        ControlInterface::close_connection(
+            &ci.state,
+            &ci.connection_closed_reason,
+            &response_sender,
+            &ci.tasks_shutdown,
+            "fifo broken".to_owned(),
+        )
+        .await;
+
+        assert_eq!(get_state(&ci), ControlInterfaceState::ConnectionClosed);
+        assert!(ci.tasks_shutdown.is_cancelled());
+        let response = response_receiver.recv().await.unwrap();
+        assert!(
+            matches!(response.content, ResponseType::ConnectionClosedReason(reason) if reason == "fifo broken")
+        );
+
+        // Requests fail immediately after the connection was closed
+        let result = ci.write_request(generate_test_request()).await;
+        assert!(matches!(
+            result,
+            Err(AnkaiosError::ConnectionClosedError(reason)) if reason == "fifo broken"
+        ));
+    }
+
+    #[tokio::test]
+    async fn utest_control_interface_mark_agent_unresponsive() {
+        let (response_sender, _) = mpsc::channel::<Response>(CHANNEL_SIZE);
+        let mut ci = ControlInterface::new(response_sender);
+        let (priority_sender, mut priority_receiver) = mpsc::unbounded_channel::<ToAnkaios>();
+        ci.priority_ch_sender = Some(priority_sender);
+
+        // Nothing happens if not connected
+        ControlInterface::change_state(&ci.state, ControlInterfaceState::Initialized);
+        ci.mark_agent_unresponsive();
+        assert_eq!(get_state(&ci), ControlInterfaceState::Initialized);
+        assert!(!ci.agent_disconnected.load(Ordering::Relaxed));
+        priority_receiver.try_recv().unwrap_err();
+
+        ControlInterface::change_state(&ci.state, ControlInterfaceState::Connected);
+        ci.mark_agent_unresponsive();
+        assert_eq!(get_state(&ci), ControlInterfaceState::AgentDisconnected);
+        // The log campaigns are resubscribed once connected again
+        assert!(ci.agent_disconnected.load(Ordering::Relaxed));
+        assert!(matches!(
+            priority_receiver.try_recv().unwrap().to_ankaios_enum,
+            Some(ToAnkaiosEnum::Hello(_))
+        ));
+    }
 }
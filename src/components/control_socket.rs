@@ -0,0 +1,190 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`ControlSocketAddress`] enum, describing the socket
+//! used by the [`ControlInterface`](crate::components::control_interface::ControlInterface)
+//! when the FIFO pipes are not available, e.g. on virtual targets without the FIFO mount.
+//!
+//! The socket speaks the same length-delimited protobuf messages as the FIFO pipes
+//! and is configured with the [`ANKAIOS_CONTROL_SOCKET_ENV`] environment variable:
+//!
+//! - `unix:/path/to/socket` or an absolute path for a Unix socket;
+//! - `tcp:host:port` or `host:port` for a TCP socket.
+
+use std::{env, fmt, path::PathBuf};
+use tokio::{
+    io::{self, AsyncRead, AsyncWrite},
+    net::{TcpStream, UnixStream},
+};
+
+use crate::AnkaiosError;
+use crate::logging::sdk_log;
+
+/// The environment variable containing the address of the control socket.
+pub const ANKAIOS_CONTROL_SOCKET_ENV: &str = "ANKAIOS_CONTROL_SOCKET";
+/// Prefix of a Unix socket address.
+const UNIX_PREFIX: &str = "unix:";
+/// Prefix of a TCP socket address.
+const TCP_PREFIX: &str = "tcp:";
+
+/// The reading half of a connection to the control interface, a FIFO pipe or a socket.
+pub(crate) type ConnectionReader = Box<dyn AsyncRead + Send + Unpin>;
+/// The writing half of a connection to the control interface, a FIFO pipe or a socket.
+pub(crate) type ConnectionWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Enum describing the address of the control socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlSocketAddress {
+    /// A Unix socket, containing its path.
+    Unix(PathBuf),
+    /// A TCP socket, containing its `host:port` address.
+    Tcp(String),
+}
+
+impl ControlSocketAddress {
+    /// Parses the address of a control socket.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` - The address, `unix:<path>`, an absolute path, `tcp:<host>:<port>` or `<host>:<port>`.
+    ///
+    /// ## Returns
+    ///
+    /// The parsed [`ControlSocketAddress`].
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ControlInterfaceError`](AnkaiosError::ControlInterfaceError) if the address is invalid.
+    pub fn parse(address: &str) -> Result<Self, AnkaiosError> {
+        let trimmed = address.trim();
+        if let Some(path) = trimmed.strip_prefix(UNIX_PREFIX) {
+            if path.is_empty() {
+                return Err(Self::invalid(trimmed));
+            }
+            return Ok(Self::Unix(PathBuf::from(path)));
+        }
+        if trimmed.starts_with('/') {
+            return Ok(Self::Unix(PathBuf::from(trimmed)));
+        }
+        let host_port = trimmed.strip_prefix(TCP_PREFIX).unwrap_or(trimmed);
+        match host_port.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {
+                Ok(Self::Tcp(host_port.to_owned()))
+            }
+            _ => Err(Self::invalid(trimmed)),
+        }
+    }
+
+    /// Reads the address of the control socket from the [`ANKAIOS_CONTROL_SOCKET_ENV`] environment variable.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ControlSocketAddress`] if the variable is set and valid, [None] otherwise.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let address = env::var(ANKAIOS_CONTROL_SOCKET_ENV).ok()?;
+        Self::parse(&address)
+            .inspect_err(|err| {
+                sdk_log!(warn, "Ignoring {ANKAIOS_CONTROL_SOCKET_ENV}: {err}");
+            })
+            .ok()
+    }
+
+    /// Connects to the control socket.
+    ///
+    /// ## Returns
+    ///
+    /// The reading and writing halves of the connected socket.
+    ///
+    /// ## Errors
+    ///
+    /// An [`io::Error`] if the connection failed.
+    pub(crate) async fn connect(&self) -> io::Result<(ConnectionReader, ConnectionWriter)> {
+        match self {
+            Self::Unix(path) => {
+                let (reader, writer) = UnixStream::connect(path).await?.into_split();
+                Ok((Box::new(reader), Box::new(writer)))
+            }
+            Self::Tcp(address) => {
+                let stream = TcpStream::connect(address).await?;
+                stream.set_nodelay(true)?;
+                let (reader, writer) = stream.into_split();
+                Ok((Box::new(reader), Box::new(writer)))
+            }
+        }
+    }
+
+    /// Creates the error for an invalid address.
+    fn invalid(address: &str) -> AnkaiosError {
+        AnkaiosError::ControlInterfaceError(format!("Invalid control socket address '{address}'."))
+    }
+}
+
+impl fmt::Display for ControlSocketAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unix(path) => write!(f, "{UNIX_PREFIX}{}", path.display()),
+            Self::Tcp(address) => write!(f, "{TCP_PREFIX}{address}"),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::ControlSocketAddress;
+    use crate::AnkaiosError;
+
+    #[test]
+    fn utest_control_socket_address_parse() {
+        assert_eq!(
+            ControlSocketAddress::parse("unix:/run/ankaios/control.sock").unwrap(),
+            ControlSocketAddress::Unix(PathBuf::from("/run/ankaios/control.sock"))
+        );
+        assert_eq!(
+            ControlSocketAddress::parse("/tmp/control.sock").unwrap(),
+            ControlSocketAddress::Unix(PathBuf::from("/tmp/control.sock"))
+        );
+        assert_eq!(
+            ControlSocketAddress::parse("tcp:10.0.2.2:25551").unwrap(),
+            ControlSocketAddress::Tcp("10.0.2.2:25551".to_owned())
+        );
+        assert_eq!(
+            ControlSocketAddress::parse("localhost:25551").unwrap(),
+            ControlSocketAddress::Tcp("localhost:25551".to_owned())
+        );
+        assert_eq!(
+            ControlSocketAddress::parse("localhost:25551")
+                .unwrap()
+                .to_string(),
+            "tcp:localhost:25551"
+        );
+
+        for invalid in ["", "unix:", "localhost", "tcp::25551", "localhost:port"] {
+            assert!(matches!(
+                ControlSocketAddress::parse(invalid),
+                Err(AnkaiosError::ControlInterfaceError(_))
+            ));
+        }
+    }
+}
@@ -31,6 +31,22 @@
 //! };
 //! ```
 //!
+//! ## Request the logs of the last 10 minutes:
+//!
+//! ```rust
+//! use ankaios_sdk::LogsRequest;
+//! # use ankaios_sdk::WorkloadInstanceName;
+//! use std::time::Duration;
+//!
+//! let workload: WorkloadInstanceName;
+//! # let workload = WorkloadInstanceName::default();
+//! let logs_request = LogsRequest {
+//!    workload_names: vec![workload],
+//!    since_duration: Some(Duration::from_secs(10 * 60)),
+//!    ..Default::default()
+//! };
+//! ```
+//!
 //! ## Check accepted workloads in a log campaign response:
 //!
 //! ```rust
@@ -63,11 +79,26 @@
 //!         }
 //!         LogResponse::LogsStopResponse(workload_name) => {
 //!         }
+//!         LogResponse::Resubscribed => {
+//!         }
 //!     }
 //! }
 //! # })
 //! ```
 //!
+//! ## Tell the backlog from the live entries of a log tail:
+//!
+//! ```rust
+//! use ankaios_sdk::TailedLogEntry;
+//!
+//! let tailed_entry: TailedLogEntry;
+//! # tailed_entry = TailedLogEntry::Live(ankaios_sdk::LogEntry::default());
+//! match tailed_entry {
+//!     TailedLogEntry::Backlog(log_entry) => println!("old: {}", log_entry.message),
+//!     TailedLogEntry::Live(log_entry) => println!("new: {}", log_entry.message),
+//! }
+//! ```
+//!
 //! ## Extract the log and workload name from a log entry:
 //!
 //! ```rust
@@ -79,13 +110,40 @@
 //! let log_message = log_entries.message;
 //! ```
 
-use tokio::sync::mpsc::Receiver;
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    sync::mpsc::{Receiver, Sender, error::TryRecvError},
+    time::{Duration, timeout as tokio_timeout},
+};
+use tokio_util::sync::CancellationToken;
 
+use crate::logging::sdk_log;
 use crate::{
-    ankaios_api, components::workload_state_mod::WorkloadInstanceName,
-    extensions::UnreachableOption,
+    AnkaiosError, ankaios::CHANNEL_SIZE, ankaios_api,
+    components::workload_state_mod::WorkloadInstanceName, extensions::UnreachableOption,
 };
 
+/// Enum that describes what happens when the log responses of a log campaign
+/// are not received fast enough and its buffer is full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogOverflowPolicy {
+    /// Wait until there is space in the buffer. A slow consumer delays
+    /// all the responses received from the control interface.
+    #[default]
+    Block,
+    /// Drop the newly received log entries.
+    DropNewest,
+    /// Drop the oldest buffered log entries to make room for the new ones.
+    DropOldest,
+}
+
 /// Struct that represents a logs request.
 #[derive(Debug, Clone)]
 pub struct LogsRequest {
@@ -99,6 +157,16 @@ pub struct LogsRequest {
     pub since: Option<String>,
     /// Show logs before the timestamp in RFC3339 format (default: None).
     pub until: Option<String>,
+    /// Show logs of the given duration before sending the request, e.g. the last 10 minutes (default: None).
+    /// Takes precedence over `since`.
+    pub since_duration: Option<Duration>,
+    /// Show logs older than the given duration before sending the request (default: None).
+    /// Takes precedence over `until`.
+    pub until_duration: Option<Duration>,
+    /// The number of log responses buffered for the log campaign (default: 100).
+    pub buffer_size: usize,
+    /// What happens when the buffer is full (default: [`LogOverflowPolicy::Block`]).
+    pub overflow_policy: LogOverflowPolicy,
 }
 
 impl Default for LogsRequest {
@@ -115,10 +183,71 @@ impl Default for LogsRequest {
             tail: -1,
             since: None,
             until: None,
+            since_duration: None,
+            until_duration: None,
+            buffer_size: CHANNEL_SIZE,
+            overflow_policy: LogOverflowPolicy::Block,
         }
     }
 }
 
+/// Returns the RFC3339 timestamp, in UTC, of the moment the given duration before now.
+///
+/// ## Arguments
+///
+/// * `duration` - The time elapsed since the moment.
+///
+/// ## Returns
+///
+/// A [String] containing the timestamp, the UNIX epoch if the duration reaches further back.
+pub(crate) fn rfc3339_before_now(duration: Duration) -> String {
+    to_rfc3339(
+        SystemTime::now()
+            .checked_sub(duration)
+            .unwrap_or(UNIX_EPOCH),
+    )
+}
+
+/// Formats a point in time as an RFC3339 timestamp in UTC, with a precision of one second.
+///
+/// ## Arguments
+///
+/// * `time` - The point in time to format.
+///
+/// ## Returns
+///
+/// A [String] containing the timestamp, the UNIX epoch for earlier points in time.
+fn to_rfc3339(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+
+    // Converts the days since the epoch to a civil date, the years starting in March.
+    let shifted_days = days + 719_468;
+    let era = shifted_days / 146_097;
+    let day_of_era = shifted_days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60,
+        seconds_of_day % 60
+    )
+}
+
 /// Struct that represents a log entry.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct LogEntry {
@@ -144,6 +273,9 @@ pub enum LogResponse {
     LogEntries(Vec<LogEntry>),
     /// A response indicating the stop of log entries for a specific workload.
     LogsStopResponse(WorkloadInstanceName),
+    /// A marker indicating that the log campaign was resubscribed after the agent reconnected.
+    /// Log entries might have been lost or be received again.
+    Resubscribed,
 }
 
 /// Struct that represents a response of a log request.
@@ -155,6 +287,8 @@ pub struct LogCampaignResponse {
     pub accepted_workload_names: Vec<WorkloadInstanceName>,
     /// A [Receiver] that can be used to receive log responses.
     pub logs_receiver: Receiver<LogResponse>,
+    /// The number of log entries dropped because of the overflow policy.
+    dropped_count: Arc<AtomicUsize>,
 }
 
 impl LogCampaignResponse {
@@ -180,9 +314,27 @@ impl LogCampaignResponse {
             request_id,
             accepted_workload_names,
             logs_receiver,
+            dropped_count: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Gets the number of log entries dropped because the buffer of the log campaign was full.
+    ///
+    /// The count is always zero for the [`LogOverflowPolicy::Block`] policy.
+    ///
+    /// ## Returns
+    ///
+    /// The number of dropped log entries.
+    #[must_use]
+    pub fn dropped_count(&self) -> usize {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Gets the counter of the dropped log entries, shared with the task forwarding the log responses.
+    pub(crate) fn dropped_counter(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.dropped_count)
+    }
+
     #[doc(hidden)]
     /// Gets the request id.
     ///
@@ -193,6 +345,305 @@ impl LogCampaignResponse {
     pub fn get_request_id(&self) -> String {
         self.request_id.clone()
     }
+
+    /// Receives the next log response, unless cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cancellation_token` - A [`CancellationToken`] that aborts the waiting when cancelled.
+    ///
+    /// ## Returns
+    ///
+    /// The next [`LogResponse`] or [None] if the log campaign is closed.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`CancelledError`](AnkaiosError::CancelledError) if the token was cancelled before a log response was received.
+    pub async fn recv_with_cancellation(
+        &mut self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<LogResponse>, AnkaiosError> {
+        tokio::select! {
+            log_response = self.logs_receiver.recv() => Ok(log_response),
+            () = cancellation_token.cancelled() => {
+                sdk_log!(debug, "Receiving logs for request id '{}' was cancelled.", self.request_id);
+                Err(AnkaiosError::CancelledError(
+                    "Receiving logs was cancelled.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Returns the next log response if one is already available, without waiting.
+    ///
+    /// This is useful for polling the log campaign from a loop that is not driven
+    /// by the async runtime, e.g. a GUI thread.
+    ///
+    /// ## Returns
+    ///
+    /// The next [`LogResponse`] or [None] if no log response is available yet.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`ConnectionClosedError`](AnkaiosError::ConnectionClosedError) if the log campaign is closed.
+    pub fn try_next(&mut self) -> Result<Option<LogResponse>, AnkaiosError> {
+        match self.logs_receiver.try_recv() {
+            Ok(log_response) => Ok(Some(log_response)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(AnkaiosError::ConnectionClosedError(format!(
+                "The log campaign for request id '{}' is closed.",
+                self.request_id
+            ))),
+        }
+    }
+
+    /// Receives the next log response, waiting at most the given duration.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` - The maximum time to wait for a log response.
+    ///
+    /// ## Returns
+    ///
+    /// The next [`LogResponse`] or [None] if the log campaign is closed.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`TimeoutError`](AnkaiosError::TimeoutError) if no log response was received in time.
+    pub async fn recv_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<LogResponse>, AnkaiosError> {
+        tokio_timeout(timeout, self.logs_receiver.recv())
+            .await
+            .map_err(|_| AnkaiosError::TimeoutError {
+                request_id: Some(self.request_id.clone()),
+                request_type: "LogsRequest".to_owned(),
+                elapsed: timeout,
+            })
+    }
+}
+
+/// Struct that describes a log campaign that is still active.
+#[derive(Debug, Clone)]
+pub struct LogCampaignInfo {
+    /// The request id as a [String] of the initial logs request.
+    pub request_id: String,
+    /// A vector of [`WorkloadInstanceName`] that were accepted for log collection.
+    pub workload_names: Vec<WorkloadInstanceName>,
+    /// The time at which the log campaign was started.
+    pub start_time: SystemTime,
+    /// The number of log entries received from [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+    entries_received: Arc<AtomicUsize>,
+}
+
+impl LogCampaignInfo {
+    /// Creates a new `LogCampaignInfo` object for a log campaign starting now.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - The request id as a [String] of the initial logs request;
+    /// * `workload_names` - A vector of [`WorkloadInstanceName`] that were accepted for log collection.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`LogCampaignInfo`] object.
+    pub(crate) fn new(request_id: String, workload_names: Vec<WorkloadInstanceName>) -> Self {
+        LogCampaignInfo {
+            request_id,
+            workload_names,
+            start_time: SystemTime::now(),
+            entries_received: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Gets the number of log entries received for the log campaign so far,
+    /// including the ones dropped because of the overflow policy.
+    ///
+    /// ## Returns
+    ///
+    /// The number of received log entries.
+    #[must_use]
+    pub fn entries_received(&self) -> usize {
+        self.entries_received.load(Ordering::Relaxed)
+    }
+
+    /// Gets the counter of the received log entries, shared with the control interface.
+    pub(crate) fn entries_counter(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.entries_received)
+    }
+}
+
+/// Forwards the log responses of a log campaign, buffering at most `buffer_size` of them.
+/// When the buffer is full, log entries are dropped according to the `overflow_policy`,
+/// so that the sender is never blocked by a slow receiver. Stop and resubscribed markers
+/// are never dropped.
+///
+/// ## Arguments
+///
+/// * `incoming` - The [Receiver] of the log responses coming from the control interface;
+/// * `outgoing` - The [Sender] towards the log campaign receiver;
+/// * `buffer_size` - The maximum number of buffered log responses;
+/// * `overflow_policy` - The [`LogOverflowPolicy`] applied when the buffer is full;
+/// * `dropped_count` - The counter of the dropped log entries.
+pub(crate) async fn forward_with_overflow_policy(
+    mut incoming: Receiver<LogResponse>,
+    outgoing: Sender<LogResponse>,
+    buffer_size: usize,
+    overflow_policy: LogOverflowPolicy,
+    dropped_count: Arc<AtomicUsize>,
+) {
+    let mut buffer: VecDeque<LogResponse> = VecDeque::with_capacity(buffer_size);
+    loop {
+        tokio::select! {
+            // Forward the buffered log responses first to keep the buffer small
+            biased;
+            reserved = outgoing.reserve(), if !buffer.is_empty() => {
+                let Ok(permit) = reserved else {
+                    sdk_log!(debug, "Log campaign receiver dropped, stopping the forwarding.");
+                    return;
+                };
+                permit.send(buffer.pop_front().unwrap_or_unreachable());
+            }
+            received = incoming.recv() => {
+                let Some(log_response) = received else {
+                    break;
+                };
+                if buffer.len() < buffer_size {
+                    buffer.push_back(log_response);
+                    continue;
+                }
+                let dropped_entries = if overflow_policy == LogOverflowPolicy::DropOldest {
+                    let oldest_entries = buffer
+                        .iter()
+                        .position(|buffered| matches!(buffered, LogResponse::LogEntries(_)))
+                        .and_then(|index| buffer.remove(index));
+                    buffer.push_back(log_response);
+                    oldest_entries
+                } else if let LogResponse::LogEntries(_) = log_response {
+                    Some(log_response)
+                } else {
+                    buffer.push_back(log_response);
+                    None
+                };
+                if let Some(LogResponse::LogEntries(log_entries)) = dropped_entries {
+                    dropped_count.fetch_add(log_entries.len(), Ordering::Relaxed);
+                    sdk_log!(debug, "Log buffer full, dropped {} log entries.", log_entries.len());
+                }
+            }
+        }
+    }
+    for log_response in buffer {
+        if outgoing.send(log_response).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Enum that represents a log entry of a [`LogTail`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TailedLogEntry {
+    /// A log entry of the requested last lines, logged before the tail started.
+    Backlog(LogEntry),
+    /// A log entry received after the requested last lines.
+    Live(LogEntry),
+}
+
+/// Struct that represents the tail of the logs of a workload.
+#[derive(Debug)]
+pub struct LogTail {
+    /// The request id as a [String] of the underlying logs request.
+    request_id: String,
+    /// A [Receiver] of the log entries, closed when the logs of the workload stop.
+    pub entries_receiver: Receiver<TailedLogEntry>,
+}
+
+impl LogTail {
+    /// Creates a new `LogTail` object.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - The request id as a [String] of the underlying logs request;
+    /// * `entries_receiver` - A [Receiver] of the log entries.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`LogTail`] object.
+    pub(crate) fn new(request_id: String, entries_receiver: Receiver<TailedLogEntry>) -> Self {
+        LogTail {
+            request_id,
+            entries_receiver,
+        }
+    }
+
+    /// Gets the request id of the underlying logs request.
+    ///
+    /// ## Returns
+    ///
+    /// The request id as a [String].
+    #[must_use]
+    pub fn get_request_id(&self) -> String {
+        self.request_id.clone()
+    }
+}
+
+/// Forwards the log entries of a log campaign as [`TailedLogEntry`] objects.
+/// The first `backlog_lines` entries are part of the backlog, unless no entry is received
+/// for a `quiet_period`, which means that fewer lines were available.
+/// The forwarding stops when the logs of the workload stop.
+///
+/// ## Arguments
+///
+/// * `logs_receiver` - The [Receiver] of the log responses of the log campaign;
+/// * `entries_sender` - The [Sender] towards the log tail receiver;
+/// * `backlog_lines` - The number of requested last lines;
+/// * `quiet_period` - The time without entries after which the backlog is considered complete.
+pub(crate) async fn forward_tailed_log_entries(
+    mut logs_receiver: Receiver<LogResponse>,
+    entries_sender: Sender<TailedLogEntry>,
+    backlog_lines: usize,
+    quiet_period: Duration,
+) {
+    let mut remaining_backlog = backlog_lines;
+    loop {
+        let received = if remaining_backlog > 0 {
+            let Ok(received_in_time) = tokio_timeout(quiet_period, logs_receiver.recv()).await
+            else {
+                sdk_log!(
+                    trace,
+                    "No more backlog entries, {remaining_backlog} lines less than requested."
+                );
+                remaining_backlog = 0;
+                continue;
+            };
+            received_in_time
+        } else {
+            logs_receiver.recv().await
+        };
+        match received {
+            Some(LogResponse::LogEntries(log_entries)) => {
+                for log_entry in log_entries {
+                    let tailed_entry = if remaining_backlog > 0 {
+                        remaining_backlog -= 1;
+                        TailedLogEntry::Backlog(log_entry)
+                    } else {
+                        TailedLogEntry::Live(log_entry)
+                    };
+                    if entries_sender.send(tailed_entry).await.is_err() {
+                        sdk_log!(debug, "Log tail receiver dropped, stopping the forwarding.");
+                        return;
+                    }
+                }
+            }
+            Some(LogResponse::Resubscribed) => {
+                sdk_log!(
+                    debug,
+                    "Log tail resubscribed, log entries might have been lost."
+                );
+            }
+            Some(LogResponse::LogsStopResponse(_)) | None => break,
+        }
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -204,8 +655,17 @@ impl LogCampaignResponse {
 //////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::{LogCampaignResponse, LogEntry, WorkloadInstanceName, ankaios_api};
-    use tokio::sync::mpsc;
+    use super::{
+        AnkaiosError, CHANNEL_SIZE, LogCampaignInfo, LogCampaignResponse, LogEntry,
+        LogOverflowPolicy, LogResponse, Ordering, TailedLogEntry, UNIX_EPOCH, WorkloadInstanceName,
+        ankaios_api, forward_tailed_log_entries, forward_with_overflow_policy, rfc3339_before_now,
+        to_rfc3339,
+    };
+    use tokio::{
+        sync::mpsc,
+        time::{Duration, sleep},
+    };
+    use tokio_util::sync::CancellationToken;
 
     const REQUEST_ID: &str = "test_request_id";
     const AGENT_A: &str = "agent_A";
@@ -235,6 +695,32 @@ mod tests {
         assert_eq!(sdk_entry.message, TEST_LOG_MESSAGE.to_owned());
     }
 
+    #[test]
+    fn utest_to_rfc3339() {
+        assert_eq!(to_rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            to_rfc3339(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+        assert_eq!(
+            to_rfc3339(UNIX_EPOCH + Duration::from_secs(1_767_225_599)),
+            "2025-12-31T23:59:59Z"
+        );
+        assert_eq!(
+            to_rfc3339(UNIX_EPOCH - Duration::from_secs(1)),
+            "1970-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn utest_rfc3339_before_now() {
+        assert_eq!(
+            rfc3339_before_now(Duration::from_secs(u64::MAX)),
+            "1970-01-01T00:00:00Z"
+        );
+        assert!(rfc3339_before_now(Duration::from_secs(600)) < rfc3339_before_now(Duration::ZERO));
+    }
+
     #[test]
     fn utest_log_campaign_response_get_request_id() {
         let (_logs_sender, logs_receiver) = mpsc::channel(1);
@@ -242,4 +728,244 @@ mod tests {
             LogCampaignResponse::new(REQUEST_ID.to_owned(), Vec::default(), logs_receiver);
         assert_eq!(log_campaign_response.get_request_id(), REQUEST_ID);
     }
+
+    #[test]
+    fn utest_log_campaign_info_entries_received() {
+        let instance_name = WorkloadInstanceName::new(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME.to_owned(),
+            WORKLOAD_ID.to_owned(),
+        );
+        let log_campaign_info =
+            LogCampaignInfo::new(REQUEST_ID.to_owned(), vec![instance_name.clone()]);
+        assert_eq!(log_campaign_info.request_id, REQUEST_ID);
+        assert_eq!(log_campaign_info.workload_names, vec![instance_name]);
+        assert_eq!(log_campaign_info.entries_received(), 0);
+
+        let snapshot = log_campaign_info.clone();
+        log_campaign_info
+            .entries_counter()
+            .fetch_add(3, Ordering::Relaxed);
+        assert_eq!(log_campaign_info.entries_received(), 3);
+        assert_eq!(snapshot.entries_received(), 3);
+    }
+
+    #[tokio::test]
+    async fn utest_log_campaign_response_recv_with_cancellation() {
+        let (logs_sender, logs_receiver) = mpsc::channel(1);
+        let mut log_campaign_response =
+            LogCampaignResponse::new(REQUEST_ID.to_owned(), Vec::default(), logs_receiver);
+        let cancellation_token = CancellationToken::new();
+
+        let log_response = LogResponse::LogEntries(vec![LogEntry::default()]);
+        logs_sender.send(log_response.clone()).await.unwrap();
+        assert_eq!(
+            log_campaign_response
+                .recv_with_cancellation(&cancellation_token)
+                .await
+                .unwrap(),
+            Some(log_response)
+        );
+
+        cancellation_token.cancel();
+        assert!(matches!(
+            log_campaign_response
+                .recv_with_cancellation(&cancellation_token)
+                .await,
+            Err(AnkaiosError::CancelledError(_))
+        ));
+    }
+
+    #[test]
+    fn utest_log_campaign_response_try_next() {
+        let (logs_sender, logs_receiver) = mpsc::channel(1);
+        let mut log_campaign_response =
+            LogCampaignResponse::new(REQUEST_ID.to_owned(), Vec::default(), logs_receiver);
+        assert_eq!(log_campaign_response.try_next().unwrap(), None);
+
+        let log_response = LogResponse::LogEntries(vec![LogEntry::default()]);
+        logs_sender.try_send(log_response.clone()).unwrap();
+        assert_eq!(
+            log_campaign_response.try_next().unwrap(),
+            Some(log_response)
+        );
+
+        drop(logs_sender);
+        assert!(matches!(
+            log_campaign_response.try_next(),
+            Err(AnkaiosError::ConnectionClosedError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn utest_log_campaign_response_recv_timeout() {
+        let (logs_sender, logs_receiver) = mpsc::channel(1);
+        let mut log_campaign_response =
+            LogCampaignResponse::new(REQUEST_ID.to_owned(), Vec::default(), logs_receiver);
+        assert!(matches!(
+            log_campaign_response
+                .recv_timeout(Duration::from_millis(10))
+                .await,
+            Err(AnkaiosError::TimeoutError { .. })
+        ));
+
+        let log_response = LogResponse::LogEntries(vec![LogEntry::default()]);
+        logs_sender.send(log_response.clone()).await.unwrap();
+        assert_eq!(
+            log_campaign_response
+                .recv_timeout(Duration::from_millis(10))
+                .await
+                .unwrap(),
+            Some(log_response)
+        );
+
+        drop(logs_sender);
+        assert_eq!(
+            log_campaign_response
+                .recv_timeout(Duration::from_millis(10))
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    fn generate_log_entries(message: &str) -> LogResponse {
+        LogResponse::LogEntries(vec![LogEntry {
+            message: message.to_owned(),
+            ..Default::default()
+        }])
+    }
+
+    async fn forward_and_collect(
+        log_responses: Vec<LogResponse>,
+        overflow_policy: LogOverflowPolicy,
+    ) -> (Vec<LogResponse>, usize) {
+        let (incoming_sender, incoming_receiver) = mpsc::channel(CHANNEL_SIZE);
+        let (outgoing_sender, logs_receiver) = mpsc::channel(1);
+        let mut log_campaign_response =
+            LogCampaignResponse::new(REQUEST_ID.to_owned(), Vec::default(), logs_receiver);
+        for log_response in log_responses {
+            incoming_sender.send(log_response).await.unwrap();
+        }
+        drop(incoming_sender);
+
+        let forward_handle = tokio::spawn(forward_with_overflow_policy(
+            incoming_receiver,
+            outgoing_sender,
+            2,
+            overflow_policy,
+            log_campaign_response.dropped_counter(),
+        ));
+        // Let the forwarding task fill its buffer before consuming
+        sleep(Duration::from_millis(50)).await;
+        let dropped_count = log_campaign_response.dropped_count();
+
+        let mut received = Vec::new();
+        while let Some(log_response) = log_campaign_response.logs_receiver.recv().await {
+            received.push(log_response);
+        }
+        forward_handle.await.unwrap();
+        (received, dropped_count)
+    }
+
+    #[tokio::test]
+    async fn utest_forward_with_overflow_policy_drop_newest() {
+        let instance_name = WorkloadInstanceName::default();
+        let (received, dropped_count) = forward_and_collect(
+            vec![
+                generate_log_entries("1"),
+                generate_log_entries("2"),
+                generate_log_entries("3"),
+                generate_log_entries("4"),
+                LogResponse::LogsStopResponse(instance_name.clone()),
+            ],
+            LogOverflowPolicy::DropNewest,
+        )
+        .await;
+
+        assert_eq!(
+            received,
+            vec![
+                generate_log_entries("1"),
+                generate_log_entries("2"),
+                generate_log_entries("3"),
+                LogResponse::LogsStopResponse(instance_name),
+            ]
+        );
+        assert_eq!(dropped_count, 1);
+    }
+
+    #[tokio::test]
+    async fn utest_forward_with_overflow_policy_drop_oldest() {
+        let (received, dropped_count) = forward_and_collect(
+            vec![
+                generate_log_entries("1"),
+                generate_log_entries("2"),
+                generate_log_entries("3"),
+                generate_log_entries("4"),
+                generate_log_entries("5"),
+            ],
+            LogOverflowPolicy::DropOldest,
+        )
+        .await;
+
+        assert_eq!(
+            received,
+            vec![
+                generate_log_entries("1"),
+                generate_log_entries("4"),
+                generate_log_entries("5"),
+            ]
+        );
+        assert_eq!(dropped_count, 2);
+    }
+
+    #[tokio::test]
+    async fn utest_forward_tailed_log_entries() {
+        let (logs_sender, logs_receiver) = mpsc::channel(CHANNEL_SIZE);
+        let (entries_sender, mut entries_receiver) = mpsc::channel(CHANNEL_SIZE);
+        let log_entry = LogEntry {
+            message: TEST_LOG_MESSAGE.to_owned(),
+            ..Default::default()
+        };
+
+        let forwarding = tokio::spawn(forward_tailed_log_entries(
+            logs_receiver,
+            entries_sender,
+            3,
+            Duration::from_millis(20),
+        ));
+
+        // Fewer lines than requested are available
+        logs_sender
+            .send(LogResponse::LogEntries(vec![log_entry.clone(); 2]))
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(50)).await;
+        logs_sender
+            .send(LogResponse::LogEntries(vec![log_entry.clone()]))
+            .await
+            .unwrap();
+        logs_sender.send(LogResponse::Resubscribed).await.unwrap();
+        logs_sender
+            .send(LogResponse::LogsStopResponse(
+                WorkloadInstanceName::default(),
+            ))
+            .await
+            .unwrap();
+        forwarding.await.unwrap();
+
+        let mut tailed_entries = Vec::new();
+        while let Some(tailed_entry) = entries_receiver.recv().await {
+            tailed_entries.push(tailed_entry);
+        }
+        assert_eq!(
+            tailed_entries,
+            vec![
+                TailedLogEntry::Backlog(log_entry.clone()),
+                TailedLogEntry::Backlog(log_entry.clone()),
+                TailedLogEntry::Live(log_entry),
+            ]
+        );
+    }
 }
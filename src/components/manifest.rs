@@ -12,18 +12,29 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-//! This module contains the [Manifest] struct.
+//! This module contains the [Manifest] struct, its mutable views and the [`ManifestMergePolicy`] enum.
 
+use super::complete_state::{SUPPORTED_API_VERSIONS, config_item_from_value, config_item_to_value};
+use super::manifest_schema::{self, MANIFEST_SCHEMA};
+use super::mask::check_name;
 use super::workload_mod::WORKLOADS_PREFIX;
 use crate::ankaios_api;
-use crate::{AnkaiosError, Workload};
+use crate::{AnkaiosError, CompleteState, Workload};
 use ankaios_api::ank_base;
-use std::{collections::HashMap, path::Path};
+use serde_yaml::Value;
+use std::{
+    collections::HashMap,
+    io::{self, Read},
+    mem,
+    ops::{Deref, DerefMut},
+    path::Path,
+    sync::LazyLock,
+};
 
 // Disable this from coverage
 // https://github.com/rust-lang/rust/issues/84605
 #[cfg(not(test))]
-use std::{fs, io};
+use std::fs;
 /// Helper function to read a file to a string.
 #[cfg(not(test))]
 fn read_file_to_string(path: &Path) -> Result<String, io::Error> {
@@ -35,6 +46,25 @@ use self::read_to_string_mock as read_file_to_string;
 
 /// The prefix for the configs in the desired state.
 pub const CONFIGS_PREFIX: &str = "desiredState.configs";
+/// The path that stands for the standard input, as in `apply -f -`.
+const STDIN_PATH: &str = "-";
+
+/// The parsed [`MANIFEST_SCHEMA`].
+static PARSED_MANIFEST_SCHEMA: LazyLock<serde_json::Value> =
+    LazyLock::new(|| serde_json::from_str(MANIFEST_SCHEMA).unwrap_or_else(|_| unreachable!()));
+
+/// Enum that defines how [`Manifest::merge`] handles workloads and configs
+/// present in both manifests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestMergePolicy {
+    /// The merge fails on the first conflict.
+    Error,
+    /// The workload or config of the overlay replaces the one of the base.
+    OverlayWins,
+    /// Mappings are merged recursively, including the runtime configs given as YAML mappings,
+    /// while other values of the overlay replace the ones of the base.
+    DeepMerge,
+}
 
 /// Struct represents a manifest file.
 ///
@@ -52,6 +82,14 @@ pub const CONFIGS_PREFIX: &str = "desiredState.configs";
 /// let manifest = Manifest::from_file(Path::new("path/to/manifest.yaml")).unwrap();
 /// ```
 ///
+/// ## Load a manifest piped to the standard input:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::Manifest;
+/// #
+/// let manifest = Manifest::from_stdin().unwrap();
+/// ```
+///
 /// ## Load a manifest from a [String]:
 ///
 /// ```rust
@@ -89,7 +127,8 @@ impl Manifest {
     ///
     /// ## Errors
     ///
-    /// Returns an [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid.
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
     pub fn from_dict(manifest: serde_yaml::Value) -> Result<Manifest, AnkaiosError> {
         Manifest::try_from(manifest)
     }
@@ -106,12 +145,14 @@ impl Manifest {
     ///
     /// ## Errors
     ///
-    /// Returns an [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid.
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
     pub fn from_string<T: Into<String>>(manifest: T) -> Result<Manifest, AnkaiosError> {
         Manifest::try_from(manifest.into())
     }
 
     /// Create a new `Manifest` object from a file's [Path].
+    /// The path `-` reads the manifest from the standard input, see [`Manifest::from_stdin`].
     ///
     /// ## Arguments
     ///
@@ -123,12 +164,142 @@ impl Manifest {
     ///
     /// ## Errors
     ///
-    /// Returns an [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid.
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
     pub fn from_file(path: &Path) -> Result<Manifest, AnkaiosError> {
         Manifest::try_from(path)
     }
 
-    /// Calculate the masks for the manifest.
+    /// Create a new `Manifest` object from a file's [Path], validating it against the schema of
+    /// the manifests first, see [`Manifest::validate_schema`].
+    /// The path `-` reads the manifest from the standard input.
+    ///
+    /// Unlike [`Manifest::from_file`], the errors while reading the file are kept as they are,
+    /// so that a missing file can be told apart from an invalid manifest.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - A [Path] object representing the manifest file.
+    ///
+    /// ## Returns
+    ///
+    /// A [Manifest] object if the manifest is valid.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the file can't be read;
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest is not valid YAML;
+    /// - [`AnkaiosError`]::[`ManifestSchemaError`](AnkaiosError::ManifestSchemaError) containing the violations if the manifest does not match the schema;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
+    pub fn from_file_validated(path: &Path) -> Result<Manifest, AnkaiosError> {
+        let content = if path == Path::new(STDIN_PATH) {
+            let mut content = String::new();
+            io::stdin().lock().read_to_string(&mut content)?;
+            content
+        } else {
+            read_file_to_string(path)?
+        };
+        let manifest: Value = serde_yaml::from_str(&content)
+            .map_err(|err| AnkaiosError::ManifestParsingError(err.to_string()))?;
+        Self::validate_schema(&manifest)?;
+        Self::from_dict(manifest)
+    }
+
+    /// Create a new `Manifest` object from the standard input, read until its end.
+    /// This allows piping the manifest from a generation tool without writing a temporary file.
+    ///
+    /// ## Returns
+    ///
+    /// A [Manifest] object if the manifest is valid.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the standard input can't be read or the manifest is not valid;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
+    pub fn from_stdin() -> Result<Manifest, AnkaiosError> {
+        Manifest::from_reader(io::stdin().lock())
+    }
+
+    /// Create a new `Manifest` object from a reader, read until its end.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The reader providing the manifest, e.g. a pipe.
+    ///
+    /// ## Returns
+    ///
+    /// A [Manifest] object if the manifest is valid.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the reader fails or the manifest is not valid;
+    /// - [`AnkaiosError`]::[`UnsupportedApiVersion`](AnkaiosError::UnsupportedApiVersion) if the `apiVersion` of the manifest is not supported.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Manifest, AnkaiosError> {
+        let mut content = String::new();
+        reader
+            .read_to_string(&mut content)
+            .map_err(|err| AnkaiosError::ManifestParsingError(err.to_string()))?;
+        Self::from_string(content)
+    }
+
+    /// Validates a manifest against the schema of the [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+    /// manifests embedded in the SDK, see [`MANIFEST_SCHEMA`].
+    ///
+    /// Unlike the parsing, which ignores the unknown keys, the validation reports all the
+    /// unknown keys, wrong types and missing required fields, so a manifest can be checked
+    /// before it is submitted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `manifest` - A [`serde_yaml::Value`] object representing the manifest.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ManifestSchemaError`](AnkaiosError::ManifestSchemaError) containing the violations if the manifest does not match the schema;
+    /// - [`AnkaiosError`]::[`ManifestParsingError`](AnkaiosError::ManifestParsingError) if the manifest can't be represented as JSON, e.g. it has non-string keys.
+    pub fn validate_schema(manifest: &serde_yaml::Value) -> Result<(), AnkaiosError> {
+        Self::validate_schema_with(manifest, &PARSED_MANIFEST_SCHEMA)
+    }
+
+    /// Validates a manifest against the given JSON schema, e.g. the schema of another version of
+    /// [Ankaios](https://eclipse-ankaios.github.io/ankaios) downloaded by the application.
+    ///
+    /// Only the keywords `$ref` to local definitions, `type`, `enum`, `required`, `properties`,
+    /// `additionalProperties`, `items`, `anyOf` and `oneOf` are checked.
+    ///
+    /// ## Arguments
+    ///
+    /// * `manifest` - A [`serde_yaml::Value`] object representing the manifest;
+    /// * `schema` - The JSON schema the manifest must match.
+    ///
+    /// ## Errors
+    ///
+    /// The same as [`validate_schema`](Manifest::validate_schema).
+    pub fn validate_schema_with(
+        manifest: &serde_yaml::Value,
+        schema: &serde_json::Value,
+    ) -> Result<(), AnkaiosError> {
+        let json_manifest = serde_json::to_value(manifest)
+            .map_err(|err| AnkaiosError::ManifestParsingError(err.to_string()))?;
+        let violations = manifest_schema::validate(schema, &json_manifest);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AnkaiosError::ManifestSchemaError(violations))
+        }
+    }
+
+    /// Get the API version of the manifest.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] containing the API version.
+    #[must_use]
+    pub fn get_api_version(&self) -> String {
+        self.desired_state.api_version.clone()
+    }
+
+    /// Calculate the masks for the manifest, sorted alphabetically.
     ///
     /// ## Returns
     ///
@@ -146,9 +317,385 @@ impl Manifest {
                 masks.push(format!("{CONFIGS_PREFIX}.{config_name}"));
             }
         }
+        masks.sort();
         masks
     }
 
+    /// Checks that the names of the workloads and configs of the manifest can be used in masks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a name is empty,
+    /// contains a dot or is the wildcard `*`.
+    pub(crate) fn check_mask_names(&self) -> Result<(), AnkaiosError> {
+        let workload_names = self
+            .desired_state
+            .workloads
+            .iter()
+            .flat_map(|workloads| workloads.workloads.keys());
+        let config_names = self
+            .desired_state
+            .configs
+            .iter()
+            .flat_map(|configs| configs.configs.keys());
+        workload_names
+            .chain(config_names)
+            .try_for_each(|name| check_name(name))
+    }
+
+    /// Get the workloads of the manifest.
+    ///
+    /// ## Returns
+    ///
+    /// A [vector](Vec) of the [Workload]s, sorted by name.
+    #[must_use]
+    pub fn workloads(&self) -> Vec<Workload> {
+        let mut workloads: Vec<Workload> = self
+            .desired_state
+            .workloads
+            .as_ref()
+            .map(|workloads| {
+                workloads
+                    .workloads
+                    .iter()
+                    .map(|(name, workload)| Workload::new_from_proto(name, workload.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        workloads.sort_by(|first, second| first.name.cmp(&second.name));
+        workloads
+    }
+
+    /// Get a mutable view of the workloads of the manifest.
+    /// The changes, including added, removed or renamed workloads, are written
+    /// back to the manifest when the view is dropped.
+    ///
+    /// ## Returns
+    ///
+    /// A [`ManifestWorkloadsMut`] dereferencing to the [vector](Vec) of [Workload]s, sorted by name.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use ankaios_sdk::Manifest;
+    /// #
+    /// # let mut manifest = Manifest::from_string("apiVersion: v1").unwrap();
+    /// for workload in manifest.workloads_mut().iter_mut() {
+    ///     workload.update_agent_name("agent_B");
+    /// }
+    /// ```
+    pub fn workloads_mut(&mut self) -> ManifestWorkloadsMut<'_> {
+        let workloads = self.workloads();
+        ManifestWorkloadsMut {
+            manifest: self,
+            workloads,
+        }
+    }
+
+    /// Add a workload to the manifest, replacing the workload with the same name.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload` - The [Workload] to add.
+    pub fn add_workload(&mut self, workload: Workload) {
+        self.desired_state
+            .workloads
+            .get_or_insert_with(ank_base::WorkloadMap::default)
+            .workloads
+            .insert(workload.name.clone(), workload.to_proto());
+    }
+
+    /// Remove a workload from the manifest.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload_name` - The name of the workload to remove.
+    ///
+    /// ## Returns
+    ///
+    /// The removed [Workload] or [None] if the manifest has no workload with this name.
+    pub fn remove_workload(&mut self, workload_name: &str) -> Option<Workload> {
+        let workloads = self.desired_state.workloads.as_mut()?;
+        let workload = workloads.workloads.remove(workload_name)?;
+        if workloads.workloads.is_empty() {
+            self.desired_state.workloads = None;
+        }
+        Some(Workload::new_from_proto(workload_name, workload))
+    }
+
+    /// Get a mutable view of the configs of the manifest.
+    /// The changes are written back to the manifest when the view is dropped.
+    /// Configs consist of strings, sequences and mappings, other values are written back empty.
+    ///
+    /// ## Returns
+    ///
+    /// A [`ManifestConfigsMut`] dereferencing to the [`HashMap`] of the configs by name.
+    pub fn configs_mut(&mut self) -> ManifestConfigsMut<'_> {
+        let configs = self
+            .desired_state
+            .configs
+            .as_ref()
+            .map(|configs| {
+                configs
+                    .configs
+                    .iter()
+                    .map(|(name, config)| (name.clone(), config_item_to_value(config)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        ManifestConfigsMut {
+            manifest: self,
+            configs,
+        }
+    }
+
+    /// Merges an overlay into the manifest, e.g. to apply environment specific
+    /// agent mappings or image tags to a base manifest.
+    ///
+    /// ## Arguments
+    ///
+    /// * `overlay` - The [Manifest] merged on top of this one;
+    /// * `policy` - The [`ManifestMergePolicy`] for the workloads and configs present in both manifests.
+    ///
+    /// ## Returns
+    ///
+    /// The merged [Manifest].
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`ManifestMergeError`](AnkaiosError::ManifestMergeError) if the API versions differ,
+    ///   if there is a conflict with the [`Error`](ManifestMergePolicy::Error) policy or if the merged values are not valid.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use ankaios_sdk::{Manifest, ManifestMergePolicy};
+    /// #
+    /// let base = Manifest::from_string("apiVersion: v1\nconfigs:\n  tag: \"1.0\"").unwrap();
+    /// let overlay = Manifest::from_string("apiVersion: v1\nconfigs:\n  tag: \"2.0\"").unwrap();
+    /// let merged = base.merge(overlay, ManifestMergePolicy::OverlayWins).unwrap();
+    /// ```
+    pub fn merge(
+        self,
+        overlay: Manifest,
+        policy: ManifestMergePolicy,
+    ) -> Result<Manifest, AnkaiosError> {
+        let mut desired_state = self.desired_state;
+        if desired_state.api_version != overlay.desired_state.api_version {
+            return Err(AnkaiosError::ManifestMergeError(format!(
+                "The apiVersion '{}' of the overlay differs from '{}'.",
+                overlay.desired_state.api_version, desired_state.api_version
+            )));
+        }
+
+        if let Some(overlay_workloads) = overlay.desired_state.workloads {
+            let workloads = &mut desired_state
+                .workloads
+                .get_or_insert_with(ank_base::WorkloadMap::default)
+                .workloads;
+            for (name, overlay_workload) in overlay_workloads.workloads {
+                let merged_workload = match workloads.remove(&name) {
+                    None => overlay_workload,
+                    Some(_) if policy == ManifestMergePolicy::Error => {
+                        return Err(AnkaiosError::ManifestMergeError(format!(
+                            "Workload '{name}' is defined in both manifests."
+                        )));
+                    }
+                    Some(_) if policy == ManifestMergePolicy::OverlayWins => overlay_workload,
+                    Some(base_workload) => {
+                        Self::deep_merge_workloads(&name, base_workload, overlay_workload)?
+                    }
+                };
+                workloads.insert(name, merged_workload);
+            }
+        }
+
+        if let Some(overlay_configs) = overlay.desired_state.configs {
+            let configs = &mut desired_state
+                .configs
+                .get_or_insert_with(ank_base::ConfigMap::default)
+                .configs;
+            for (name, overlay_config) in overlay_configs.configs {
+                let merged_config = match configs.remove(&name) {
+                    None => overlay_config,
+                    Some(_) if policy == ManifestMergePolicy::Error => {
+                        return Err(AnkaiosError::ManifestMergeError(format!(
+                            "Config '{name}' is defined in both manifests."
+                        )));
+                    }
+                    Some(_) if policy == ManifestMergePolicy::OverlayWins => overlay_config,
+                    Some(base_config) => {
+                        Self::deep_merge_configs(&name, &base_config, &overlay_config)?
+                    }
+                };
+                configs.insert(name, merged_config);
+            }
+        }
+
+        Ok(Self { desired_state })
+    }
+
+    /// Merges two definitions of a workload recursively.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - The name of the workload;
+    /// * `base` - The workload of the base manifest;
+    /// * `overlay` - The workload of the overlay manifest.
+    ///
+    /// ## Returns
+    ///
+    /// The merged workload.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`ManifestMergeError`](AnkaiosError::ManifestMergeError) if the merged workload is not valid.
+    fn deep_merge_workloads(
+        name: &str,
+        base: ank_base::Workload,
+        overlay: ank_base::Workload,
+    ) -> Result<ank_base::Workload, AnkaiosError> {
+        let merge_error = |err: AnkaiosError| {
+            AnkaiosError::ManifestMergeError(format!("Workload '{name}' can't be merged: {err}"))
+        };
+        let base_workload = Workload::new_from_proto(name, base);
+        let overlay_workload = Workload::new_from_proto(name, overlay);
+        let merged_dict = match deep_merge(
+            Value::Mapping(base_workload.to_dict()),
+            Value::Mapping(overlay_workload.to_dict()),
+        ) {
+            Value::Mapping(mapping) => mapping,
+            _ => unreachable!(),
+        };
+        let mut merged_workload =
+            Workload::new_from_dict(name, &merged_dict).map_err(merge_error)?;
+
+        // The runtime configs are YAML strings, merged only if both are mappings
+        if let (Ok(base_config @ Value::Mapping(_)), Ok(overlay_config @ Value::Mapping(_))) = (
+            base_workload.runtime_config_as::<Value>(),
+            overlay_workload.runtime_config_as::<Value>(),
+        ) {
+            merged_workload
+                .set_runtime_config_from(&deep_merge(base_config, overlay_config))
+                .map_err(merge_error)?;
+        }
+        Ok(merged_workload.to_proto())
+    }
+
+    /// Merges two values of a config recursively.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - The name of the config;
+    /// * `base` - The config of the base manifest;
+    /// * `overlay` - The config of the overlay manifest.
+    ///
+    /// ## Returns
+    ///
+    /// The merged config.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`ManifestMergeError`](AnkaiosError::ManifestMergeError) if the configs can't be converted.
+    fn deep_merge_configs(
+        name: &str,
+        base: &ank_base::ConfigItem,
+        overlay: &ank_base::ConfigItem,
+    ) -> Result<ank_base::ConfigItem, AnkaiosError> {
+        let merge_error = |err: serde_yaml::Error| {
+            AnkaiosError::ManifestMergeError(format!("Config '{name}' can't be merged: {err}"))
+        };
+        let merged_value = deep_merge(
+            serde_yaml::to_value(base).map_err(merge_error)?,
+            serde_yaml::to_value(overlay).map_err(merge_error)?,
+        );
+        serde_yaml::from_value(merged_value).map_err(merge_error)
+    }
+
+    /// Calculate the masks for the manifest without the parts under the excluded paths, sorted alphabetically.
+    ///
+    /// ## Arguments
+    ///
+    /// * `excluded_paths` - The paths to exclude, e.g. `desiredState.workloads` to keep only the configs.
+    ///
+    /// ## Returns
+    ///
+    /// A [vector](Vec) of [strings](String) representing the masks.
+    #[must_use]
+    pub fn calculate_masks_excluding<T: AsRef<str>>(&self, excluded_paths: &[T]) -> Vec<String> {
+        self.calculate_masks()
+            .into_iter()
+            .filter(|mask| {
+                !excluded_paths
+                    .iter()
+                    .any(|path| is_under_path(mask, path.as_ref()))
+            })
+            .collect()
+    }
+
+    /// Calculate the masks for the parts of the manifest selected by the included masks, sorted alphabetically.
+    ///
+    /// An included mask selects the masks of the manifest under it. An included mask more specific
+    /// than a mask of the manifest, e.g. a single field of a workload, is used as it is.
+    /// This prevents a broad mask like `desiredState.configs` from deleting the configs
+    /// that are not part of the manifest.
+    ///
+    /// ## Arguments
+    ///
+    /// * `include_masks` - The masks selecting the parts of the manifest.
+    ///
+    /// ## Returns
+    ///
+    /// A [vector](Vec) of [strings](String) representing the masks.
+    pub(crate) fn calculate_masks_including(&self, include_masks: &[String]) -> Vec<String> {
+        let manifest_masks = self.calculate_masks();
+        let mut masks: Vec<String> = manifest_masks
+            .iter()
+            .filter(|mask| {
+                include_masks
+                    .iter()
+                    .any(|include_mask| is_under_path(mask, include_mask))
+            })
+            .cloned()
+            .collect();
+        masks.extend(
+            include_masks
+                .iter()
+                .filter(|include_mask| {
+                    manifest_masks.iter().any(|manifest_mask| {
+                        *include_mask != manifest_mask && is_under_path(include_mask, manifest_mask)
+                    })
+                })
+                .cloned(),
+        );
+        masks.sort();
+        masks.dedup();
+        masks
+    }
+
+    /// Get the names of the agents referenced by the workloads of the manifest
+    /// that are affected by the given masks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `masks` - The masks that are applied.
+    ///
+    /// ## Returns
+    ///
+    /// A [vector](Vec) of [strings](String) representing the agent names.
+    pub(crate) fn get_agent_names_for_masks(&self, masks: &[String]) -> Vec<String> {
+        self.workloads()
+            .into_iter()
+            .filter(|workload| {
+                let workload_mask = format!("{WORKLOADS_PREFIX}.{}", workload.name);
+                masks.iter().any(|mask| {
+                    is_under_path(mask, &workload_mask) || is_under_path(&workload_mask, mask)
+                })
+            })
+            .filter_map(|workload| workload.workload.agent)
+            .collect()
+    }
+
     /// Get the manifest as a [`ank_base::State`].
     ///
     /// ## Returns
@@ -160,6 +707,131 @@ impl Manifest {
     }
 }
 
+/// A mutable view of the workloads of a [Manifest], returned by [`Manifest::workloads_mut`].
+///
+/// The workloads are written back to the manifest when the view is dropped.
+/// If several workloads have the same name, the last one is kept.
+#[derive(Debug)]
+pub struct ManifestWorkloadsMut<'a> {
+    /// The manifest the workloads are written back to.
+    manifest: &'a mut Manifest,
+    /// The workloads of the manifest.
+    workloads: Vec<Workload>,
+}
+
+impl Deref for ManifestWorkloadsMut<'_> {
+    type Target = Vec<Workload>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.workloads
+    }
+}
+
+impl DerefMut for ManifestWorkloadsMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.workloads
+    }
+}
+
+impl Drop for ManifestWorkloadsMut<'_> {
+    fn drop(&mut self) {
+        self.manifest.desired_state.workloads = None;
+        for workload in mem::take(&mut self.workloads) {
+            self.manifest.add_workload(workload);
+        }
+    }
+}
+
+/// A mutable view of the configs of a [Manifest], returned by [`Manifest::configs_mut`].
+///
+/// The configs are written back to the manifest when the view is dropped.
+#[derive(Debug)]
+pub struct ManifestConfigsMut<'a> {
+    /// The manifest the configs are written back to.
+    manifest: &'a mut Manifest,
+    /// The configs of the manifest by name.
+    configs: HashMap<String, Value>,
+}
+
+impl Deref for ManifestConfigsMut<'_> {
+    type Target = HashMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.configs
+    }
+}
+
+impl DerefMut for ManifestConfigsMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.configs
+    }
+}
+
+impl Drop for ManifestConfigsMut<'_> {
+    fn drop(&mut self) {
+        let configs = mem::take(&mut self.configs);
+        self.manifest.desired_state.configs = if configs.is_empty() {
+            None
+        } else {
+            Some(ank_base::ConfigMap {
+                configs: configs
+                    .iter()
+                    .map(|(name, value)| (name.clone(), config_item_from_value(value)))
+                    .collect(),
+            })
+        };
+    }
+}
+
+/// Checks whether a mask is equal to or below a path of the state.
+///
+/// ## Arguments
+///
+/// * `mask` - The mask to check;
+/// * `path` - The path of the state, e.g. `desiredState.workloads`.
+///
+/// ## Returns
+///
+/// `true` if the mask is the path or below it.
+fn is_under_path(mask: &str, path: &str) -> bool {
+    mask.strip_prefix(path)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Merges two YAML values, merging mappings recursively and otherwise preferring the overlay.
+///
+/// ## Arguments
+///
+/// * `base` - The base value;
+/// * `overlay` - The value merged on top of the base.
+///
+/// ## Returns
+///
+/// The merged [Value].
+fn deep_merge(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base_mapping), Value::Mapping(overlay_mapping)) => {
+            for (key, overlay_value) in overlay_mapping {
+                if let Some(base_value) = base_mapping.get_mut(&key) {
+                    *base_value = deep_merge(mem::take(base_value), overlay_value);
+                } else {
+                    base_mapping.insert(key, overlay_value);
+                }
+            }
+            Value::Mapping(base_mapping)
+        }
+        (_, overlay_value) => overlay_value,
+    }
+}
+
+impl From<CompleteState> for Manifest {
+    fn from(complete_state: CompleteState) -> Self {
+        Self {
+            desired_state: complete_state.to_proto().desired_state.unwrap_or_default(),
+        }
+    }
+}
+
 impl TryFrom<serde_yaml::Value> for Manifest {
     type Error = AnkaiosError;
 
@@ -180,6 +852,12 @@ impl TryFrom<serde_yaml::Value> for Manifest {
                 ));
             }
         };
+        if !SUPPORTED_API_VERSIONS.contains(&api_version.as_str()) {
+            return Err(AnkaiosError::UnsupportedApiVersion(
+                api_version,
+                SUPPORTED_API_VERSIONS.join(", "),
+            ));
+        }
 
         // Extract workloads
         let mut workloads: ank_base::WorkloadMap = ank_base::WorkloadMap {
@@ -255,6 +933,9 @@ impl TryFrom<&Path> for Manifest {
     type Error = AnkaiosError;
 
     fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        if path == Path::new(STDIN_PATH) {
+            return Self::from_stdin();
+        }
         match read_file_to_string(path) {
             Ok(content) => Self::from_string(content),
             Err(e) => Err(AnkaiosError::ManifestParsingError(e.to_string())),
@@ -278,7 +959,7 @@ pub fn read_to_string_mock(path: &Path) -> Result<String, std::io::Error> {
 }
 
 #[cfg(test)]
-static MANIFEST_CONTENT: &str = r#"apiVersion: v1
+pub(crate) static MANIFEST_CONTENT: &str = r#"apiVersion: v1
 workloads:
     nginx_test:
         runtime: podman
@@ -304,7 +985,10 @@ pub fn generate_test_manifest() -> Manifest {
 
 #[cfg(test)]
 mod tests {
-    use super::{MANIFEST_CONTENT, Manifest};
+    use super::{
+        AnkaiosError, CompleteState, MANIFEST_CONTENT, Manifest, ManifestMergePolicy,
+        generate_test_manifest,
+    };
     use serde_yaml;
     use std::path::Path;
 
@@ -312,17 +996,115 @@ mod tests {
     fn utest_creation() {
         let manifest = Manifest::from_file(Path::new(MANIFEST_CONTENT)).unwrap();
         assert_eq!(manifest.desired_state.api_version, "v1");
-        let masks = manifest.calculate_masks();
-        assert!(masks.contains(&"desiredState.workloads.nginx_test".to_owned()));
-        assert!(masks.contains(&"desiredState.configs.config1".to_owned()));
-        assert!(masks.contains(&"desiredState.configs.config2".to_owned()));
-        assert!(masks.contains(&"desiredState.configs.config3".to_owned()));
+        assert_eq!(
+            manifest.calculate_masks(),
+            vec![
+                "desiredState.configs.config1".to_owned(),
+                "desiredState.configs.config2".to_owned(),
+                "desiredState.configs.config3".to_owned(),
+                "desiredState.workloads.nginx_test".to_owned(),
+            ]
+        );
+
+        assert_eq!(
+            manifest.get_agent_names_for_masks(&manifest.calculate_masks()),
+            vec!["agent_A".to_owned()]
+        );
 
         let _ = Manifest::try_from(Path::new("path"));
         let _ = Manifest::try_from(MANIFEST_CONTENT.to_owned());
         let _ = Manifest::try_from(serde_yaml::Value::default());
     }
 
+    #[test]
+    fn utest_from_reader() {
+        struct FailingReader;
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("closed pipe"))
+            }
+        }
+
+        let manifest = Manifest::from_reader(MANIFEST_CONTENT.as_bytes()).unwrap();
+        assert_eq!(
+            manifest.calculate_masks(),
+            Manifest::from_string(MANIFEST_CONTENT)
+                .unwrap()
+                .calculate_masks()
+        );
+        assert!(matches!(
+            Manifest::from_reader(FailingReader),
+            Err(AnkaiosError::ManifestParsingError(msg)) if msg == "closed pipe"
+        ));
+        assert!(matches!(
+            Manifest::from_reader("apiVersion: v0".as_bytes()),
+            Err(AnkaiosError::UnsupportedApiVersion(..))
+        ));
+    }
+
+    #[test]
+    fn utest_from_file_validated() {
+        let manifest = Manifest::from_file_validated(Path::new(MANIFEST_CONTENT)).unwrap();
+        assert_eq!(
+            manifest.calculate_masks(),
+            generate_test_manifest().calculate_masks()
+        );
+
+        assert!(matches!(
+            Manifest::from_file_validated(Path::new("apiVersion: v1\nworkloads: []")),
+            Err(AnkaiosError::ManifestSchemaError(_))
+        ));
+        assert!(matches!(
+            Manifest::from_file_validated(Path::new("apiVersion: [v1")),
+            Err(AnkaiosError::ManifestParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn utest_validate_schema() {
+        let manifest: serde_yaml::Value = serde_yaml::from_str(MANIFEST_CONTENT).unwrap();
+        assert!(Manifest::validate_schema(&manifest).is_ok());
+
+        let invalid: serde_yaml::Value = serde_yaml::from_str(
+            r"apiVersion: v1
+workloads:
+    nginx:
+        agent: agent_A
+        runtime: podman
+        restartPolicy: SOMETIMES
+        tags: [a, b]
+        image: nginx
+        files:
+            - mount_point: /etc/conf
+",
+        )
+        .unwrap();
+        let Err(AnkaiosError::ManifestSchemaError(violations)) =
+            Manifest::validate_schema(&invalid)
+        else {
+            panic!("Expected a schema error");
+        };
+        let paths: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "workloads.nginx",
+                "workloads.nginx.files[0]",
+                "workloads.nginx.image",
+                "workloads.nginx.restartPolicy",
+                "workloads.nginx.tags",
+            ]
+        );
+        assert_eq!(
+            violations[0].message,
+            "missing required field 'runtimeConfig'"
+        );
+        assert_eq!(violations[2].message, "unknown field");
+    }
+
     #[test]
     fn utest_no_workloads() {
         let manifest_result = Manifest::from_string("apiVersion: v1");
@@ -330,4 +1112,281 @@ mod tests {
         let manifest: Manifest = manifest_result.unwrap();
         assert_eq!(manifest.calculate_masks().len(), 0);
     }
+
+    #[test]
+    fn utest_from_complete_state() {
+        let manifest = Manifest::from_file(Path::new(MANIFEST_CONTENT)).unwrap();
+        let complete_state = CompleteState::from(manifest.clone());
+        let manifest_from_state = Manifest::from(complete_state);
+        assert_eq!(manifest_from_state.desired_state, manifest.desired_state);
+    }
+
+    #[test]
+    fn utest_malformed_manifests() {
+        let malformed_documents = [
+            "",
+            "- v1",
+            "apiVersion: 1",
+            "apiVersion: v1\nworkloads: []",
+            "apiVersion: v1\nworkloads:\n  1: {}",
+            "apiVersion: v1\nworkloads:\n  nginx: 5",
+            "apiVersion: v1\nconfigs: 5",
+            "apiVersion: v1\nworkloads:\n  nginx:\n    agent: [agent_A]",
+            "apiVersion: v1\nworkloads:\n  nginx: {agent: agent_A, runtime: {}}",
+            "apiVersion: v1\nworkloads:\n  nginx: {agent: agent_A",
+        ];
+        let malformed_fields = [
+            "restartPolicy: 3",
+            "restartPolicy: SOMETIMES",
+            "dependencies: [other]",
+            "dependencies: {1: ADD_COND_RUNNING}",
+            "dependencies: {other: 1}",
+            "dependencies: {other: ADD_COND_SOMETIMES}",
+            "tags: tag",
+            "tags: {key: [value]}",
+            "controlInterfaceAccess: 5",
+            "controlInterfaceAccess: {allowRules: rule}",
+            "controlInterfaceAccess: {allowRules: [rule]}",
+            "controlInterfaceAccess: {allowRules: [{filterMask: [a]}]}",
+            "controlInterfaceAccess: {allowRules: [{operation: Read}]}",
+            "controlInterfaceAccess: {allowRules: [{operation: Read, filterMask: a}]}",
+            "controlInterfaceAccess: {allowRules: [{operation: Read, filterMask: [1]}]}",
+            "controlInterfaceAccess: {denyRules: [{operation: [Read], filterMask: [a]}]}",
+            "configs: [config1]",
+            "configs: {alias: [config1]}",
+            "files: {mountPoint: /file}",
+            "files: [file]",
+            "files: [{mountPoint: 1, data: text}]",
+            "files: [{data: text}]",
+        ];
+
+        // Malformed documents must be reported as errors, never panic
+        for document in malformed_documents {
+            assert!(
+                Manifest::from_string(document).is_err(),
+                "Document accepted: {document:?}"
+            );
+        }
+        let workload_document = |field: &str| {
+            format!(
+                "apiVersion: v1\nworkloads:\n  nginx:\n    agent: agent_A\n    runtime: podman\n    runtimeConfig: \"image: nginx\"\n    {field}"
+            )
+        };
+        assert!(Manifest::from_string(workload_document("tags: {key: value}")).is_ok());
+        for field in malformed_fields {
+            let document = workload_document(field);
+            assert!(
+                Manifest::from_string(document.as_str()).is_err(),
+                "Document accepted: {document:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn utest_api_version() {
+        let manifest = Manifest::from_string("apiVersion: v1").unwrap();
+        assert_eq!(manifest.get_api_version(), "v1");
+
+        assert!(matches!(
+            Manifest::from_string("apiVersion: v0.1"),
+            Err(AnkaiosError::UnsupportedApiVersion(version, supported))
+                if version == "v0.1" && supported == "v1"
+        ));
+    }
+
+    #[test]
+    fn utest_workloads_and_configs_mut() {
+        let mut manifest = generate_test_manifest();
+        let workloads = manifest.workloads();
+        assert_eq!(workloads.len(), 1);
+        assert_eq!(workloads[0].name, "nginx_test");
+
+        let mut workload = workloads[0].clone();
+        workload.update_workload_name("nginx_copy");
+        manifest.add_workload(workload);
+        for workload in manifest.workloads_mut().iter_mut() {
+            workload.update_agent_name("agent_B");
+        }
+        assert_eq!(
+            manifest
+                .workloads()
+                .iter()
+                .map(|workload| (workload.name.as_str(), workload.workload.agent.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("nginx_copy", Some("agent_B")),
+                ("nginx_test", Some("agent_B"))
+            ]
+        );
+
+        // Renaming through the view moves the workload
+        manifest.workloads_mut()[0].update_workload_name("nginx_renamed");
+        assert_eq!(
+            manifest.calculate_masks()[3..],
+            [
+                "desiredState.workloads.nginx_renamed".to_owned(),
+                "desiredState.workloads.nginx_test".to_owned()
+            ]
+        );
+
+        assert_eq!(
+            manifest.remove_workload("nginx_renamed").unwrap().name,
+            "nginx_renamed"
+        );
+        assert!(manifest.remove_workload("nginx_renamed").is_none());
+        assert!(manifest.remove_workload("nginx_test").is_some());
+        assert!(manifest.desired_state.workloads.is_none());
+
+        let mut configs = manifest.configs_mut();
+        assert_eq!(configs.len(), 3);
+        configs.remove("config2");
+        configs.insert("config4".to_owned(), serde_yaml::Value::from("value6"));
+        drop(configs);
+        assert_eq!(
+            manifest.calculate_masks(),
+            vec![
+                "desiredState.configs.config1".to_owned(),
+                "desiredState.configs.config3".to_owned(),
+                "desiredState.configs.config4".to_owned(),
+            ]
+        );
+        assert_eq!(
+            CompleteState::from(manifest.clone()).get_configs()["config4"],
+            serde_yaml::Value::from("value6")
+        );
+
+        manifest.configs_mut().clear();
+        assert!(manifest.calculate_masks().is_empty());
+    }
+
+    #[test]
+    fn utest_partial_masks() {
+        let manifest = generate_test_manifest();
+        assert_eq!(
+            manifest.calculate_masks_excluding(&[
+                "desiredState.workloads",
+                "desiredState.configs.config2"
+            ]),
+            vec![
+                "desiredState.configs.config1".to_owned(),
+                "desiredState.configs.config3".to_owned(),
+            ]
+        );
+        assert_eq!(
+            manifest.calculate_masks_excluding(&["desiredState.config"]),
+            manifest.calculate_masks()
+        );
+
+        let masks = manifest.calculate_masks_including(&[
+            "desiredState.configs.config1".to_owned(),
+            "desiredState.workloads.nginx_test.agent".to_owned(),
+            "desiredState.workloads.other".to_owned(),
+        ]);
+        assert_eq!(
+            masks,
+            vec![
+                "desiredState.configs.config1".to_owned(),
+                "desiredState.workloads.nginx_test.agent".to_owned(),
+            ]
+        );
+        assert_eq!(
+            manifest.get_agent_names_for_masks(&masks),
+            vec!["agent_A".to_owned()]
+        );
+        assert!(
+            manifest
+                .get_agent_names_for_masks(&["desiredState.configs".to_owned()])
+                .is_empty()
+        );
+        assert_eq!(
+            manifest.calculate_masks_including(&["desiredState".to_owned()]),
+            manifest.calculate_masks()
+        );
+    }
+
+    #[test]
+    fn utest_merge() {
+        let overlay_content = r#"apiVersion: v1
+workloads:
+    nginx_test:
+        runtime: podman
+        agent: agent_B
+        runtimeConfig: |
+            commandOptions: ["-p", "8080:80"]
+    nginx_prod:
+        runtime: podman
+        agent: agent_B
+        runtimeConfig: |
+            image: image/prod
+configs:
+    config1: "value_overlay"
+    config3:
+        field2: "value_overlay"
+"#;
+        let base = || Manifest::from_string(MANIFEST_CONTENT).unwrap();
+        let overlay = || Manifest::from_string(overlay_content).unwrap();
+
+        assert!(matches!(
+            base().merge(overlay(), ManifestMergePolicy::Error),
+            Err(AnkaiosError::ManifestMergeError(_))
+        ));
+        let mut other_version = overlay();
+        "v0.1".clone_into(&mut other_version.desired_state.api_version);
+        assert!(matches!(
+            base().merge(other_version, ManifestMergePolicy::OverlayWins),
+            Err(AnkaiosError::ManifestMergeError(_))
+        ));
+
+        let merged = base()
+            .merge(overlay(), ManifestMergePolicy::OverlayWins)
+            .unwrap();
+        let workloads = CompleteState::from(merged.clone()).get_workloads();
+        assert_eq!(workloads.len(), 2);
+        assert_eq!(
+            merged.get_agent_names_for_masks(&merged.calculate_masks()),
+            vec!["agent_B", "agent_B"]
+        );
+        let merged_state = CompleteState::from(merged);
+        let nginx_test = merged_state.get_workload("nginx_test").unwrap();
+        assert_eq!(
+            nginx_test.runtime_config_as::<serde_yaml::Value>().unwrap(),
+            serde_yaml::from_str::<serde_yaml::Value>(r#"commandOptions: ["-p", "8080:80"]"#)
+                .unwrap()
+        );
+        assert!(nginx_test.get_configs().is_empty());
+        let configs = merged_state.get_configs();
+        assert_eq!(configs["config1"], serde_yaml::Value::from("value_overlay"));
+        assert_eq!(
+            configs["config3"],
+            serde_yaml::from_str::<serde_yaml::Value>(r#"field2: "value_overlay""#).unwrap()
+        );
+
+        let merged_state = CompleteState::from(
+            base()
+                .merge(overlay(), ManifestMergePolicy::DeepMerge)
+                .unwrap(),
+        );
+        let nginx_test = merged_state.get_workload("nginx_test").unwrap();
+        assert_eq!(nginx_test.workload.agent, Some("agent_B".to_owned()));
+        assert_eq!(nginx_test.workload.restart_policy, Some(0));
+        assert_eq!(nginx_test.get_configs().len(), 1);
+        assert_eq!(
+            nginx_test.runtime_config_as::<serde_yaml::Value>().unwrap(),
+            serde_yaml::from_str::<serde_yaml::Value>(
+                r#"{image: image/test, commandOptions: ["-p", "8080:80"]}"#
+            )
+            .unwrap()
+        );
+        let configs = merged_state.get_configs();
+        assert_eq!(configs["config1"], serde_yaml::Value::from("value_overlay"));
+        let base_configs = CompleteState::from(base()).get_configs();
+        assert_eq!(
+            configs["config3"]["field1"],
+            base_configs["config3"]["field1"]
+        );
+        assert_eq!(
+            configs["config3"]["field2"],
+            serde_yaml::Value::from("value_overlay")
+        );
+    }
 }
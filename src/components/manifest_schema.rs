@@ -0,0 +1,295 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the JSON schema of the [Ankaios] manifests, the [`SchemaViolation`]
+//! struct and the validation of a manifest against a schema, used by
+//! [`Manifest::validate_schema`](crate::Manifest::validate_schema).
+//!
+//! The validation supports the subset of JSON schema used by the manifest schema:
+//! `$ref` to local definitions, `type`, `enum`, `required`, `properties`,
+//! `additionalProperties`, `items`, `anyOf` and `oneOf`. Other keywords are ignored.
+//!
+//! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+
+use serde_json::Value;
+use std::fmt;
+
+/// The JSON schema of the manifests with the API version `v1`, embedded in the SDK.
+pub const MANIFEST_SCHEMA: &str = include_str!("manifest_schema.json");
+
+/// Struct describing a part of a manifest that does not match the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// The path of the invalid value, e.g. `workloads.nginx.restartPolicy`, empty for the root.
+    pub path: String,
+    /// The description of the violation.
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "<root>: {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Validates a value against a JSON schema.
+///
+/// ## Arguments
+///
+/// * `schema` - The JSON schema;
+/// * `value` - The value to validate.
+///
+/// ## Returns
+///
+/// The [`SchemaViolation`]s found, empty if the value matches the schema.
+pub(crate) fn validate(schema: &Value, value: &Value) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    validate_node(schema, schema, value, "", &mut violations);
+    violations
+}
+
+/// Validates a value against a node of the schema, recursing into its children.
+///
+/// ## Arguments
+///
+/// * `root` - The complete schema, used to resolve the `$ref` keywords;
+/// * `schema` - The node of the schema the value must match;
+/// * `value` - The value to validate;
+/// * `path` - The path of the value;
+/// * `violations` - The violations found so far.
+fn validate_node(
+    root: &Value,
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let violation = |message: String| SchemaViolation {
+        path: path.to_owned(),
+        message,
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match reference
+            .strip_prefix('#')
+            .and_then(|ptr| root.pointer(ptr))
+        {
+            Some(referenced) => validate_node(root, referenced, value, path, violations),
+            None => violations.push(violation(format!(
+                "unresolvable schema reference '{reference}'"
+            ))),
+        }
+    }
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(type_name) => vec![type_name.as_str()],
+            Value::Array(type_names) => type_names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.iter().any(|type_name| has_type(value, type_name)) {
+            violations.push(violation(format!(
+                "expected {}, found {}",
+                allowed.join(" or "),
+                type_of(value)
+            )));
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let allowed_values: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            violations.push(violation(format!(
+                "{value} is not one of {}",
+                allowed_values.join(", ")
+            )));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for field in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(field) {
+                    violations.push(violation(format!("missing required field '{field}'")));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, child) in object {
+            let child_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{path}.{key}")
+            };
+            match (
+                properties.and_then(|props| props.get(key)),
+                schema.get("additionalProperties"),
+            ) {
+                (Some(child_schema), _) => {
+                    validate_node(root, child_schema, child, &child_path, violations);
+                }
+                (None, Some(Value::Bool(false))) => violations.push(SchemaViolation {
+                    path: child_path,
+                    message: "unknown field".to_owned(),
+                }),
+                (None, Some(child_schema)) if child_schema.is_object() => {
+                    validate_node(root, child_schema, child, &child_path, violations);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (index, item) in array.iter().enumerate() {
+            validate_node(root, items, item, &format!("{path}[{index}]"), violations);
+        }
+    }
+
+    let matching_count = |subschemas: &Vec<Value>| {
+        subschemas
+            .iter()
+            .filter(|subschema| {
+                let mut sub_violations = Vec::new();
+                validate_node(root, subschema, value, path, &mut sub_violations);
+                sub_violations.is_empty()
+            })
+            .count()
+    };
+    if let Some(subschemas) = schema.get("anyOf").and_then(Value::as_array) {
+        if matching_count(subschemas) == 0 {
+            violations.push(violation(
+                "does not match any of the allowed variants".to_owned(),
+            ));
+        }
+    }
+    if let Some(subschemas) = schema.get("oneOf").and_then(Value::as_array) {
+        match matching_count(subschemas) {
+            1 => {}
+            0 => violations.push(violation(
+                "does not match any of the allowed variants".to_owned(),
+            )),
+            _ => violations.push(violation(
+                "matches more than one of the exclusive variants".to_owned(),
+            )),
+        }
+    }
+}
+
+/// Checks whether a value has the given JSON schema type.
+fn has_type(value: &Value, type_name: &str) -> bool {
+    match type_name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        _ => false,
+    }
+}
+
+/// Returns the JSON schema type of a value.
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{MANIFEST_SCHEMA, SchemaViolation, validate};
+
+    #[test]
+    fn utest_validate_keywords() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "additionalProperties": false,
+            "properties": {
+                "name": {"type": "string"},
+                "mode": {"enum": ["a", "b"]},
+                "items": {"type": "array", "items": {"$ref": "#/$defs/item"}},
+                "value": {"oneOf": [{"type": "string"}, {"type": "integer"}]}
+            },
+            "$defs": {"item": {"type": "integer"}}
+        });
+
+        assert!(validate(&schema, &json!({"name": "x", "items": [1, 2], "value": 3})).is_empty());
+        assert_eq!(
+            validate(
+                &schema,
+                &json!({"mode": "c", "items": [1, "2"], "other": true, "value": []})
+            ),
+            vec![
+                SchemaViolation {
+                    path: String::new(),
+                    message: "missing required field 'name'".to_owned(),
+                },
+                SchemaViolation {
+                    path: "items[1]".to_owned(),
+                    message: "expected integer, found string".to_owned(),
+                },
+                SchemaViolation {
+                    path: "mode".to_owned(),
+                    message: "\"c\" is not one of \"a\", \"b\"".to_owned(),
+                },
+                SchemaViolation {
+                    path: "other".to_owned(),
+                    message: "unknown field".to_owned(),
+                },
+                SchemaViolation {
+                    path: "value".to_owned(),
+                    message: "does not match any of the allowed variants".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn utest_manifest_schema_is_valid_json() {
+        let schema: serde_json::Value = serde_json::from_str(MANIFEST_SCHEMA).unwrap();
+        assert!(schema.pointer("/$defs/workload").is_some());
+        assert_eq!(
+            SchemaViolation {
+                path: String::new(),
+                message: "expected object, found string".to_owned(),
+            }
+            .to_string(),
+            "<root>: expected object, found string"
+        );
+    }
+}
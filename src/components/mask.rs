@@ -0,0 +1,529 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`Mask`] and [`MaskSet`] structs, which represent
+//! the field and update masks sent to [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+//!
+//! # Example
+//!
+//! ## Collect masks, collapsing the ones covered by others:
+//!
+//! ```rust
+//! use ankaios_sdk::{Mask, MaskSet};
+//!
+//! let workload = Mask::new("desiredState.workloads.nginx").unwrap();
+//! let mut masks = MaskSet::new();
+//! masks.insert(workload.join("agent").unwrap());
+//! masks.insert(workload);
+//! assert_eq!(masks, vec!["desiredState.workloads.nginx"]);
+//! ```
+
+use std::{fmt, vec};
+
+use crate::AnkaiosError;
+
+/// The separator between the segments of a mask.
+const SEGMENT_SEPARATOR: char = '.';
+/// The segment matching any other segment.
+const WILDCARD_SEGMENT: &str = "*";
+
+/// A single field or update mask, e.g. `desiredState.workloads.nginx`.
+///
+/// A mask consists of non-empty segments separated by dots, without whitespace.
+/// The segment `*` matches any segment.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Mask(String);
+
+impl Mask {
+    /// Creates a new [Mask] after validating its syntax.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mask` - A [String] that represents the mask.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Mask] instance.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if the mask
+    /// is empty, has an empty segment or contains whitespace.
+    pub fn new<T: Into<String>>(mask: T) -> Result<Self, AnkaiosError> {
+        let mask_str = mask.into();
+        if mask_str.split(SEGMENT_SEPARATOR).any(str::is_empty) {
+            return Err(AnkaiosError::MaskError(format!(
+                "'{mask_str}' has an empty segment"
+            )));
+        }
+        if mask_str.contains(char::is_whitespace) {
+            return Err(AnkaiosError::MaskError(format!(
+                "'{mask_str}' contains whitespace"
+            )));
+        }
+        Ok(Self(mask_str))
+    }
+
+    #[doc(hidden)]
+    /// Creates a new [Mask] without validation, for masks built by the SDK itself.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mask` - A [String] that represents the mask.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Mask] instance.
+    pub(crate) fn new_unchecked<T: Into<String>>(mask: T) -> Self {
+        Self(mask.into())
+    }
+
+    /// Creates a new [Mask] by appending a segment.
+    ///
+    /// ## Arguments
+    ///
+    /// * `segment` - A [str] that represents the segment to append.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Mask] instance.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if the result is not a valid mask.
+    pub fn join(&self, segment: &str) -> Result<Self, AnkaiosError> {
+        Self::new(format!("{}{SEGMENT_SEPARATOR}{segment}", self.0))
+    }
+
+    /// Creates a new [Mask] by appending the name of a workload, config or agent
+    /// as a single segment.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - A [str] containing the name to append.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Mask] instance.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if the name is empty,
+    /// contains a dot, is the wildcard `*` or contains whitespace.
+    pub fn join_name(&self, name: &str) -> Result<Self, AnkaiosError> {
+        check_name(name)?;
+        self.join(name)
+    }
+
+    /// Returns the mask as a string slice.
+    ///
+    /// ## Returns
+    ///
+    /// A [str] containing the mask.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns an iterator over the segments of the mask.
+    ///
+    /// ## Returns
+    ///
+    /// An iterator over the segments.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split(SEGMENT_SEPARATOR)
+    }
+
+    /// Checks whether this mask covers another one, i.e. whether the other mask
+    /// selects the same field or a field nested inside this one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The [Mask] to check.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if this mask covers the other one.
+    #[must_use]
+    pub fn covers(&self, other: &Mask) -> bool {
+        let mut other_segments = other.segments();
+        self.segments().all(|segment| {
+            other_segments.next().is_some_and(|other_segment| {
+                segment == WILDCARD_SEGMENT || segment == other_segment
+            })
+        })
+    }
+}
+
+/// Checks that a name can be used as a single literal segment of a mask.
+///
+/// ## Arguments
+///
+/// * `name` - A [str] containing the name of a workload, config or agent.
+///
+/// ## Errors
+///
+/// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if the name is empty,
+/// contains a dot or is the wildcard `*`.
+pub(crate) fn check_name(name: &str) -> Result<(), AnkaiosError> {
+    if name.is_empty() {
+        return Err(AnkaiosError::MaskError("the name is empty".to_owned()));
+    }
+    if name.contains(SEGMENT_SEPARATOR) {
+        return Err(AnkaiosError::MaskError(format!(
+            "the name '{name}' contains '{SEGMENT_SEPARATOR}'"
+        )));
+    }
+    if name == WILDCARD_SEGMENT {
+        return Err(AnkaiosError::MaskError(format!(
+            "the name '{name}' is the wildcard"
+        )));
+    }
+    Ok(())
+}
+
+impl fmt::Debug for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl fmt::Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for Mask {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Mask> for String {
+    fn from(mask: Mask) -> Self {
+        mask.0
+    }
+}
+
+/// An ordered set of [masks](Mask) without redundancy.
+///
+/// Inserting a mask already covered by another one has no effect and inserting
+/// a mask covering others replaces them, so no field is sent twice.
+/// The insertion order is kept otherwise.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct MaskSet {
+    /// The masks, none of them covering another one.
+    masks: Vec<Mask>,
+}
+
+impl MaskSet {
+    /// Creates a new empty [`MaskSet`].
+    ///
+    /// ## Returns
+    ///
+    /// A new [`MaskSet`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`MaskSet`] after validating the syntax of all masks.
+    ///
+    /// ## Arguments
+    ///
+    /// * `masks` - The masks to add.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`MaskSet`] instance.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`MaskError`](AnkaiosError::MaskError) if a mask is not valid.
+    pub fn parse<T: Into<String>, I: IntoIterator<Item = T>>(
+        masks: I,
+    ) -> Result<Self, AnkaiosError> {
+        masks.into_iter().map(Mask::new).collect()
+    }
+
+    #[doc(hidden)]
+    /// Creates a new [`MaskSet`] without validation, for masks built by the SDK itself.
+    ///
+    /// ## Arguments
+    ///
+    /// * `masks` - The masks to add.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`MaskSet`] instance.
+    pub(crate) fn new_unchecked<T: Into<String>, I: IntoIterator<Item = T>>(masks: I) -> Self {
+        masks.into_iter().map(Mask::new_unchecked).collect()
+    }
+
+    /// Inserts a mask, unless it is already covered by another mask of the set.
+    /// The masks covered by the new mask are removed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mask` - The [Mask] to insert.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the mask was inserted.
+    pub fn insert(&mut self, mask: Mask) -> bool {
+        if self.covers(&mask) {
+            return false;
+        }
+        self.masks.retain(|existing| !mask.covers(existing));
+        self.masks.push(mask);
+        true
+    }
+
+    /// Checks whether a mask is covered by a mask of the set.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mask` - The [Mask] to check.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the mask is covered.
+    #[must_use]
+    pub fn covers(&self, mask: &Mask) -> bool {
+        self.masks.iter().any(|existing| existing.covers(mask))
+    }
+
+    /// Returns the number of masks in the set.
+    ///
+    /// ## Returns
+    ///
+    /// The number of masks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.masks.len()
+    }
+
+    /// Checks whether the set contains no mask.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the set is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.masks.is_empty()
+    }
+
+    /// Removes all masks.
+    pub fn clear(&mut self) {
+        self.masks.clear();
+    }
+
+    /// Returns an iterator over the masks.
+    ///
+    /// ## Returns
+    ///
+    /// An iterator over the [masks](Mask).
+    pub fn iter(&self) -> impl Iterator<Item = &Mask> {
+        self.masks.iter()
+    }
+
+    /// Converts the set into the masks as strings, as sent to [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] containing the masks.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<String> {
+        self.masks.into_iter().map(String::from).collect()
+    }
+}
+
+impl fmt::Debug for MaskSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.masks).finish()
+    }
+}
+
+impl FromIterator<Mask> for MaskSet {
+    fn from_iter<I: IntoIterator<Item = Mask>>(iter: I) -> Self {
+        let mut mask_set = Self::new();
+        for mask in iter {
+            mask_set.insert(mask);
+        }
+        mask_set
+    }
+}
+
+impl IntoIterator for MaskSet {
+    type Item = Mask;
+    type IntoIter = vec::IntoIter<Mask>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.masks.into_iter()
+    }
+}
+
+impl From<Mask> for MaskSet {
+    fn from(mask: Mask) -> Self {
+        Self { masks: vec![mask] }
+    }
+}
+
+/// Collects the masks after validating their syntax, see [`MaskSet::parse`].
+impl TryFrom<Vec<String>> for MaskSet {
+    type Error = AnkaiosError;
+
+    fn try_from(masks: Vec<String>) -> Result<Self, Self::Error> {
+        Self::parse(masks)
+    }
+}
+
+impl From<MaskSet> for Vec<String> {
+    fn from(mask_set: MaskSet) -> Self {
+        mask_set.into_vec()
+    }
+}
+
+impl<T: AsRef<str>> PartialEq<Vec<T>> for MaskSet {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.masks.len() == other.len()
+            && self
+                .masks
+                .iter()
+                .zip(other)
+                .all(|(mask, other_mask)| mask.as_str() == other_mask.as_ref())
+    }
+}
+
+impl PartialEq<MaskSet> for Vec<String> {
+    fn eq(&self, other: &MaskSet) -> bool {
+        other == self
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Mask, MaskSet};
+    use crate::AnkaiosError;
+
+    #[test]
+    fn utest_mask_join_name() {
+        let workloads = Mask::new("desiredState.workloads").unwrap();
+        assert_eq!(
+            workloads.join_name("nginx").unwrap().as_str(),
+            "desiredState.workloads.nginx"
+        );
+        for invalid in ["", "nginx.files", "*", "a b"] {
+            assert!(matches!(
+                workloads.join_name(invalid),
+                Err(AnkaiosError::MaskError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn utest_mask_validation() {
+        assert!(Mask::new("desiredState.workloads.nginx").is_ok());
+        assert!(Mask::new("workloadStates.*").is_ok());
+        for invalid in [
+            "",
+            "desiredState..workloads",
+            ".workloads",
+            "workloads.",
+            "a b",
+        ] {
+            assert!(matches!(
+                Mask::new(invalid),
+                Err(AnkaiosError::MaskError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn utest_mask_join_and_covers() {
+        let workload = Mask::new("desiredState.workloads.Test").unwrap();
+        let agent = workload.join("agent").unwrap();
+        assert_eq!(agent.as_str(), "desiredState.workloads.Test.agent");
+        assert!(workload.join("").is_err());
+
+        assert!(workload.covers(&workload));
+        assert!(workload.covers(&agent));
+        assert!(!agent.covers(&workload));
+        // Segments are compared entirely, not as string prefixes
+        assert!(!workload.covers(&Mask::new("desiredState.workloads.Test2").unwrap()));
+        assert!(
+            Mask::new("workloadStates.*")
+                .unwrap()
+                .covers(&Mask::new("workloadStates.agent_A.nginx").unwrap())
+        );
+    }
+
+    #[test]
+    fn utest_mask_set_collapses_covered_masks() {
+        let mut masks = MaskSet::try_from(vec![
+            "desiredState.workloads.Test.agent".to_owned(),
+            "desiredState.workloads.Test.tags.key".to_owned(),
+            "desiredState.configs.config_1".to_owned(),
+        ])
+        .unwrap();
+        assert_eq!(masks.len(), 3);
+
+        assert!(!masks.insert(Mask::new("desiredState.configs.config_1").unwrap()));
+        assert!(masks.insert(Mask::new("desiredState.workloads.Test").unwrap()));
+        assert_eq!(
+            masks,
+            vec![
+                "desiredState.configs.config_1",
+                "desiredState.workloads.Test"
+            ]
+        );
+        assert!(!masks.insert(Mask::new("desiredState.workloads.Test.runtime").unwrap()));
+        assert_eq!(
+            Vec::<String>::from(masks.clone()),
+            vec![
+                "desiredState.configs.config_1".to_owned(),
+                "desiredState.workloads.Test".to_owned()
+            ]
+        );
+        assert_eq!(
+            format!("{masks:?}"),
+            "[\"desiredState.configs.config_1\", \"desiredState.workloads.Test\"]"
+        );
+
+        masks.clear();
+        assert!(masks.is_empty());
+    }
+
+    #[test]
+    fn utest_mask_set_parse() {
+        assert_eq!(
+            MaskSet::parse(["workloadStates", "workloadStates.agent_A"]).unwrap(),
+            vec!["workloadStates"]
+        );
+        assert!(MaskSet::parse(["workloadStates", "agents..agent_A"]).is_err());
+        assert!(matches!(
+            MaskSet::try_from(vec!["workload States".to_owned()]),
+            Err(AnkaiosError::MaskError(_))
+        ));
+    }
+}
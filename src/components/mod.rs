@@ -17,12 +17,21 @@
 //!
 //! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
 
+pub mod audit_log;
 pub mod complete_state;
+pub mod config_value;
 pub mod control_interface;
+pub mod control_socket;
 pub mod event_types;
 pub mod log_types;
 pub mod manifest;
+pub mod manifest_schema;
+pub mod mask;
+pub mod placement;
 pub mod request;
 pub mod response;
+pub mod server_capabilities;
+pub mod state_cache;
+pub mod state_query;
 pub mod workload_mod;
 pub mod workload_state_mod;
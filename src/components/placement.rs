@@ -0,0 +1,182 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`PlacementStrategy`] enum, which describes how
+//! [`Ankaios::select_agent`](crate::Ankaios::select_agent) chooses the agent of a workload
+//! among the connected agents.
+//!
+//! # Example
+//!
+//! ## Place a workload on the agent with the most free memory:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::{Ankaios, PlacementStrategy, Workload};
+//! # use tokio::runtime::Runtime;
+//! #
+//! # Runtime::new().unwrap().block_on(async {
+//! let ankaios = Ankaios::new().await.unwrap();
+//! let agent_name = ankaios
+//!     .select_agent(PlacementStrategy::MostFreeMemory)
+//!     .await
+//!     .unwrap();
+//! let workload = Workload::builder()
+//!     .workload_name("nginx")
+//!     .agent_name(agent_name)
+//!     .runtime("podman")
+//!     .restart_policy("NEVER")
+//!     .runtime_config("image: docker.io/library/nginx")
+//!     .build()
+//!     .unwrap();
+//! ankaios.apply_workload(workload).await.unwrap();
+//! # })
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::AgentAttributes;
+
+/// Enum describing how an agent is chosen among the connected agents.
+///
+/// The ties are broken by the agent name, so the choice is deterministic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlacementStrategy {
+    /// The agent reporting the lowest CPU usage.
+    LeastCpu,
+    /// The agent reporting the most free memory.
+    MostFreeMemory,
+    /// The agents in turn, ordered by name.
+    RoundRobin,
+}
+
+/// Chooses an agent according to the strategy.
+/// The agents not reporting the resource used by the strategy are skipped.
+///
+/// ## Arguments
+///
+/// * `agents` - The connected agents;
+/// * `strategy` - The [`PlacementStrategy`] to apply;
+/// * `round_robin_index` - The index of the next agent for [`PlacementStrategy::RoundRobin`], incremented on use.
+///
+/// ## Returns
+///
+/// The name of the chosen agent, [None] if no agent is suitable.
+pub(crate) fn select_agent(
+    agents: &HashMap<String, AgentAttributes>,
+    strategy: PlacementStrategy,
+    round_robin_index: &AtomicUsize,
+) -> Option<String> {
+    let mut sorted_agents: Vec<(&String, &AgentAttributes)> = agents.iter().collect();
+    sorted_agents.sort_by_key(|(name, _)| *name);
+
+    let selected = match strategy {
+        PlacementStrategy::LeastCpu => sorted_agents
+            .into_iter()
+            .filter_map(|(name, attributes)| Some((attributes.cpu_usage()?, name)))
+            .min_by_key(|(cpu_usage, _)| *cpu_usage)
+            .map(|(_, name)| name),
+        PlacementStrategy::MostFreeMemory => sorted_agents
+            .into_iter()
+            .filter_map(|(name, attributes)| Some((attributes.free_memory()?, name)))
+            .min_by_key(|(free_memory, _)| Reverse(*free_memory))
+            .map(|(_, name)| name),
+        PlacementStrategy::RoundRobin => {
+            if sorted_agents.is_empty() {
+                return None;
+            }
+            let index = round_robin_index.fetch_add(1, Ordering::Relaxed) % sorted_agents.len();
+            sorted_agents.get(index).map(|(name, _)| *name)
+        }
+    };
+    selected.cloned()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicUsize;
+
+    use super::{PlacementStrategy, select_agent};
+    use crate::AgentAttributes;
+
+    fn generate_agent(cpu_usage: &str, free_memory: &str) -> AgentAttributes {
+        AgentAttributes {
+            tags: HashMap::new(),
+            status: HashMap::from([
+                ("cpu_usage".to_owned(), cpu_usage.to_owned()),
+                ("free_memory".to_owned(), free_memory.to_owned()),
+            ]),
+        }
+    }
+
+    #[test]
+    fn utest_select_agent_by_resources() {
+        let agents = HashMap::from([
+            ("agent_A".to_owned(), generate_agent("50", "1024")),
+            ("agent_B".to_owned(), generate_agent("10", "512")),
+            ("agent_C".to_owned(), generate_agent("10", "4096")),
+            ("agent_D".to_owned(), generate_agent("N/A", "N/A")),
+        ]);
+        let index = AtomicUsize::new(0);
+
+        assert_eq!(
+            select_agent(&agents, PlacementStrategy::LeastCpu, &index),
+            Some("agent_B".to_owned())
+        );
+        assert_eq!(
+            select_agent(&agents, PlacementStrategy::MostFreeMemory, &index),
+            Some("agent_C".to_owned())
+        );
+
+        let unreported = HashMap::from([("agent_D".to_owned(), generate_agent("N/A", "N/A"))]);
+        assert_eq!(
+            select_agent(&unreported, PlacementStrategy::LeastCpu, &index),
+            None
+        );
+    }
+
+    #[test]
+    fn utest_select_agent_round_robin() {
+        let agents = HashMap::from([
+            ("agent_B".to_owned(), AgentAttributes::default()),
+            ("agent_A".to_owned(), AgentAttributes::default()),
+        ]);
+        let index = AtomicUsize::new(0);
+
+        let selected: Vec<Option<String>> = (0..3)
+            .map(|_| select_agent(&agents, PlacementStrategy::RoundRobin, &index))
+            .collect();
+        assert_eq!(
+            selected,
+            vec![
+                Some("agent_A".to_owned()),
+                Some("agent_B".to_owned()),
+                Some("agent_A".to_owned())
+            ]
+        );
+        assert_eq!(
+            select_agent(&HashMap::new(), PlacementStrategy::RoundRobin, &index),
+            None
+        );
+    }
+}
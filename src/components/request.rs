@@ -22,45 +22,49 @@
 //! ## Create a request for updating the state:
 //!
 //! ```rust
-//! use ankaios_sdk::{CompleteState, UpdateStateRequest};
+//! use ankaios_sdk::{CompleteState, MaskSet, UpdateStateRequest};
 //!
 //! let complete_state = CompleteState::new();
-//! let _request = UpdateStateRequest::new(&complete_state, Vec::default());
+//! let _request = UpdateStateRequest::new(&complete_state, MaskSet::new());
 //! ```
 //!
 //! ## Create a request for getting the state:
 //!
 //! ```rust
-//! use ankaios_sdk::GetStateRequest;
+//! use ankaios_sdk::{GetStateRequest, MaskSet};
 //!
-//! let mut request = GetStateRequest::new(Vec::default());
+//! let mut request = GetStateRequest::new(MaskSet::new());
 //! ```
 //!
 //! ## Get the request ID:
 //!
 //! ```rust
-//! # use ankaios_sdk::{GetStateRequest, Request};
+//! # use ankaios_sdk::{GetStateRequest, MaskSet, Request};
 //! #
-//! # let mut request = GetStateRequest::new(Vec::default());
+//! # let mut request = GetStateRequest::new(MaskSet::new());
 //! let request_id = request.get_id();
 //! ```
 //!
 //! ## Create a request for getting the complete state filtered according to the provided field masks:
 //!
 //! ```rust
-//! # use ankaios_sdk::GetStateRequest;
+//! # use ankaios_sdk::{GetStateRequest, MaskSet};
 //! #
-//! let request = GetStateRequest::new(vec!["desiredState.workloads".to_owned()]);
+//! let masks = MaskSet::parse(["desiredState.workloads"]).unwrap();
+//! let request = GetStateRequest::new(masks);
 //! ```
 
 use crate::LogsRequest;
 use crate::ankaios_api;
 use crate::components::complete_state::CompleteState;
+use crate::components::log_types::rfc3339_before_now;
+use crate::components::mask::MaskSet;
+use crate::logging::sdk_log;
 use ankaios_api::ank_base::{
     CompleteStateRequest, Request as AnkaiosRequest,
     UpdateStateRequest as AnkaiosUpdateStateRequest, request::RequestContent,
 };
-use std::fmt;
+use std::{any, fmt};
 use uuid::Uuid;
 
 /// Trait that represents a request that can be made to the [Ankaios] application.
@@ -80,6 +84,68 @@ pub trait Request {
     ///
     /// A [String] containing the unique identifier of the request.
     fn get_id(&self) -> String;
+
+    /// Adds a prefix to the unique identifier of the request.
+    ///
+    /// Requests that refer to the identifier of a previous request keep their identifier.
+    ///
+    /// ## Arguments
+    ///
+    /// * `prefix` - The prefix to be added to the identifier.
+    fn set_id_prefix(&mut self, _prefix: &str) {}
+
+    /// Returns the update masks of the request, without copying the proto message.
+    ///
+    /// ## Returns
+    ///
+    /// The update masks if the request updates the state, [None] otherwise.
+    fn update_mask(&self) -> Option<&[String]> {
+        None
+    }
+
+    /// Returns the name of the type of the request, e.g. for error messages.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] containing the name of the type of the request.
+    fn get_type(&self) -> String {
+        let type_name = any::type_name::<Self>();
+        type_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(type_name)
+            .to_owned()
+    }
+}
+
+/// Adds a prefix to the identifier of a request and of its proto message, see [`Request::set_id_prefix`].
+///
+/// ## Arguments
+///
+/// * `prefix` - The prefix to be added to the identifier;
+/// * `request_id` - The identifier of the request;
+/// * `request` - The [`AnkaiosRequest`] proto message of the request.
+fn prefix_id(prefix: &str, request_id: &mut String, request: &mut AnkaiosRequest) {
+    *request_id = format!("{prefix}-{request_id}");
+    request.request_id.clone_from(request_id);
+}
+
+/// Returns the update masks of a request proto message, see [`Request::update_mask`].
+///
+/// ## Arguments
+///
+/// * `request` - The [`AnkaiosRequest`] proto message.
+///
+/// ## Returns
+///
+/// The update masks if the request updates the state, [None] otherwise.
+fn update_mask_of(request: &AnkaiosRequest) -> Option<&[String]> {
+    match request.request_content.as_ref() {
+        Some(RequestContent::UpdateStateRequest(update_state_request)) => {
+            Some(&update_state_request.update_mask)
+        }
+        _ => None,
+    }
 }
 
 /// Struct that represents a request to get the state of the [Ankaios] application.
@@ -113,21 +179,24 @@ impl GetStateRequest {
     ///
     /// ## Arguments
     ///
-    /// * `masks` - The field masks to be used for the request.
+    /// * `masks` - The validated field masks to be used for the request, a [`Mask`](crate::Mask) or a [`MaskSet`].
     ///
     /// ## Returns
     ///
     /// A new [`GetStateRequest`] object.
     #[must_use]
-    pub fn new(masks: Vec<String>) -> Self {
+    pub fn new<M: Into<MaskSet>>(masks: M) -> Self {
         let request_id = Uuid::new_v4().to_string();
-        log::debug!("Creating new request of type GetStateRequest with id {request_id}");
+        sdk_log!(
+            debug,
+            "Creating new request of type GetStateRequest with id {request_id}"
+        );
 
         Self {
             request: AnkaiosRequest {
                 request_id: request_id.clone(),
                 request_content: Some(RequestContent::CompleteStateRequest(CompleteStateRequest {
-                    field_mask: masks,
+                    field_mask: masks.into().into_vec(),
                     subscribe_for_events: false,
                 })),
             },
@@ -144,6 +213,10 @@ impl Request for GetStateRequest {
     fn get_id(&self) -> String {
         self.request_id.clone()
     }
+
+    fn set_id_prefix(&mut self, prefix: &str) {
+        prefix_id(prefix, &mut self.request_id, &mut self.request);
+    }
 }
 
 impl fmt::Debug for GetStateRequest {
@@ -158,19 +231,22 @@ impl UpdateStateRequest {
     /// ## Arguments
     ///
     /// * `complete_state` - The complete state to be set.
-    /// * `masks` - The update masks to be used.
+    /// * `masks` - The validated update masks to be used, a [`Mask`](crate::Mask) or a [`MaskSet`].
     ///
     /// ## Returns
     ///
     /// A new [`UpdateStateRequest`] object.
     #[must_use]
-    pub fn new(complete_state: &CompleteState, masks: Vec<String>) -> Self {
+    pub fn new<M: Into<MaskSet>>(complete_state: &CompleteState, masks: M) -> Self {
         let request_id = Uuid::new_v4().to_string();
-        log::debug!("Creating new request of type UpdateStateRequest with id {request_id}");
+        sdk_log!(
+            debug,
+            "Creating new request of type UpdateStateRequest with id {request_id}"
+        );
 
         let update_state_request = AnkaiosUpdateStateRequest {
             new_state: Some(complete_state.to_proto()),
-            update_mask: masks,
+            update_mask: masks.into().into_vec(),
         };
 
         Self {
@@ -193,6 +269,14 @@ impl Request for UpdateStateRequest {
     fn get_id(&self) -> String {
         self.request_id.clone()
     }
+
+    fn set_id_prefix(&mut self, prefix: &str) {
+        prefix_id(prefix, &mut self.request_id, &mut self.request);
+    }
+
+    fn update_mask(&self) -> Option<&[String]> {
+        update_mask_of(&self.request)
+    }
 }
 
 impl fmt::Debug for UpdateStateRequest {
@@ -238,8 +322,14 @@ impl From<LogsRequest> for AnkaiosLogsRequest {
                             .collect(),
                         follow: Some(logs_request.follow),
                         tail: Some(logs_request.tail),
-                        since: logs_request.since,
-                        until: logs_request.until,
+                        since: logs_request
+                            .since_duration
+                            .map(rfc3339_before_now)
+                            .or(logs_request.since),
+                        until: logs_request
+                            .until_duration
+                            .map(rfc3339_before_now)
+                            .or(logs_request.until),
                     },
                 )),
             },
@@ -256,6 +346,10 @@ impl Request for AnkaiosLogsRequest {
     fn get_id(&self) -> String {
         self.request_id.clone()
     }
+
+    fn set_id_prefix(&mut self, prefix: &str) {
+        prefix_id(prefix, &mut self.request_id, &mut self.request);
+    }
 }
 
 impl fmt::Debug for AnkaiosLogsRequest {
@@ -288,7 +382,10 @@ impl LogsCancelRequest {
     ///
     /// A new [`LogsCancelRequest`] object.
     pub fn new(request_id: String) -> Self {
-        log::debug!("Creating new request of type LogsCancelRequest with id '{request_id}'");
+        sdk_log!(
+            debug,
+            "Creating new request of type LogsCancelRequest with id '{request_id}'"
+        );
         Self {
             request: AnkaiosRequest {
                 request_id: request_id.clone(),
@@ -335,20 +432,23 @@ impl EventsRequest {
     ///
     /// ## Arguments
     ///
-    /// * `masks` - The field masks to be used for the request.
+    /// * `masks` - The validated field masks to be used for the request, a [`Mask`](crate::Mask) or a [`MaskSet`].
     ///
     /// ## Returns
     ///
     /// A new [`EventsRequest`] object.
-    pub fn new(masks: Vec<String>) -> Self {
+    pub fn new<M: Into<MaskSet>>(masks: M) -> Self {
         let request_id = Uuid::new_v4().to_string();
-        log::debug!("Creating new request of type EventsRequest with id {request_id}");
+        sdk_log!(
+            debug,
+            "Creating new request of type EventsRequest with id {request_id}"
+        );
 
         Self {
             request: AnkaiosRequest {
                 request_id: request_id.clone(),
                 request_content: Some(RequestContent::CompleteStateRequest(CompleteStateRequest {
-                    field_mask: masks,
+                    field_mask: masks.into().into_vec(),
                     subscribe_for_events: true,
                 })),
             },
@@ -365,6 +465,10 @@ impl Request for EventsRequest {
     fn get_id(&self) -> String {
         self.request_id.clone()
     }
+
+    fn set_id_prefix(&mut self, prefix: &str) {
+        prefix_id(prefix, &mut self.request_id, &mut self.request);
+    }
 }
 
 impl fmt::Debug for EventsRequest {
@@ -397,7 +501,10 @@ impl EventsCancelRequest {
     ///
     /// A new [`EventsCancelRequest`] object.
     pub fn new(request_id: String) -> Self {
-        log::debug!("Creating new request of type EventsCancelRequest with id '{request_id}'");
+        sdk_log!(
+            debug,
+            "Creating new request of type EventsCancelRequest with id '{request_id}'"
+        );
         Self {
             request: AnkaiosRequest {
                 request_id: request_id.clone(),
@@ -426,6 +533,67 @@ impl fmt::Debug for EventsCancelRequest {
     }
 }
 
+/// Struct that represents a raw request sent as is to the [Ankaios] application.
+///
+/// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+#[cfg(feature = "unstable-proto")]
+#[derive(PartialEq)]
+pub(crate) struct RawRequest {
+    /// The request proto message that will be sent to the cluster.
+    #[allow(clippy::struct_field_names)]
+    pub(crate) request: AnkaiosRequest,
+}
+
+#[cfg(feature = "unstable-proto")]
+impl RawRequest {
+    /// Creates a new `RawRequest`. If the request has no ID, a new one is generated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` - The [`AnkaiosRequest`] proto message to be sent.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`RawRequest`] object.
+    pub(crate) fn new(mut request: AnkaiosRequest) -> Self {
+        if request.request_id.is_empty() {
+            request.request_id = Uuid::new_v4().to_string();
+        }
+        sdk_log!(
+            debug,
+            "Creating new request of type RawRequest with id {}",
+            request.request_id
+        );
+        Self { request }
+    }
+}
+
+#[cfg(feature = "unstable-proto")]
+impl Request for RawRequest {
+    fn to_proto(&self) -> AnkaiosRequest {
+        self.request.clone()
+    }
+
+    fn get_id(&self) -> String {
+        self.request.request_id.clone()
+    }
+
+    fn set_id_prefix(&mut self, prefix: &str) {
+        self.request.request_id = format!("{prefix}-{}", self.request.request_id);
+    }
+
+    fn update_mask(&self) -> Option<&[String]> {
+        update_mask_of(&self.request)
+    }
+}
+
+#[cfg(feature = "unstable-proto")]
+impl fmt::Debug for RawRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.to_proto())
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -436,7 +604,10 @@ impl fmt::Debug for EventsCancelRequest {
 
 #[cfg(test)]
 pub fn generate_test_request() -> impl Request {
-    UpdateStateRequest::new(&CompleteState::default(), vec!["test_mask".to_owned()])
+    UpdateStateRequest::new(
+        &CompleteState::default(),
+        MaskSet::new_unchecked(["test_mask"]),
+    )
 }
 
 #[cfg(test)]
@@ -449,7 +620,8 @@ mod tests {
     };
     use ankaios_api::ank_base::Request as AnkaiosRequest;
 
-    use super::{CompleteState, GetStateRequest, Request, UpdateStateRequest};
+    use super::{CompleteState, GetStateRequest, MaskSet, Request, UpdateStateRequest};
+    use std::time::Duration;
 
     const REQUEST_ID: &str = "test_id";
 
@@ -457,9 +629,13 @@ mod tests {
     fn utest_request_update_state() {
         let request = UpdateStateRequest::new(
             &CompleteState::default(),
-            vec!["mask1".to_owned(), "mask2".to_owned()],
+            MaskSet::new_unchecked(["mask1", "mask2"]),
         );
         let id = request.get_id();
+        assert_eq!(
+            request.update_mask(),
+            Some(["mask1".to_owned(), "mask2".to_owned()].as_slice())
+        );
 
         assert_eq!(
             request.to_proto(),
@@ -475,11 +651,12 @@ mod tests {
         );
 
         assert_eq!(format!("{request:?}"), format!("{:?}", request.to_proto()));
+        assert_eq!(request.get_type(), "UpdateStateRequest");
     }
 
     #[test]
     fn utest_request_get_state() {
-        let request = GetStateRequest::new(vec!["mask1".to_owned(), "mask2".to_owned()]);
+        let request = GetStateRequest::new(MaskSet::new_unchecked(["mask1", "mask2"]));
         let id = request.get_id();
 
         assert_eq!(
@@ -504,8 +681,7 @@ mod tests {
             workload_names: Vec::new(),
             follow: false,
             tail: 10,
-            since: None,
-            until: None,
+            ..Default::default()
         };
         let request = AnkaiosLogsRequest::from(logs_request);
         let id = request.get_id();
@@ -529,6 +705,46 @@ mod tests {
         assert_eq!(format!("{request:?}"), format!("{:?}", request.to_proto()));
     }
 
+    #[test]
+    fn utest_request_logs_with_durations() {
+        let logs_request = LogsRequest {
+            since: Some("2025-01-01T00:00:00Z".to_owned()),
+            until: Some("2025-01-02T00:00:00Z".to_owned()),
+            since_duration: Some(Duration::from_secs(u64::MAX)),
+            ..Default::default()
+        };
+        let request = AnkaiosLogsRequest::from(logs_request);
+
+        let Some(ankaios_api::ank_base::RequestContent::LogsRequest(proto_logs_request)) =
+            request.to_proto().request_content
+        else {
+            panic!("Expected a logs request");
+        };
+        assert_eq!(
+            proto_logs_request.since,
+            Some("1970-01-01T00:00:00Z".to_owned())
+        );
+        assert_eq!(
+            proto_logs_request.until,
+            Some("2025-01-02T00:00:00Z".to_owned())
+        );
+    }
+
+    #[test]
+    fn utest_request_set_id_prefix() {
+        let mut request = GetStateRequest::new(MaskSet::new_unchecked(["mask1"]));
+        let id = request.get_id();
+        request.set_id_prefix("prefix");
+
+        assert_eq!(request.get_id(), format!("prefix-{id}"));
+        assert_eq!(request.to_proto().request_id, format!("prefix-{id}"));
+
+        // Requests referring to a previous request keep their id
+        let mut cancel_request = LogsCancelRequest::new(REQUEST_ID.to_owned());
+        cancel_request.set_id_prefix("prefix");
+        assert_eq!(cancel_request.get_id(), REQUEST_ID);
+    }
+
     #[test]
     fn utest_request_logs_cancel() {
         let request = LogsCancelRequest::new(REQUEST_ID.to_owned());
@@ -549,7 +765,7 @@ mod tests {
     #[test]
     fn utest_request_events() {
         let masks = vec!["mask1".to_owned(), "mask2".to_owned()];
-        let request = EventsRequest::new(masks.clone());
+        let request = EventsRequest::new(MaskSet::new_unchecked(masks.clone()));
         let id = request.get_id();
 
         assert_eq!(
@@ -584,4 +800,31 @@ mod tests {
 
         assert_eq!(format!("{request:?}"), format!("{:?}", request.to_proto()));
     }
+
+    #[cfg(feature = "unstable-proto")]
+    #[test]
+    fn utest_request_raw() {
+        use super::RawRequest;
+
+        let request = RawRequest::new(AnkaiosRequest {
+            request_id: REQUEST_ID.to_owned(),
+            request_content: None,
+        });
+        assert_eq!(request.get_id(), REQUEST_ID);
+        assert_eq!(format!("{request:?}"), format!("{:?}", request.to_proto()));
+
+        let request_without_id = RawRequest::new(AnkaiosRequest::default());
+        assert!(!request_without_id.get_id().is_empty());
+
+        let mut prefixed_request = RawRequest::new(AnkaiosRequest {
+            request_id: REQUEST_ID.to_owned(),
+            request_content: None,
+        });
+        prefixed_request.set_id_prefix("app");
+        assert_eq!(prefixed_request.get_id(), format!("app-{REQUEST_ID}"));
+        assert_eq!(
+            prefixed_request.to_proto().request_id,
+            format!("app-{REQUEST_ID}")
+        );
+    }
 }
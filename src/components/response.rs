@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-//! This module contains the [Response] and [`UpdateStateSuccess`] structs and the [`ResponseType`] enum.
+//! This module contains the [Response], [`TappedResponse`], [`UpdateStateSuccess`] and [`BatchResult`] structs and the [`ResponseType`] enum.
 //!
 //! # Examples
 //!
@@ -47,7 +47,7 @@
 //! let dict = update_state_success.to_dict();
 //! ```
 
-use super::workload_state_mod::WorkloadInstanceName;
+use super::workload_state_mod::{ProcessOutcome, WorkloadExecutionState, WorkloadInstanceName};
 use crate::ankaios_api::{self};
 use crate::components::complete_state::CompleteState;
 use crate::components::event_types::EventEntry;
@@ -60,6 +60,7 @@ use ankaios_api::ank_base::{
 use ankaios_api::control_api::{FromAnkaios, from_ankaios::FromAnkaiosEnum};
 use std::collections::HashMap;
 use std::default;
+use std::time::Duration;
 
 /// Enum that represents the type of responses that can be provided by the [Ankaios] cluster.
 ///
@@ -88,6 +89,9 @@ pub enum ResponseType {
     EventResponse(Box<EventEntry>),
     /// The success of an events cancel request.
     EventsCancelAccepted,
+    /// The response to a raw request, kept as is.
+    #[cfg(feature = "unstable-proto")]
+    RawResponse(Box<ankaios_api::ank_base::Response>),
 }
 
 /// Struct that represents a response from the [Ankaios] cluster.
@@ -101,6 +105,15 @@ pub struct Response {
     pub id: String,
 }
 
+/// Struct that represents a response observed through [`debug_tap`](crate::Ankaios::debug_tap).
+#[derive(Clone, Debug)]
+pub struct TappedResponse {
+    /// The received response, before it is dispatched.
+    pub response: Response,
+    /// The size in bytes of the encoded message.
+    pub size: usize,
+}
+
 /// Struct that handles the `UpdateStateSuccess` response.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct UpdateStateSuccess {
@@ -110,6 +123,99 @@ pub struct UpdateStateSuccess {
     pub deleted_workloads: Vec<WorkloadInstanceName>,
 }
 
+/// Struct that represents the result of an update of the configs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigUpdateResult {
+    /// The names of the configs that were updated, sorted.
+    pub updated_configs: Vec<String>,
+    /// The error messages by config name, for the configs rejected by [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+    /// Empty as long as [Ankaios](https://eclipse-ankaios.github.io/ankaios) rejects the whole update with an error instead.
+    pub failed_configs: HashMap<String, String>,
+}
+
+impl ConfigUpdateResult {
+    /// Creates a new `ConfigUpdateResult` for configs that were all updated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `updated_configs` - The names of the updated configs.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`ConfigUpdateResult`] instance.
+    pub(crate) fn new<I: IntoIterator<Item = String>>(updated_configs: I) -> Self {
+        let mut sorted_configs: Vec<String> = updated_configs.into_iter().collect();
+        sorted_configs.sort();
+        Self {
+            updated_configs: sorted_configs,
+            failed_configs: HashMap::new(),
+        }
+    }
+
+    /// Checks whether all the configs were updated.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if no config was rejected.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failed_configs.is_empty()
+    }
+}
+
+/// Enum that represents the outcome of a conditional workload apply.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApplyWorkloadResult {
+    /// The workload differed from the current state and was applied.
+    Applied(UpdateStateSuccess),
+    /// The workload was identical to the current state, no update was sent.
+    Unchanged,
+}
+
+/// Enum that represents the outcome of ensuring a config has a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ensured {
+    /// The config already had the value, no update was sent.
+    Unchanged,
+    /// The config had another value and was updated.
+    Updated,
+    /// The config did not exist and was created.
+    Created,
+}
+
+/// Struct that represents the result of a workload run to completion.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchResult {
+    /// The final execution state of the workload, either succeeded or failed.
+    pub state: WorkloadExecutionState,
+    /// The log entries of the workload, empty if the logs were not collected.
+    pub logs: Vec<LogEntry>,
+    /// The time from applying the workload until it finished.
+    pub duration: Duration,
+}
+
+impl BatchResult {
+    /// Checks whether the workload succeeded.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload finished in the [`Succeeded`](crate::WorkloadStateEnum::Succeeded) state.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.outcome().is_some_and(|outcome| outcome.success())
+    }
+
+    /// Returns the outcome of the workload as a process outcome.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ProcessOutcome`] of the final state, see [`WorkloadExecutionState::as_process_outcome`].
+    #[must_use]
+    pub fn outcome(&self) -> Option<ProcessOutcome> {
+        self.state.as_process_outcome()
+    }
+}
+
 impl default::Default for ResponseType {
     fn default() -> Self {
         ResponseType::Error(String::default())
@@ -365,7 +471,7 @@ pub fn get_test_proto_from_ankaios_log_entries_response(
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub fn generate_test_proto_log_entries_response() -> ankaios_api::ank_base::LogEntriesResponse {
     ankaios_api::ank_base::LogEntriesResponse {
         log_entries: vec![
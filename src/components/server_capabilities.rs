@@ -0,0 +1,162 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`ServerCapabilities`] struct, which describes
+//! the features supported by the [Ankaios](https://eclipse-ankaios.github.io/ankaios)
+//! cluster the SDK is connected to.
+//!
+//! # Example
+//!
+//! ## Check whether the logs can be requested:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::Ankaios;
+//! # use tokio::runtime::Runtime;
+//! #
+//! # Runtime::new().unwrap().block_on(async {
+//! let ankaios = Ankaios::new().await.unwrap();
+//! if ankaios.server_capabilities().await.unwrap().logs {
+//!     // Request the logs
+//! }
+//! # })
+//! ```
+
+use crate::logging::sdk_log;
+
+/// The first API version guaranteeing the configs.
+const CONFIGS_MIN_API_VERSION: (u64, u64) = (1, 0);
+/// The first API version guaranteeing the files of the workloads.
+const FILES_MIN_API_VERSION: (u64, u64) = (1, 0);
+/// The first API version guaranteeing the logs of the workloads.
+const LOGS_MIN_API_VERSION: (u64, u64) = (1, 0);
+/// The first API version guaranteeing the events of the state changes.
+const EVENTS_MIN_API_VERSION: (u64, u64) = (1, 0);
+
+/// Struct describing the features supported by the connected
+/// [Ankaios](https://eclipse-ankaios.github.io/ankaios) cluster,
+/// derived from the API version of the desired state reported by the server.
+///
+/// The `v0.1` API was used by all the releases before `1.0`, which added the
+/// features one after the other. As the API version does not tell them apart,
+/// no feature is reported for them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerCapabilities {
+    /// The API version reported by the server.
+    pub api_version: String,
+    /// Whether the configs can be managed.
+    pub configs: bool,
+    /// Whether files can be assigned to the workloads.
+    pub files: bool,
+    /// Whether the logs of the workloads can be requested.
+    pub logs: bool,
+    /// Whether the events of the state changes can be registered for.
+    pub events: bool,
+}
+
+impl ServerCapabilities {
+    /// Derives the supported features from the API version reported by the server.
+    /// No feature is supported if the version can not be parsed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api_version` - The `apiVersion` of the desired state, e.g. `v1`.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`ServerCapabilities`] object.
+    #[must_use]
+    pub fn from_api_version(api_version: &str) -> Self {
+        let Some(version) = parse_major_minor(api_version) else {
+            sdk_log!(warn, "Could not parse the API version '{api_version}'.");
+            return Self {
+                api_version: api_version.to_owned(),
+                ..Default::default()
+            };
+        };
+        Self {
+            api_version: api_version.to_owned(),
+            configs: version >= CONFIGS_MIN_API_VERSION,
+            files: version >= FILES_MIN_API_VERSION,
+            logs: version >= LOGS_MIN_API_VERSION,
+            events: version >= EVENTS_MIN_API_VERSION,
+        }
+    }
+}
+
+/// Parses the major and minor numbers of a version like `1.0.0`, `v0.6` or `0.7.0-rc1`.
+///
+/// ## Arguments
+///
+/// * `version` - The version to parse.
+///
+/// ## Returns
+///
+/// The major and minor numbers, [None] if the version is invalid.
+fn parse_major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut numbers = version.trim_start_matches('v').split(['.', '-', '+']);
+    let major = numbers.next()?.parse().ok()?;
+    let minor = numbers.next().map_or(Some(0), |minor| minor.parse().ok())?;
+    Some((major, minor))
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerCapabilities, parse_major_minor};
+
+    #[test]
+    fn utest_parse_major_minor() {
+        assert_eq!(parse_major_minor("1.0.0"), Some((1, 0)));
+        assert_eq!(parse_major_minor("v0.6"), Some((0, 6)));
+        assert_eq!(parse_major_minor("0.7.0-rc1"), Some((0, 7)));
+        assert_eq!(parse_major_minor("2"), Some((2, 0)));
+        assert_eq!(parse_major_minor("invalid"), None);
+        assert_eq!(parse_major_minor("1.x"), None);
+    }
+
+    #[test]
+    fn utest_server_capabilities_from_api_version() {
+        assert_eq!(
+            ServerCapabilities::from_api_version("v1"),
+            ServerCapabilities {
+                api_version: "v1".to_owned(),
+                configs: true,
+                files: true,
+                logs: true,
+                events: true,
+            }
+        );
+        assert_eq!(
+            ServerCapabilities::from_api_version("v0.1"),
+            ServerCapabilities {
+                api_version: "v0.1".to_owned(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            ServerCapabilities::from_api_version("unknown"),
+            ServerCapabilities {
+                api_version: "unknown".to_owned(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`CachePolicy`] struct, which configures the
+//! cache of the [`CompleteState`] objects returned by [`get_state`](crate::Ankaios::get_state).
+
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::CompleteState;
+use crate::components::mask::MaskSet;
+
+/// Struct configuring the cache of the states requested through the SDK.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// The time a cached state is served before it is requested again.
+    pub ttl: Duration,
+    /// The maximum number of field mask combinations cached.
+    pub max_entries: usize,
+}
+
+/// Collapses field masks into a [`MaskSet`] and sorts them, so that equivalent requests share the same key.
+///
+/// ## Arguments
+///
+/// * `field_masks` - The field masks of a request.
+///
+/// ## Returns
+///
+/// The normalized field masks.
+pub(crate) fn normalize_field_masks(field_masks: &[String]) -> Vec<String> {
+    let mut normalized = MaskSet::new_unchecked(field_masks).into_vec();
+    normalized.sort();
+    normalized
+}
+
+#[doc(hidden)]
+/// Bounded cache of the [`CompleteState`] objects, keyed by field masks.
+#[derive(Debug)]
+pub(crate) struct StateCache {
+    /// The policy of the cache.
+    policy: CachePolicy,
+    /// The cached states and the time they were received, by sorted field masks.
+    entries: HashMap<Vec<String>, (Instant, CompleteState)>,
+    /// The number of invalidations, bumped by [`clear`](StateCache::clear).
+    generation: u64,
+}
+
+impl StateCache {
+    /// Creates a new empty `StateCache`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `policy` - The [`CachePolicy`] of the cache.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`StateCache`] instance.
+    pub(crate) fn new(policy: CachePolicy) -> Self {
+        Self {
+            policy,
+            entries: HashMap::new(),
+            generation: 0,
+        }
+    }
+
+    /// Changes the policy of the cache, dropping the entries that no longer fit.
+    ///
+    /// ## Arguments
+    ///
+    /// * `policy` - The new [`CachePolicy`].
+    pub(crate) fn set_policy(&mut self, policy: CachePolicy) {
+        self.policy = policy;
+        self.remove_expired();
+        self.truncate(self.policy.max_entries);
+    }
+
+    /// Returns the cached state for the given field masks, if not expired.
+    ///
+    /// ## Arguments
+    ///
+    /// * `field_masks` - The field masks of the request.
+    ///
+    /// ## Returns
+    ///
+    /// A copy of the cached [`CompleteState`], or [None] if missing or expired.
+    pub(crate) fn get(&self, field_masks: &[String]) -> Option<CompleteState> {
+        self.entries
+            .get(&normalize_field_masks(field_masks))
+            .filter(|(received, _)| received.elapsed() < self.policy.ttl)
+            .map(|(_, state)| state.clone())
+    }
+
+    /// Returns the current generation of the cache, to be passed to [`insert`](StateCache::insert)
+    /// for a state requested from now on.
+    ///
+    /// ## Returns
+    ///
+    /// The number of invalidations so far.
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Caches a state, dropping the oldest entry if the cache is full.
+    /// The state is not cached if the cache was invalidated since it was requested,
+    /// as it might predate the update that caused the invalidation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `generation` - The [`generation`](StateCache::generation) when the state was requested;
+    /// * `field_masks` - The field masks of the request;
+    /// * `state` - The [`CompleteState`] received.
+    pub(crate) fn insert(&mut self, generation: u64, field_masks: &[String], state: CompleteState) {
+        if self.policy.max_entries == 0 || generation != self.generation {
+            return;
+        }
+        let key = normalize_field_masks(field_masks);
+        self.entries.remove(&key);
+        self.remove_expired();
+        self.truncate(self.policy.max_entries.saturating_sub(1));
+        self.entries.insert(key, (Instant::now(), state));
+    }
+
+    /// Drops all the cached states and starts a new generation.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Drops the expired entries.
+    fn remove_expired(&mut self) {
+        let ttl = self.policy.ttl;
+        self.entries
+            .retain(|_, (received, _)| received.elapsed() < ttl);
+    }
+
+    /// Drops the oldest entries until at most `max_entries` are left.
+    fn truncate(&mut self, max_entries: usize) {
+        while self.entries.len() > max_entries {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (received, _))| *received)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(key) => {
+                    self.entries.remove(&key);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{CachePolicy, StateCache};
+    use crate::CompleteState;
+    use std::time::Duration;
+
+    fn masks(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[tokio::test]
+    async fn utest_state_cache_ttl() {
+        let mut cache = StateCache::new(CachePolicy {
+            ttl: Duration::from_millis(50),
+            max_entries: 2,
+        });
+        cache.insert(0, &masks(&["a", "b"]), CompleteState::default());
+        assert!(cache.get(&masks(&["b", "a"])).is_some());
+        assert!(cache.get(&masks(&["b", "a.c", "a"])).is_some());
+        assert!(cache.get(&masks(&["a"])).is_none());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(cache.get(&masks(&["a", "b"])).is_none());
+    }
+
+    #[tokio::test]
+    async fn utest_state_cache_max_entries() {
+        let mut cache = StateCache::new(CachePolicy {
+            ttl: Duration::from_secs(10),
+            max_entries: 2,
+        });
+        cache.insert(0, &masks(&["a"]), CompleteState::default());
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        cache.insert(0, &masks(&["b"]), CompleteState::default());
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        cache.insert(0, &masks(&["c"]), CompleteState::default());
+        assert!(cache.get(&masks(&["a"])).is_none());
+        assert!(cache.get(&masks(&["b"])).is_some());
+        assert!(cache.get(&masks(&["c"])).is_some());
+
+        cache.set_policy(CachePolicy {
+            ttl: Duration::from_secs(10),
+            max_entries: 1,
+        });
+        assert!(cache.get(&masks(&["b"])).is_none());
+        assert!(cache.get(&masks(&["c"])).is_some());
+
+        cache.clear();
+        assert!(cache.get(&masks(&["c"])).is_none());
+    }
+
+    #[test]
+    fn utest_state_cache_generation() {
+        let mut cache = StateCache::new(CachePolicy {
+            ttl: Duration::from_secs(10),
+            max_entries: 2,
+        });
+        let generation = cache.generation();
+        cache.clear();
+        cache.insert(generation, &masks(&["a"]), CompleteState::default());
+        assert!(cache.get(&masks(&["a"])).is_none());
+
+        cache.insert(cache.generation(), &masks(&["a"]), CompleteState::default());
+        assert!(cache.get(&masks(&["a"])).is_some());
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`StateQuery`] struct, a builder for the field masks
+//! passed to [`get_state`](crate::Ankaios::get_state).
+
+use crate::WorkloadInstanceName;
+use crate::ankaios::{AGENTS_PREFIX, DESIRED_STATE_PREFIX, WORKLOAD_STATES_PREFIX};
+use crate::components::manifest::CONFIGS_PREFIX;
+use crate::components::workload_mod::WORKLOADS_PREFIX;
+
+/// A builder for the field masks of a state request.
+///
+/// # Example
+///
+/// ## Request the workloads and the state of an agent:
+///
+/// ```rust
+/// use ankaios_sdk::StateQuery;
+///
+/// let field_masks: Vec<String> = StateQuery::new()
+///     .workloads()
+///     .workload_states()
+///     .agent("agent_A")
+///     .build();
+/// assert_eq!(
+///     field_masks,
+///     vec!["desiredState.workloads", "workloadStates", "agents.agent_A"]
+/// );
+/// ```
+#[must_use] // Added to ensure that the returned Self from the methods is used.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateQuery {
+    /// The field masks added so far.
+    field_masks: Vec<String>,
+}
+
+impl StateQuery {
+    /// Creates a new [`StateQuery`] instance without any field mask,
+    /// which requests the complete state.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`StateQuery`] instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field mask, ignoring duplicates.
+    ///
+    /// ## Arguments
+    ///
+    /// * `field_mask` - A [String] that represents the field mask.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn field<T: Into<String>>(mut self, field_mask: T) -> Self {
+        let mask = field_mask.into();
+        if !self.field_masks.contains(&mask) {
+            self.field_masks.push(mask);
+        }
+        self
+    }
+
+    /// Adds the whole desired state.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn desired_state(self) -> Self {
+        self.field(DESIRED_STATE_PREFIX)
+    }
+
+    /// Adds all the workloads of the desired state.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn workloads(self) -> Self {
+        self.field(WORKLOADS_PREFIX)
+    }
+
+    /// Adds a workload of the desired state.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload_name` - A [String] that represents the name of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn workload<T: Into<String>>(self, workload_name: T) -> Self {
+        self.field(format!("{WORKLOADS_PREFIX}.{}", workload_name.into()))
+    }
+
+    /// Adds all the configs of the desired state.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn configs(self) -> Self {
+        self.field(CONFIGS_PREFIX)
+    }
+
+    /// Adds a config of the desired state.
+    ///
+    /// ## Arguments
+    ///
+    /// * `config_name` - A [String] that represents the name of the config.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn config<T: Into<String>>(self, config_name: T) -> Self {
+        self.field(format!("{CONFIGS_PREFIX}.{}", config_name.into()))
+    }
+
+    /// Adds all the agents.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn agents(self) -> Self {
+        self.field(AGENTS_PREFIX)
+    }
+
+    /// Adds an agent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agent_name` - A [String] that represents the name of the agent.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn agent<T: Into<String>>(self, agent_name: T) -> Self {
+        self.field(format!("{AGENTS_PREFIX}.{}", agent_name.into()))
+    }
+
+    /// Adds all the workload states.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn workload_states(self) -> Self {
+        self.field(WORKLOAD_STATES_PREFIX)
+    }
+
+    /// Adds the workload states of the workloads running on an agent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agent_name` - A [String] that represents the name of the agent.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn workload_states_on_agent<T: Into<String>>(self, agent_name: T) -> Self {
+        self.field(format!("{WORKLOAD_STATES_PREFIX}.{}", agent_name.into()))
+    }
+
+    /// Adds the workload state of a workload instance.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload instance.
+    ///
+    /// ## Returns
+    ///
+    /// The [`StateQuery`] instance.
+    pub fn workload_state(self, instance_name: &WorkloadInstanceName) -> Self {
+        self.field(instance_name.state_mask())
+    }
+
+    /// Returns whether no field mask was added, in which case the complete state is requested.
+    ///
+    /// ## Returns
+    ///
+    /// A [bool] indicating whether the query is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.field_masks.is_empty()
+    }
+
+    /// Builds the field masks.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [String]s containing the field masks, in the order they were added.
+    #[must_use]
+    pub fn build(self) -> Vec<String> {
+        self.field_masks
+    }
+}
+
+impl From<StateQuery> for Vec<String> {
+    fn from(query: StateQuery) -> Self {
+        query.build()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::StateQuery;
+    use crate::WorkloadInstanceName;
+
+    #[test]
+    fn utest_state_query() {
+        assert!(StateQuery::new().is_empty());
+
+        let instance_name =
+            WorkloadInstanceName::new("agent_A".to_owned(), "nginx".to_owned(), "1234".to_owned());
+        let field_masks: Vec<String> = StateQuery::new()
+            .desired_state()
+            .workloads()
+            .workload("nginx")
+            .configs()
+            .config("config_1")
+            .agents()
+            .agent("agent_A")
+            .workload_states()
+            .workload_states_on_agent("agent_A")
+            .workload_state(&instance_name)
+            .workloads()
+            .into();
+        assert_eq!(
+            field_masks,
+            vec![
+                "desiredState",
+                "desiredState.workloads",
+                "desiredState.workloads.nginx",
+                "desiredState.configs",
+                "desiredState.configs.config_1",
+                "agents",
+                "agents.agent_A",
+                "workloadStates",
+                "workloadStates.agent_A",
+                "workloadStates.agent_A.nginx.1234",
+            ]
+        );
+    }
+}
@@ -12,9 +12,13 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use super::secret::{MaybeSecret, Secret};
 use crate::AnkaiosError;
 use crate::ankaios_api::ank_base;
+use crate::logging::sdk_log;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use serde_yaml::{Mapping, Value};
+use std::{collections::HashMap, io::Read};
 
 /// Key name for mount point of workload file.
 pub const FILE_MOUNT_POINT_KEY: &str = "mount_point";
@@ -22,6 +26,15 @@ pub const FILE_MOUNT_POINT_KEY: &str = "mount_point";
 pub const FILE_DATA_KEY: &str = "data";
 /// Key name for binary data of workload file.
 pub const FILE_BINARY_DATA_KEY: &str = "binaryData";
+/// Maximum size in bytes of the content of a workload file, as sent to Ankaios.
+/// The files are part of the desired state, which must fit in a single message
+/// of 4 MiB, the default limit of the gRPC connection between the Ankaios components.
+pub const MAX_FILE_SIZE: usize = 4 * 1024 * 1024;
+/// Characters of the template syntax beyond plain references to config values,
+/// e.g. helpers, blocks, partials, comments, parent paths and whitespace control.
+const UNSUPPORTED_TEMPLATE_CHARACTERS: [char; 16] = [
+    '#', '/', '>', '!', '^', '&', '@', '~', '(', ')', '[', ']', '=', '"', '\'', '|',
+];
 
 /// Represents a file that can be mounted to a workload.
 ///
@@ -44,6 +57,14 @@ pub const FILE_BINARY_DATA_KEY: &str = "binaryData";
 ///
 /// let binary_file = File::from_binary_data("/usr/share/app/binary_file", "iVBORw0KGgoARYANSUhEUgA...");
 /// ```
+///
+/// ## Create a binary file from raw bytes:
+///
+/// ```rust
+/// use ankaios_sdk::File;
+///
+/// let binary_file = File::from_bytes("/usr/share/app/binary_file", &[0x89, 0x50, 0x4e, 0x47]).unwrap();
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct File {
     /// The path where the file will be mounted in the container.
@@ -55,12 +76,25 @@ pub struct File {
 /// Represents the content type of a [`File`].
 ///
 /// A file can contain either data or binary data (base64 encoded).
+/// Sensitive data is wrapped in a [`Secret`], so that it is redacted when printed.
 #[derive(Debug, Clone, PartialEq)]
 pub enum FileContent {
     /// Data content stored as a UTF-8 string.
     Data(String),
     /// Binary content stored as a base64-encoded string.
     BinaryData(String),
+    /// Sensitive data content, sent to Ankaios as data.
+    SecretData(Secret),
+}
+
+impl FileContent {
+    /// Gets the content as sent to Ankaios, including the exposed secret data.
+    fn as_str(&self) -> &str {
+        match self {
+            FileContent::Data(content) | FileContent::BinaryData(content) => content,
+            FileContent::SecretData(secret) => secret.expose_secret(),
+        }
+    }
 }
 
 impl File {
@@ -69,15 +103,19 @@ impl File {
     /// ## Arguments
     ///
     /// * `mount_point` - The path where the file will be mounted in the container
-    /// * `content` - The data content of the file
+    /// * `content` - The data content of the file, either plain text or a [`Secret`]
     ///
     /// ## Returns
     ///
     /// A new `File` instance with data content.
-    pub fn from_data<T: Into<String>>(mount_point: T, content: T) -> Self {
+    pub fn from_data<T: Into<String>, U: MaybeSecret>(mount_point: T, content: U) -> Self {
+        let file_content = match content.into_content() {
+            (data, false) => FileContent::Data(data),
+            (data, true) => FileContent::SecretData(Secret::new(data)),
+        };
         Self {
             mount_point: mount_point.into(),
-            content: FileContent::Data(content.into()),
+            content: file_content,
         }
     }
 
@@ -98,6 +136,183 @@ impl File {
         }
     }
 
+    /// Creates a new file with binary data content from raw bytes.
+    /// The bytes are base64-encoded.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mount_point` - The path where the file will be mounted in the container
+    /// * `bytes` - The raw content of the file
+    ///
+    /// ## Returns
+    ///
+    /// A new `File` instance with binary data content.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`FileTooLargeError`](AnkaiosError::FileTooLargeError) if the encoded content exceeds [`MAX_FILE_SIZE`].
+    pub fn from_bytes<T: Into<String>>(mount_point: T, bytes: &[u8]) -> Result<Self, AnkaiosError> {
+        let file = Self {
+            mount_point: mount_point.into(),
+            content: FileContent::BinaryData(BASE64.encode(bytes)),
+        };
+        file.validate_size()?;
+        Ok(file)
+    }
+
+    /// Creates a new file with the content read from a reader.
+    ///
+    /// Valid UTF-8 content is stored as data, any other content as base64-encoded binary data.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mount_point` - The path where the file will be mounted in the container
+    /// * `reader` - The reader providing the content of the file
+    ///
+    /// ## Returns
+    ///
+    /// A new `File` instance with the read content.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if reading the content failed;
+    /// - [`AnkaiosError`]::[`FileTooLargeError`](AnkaiosError::FileTooLargeError) if the content exceeds [`MAX_FILE_SIZE`].
+    pub fn from_reader<T: Into<String>, R: Read>(
+        mount_point: T,
+        reader: R,
+    ) -> Result<Self, AnkaiosError> {
+        let mut bytes = Vec::new();
+        // Read one byte more than allowed to detect content that is too large
+        // without reading the whole reader.
+        reader
+            .take(
+                u64::try_from(MAX_FILE_SIZE)
+                    .unwrap_or(u64::MAX)
+                    .saturating_add(1),
+            )
+            .read_to_end(&mut bytes)?;
+        match String::from_utf8(bytes) {
+            Ok(data) => {
+                let file = Self::from_data(mount_point.into(), data);
+                file.validate_size()?;
+                Ok(file)
+            }
+            Err(err) => Self::from_bytes(mount_point, err.as_bytes()),
+        }
+    }
+
+    /// Checks that the content of the file does not exceed [`MAX_FILE_SIZE`].
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`FileTooLargeError`](AnkaiosError::FileTooLargeError) if the content is too large.
+    pub fn validate_size(&self) -> Result<(), AnkaiosError> {
+        let size = self.content.as_str().len();
+        if size > MAX_FILE_SIZE {
+            return Err(AnkaiosError::FileTooLargeError(
+                self.mount_point.clone(),
+                size,
+                MAX_FILE_SIZE,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Renders the templated data content of the file the way Ankaios does before mounting it.
+    ///
+    /// Only plain references to config values are supported, e.g. `{{alias.key}}`,
+    /// `{{ alias.list.0 }}` or `{{{alias.key}}}`. Helpers, blocks, partials, comments,
+    /// whitespace control, raw blocks and escaped expressions are rejected instead of
+    /// being passed through. Binary data content is never rendered.
+    ///
+    /// ## Arguments
+    ///
+    /// * `configs` - The config values of the workload, by alias
+    ///
+    /// ## Returns
+    ///
+    /// A new `File` instance with the rendered content.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`FileRenderingError`](AnkaiosError::FileRenderingError) if the template uses
+    /// unsupported expressions or references unknown config values.
+    pub(crate) fn render(&self, configs: &HashMap<String, Value>) -> Result<Self, AnkaiosError> {
+        let FileContent::Data(template) = &self.content else {
+            return Ok(self.clone());
+        };
+        let rendering_error =
+            |reason: String| AnkaiosError::FileRenderingError(self.mount_point.clone(), reason);
+
+        let mut rendered = String::with_capacity(template.len());
+        let mut remaining = template.as_str();
+        while let Some(start) = remaining.find("{{") {
+            if remaining[..start].ends_with('\\') {
+                return Err(rendering_error("unsupported escaped expression".to_owned()));
+            }
+            rendered.push_str(&remaining[..start]);
+            remaining = &remaining[start..];
+            if remaining.starts_with("{{{{") {
+                return Err(rendering_error("unsupported raw block".to_owned()));
+            }
+            let (opening, closing) = if remaining.starts_with("{{{") {
+                ("{{{", "}}}")
+            } else {
+                ("{{", "}}")
+            };
+            let Some(length) = remaining[opening.len()..].find(closing) else {
+                return Err(rendering_error("unterminated expression".to_owned()));
+            };
+            let end = opening.len() + length;
+            let expression = remaining[opening.len()..end].trim();
+            remaining = &remaining[end + closing.len()..];
+
+            if expression.is_empty()
+                || expression.contains(char::is_whitespace)
+                || expression.contains(UNSUPPORTED_TEMPLATE_CHARACTERS)
+            {
+                return Err(rendering_error(format!(
+                    "unsupported expression '{expression}'"
+                )));
+            }
+            let mut path = expression.split('.');
+            let referenced = path
+                .next()
+                .and_then(|alias| configs.get(alias))
+                .and_then(|config| {
+                    path.try_fold(config, |current, key| match current {
+                        Value::Mapping(mapping) => mapping.get(key),
+                        Value::Sequence(sequence) => key
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| sequence.get(index)),
+                        _ => None,
+                    })
+                });
+            match referenced {
+                Some(Value::String(value)) => rendered.push_str(value),
+                Some(Value::Number(value)) => rendered.push_str(&value.to_string()),
+                Some(Value::Bool(value)) => rendered.push_str(&value.to_string()),
+                Some(_) => {
+                    return Err(rendering_error(format!(
+                        "'{expression}' does not reference a single value"
+                    )));
+                }
+                None => {
+                    return Err(rendering_error(format!(
+                        "unknown config reference '{expression}'"
+                    )));
+                }
+            }
+        }
+        rendered.push_str(remaining);
+
+        Ok(Self {
+            mount_point: self.mount_point.clone(),
+            content: FileContent::Data(rendered),
+        })
+    }
+
     /// Converts the file to a Mapping representation.
     ///
     /// ## Returns
@@ -124,6 +339,12 @@ impl File {
                     Value::String(content.clone()),
                 );
             }
+            FileContent::SecretData(secret) => {
+                dict.insert(
+                    Value::String(FILE_DATA_KEY.to_owned()),
+                    Value::String(secret.expose_secret().to_owned()),
+                );
+            }
         }
 
         dict
@@ -210,6 +431,9 @@ impl File {
             FileContent::BinaryData(binary_data) => {
                 Some(ank_base::FileContent::BinaryData(binary_data))
             }
+            FileContent::SecretData(secret) => Some(ank_base::FileContent::Data(
+                secret.expose_secret().to_owned(),
+            )),
         };
 
         ank_base::File {
@@ -235,7 +459,8 @@ impl File {
                 content: FileContent::BinaryData(binary_data),
             },
             None => {
-                log::warn!(
+                sdk_log!(
+                    warn,
                     "This case is unreachable in reality as ank_base::File always contains either Data or BinaryData"
                 );
                 File {
@@ -634,4 +859,107 @@ mod tests {
         assert_ne!(data_content1, data_content3);
         assert_ne!(data_content1, binary_data_content);
     }
+
+    #[test]
+    fn test_from_bytes() {
+        let file = File::from_bytes("/usr/share/app/binary", &[0x00, 0xff, 0x10]).unwrap();
+        assert_eq!(file.mount_point, "/usr/share/app/binary");
+        assert_eq!(file.content, FileContent::BinaryData("AP8Q".to_owned()));
+    }
+
+    #[test]
+    fn test_from_bytes_too_large() {
+        let bytes = vec![0_u8; MAX_FILE_SIZE];
+        let result = File::from_bytes("/usr/share/app/binary", &bytes);
+        assert!(matches!(
+            result,
+            Err(AnkaiosError::FileTooLargeError(_, _, MAX_FILE_SIZE))
+        ));
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let data_file = File::from_reader("/etc/config.txt", "Hello, World!".as_bytes()).unwrap();
+        assert_eq!(
+            data_file.content,
+            FileContent::Data("Hello, World!".to_owned())
+        );
+
+        let binary_file =
+            File::from_reader("/usr/share/app/binary", [0x00, 0xff].as_slice()).unwrap();
+        assert_eq!(
+            binary_file.content,
+            FileContent::BinaryData("AP8=".to_owned())
+        );
+
+        let large_content = vec![b'a'; MAX_FILE_SIZE + 10];
+        assert!(matches!(
+            File::from_reader("/etc/config.txt", large_content.as_slice()),
+            Err(AnkaiosError::FileTooLargeError(_, size, MAX_FILE_SIZE)) if size == MAX_FILE_SIZE + 1
+        ));
+    }
+
+    #[test]
+    fn test_render() {
+        let configs = HashMap::from([(
+            "cfg".to_owned(),
+            serde_yaml::from_str::<Value>("{port: \"8080\", hosts: [a, b], nested: {x: y}}")
+                .unwrap(),
+        )]);
+
+        let file = File::from_data(
+            "/etc/config.txt",
+            "port={{cfg.port}} host={{ cfg.hosts.1 }} x={{{cfg.nested.x}}}",
+        );
+        assert_eq!(
+            file.render(&configs).unwrap().content,
+            FileContent::Data("port=8080 host=b x=y".to_owned())
+        );
+
+        let binary_file = File::from_binary_data("/usr/share/app/binary", "e3tjZmcucG9ydH19");
+        assert_eq!(binary_file.render(&configs).unwrap(), binary_file);
+
+        for template in [
+            "{{cfg.unknown}}",
+            "{{other.port}}",
+            "{{cfg.nested}}",
+            "{{#each cfg.hosts}}{{this}}{{/each}}",
+            "{{cfg.port",
+            "{{{cfg.port}}",
+            "{{~cfg.port~}}",
+            "{{! comment }}",
+            "{{> partial}}",
+            "{{../cfg.port}}",
+            "{{cfg.hosts.[0]}}",
+            "{{{{raw}}}}{{cfg.port}}{{{{/raw}}}}",
+            "\\{{cfg.port}}",
+        ] {
+            assert!(matches!(
+                File::from_data("/etc/config.txt", template).render(&configs),
+                Err(AnkaiosError::FileRenderingError(_, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_from_secret_data() {
+        let file = File::from_data("/etc/credentials", Secret::new("password"));
+        assert_eq!(
+            file.content,
+            FileContent::SecretData(Secret::new("password"))
+        );
+        assert!(!format!("{file:?}").contains("password"));
+
+        let proto = file.clone().into_proto();
+        assert_eq!(
+            proto.file_content,
+            Some(ank_base::FileContent::Data("password".to_owned()))
+        );
+        assert_eq!(
+            file.to_dict()
+                .get(Value::String(FILE_DATA_KEY.to_owned()))
+                .and_then(Value::as_str),
+            Some("password")
+        );
+    }
 }
@@ -18,12 +18,14 @@
 //! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
 
 mod file;
+mod secret;
 mod workload;
 mod workload_builder;
 
-pub use file::{File, FileContent};
+pub use file::{File, FileContent, MAX_FILE_SIZE};
+pub use secret::{MaybeSecret, Secret};
 pub use workload::{WORKLOADS_PREFIX, Workload};
 pub use workload_builder::WorkloadBuilder;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub mod test_helpers;
@@ -0,0 +1,128 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`Secret`] struct, which wraps sensitive
+//! workload content so that it is never printed.
+
+use std::fmt;
+
+/// The text printed instead of the content of a [`Secret`].
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Wraps sensitive content, like credentials, used in a runtime config or a file.
+///
+/// The [Debug](fmt::Debug) and [Display](fmt::Display) implementations redact the content.
+/// Workloads built with secret content redact it as well when printed.
+///
+/// # Examples
+///
+/// ```rust
+/// use ankaios_sdk::{File, Secret, Workload};
+///
+/// let workload = Workload::builder()
+///     .workload_name("example_workload")
+///     .agent_name("agent_A")
+///     .runtime("podman")
+///     .runtime_config(Secret::new("image: registry.example.com/app\ncommandOptions: [\"-e\", \"TOKEN=1234\"]"))
+///     .add_file(File::from_data("/etc/credentials", Secret::new("password")))
+///     .build()
+///     .unwrap();
+/// assert!(!format!("{workload:?}").contains("1234"));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Creates a new `Secret`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `content` - The sensitive content.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`Secret`] instance.
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Self(content.into())
+    }
+
+    /// Gets the sensitive content.
+    ///
+    /// ## Returns
+    ///
+    /// The content of the secret as a [&str].
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({REDACTED})")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{REDACTED}")
+    }
+}
+
+/// Trait for the content accepted by the runtime config and the file data,
+/// which can be either plain text or a [`Secret`].
+pub trait MaybeSecret {
+    /// Converts the value into its content.
+    ///
+    /// ## Returns
+    ///
+    /// The content and whether it is secret.
+    fn into_content(self) -> (String, bool);
+}
+
+impl<T: Into<String>> MaybeSecret for T {
+    fn into_content(self) -> (String, bool) {
+        (self.into(), false)
+    }
+}
+
+impl MaybeSecret for Secret {
+    fn into_content(self) -> (String, bool) {
+        (self.0, true)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{MaybeSecret, Secret};
+
+    #[test]
+    fn utest_secret() {
+        let secret = Secret::new("password");
+        assert_eq!(secret.expose_secret(), "password");
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+        assert_eq!(format!("{secret}"), "[REDACTED]");
+
+        assert_eq!(secret.into_content(), ("password".to_owned(), true));
+        assert_eq!("text".into_content(), ("text".to_owned(), false));
+    }
+}
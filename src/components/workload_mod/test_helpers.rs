@@ -14,10 +14,13 @@
 
 use crate::Workload;
 use crate::ankaios_api;
+use crate::components::mask::MaskSet;
 use ankaios_api::ank_base;
 use std::collections::HashMap;
+#[cfg(test)]
 use std::path::Path;
 
+#[cfg(test)]
 #[allow(clippy::unnecessary_wraps)]
 pub fn read_to_string_mock(path: &Path) -> Result<String, std::io::Error> {
     Ok(path.to_str().unwrap().to_owned())
@@ -106,7 +109,9 @@ pub fn generate_test_workload<T: Into<String>>(
     Workload {
         workload: generate_test_workload_proto(agent_name, runtime_name),
         main_mask: format!("desiredState.workloads.{}", name.clone()),
-        masks: vec![format!("desiredState.workloads.{}", name.clone())],
+        masks: MaskSet::new_unchecked([format!("desiredState.workloads.{}", name.clone())]),
         name,
+        secret_runtime_config: false,
+        secret_files: Vec::new(),
     }
 }
@@ -16,9 +16,26 @@ use crate::AnkaiosError;
 use crate::File;
 use crate::WorkloadBuilder;
 use crate::ankaios_api;
+use crate::components::mask::{Mask, MaskSet};
+use crate::components::workload_mod::{
+    FileContent,
+    secret::{MaybeSecret, REDACTED, Secret},
+};
+use crate::components::workload_state_mod::{
+    unknown_enum_value_from_str, unknown_enum_value_to_str,
+};
 use ankaios_api::ank_base;
+use prost::Message;
+use serde::{Serialize, de::DeserializeOwned};
 use serde_yaml::Value;
-use std::{borrow::ToOwned, collections::HashMap, convert::Into, path::Path, vec};
+use std::{
+    borrow::ToOwned,
+    collections::{BTreeMap, HashMap},
+    convert::Into,
+    fmt,
+    path::Path,
+    vec,
+};
 
 // Disable this from coverage
 // https://github.com/rust-lang/rust/issues/84605
@@ -65,7 +82,6 @@ const SUBFIELD_ACCESS_STATE_RULE: &str = "StateRule";
 const FIELD_CONFIGS: &str = "configs";
 /// The field name for files.
 const FIELD_FILES: &str = "files";
-
 /// Represents a workload with various attributes and methods to update them.
 ///
 /// The `Workload` struct is used to store the [Ankaios] workload, allowing for
@@ -159,7 +175,7 @@ const FIELD_FILES: &str = "files";
 /// #   .build().unwrap();
 /// println!("{:?}", workload);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Workload {
     #[doc(hidden)]
     /// The underlying workload data from the proto file.
@@ -167,10 +183,38 @@ pub struct Workload {
     #[doc(hidden)]
     /// The main mask of the workload.
     pub(crate) main_mask: String,
-    /// A vector of strings representing the masks for the workload.
-    pub masks: Vec<String>,
+    /// The masks for the workload, i.e. the fields to be applied.
+    pub masks: MaskSet,
     /// The name of the workload.
     pub name: String,
+    #[doc(hidden)]
+    /// Whether the runtime config is a [Secret].
+    pub(crate) secret_runtime_config: bool,
+    #[doc(hidden)]
+    /// The mount points of the files with secret data.
+    pub(crate) secret_files: Vec<String>,
+}
+
+impl fmt::Debug for Workload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut workload = self.workload.clone();
+        if self.secret_runtime_config && workload.runtime_config.is_some() {
+            workload.runtime_config = Some(REDACTED.to_owned());
+        }
+        if let Some(files) = workload.files.as_mut() {
+            for file in &mut files.files {
+                if self.secret_files.contains(&file.mount_point) {
+                    file.file_content = Some(ank_base::FileContent::Data(REDACTED.to_owned()));
+                }
+            }
+        }
+        f.debug_struct("Workload")
+            .field("workload", &workload)
+            .field("main_mask", &self.main_mask)
+            .field("masks", &self.masks)
+            .field("name", &self.name)
+            .finish()
+    }
 }
 
 impl Workload {
@@ -190,8 +234,12 @@ impl Workload {
         Self {
             workload: ank_base::Workload::default(),
             main_mask: format!("{WORKLOADS_PREFIX}.{name_str}"),
-            masks: vec![format!("{WORKLOADS_PREFIX}.{name_str}")],
+            masks: MaskSet::from(Mask::new_unchecked(format!(
+                "{WORKLOADS_PREFIX}.{name_str}"
+            ))),
             name: name_str,
+            secret_runtime_config: false,
+            secret_files: Vec::new(),
         }
     }
 
@@ -211,8 +259,10 @@ impl Workload {
         Self {
             workload: proto,
             main_mask: format!("{WORKLOADS_PREFIX}.{name_str}"),
-            masks: vec![],
+            masks: MaskSet::new(),
             name: name_str,
+            secret_runtime_config: false,
+            secret_files: Vec::new(),
         }
     }
 
@@ -459,6 +509,19 @@ impl Workload {
         wl_builder.build()
     }
 
+    /// Returns the size in bytes of the encoded [Workload].
+    ///
+    /// Useful to check in advance that a workload, e.g. with large embedded files,
+    /// fits into the [`MAX_MESSAGE_SIZE`](crate::MAX_MESSAGE_SIZE) of the control interface.
+    ///
+    /// ## Returns
+    ///
+    /// The encoded size of the [Workload] in bytes.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.workload.encoded_len()
+    }
+
     /// Converts the `Workload` instance to a proto message.
     ///
     /// ## Returns
@@ -471,6 +534,7 @@ impl Workload {
     }
 
     /// Converts the `Workload` instance to a [`serde_yaml::Mapping`].
+    /// The entries of the maps are sorted by key, so the output is deterministic.
     ///
     /// ## Returns
     ///
@@ -497,12 +561,10 @@ impl Workload {
             );
         }
         if let Some(restart_policy) = self.workload.restart_policy {
-            if let Ok(ank_restart_policy) = ank_base::RestartPolicy::try_from(restart_policy) {
-                dict.insert(
-                    Value::String(FIELD_RESTART_POLICY.to_owned()),
-                    Value::String(ank_restart_policy.as_str_name().to_owned()),
-                );
-            }
+            dict.insert(
+                Value::String(FIELD_RESTART_POLICY.to_owned()),
+                Value::String(Self::restart_policy_to_str(restart_policy)),
+            );
         }
         if let Some(dependencies) = self.workload.dependencies.clone() {
             let mut deps = serde_yaml::Mapping::new();
@@ -510,13 +572,11 @@ impl Workload {
                 Value::String(FIELD_DEPENDENCIES.to_owned()),
                 Value::Mapping(serde_yaml::Mapping::new()),
             );
-            for (key, value) in &dependencies.dependencies {
-                if let Ok(cond) = ank_base::AddCondition::try_from(*value) {
-                    deps.insert(
-                        Value::String(key.clone()),
-                        Value::String(cond.as_str_name().to_owned()),
-                    );
-                }
+            for (key, value) in dependencies.dependencies.iter().collect::<BTreeMap<_, _>>() {
+                deps.insert(
+                    Value::String(key.clone()),
+                    Value::String(Self::add_condition_to_str(*value)),
+                );
             }
             dict.insert(
                 Value::String(FIELD_DEPENDENCIES.to_owned()),
@@ -525,7 +585,7 @@ impl Workload {
         }
         if let Some(wl_tags) = self.workload.tags.clone() {
             let mut tags = serde_yaml::Mapping::new();
-            for (key, value) in &wl_tags.tags {
+            for (key, value) in wl_tags.tags.iter().collect::<BTreeMap<_, _>>() {
                 tags.insert(Value::String(key.clone()), Value::String(value.clone()));
             }
             dict.insert(Value::String(FIELD_TAGS.to_owned()), Value::Mapping(tags));
@@ -610,7 +670,7 @@ impl Workload {
         }
         if let Some(wl_configs) = self.workload.configs.clone() {
             let mut configs = serde_yaml::Mapping::new();
-            for (alias, name) in &wl_configs.configs {
+            for (alias, name) in wl_configs.configs.iter().collect::<BTreeMap<_, _>>() {
                 configs.insert(Value::String(alias.clone()), Value::String(name.clone()));
             }
             dict.insert(
@@ -644,6 +704,25 @@ impl Workload {
         WorkloadBuilder::new()
     }
 
+    /// Clears the masks of the workload, so that only the fields updated afterwards are applied.
+    /// A workload without masks is applied entirely, using its main mask.
+    pub fn reset_masks(&mut self) {
+        self.masks.clear();
+    }
+
+    /// Marks the whole workload to be applied, replacing the masks with the main mask.
+    /// Useful to re-apply a workload obtained with [`get_workload`](crate::Ankaios::get_workload)
+    /// after modifying it.
+    ///
+    /// ## Returns
+    ///
+    /// The [Workload] with the main mask as only mask.
+    #[must_use]
+    pub fn into_full_update(mut self) -> Self {
+        self.masks = MaskSet::from(Mask::new_unchecked(self.main_mask.clone()));
+        self
+    }
+
     /// Updates the name of the workload.
     ///
     /// ## Arguments
@@ -652,7 +731,7 @@ impl Workload {
     pub fn update_workload_name<T: Into<String>>(&mut self, new_name: T) {
         self.name = new_name.into();
         self.main_mask = format!("{WORKLOADS_PREFIX}.{}", self.name);
-        self.masks = vec![format!("{WORKLOADS_PREFIX}.{}", self.name)];
+        self.masks = MaskSet::from(Mask::new_unchecked(self.main_mask.clone()));
     }
 
     /// Updates the agent name of the workload.
@@ -679,12 +758,58 @@ impl Workload {
     ///
     /// ## Arguments
     ///
-    /// - `runtime_config` - A [String] that represents the new [runtime config](ank_base::Workload).
-    pub fn update_runtime_config<T: Into<String>>(&mut self, runtime_config: T) {
-        self.workload.runtime_config = Some(runtime_config.into());
+    /// - `runtime_config` - A [String] or a [Secret] that represents the new [runtime config](ank_base::Workload).
+    pub fn update_runtime_config<T: MaybeSecret>(&mut self, runtime_config: T) {
+        let (content, is_secret) = runtime_config.into_content();
+        self.workload.runtime_config = Some(content);
+        self.secret_runtime_config = is_secret;
         self.add_mask(format!("{}.{FIELD_RUNTIME_CONFIG}", self.main_mask));
     }
 
+    /// Parses the runtime config of the workload into a structured type.
+    ///
+    /// ## Returns
+    ///
+    /// The runtime config deserialized from YAML into `T`.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`WorkloadFieldError`](AnkaiosError::WorkloadFieldError) if the runtime config is not set;
+    /// - [`AnkaiosError`]::[`YamlError`](AnkaiosError::YamlError) if the runtime config cannot be deserialized into `T`.
+    pub fn runtime_config_as<T: DeserializeOwned>(&self) -> Result<T, AnkaiosError> {
+        let runtime_config = self.workload.runtime_config.as_deref().ok_or_else(|| {
+            AnkaiosError::WorkloadFieldError(
+                FIELD_RUNTIME_CONFIG.to_owned(),
+                "runtime config is not set".to_owned(),
+            )
+        })?;
+        Ok(serde_yaml::from_str(runtime_config)?)
+    }
+
+    /// Updates the runtime config of the workload by serializing a structured type to YAML.
+    /// A runtime config set as a [Secret] stays a secret.
+    ///
+    /// ## Arguments
+    ///
+    /// - `runtime_config` - The value to serialize as the new [runtime config](ank_base::Workload).
+    ///
+    /// ## Errors
+    ///
+    /// An [`AnkaiosError`]::[`YamlError`](AnkaiosError::YamlError) if the value
+    /// cannot be serialized to YAML.
+    pub fn set_runtime_config_from<T: Serialize>(
+        &mut self,
+        runtime_config: &T,
+    ) -> Result<(), AnkaiosError> {
+        let runtime_config_yaml = serde_yaml::to_string(runtime_config)?;
+        if self.secret_runtime_config {
+            self.update_runtime_config(Secret::new(runtime_config_yaml));
+        } else {
+            self.update_runtime_config(runtime_config_yaml);
+        }
+        Ok(())
+    }
+
     /// Updates the runtime config of the workload using a file.
     ///
     /// ## Arguments
@@ -704,7 +829,8 @@ impl Workload {
     }
 
     /// Updates the restart policy of the workload.
-    /// Allowed values are "`NEVER`", "`ON_FAILURE`" and "`ALWAYS`".
+    /// Allowed values are "`NEVER`", "`ON_FAILURE`" and "`ALWAYS`", or "`Unknown(<value>)`"
+    /// to keep a value unknown to the SDK.
     ///
     /// ## Arguments
     ///
@@ -721,12 +847,15 @@ impl Workload {
         self.workload.restart_policy =
             match ank_base::RestartPolicy::from_str_name(&restart_policy_str.clone()) {
                 Some(policy) => Some(policy as i32),
-                _ => {
-                    return Err(AnkaiosError::WorkloadFieldError(
-                        FIELD_RESTART_POLICY.to_owned(),
-                        restart_policy_str,
-                    ));
-                }
+                _ => match unknown_enum_value_from_str(&restart_policy_str) {
+                    Some(value) => Some(value),
+                    None => {
+                        return Err(AnkaiosError::WorkloadFieldError(
+                            FIELD_RESTART_POLICY.to_owned(),
+                            restart_policy_str,
+                        ));
+                    }
+                },
             };
         self.add_mask(format!("{}.{FIELD_RESTART_POLICY}", self.main_mask));
         Ok(())
@@ -742,16 +871,15 @@ impl Workload {
         let mut dependencies = HashMap::new();
         if let Some(deps) = &self.workload.dependencies {
             for (key, value) in &deps.dependencies {
-                if let Ok(add_cond) = ank_base::AddCondition::try_from(*value) {
-                    dependencies.insert(key.clone(), add_cond.as_str_name().to_owned());
-                }
+                dependencies.insert(key.clone(), Self::add_condition_to_str(*value));
             }
         }
         dependencies
     }
 
     /// Updates the dependencies of the workload.
-    /// Allowed values for the conditions are "`ADD_COND_RUNNING`", "`ADD_COND_SUCCEEDED`" and "`ADD_COND_FAILED`".
+    /// Allowed values for the conditions are "`ADD_COND_RUNNING`", "`ADD_COND_SUCCEEDED`" and "`ADD_COND_FAILED`",
+    /// or "`Unknown(<value>)`" to keep a value unknown to the SDK.
     ///
     /// ## Arguments
     ///
@@ -769,12 +897,15 @@ impl Workload {
             let cond = condition.into();
             let add_condition = match ank_base::AddCondition::from_str_name(&cond.clone()) {
                 Some(add_cond) => add_cond as i32,
-                _ => {
-                    return Err(AnkaiosError::WorkloadFieldError(
-                        "dependency condition".to_owned(),
-                        cond,
-                    ));
-                }
+                _ => match unknown_enum_value_from_str(&cond) {
+                    Some(value) => value,
+                    None => {
+                        return Err(AnkaiosError::WorkloadFieldError(
+                            "dependency condition".to_owned(),
+                            cond,
+                        ));
+                    }
+                },
             };
             if let Some(deps) = self.workload.dependencies.as_mut() {
                 deps.dependencies
@@ -800,12 +931,7 @@ impl Workload {
             tags.tags.insert(key_str.clone(), value.into());
         }
 
-        if !self
-            .masks
-            .contains(&format!("{}.{FIELD_TAGS}", self.main_mask))
-        {
-            self.add_mask(format!("{}.{FIELD_TAGS}.{key_str}", self.main_mask));
-        }
+        self.add_mask(format!("{}.{FIELD_TAGS}.{key_str}", self.main_mask));
     }
 
     /// Getter for the tags of the workload.
@@ -840,8 +966,6 @@ impl Workload {
             }
             ank_tags
         });
-        self.masks
-            .retain(|mask| !mask.starts_with(&format!("{}.{FIELD_TAGS}", self.main_mask)));
         self.add_mask(format!("{}.{FIELD_TAGS}", self.main_mask));
     }
 
@@ -871,12 +995,12 @@ impl Workload {
                         "Write" => ank_base::ReadWriteEnum::RwWrite as i32,
                         "Read" => ank_base::ReadWriteEnum::RwRead as i32,
                         "ReadWrite" => ank_base::ReadWriteEnum::RwReadWrite as i32,
-                        _ => {
-                            return Err(AnkaiosError::WorkloadFieldError(
+                        _ => unknown_enum_value_from_str(operation).ok_or_else(|| {
+                            AnkaiosError::WorkloadFieldError(
                                 SUBFIELD_ACCESS_OPERATION.to_owned(),
                                 operation.to_owned(),
-                            ));
-                        }
+                            )
+                        })?,
                     },
                     filter_masks,
                 },
@@ -884,7 +1008,40 @@ impl Workload {
         })
     }
 
+    /// Converts a restart policy to its name, `Unknown(<value>)` if it is unknown to the SDK.
+    ///
+    /// ## Arguments
+    ///
+    /// - `restart_policy` - The raw value of the [`RestartPolicy`](ank_base::RestartPolicy).
+    ///
+    /// ## Returns
+    ///
+    /// The name of the restart policy.
+    pub(crate) fn restart_policy_to_str(restart_policy: i32) -> String {
+        ank_base::RestartPolicy::try_from(restart_policy).map_or_else(
+            |_| unknown_enum_value_to_str(restart_policy),
+            |policy| policy.as_str_name().to_owned(),
+        )
+    }
+
+    /// Converts a dependency condition to its name, `Unknown(<value>)` if it is unknown to the SDK.
+    ///
+    /// ## Arguments
+    ///
+    /// - `condition` - The raw value of the [`AddCondition`](ank_base::AddCondition).
+    ///
+    /// ## Returns
+    ///
+    /// The name of the dependency condition.
+    fn add_condition_to_str(condition: i32) -> String {
+        ank_base::AddCondition::try_from(condition).map_or_else(
+            |_| unknown_enum_value_to_str(condition),
+            |add_cond| add_cond.as_str_name().to_owned(),
+        )
+    }
+
     /// Converts an [`AccessRightsRule`](ank_base::AccessRightsRule) to a tuple of [Strings](String).
+    /// An operation unknown to the SDK is converted to `Unknown(<value>)`.
     ///     
     /// ## Arguments
     ///
@@ -914,12 +1071,7 @@ impl Workload {
                         ));
                     }
                 },
-                _ => {
-                    return Err(AnkaiosError::WorkloadFieldError(
-                        SUBFIELD_ACCESS_OPERATION.to_owned(),
-                        rule.operation.to_string(),
-                    ));
-                }
+                Err(_) => unknown_enum_value_to_str(rule.operation),
             },
             rule.filter_masks.clone(),
         ))
@@ -1107,6 +1259,9 @@ impl Workload {
             self.add_mask(format!("{}.{FIELD_FILES}", self.main_mask));
         }
 
+        if let FileContent::SecretData(_) = file.content {
+            self.secret_files.push(file.mount_point.clone());
+        }
         if let Some(files) = self.workload.files.as_mut() {
             files.files.push(file.into_proto());
         }
@@ -1124,7 +1279,17 @@ impl Workload {
                 .files
                 .clone()
                 .into_iter()
-                .map(File::from_proto)
+                .map(|file_proto| {
+                    let file = File::from_proto(file_proto);
+                    match file.content {
+                        FileContent::Data(data)
+                            if self.secret_files.contains(&file.mount_point) =>
+                        {
+                            File::from_data(file.mount_point, Secret::new(data))
+                        }
+                        _ => file,
+                    }
+                })
                 .collect()
         } else {
             Vec::new()
@@ -1139,6 +1304,11 @@ impl Workload {
     ///
     /// - `files` - A vector of [File] objects to set as the workload's files.
     pub fn update_files(&mut self, files: Vec<File>) {
+        self.secret_files = files
+            .iter()
+            .filter(|file| matches!(file.content, FileContent::SecretData(_)))
+            .map(|file| file.mount_point.clone())
+            .collect();
         if files.is_empty() {
             self.workload.files = None;
         } else {
@@ -1152,35 +1322,123 @@ impl Workload {
         }
     }
 
-    /// Adds a mask to the workload.
+    #[doc(hidden)]
+    /// Checks if the workload is semantically equal to another one.
+    ///
+    /// Unset optional fields are considered equal to their empty defaults
+    /// and the order of the files is ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// - `other` - The [Workload] to compare against.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if both workloads describe the same desired state.
+    pub(crate) fn is_equivalent_to(&self, other: &Workload) -> bool {
+        self.name == other.name
+            && Self::normalized_proto(&self.workload) == Self::normalized_proto(&other.workload)
+    }
+
+    /// Returns a copy of the given proto with the optional fields filled with their defaults.
+    ///
+    /// ## Arguments
+    ///
+    /// - `workload` - The [`ank_base::Workload`] to normalize.
+    ///
+    /// ## Returns
+    ///
+    /// The normalized [`ank_base::Workload`].
+    fn normalized_proto(workload: &ank_base::Workload) -> ank_base::Workload {
+        let mut normalized = workload.clone();
+        normalized
+            .restart_policy
+            .get_or_insert(ank_base::RestartPolicy::Never as i32);
+        normalized.dependencies.get_or_insert_default();
+        normalized.tags.get_or_insert_default();
+        normalized.control_interface_access.get_or_insert_default();
+        normalized.configs.get_or_insert_default();
+        normalized
+            .files
+            .get_or_insert_default()
+            .files
+            .sort_by(|first, second| first.mount_point.cmp(&second.mount_point));
+        normalized
+    }
+
+    /// Adds a mask to the workload, unless it is covered by an existing mask.
+    /// The existing masks covered by the new one are removed.
     ///
     /// ## Arguments
     ///
     /// - `mask` - A [String] containing the mask to be added.
     fn add_mask(&mut self, mask: String) {
-        if !self.masks.contains(&mask) && !self.masks.contains(&self.main_mask) {
-            let configs_mask = format!("{}.{}", self.main_mask.as_str(), FIELD_CONFIGS);
-            if mask == configs_mask {
-                let masks_to_remove: Vec<String> = self
-                    .masks
-                    .iter()
-                    .filter(|mask_| mask_.starts_with(&configs_mask))
-                    .cloned()
-                    .collect();
-                for mask_ in masks_to_remove {
-                    if let Some(pos) = self.masks.iter().position(|m| m == &mask_) {
-                        self.masks.remove(pos);
-                    }
-                }
-                self.masks.push(configs_mask);
-            } else if mask.starts_with(&configs_mask) && self.masks.contains(&configs_mask) {
-            } else {
-                self.masks.push(mask);
+        self.masks.insert(Mask::new_unchecked(mask));
+    }
+}
+
+impl TryFrom<Value> for Workload {
+    type Error = AnkaiosError;
+
+    /// Creates a [Workload] from a YAML value equivalent to a single entry of the
+    /// workloads of a manifest, i.e. a mapping from the workload name to its fields.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`WorkloadFieldError`](AnkaiosError::WorkloadFieldError) if the value is not a single workload entry or a field is invalid;
+    /// - [`AnkaiosError`]::[`WorkloadBuilderError`](AnkaiosError::WorkloadBuilderError) if the workload could not be built.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid_entry = || {
+            AnkaiosError::WorkloadFieldError(
+                "workload".to_owned(),
+                "Should be a mapping with a single workload name".to_owned(),
+            )
+        };
+        let mapping = value.as_mapping().ok_or_else(invalid_entry)?;
+        let mut entries = mapping.iter();
+        match (entries.next(), entries.next()) {
+            (Some((Value::String(name), Value::Mapping(dict_workload))), None) => {
+                Self::new_from_dict(name.clone(), dict_workload)
             }
+            _ => Err(invalid_entry()),
         }
     }
 }
 
+#[cfg(feature = "proto-types")]
+impl<T: Into<String>> From<(T, ank_base::Workload)> for Workload {
+    /// Creates a [Workload] from its name and its proto. The name is not part of the proto,
+    /// as it is the key of the workload in the desired state.
+    fn from((name, proto): (T, ank_base::Workload)) -> Self {
+        Self::new_from_proto(name, proto)
+    }
+}
+
+#[cfg(feature = "proto-types")]
+impl From<Workload> for ank_base::Workload {
+    fn from(workload: Workload) -> Self {
+        workload.to_proto()
+    }
+}
+
+impl TryFrom<&str> for Workload {
+    type Error = AnkaiosError;
+
+    /// Creates a [Workload] from a YAML string equivalent to a single entry of the
+    /// workloads of a manifest.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`WorkloadFieldError`](AnkaiosError::WorkloadFieldError) if the string is not valid YAML, not a single workload entry or a field is invalid;
+    /// - [`AnkaiosError`]::[`WorkloadBuilderError`](AnkaiosError::WorkloadBuilderError) if the workload could not be built.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let yaml_value: Value = serde_yaml::from_str(value).map_err(|err| {
+            AnkaiosError::WorkloadFieldError("workload".to_owned(), err.to_string())
+        })?;
+        Self::try_from(yaml_value)
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -1191,8 +1449,11 @@ impl Workload {
 
 #[cfg(test)]
 mod tests {
-    use super::Workload;
-    use crate::components::workload_mod::file::File;
+    use super::{AnkaiosError, Workload};
+    use crate::ankaios_api::ank_base;
+    use crate::components::mask::MaskSet;
+    use crate::components::workload_mod::file::{File, FileContent};
+    use crate::components::workload_mod::secret::Secret;
     use crate::components::workload_mod::test_helpers::{
         generate_test_runtime_config, generate_test_workload, generate_test_workload_proto,
     };
@@ -1231,6 +1492,67 @@ mod tests {
         assert_eq!(workload.to_proto(), workload_new.unwrap().to_proto());
     }
 
+    #[test]
+    fn utest_workload_dict_unknown_enum_values() {
+        let mut workload = generate_test_workload("agent_A", "nginx", "podman");
+        workload.workload.restart_policy = Some(42);
+        workload.workload.dependencies = Some(ank_base::Dependencies {
+            dependencies: HashMap::from([("other".to_owned(), 43)]),
+        });
+        workload.workload.control_interface_access = Some(ank_base::ControlInterfaceAccess {
+            allow_rules: vec![ank_base::AccessRightsRule {
+                access_rights_rule_enum: Some(ank_base::AccessRightsRuleEnum::StateRule(
+                    ank_base::StateRule {
+                        operation: 44,
+                        filter_masks: vec!["desiredState".to_owned()],
+                    },
+                )),
+            }],
+            deny_rules: vec![],
+        });
+
+        let workload_dict = workload.to_dict();
+        assert_eq!(workload_dict.get("restartPolicy").unwrap(), "Unknown(42)");
+        assert_eq!(
+            workload.get_dependencies(),
+            HashMap::from([("other".to_owned(), "Unknown(43)".to_owned())])
+        );
+        assert_eq!(
+            workload.get_allow_rules().unwrap(),
+            vec![("Unknown(44)".to_owned(), vec!["desiredState".to_owned()])]
+        );
+
+        let workload_new = Workload::new_from_dict("nginx", &workload_dict).unwrap();
+        assert_eq!(workload.to_proto(), workload_new.to_proto());
+    }
+
+    #[test]
+    fn utest_workload_dict_sorted() {
+        let workload = Workload::builder()
+            .workload_name("nginx")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config("config")
+            .add_tag("tag_c", "value")
+            .add_tag("tag_a", "value")
+            .add_tag("tag_b", "value")
+            .add_config("alias_b", "config")
+            .add_config("alias_a", "config")
+            .build()
+            .unwrap();
+        let workload_dict = workload.to_dict();
+        let tags = workload_dict.get("tags").unwrap().as_mapping().unwrap();
+        assert_eq!(
+            tags.keys().collect::<Vec<_>>(),
+            vec!["tag_a", "tag_b", "tag_c"]
+        );
+        let configs = workload_dict.get("configs").unwrap().as_mapping().unwrap();
+        assert_eq!(
+            configs.keys().collect::<Vec<_>>(),
+            vec!["alias_a", "alias_b"]
+        );
+    }
+
     #[test]
     fn utest_update_fields() {
         let mut wl = generate_test_workload("Agent_A", "Test", "podman");
@@ -1248,6 +1570,24 @@ mod tests {
         wl.update_runtime_config("config_test");
         assert_eq!(wl.workload.runtime_config, Some("config_test".to_owned()));
 
+        assert!(matches!(
+            wl.runtime_config_as::<HashMap<String, String>>(),
+            Err(AnkaiosError::YamlError(_))
+        ));
+        let runtime_config = HashMap::from([("image".to_owned(), "nginx".to_owned())]);
+        wl.set_runtime_config_from(&runtime_config).unwrap();
+        assert_eq!(
+            wl.workload.runtime_config,
+            Some("image: nginx\n".to_owned())
+        );
+        assert_eq!(
+            wl.runtime_config_as::<HashMap<String, String>>().unwrap(),
+            runtime_config
+        );
+        wl.update_runtime_config(Secret::new("image: secret"));
+        wl.set_runtime_config_from(&runtime_config).unwrap();
+        assert!(wl.secret_runtime_config);
+
         assert!(wl.update_restart_policy("NEVER").is_ok());
         assert_eq!(wl.workload.restart_policy, Some(0));
 
@@ -1362,7 +1702,7 @@ mod tests {
             .runtime_config("config")
             .build()
             .unwrap();
-        wl.masks = Vec::default();
+        wl.masks = MaskSet::default();
         wl.add_config("alias_test_1", "config_test_1");
         let mut configs = wl.get_configs();
         assert_eq!(configs.len(), 1);
@@ -1458,8 +1798,10 @@ mod tests {
                 let mut obj = Workload {
                     workload: generate_test_workload_proto("Agent_A".to_owned(), "podman".to_owned()),
                     main_mask: format!("desiredState.workloads.Test"),
-                    masks: vec![],
+                    masks: MaskSet::default(),
                     name: "Test".to_owned(),
+                    secret_runtime_config: false,
+                    secret_files: Vec::new(),
                 };
                 // Call function and assert the mask has been added
                 let _ = obj.$method_name($($args),*);
@@ -1644,4 +1986,142 @@ mod tests {
             "Workload { workload: Workload { agent: Some(\"agent_A\"), restart_policy: None, dependencies: None, tags: None, runtime: Some(\"podman\"), runtime_config: Some(\"config\"), control_interface_access: None, configs: None, files: None }, main_mask: \"desiredState.workloads.Test\", masks: [\"desiredState.workloads.Test\"], name: \"Test\" }"
         );
     }
+
+    #[test]
+    fn utest_display_secret() {
+        let mut wl = Workload::builder()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config(Secret::new("password: 1234"))
+            .add_file(File::from_data("/etc/public.conf", "public"))
+            .add_file(File::from_data("/etc/token", Secret::new("abcd")))
+            .build()
+            .unwrap();
+        let debug = format!("{wl:?}");
+        assert!(!debug.contains("1234"));
+        assert!(!debug.contains("abcd"));
+        assert!(debug.contains("public"));
+        assert_eq!(
+            wl.workload.runtime_config,
+            Some("password: 1234".to_owned())
+        );
+
+        let files = wl.get_files();
+        assert_eq!(files.len(), 2);
+        assert!(matches!(files[0].content, FileContent::Data(_)));
+        assert!(matches!(
+            &files[1].content,
+            FileContent::SecretData(secret) if secret.expose_secret() == "abcd"
+        ));
+
+        wl.update_runtime_config("config");
+        assert!(format!("{wl:?}").contains("config"));
+    }
+
+    #[test]
+    fn utest_reset_masks() {
+        let mut wl =
+            Workload::new_from_proto("Test", generate_test_workload_proto("agent_A", "podman"));
+        assert!(wl.masks.is_empty());
+
+        wl.update_agent_name("agent_B");
+        assert_eq!(wl.masks, vec!["desiredState.workloads.Test.agent"]);
+        wl.reset_masks();
+        assert!(wl.masks.is_empty());
+
+        wl.update_runtime("docker");
+        let full_update = wl.into_full_update();
+        assert_eq!(full_update.masks, vec!["desiredState.workloads.Test"]);
+        assert_eq!(full_update.workload.runtime, Some("docker".to_owned()));
+    }
+
+    #[test]
+    fn utest_encoded_size() {
+        let mut wl = generate_test_workload("agent_A", "Test", "podman");
+        let initial_size = wl.encoded_size();
+        assert!(initial_size > 0);
+
+        wl.add_file(File::from_data("/etc/big.conf", "a".repeat(1024)));
+        assert!(wl.encoded_size() > initial_size + 1024);
+    }
+
+    #[test]
+    fn utest_is_equivalent_to() {
+        let wl = generate_test_workload("agent_A", "Test", "podman");
+        let wl_from_proto = Workload::new_from_proto("Test", wl.workload.clone());
+        assert!(wl.is_equivalent_to(&wl_from_proto));
+
+        let wl_builder = Workload::builder()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config("config")
+            .build()
+            .unwrap();
+        let mut wl_filled = wl_builder.clone();
+        wl_filled.workload.tags = Some(ank_base::Tags::default());
+        wl_filled.workload.restart_policy = Some(ank_base::RestartPolicy::Never as i32);
+        assert!(wl_builder.is_equivalent_to(&wl_filled));
+
+        let mut wl_other_files_order = wl.clone();
+        wl_other_files_order.add_file(File::from_data("a_mount_point", "Data"));
+        let mut wl_files_order = wl.clone();
+        wl_files_order.update_files(vec![
+            File::from_data("a_mount_point", "Data"),
+            File::from_data("mount_point", "Data"),
+        ]);
+        assert!(wl_files_order.is_equivalent_to(&wl_other_files_order));
+
+        let mut wl_changed = wl.clone();
+        wl_changed.update_agent_name("agent_B");
+        assert!(!wl.is_equivalent_to(&wl_changed));
+
+        let mut wl_renamed = wl.clone();
+        wl_renamed.update_workload_name("Other");
+        assert!(!wl.is_equivalent_to(&wl_renamed));
+    }
+
+    #[test]
+    fn utest_workload_try_from_yaml() {
+        let wl = Workload::try_from(
+            "nginx:
+  agent: agent_A
+  runtime: podman
+  restartPolicy: ALWAYS
+  runtimeConfig: |
+    image: docker.io/library/nginx",
+        )
+        .unwrap();
+        assert_eq!(wl.name, "nginx");
+        assert_eq!(wl.main_mask, "desiredState.workloads.nginx");
+        assert_eq!(wl.workload.agent, Some("agent_A".to_owned()));
+        assert_eq!(wl.workload.runtime, Some("podman".to_owned()));
+        assert_eq!(
+            wl.workload.runtime_config,
+            Some("image: docker.io/library/nginx".to_owned())
+        );
+
+        for invalid in [
+            "nginx: [",
+            "- nginx",
+            "nginx: podman",
+            "nginx:\n  runtime: podman\nother:\n  runtime: podman",
+        ] {
+            assert!(matches!(
+                Workload::try_from(invalid),
+                Err(AnkaiosError::WorkloadFieldError(_, _))
+            ));
+        }
+    }
+
+    #[cfg(feature = "proto-types")]
+    #[test]
+    fn utest_proto_conversion() {
+        let wl_proto = generate_test_workload_proto("agent_A".to_owned(), "podman".to_owned());
+        let workload = Workload::from(("Test", wl_proto.clone()));
+        assert_eq!(workload.name, "Test");
+        assert_eq!(workload.main_mask, "desiredState.workloads.Test");
+        assert_eq!(ank_base::Workload::from(workload), wl_proto);
+    }
 }
@@ -14,7 +14,8 @@
 
 use crate::AnkaiosError;
 use crate::Workload;
-use std::{collections::HashMap, path::Path};
+use serde_yaml::{Mapping, Value};
+use std::{collections::HashMap, fmt, path::Path};
 
 // Disable this from coverage
 // https://github.com/rust-lang/rust/issues/84605
@@ -30,6 +31,12 @@ fn read_file_to_string(path: &Path) -> Result<String, io::Error> {
 use crate::components::workload_mod::test_helpers::read_to_string_mock as read_file_to_string;
 
 use super::file::File;
+use super::secret::{MaybeSecret, REDACTED, Secret};
+
+/// The name of the runtime supporting the environment variables of the builder.
+const PODMAN_RUNTIME: &str = "podman";
+/// The key of the podman runtime config containing the options of `podman run`.
+const COMMAND_OPTIONS_KEY: &str = "commandOptions";
 
 /// A builder struct for the [Workload] struct.
 ///
@@ -45,16 +52,17 @@ use super::file::File;
 ///     .agent_name("agent_A")
 ///     .runtime("podman")
 ///     .restart_policy("NEVER")
-///     .runtime_config("image: docker.io/library/nginx\n
+///     .runtime_config("image: docker.io/library/nginx\n\
 ///                      commandOptions: [\"-p\", \"8080:80\"]")
 ///     .add_dependency("other_workload", "ADD_COND_RUNNING")
 ///     .add_tag("key1", "value1")
 ///     .add_tag("key2", "value2")
+///     .add_env("LOG_LEVEL", "debug")
 ///     .add_file(File::from_data("/etc/config.yaml", "debug: true"))
 ///     .build().unwrap();
 /// ```
 #[must_use] // Added to ensure that the returned Self from the methods is used.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct WorkloadBuilder {
     /// The name of the workload.
     pub wl_name: String,
@@ -64,6 +72,8 @@ pub struct WorkloadBuilder {
     pub wl_runtime: String,
     /// The runtime config.
     pub wl_runtime_config: String,
+    /// Whether the runtime config was provided as a [Secret].
+    pub wl_runtime_config_secret: bool,
     /// The restart policy. Allowed values: "`ALWAYS`", "`ON_FAILURE`", "`NEVER`".
     pub wl_restart_policy: Option<String>,
     /// The dependencies. Allowed values: "`ADD_COND_SUCCEEDED`", "`ADD_COND_FAILED`", "`ADD_COND_RUNNING`".
@@ -78,6 +88,34 @@ pub struct WorkloadBuilder {
     pub configs: HashMap<String, String>,
     /// The workload files.
     pub files: Vec<File>,
+    /// The environment variables, added to the podman runtime config when building.
+    pub envs: HashMap<String, String>,
+}
+
+impl fmt::Debug for WorkloadBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let runtime_config = if self.wl_runtime_config_secret {
+            REDACTED
+        } else {
+            self.wl_runtime_config.as_str()
+        };
+        f.debug_struct("WorkloadBuilder")
+            .field("wl_name", &self.wl_name)
+            .field("wl_agent_name", &self.wl_agent_name)
+            .field("wl_runtime", &self.wl_runtime)
+            .field("wl_runtime_config", &runtime_config)
+            .field("wl_runtime_config_secret", &self.wl_runtime_config_secret)
+            .field("wl_restart_policy", &self.wl_restart_policy)
+            .field("dependencies", &self.dependencies)
+            .field("tags", &self.tags)
+            .field("allow_rules", &self.allow_rules)
+            .field("deny_rules", &self.deny_rules)
+            .field("configs", &self.configs)
+            .field("files", &self.files)
+            // The values are not shown, as they might contain credentials
+            .field("envs", &self.envs.keys())
+            .finish()
+    }
 }
 
 impl WorkloadBuilder {
@@ -91,6 +129,64 @@ impl WorkloadBuilder {
         Self::default()
     }
 
+    /// Creates a new [`WorkloadBuilder`] instance pre-populated with all the fields
+    /// of an existing [Workload].
+    ///
+    /// This allows creating a copy of a workload with some changes, e.g. another
+    /// name or agent, without copying each field manually.
+    /// A secret runtime config and secret files stay secret in the builder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload` - The [Workload] to copy the fields from.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`WorkloadBuilder`] instance.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`WorkloadFieldError`](AnkaiosError::WorkloadFieldError) if
+    /// the access rules of the workload contain an unknown operation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ankaios_sdk::{Workload, WorkloadBuilder};
+    ///
+    /// let workload: Workload = WorkloadBuilder::new()
+    ///     .workload_name("nginx")
+    ///     .agent_name("agent_A")
+    ///     .runtime("podman")
+    ///     .runtime_config("image: docker.io/library/nginx")
+    ///     .build().unwrap();
+    ///
+    /// let copy: Workload = WorkloadBuilder::from_workload(&workload).unwrap()
+    ///     .workload_name("nginx_copy")
+    ///     .agent_name("agent_B")
+    ///     .build().unwrap();
+    /// ```
+    pub fn from_workload(workload: &Workload) -> Result<Self, AnkaiosError> {
+        Ok(Self {
+            wl_name: workload.name.clone(),
+            wl_agent_name: workload.workload.agent.clone().unwrap_or_default(),
+            wl_runtime: workload.workload.runtime.clone().unwrap_or_default(),
+            wl_runtime_config: workload.workload.runtime_config.clone().unwrap_or_default(),
+            wl_runtime_config_secret: workload.secret_runtime_config,
+            wl_restart_policy: workload
+                .workload
+                .restart_policy
+                .map(Workload::restart_policy_to_str),
+            dependencies: workload.get_dependencies(),
+            tags: workload.get_tags(),
+            allow_rules: workload.get_allow_rules()?,
+            deny_rules: workload.get_deny_rules()?,
+            configs: workload.get_configs(),
+            files: workload.get_files(),
+            envs: HashMap::new(),
+        })
+    }
+
     /// Sets the name of the workload.
     ///
     /// ## Arguments
@@ -137,13 +233,15 @@ impl WorkloadBuilder {
     ///
     /// ## Arguments
     ///
-    /// * `runtime_config` - A [String] that represents the runtime config.
+    /// * `runtime_config` - A [String] or a [Secret] that represents the runtime config.
     ///
     /// ## Returns
     ///
     /// The [`WorkloadBuilder`] instance.
-    pub fn runtime_config<T: Into<String>>(mut self, runtime_config: T) -> Self {
-        self.wl_runtime_config = runtime_config.into();
+    pub fn runtime_config<T: MaybeSecret>(mut self, runtime_config: T) -> Self {
+        let (content, is_secret) = runtime_config.into_content();
+        self.wl_runtime_config = content;
+        self.wl_runtime_config_secret = is_secret;
         self
     }
 
@@ -278,6 +376,82 @@ impl WorkloadBuilder {
         self
     }
 
+    /// Adds an environment variable.
+    ///
+    /// As workloads have no environment variables in [Ankaios](https://eclipse-ankaios.github.io/ankaios),
+    /// they are added to the `commandOptions` of the runtime config when building,
+    /// which is only supported for the `podman` runtime.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` - A [String] that represents the name of the environment variable;
+    /// * `value` - A [String] that represents the value of the environment variable.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadBuilder`] instance.
+    pub fn add_env<T: Into<String>>(mut self, key: T, value: T) -> Self {
+        self.envs.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds several environment variables, see [`WorkloadBuilder::add_env`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `envs` - The names and values of the environment variables.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadBuilder`] instance.
+    pub fn envs<T: Into<String>, I: IntoIterator<Item = (T, T)>>(mut self, envs: I) -> Self {
+        self.envs.extend(
+            envs.into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+        self
+    }
+
+    /// Adds the environment variables to the `commandOptions` of the podman runtime config.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] containing the runtime config with the environment variables.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AnkaiosError`]::[`WorkloadBuilderError`](AnkaiosError::WorkloadBuilderError) if the runtime
+    /// is not podman or the runtime config has not the expected format.
+    fn runtime_config_with_envs(&self) -> Result<String, AnkaiosError> {
+        if self.wl_runtime != PODMAN_RUNTIME {
+            return Err(AnkaiosError::WorkloadBuilderError(
+                "Environment variables are only supported for the podman runtime.",
+            ));
+        }
+        let mut runtime_config: Mapping =
+            serde_yaml::from_str(&self.wl_runtime_config).map_err(|_| {
+                AnkaiosError::WorkloadBuilderError("The runtime config is not a YAML mapping.")
+            })?;
+        let Value::Sequence(command_options) = runtime_config
+            .entry(Value::String(COMMAND_OPTIONS_KEY.to_owned()))
+            .or_insert_with(|| Value::Sequence(Vec::new()))
+        else {
+            return Err(AnkaiosError::WorkloadBuilderError(
+                "The commandOptions of the runtime config are not a list.",
+            ));
+        };
+        let mut sorted_envs: Vec<(&String, &String)> = self.envs.iter().collect();
+        sorted_envs.sort();
+        command_options.extend(
+            sorted_envs
+                .into_iter()
+                .map(|(key, value)| Value::String(format!("--env={key}={value}"))),
+        );
+        serde_yaml::to_string(&runtime_config).map_err(|_| {
+            AnkaiosError::WorkloadBuilderError("The runtime config could not be serialized.")
+        })
+    }
+
     /// Creates a new `Workload` instance from a Map.
     ///
     /// # Arguments
@@ -316,9 +490,19 @@ impl WorkloadBuilder {
             ));
         }
 
+        let runtime_config = if self.envs.is_empty() {
+            self.wl_runtime_config.clone()
+        } else {
+            self.runtime_config_with_envs()?
+        };
+
         wl.update_agent_name(self.wl_agent_name.clone());
         wl.update_runtime(self.wl_runtime.clone());
-        wl.update_runtime_config(self.wl_runtime_config.clone());
+        if self.wl_runtime_config_secret {
+            wl.update_runtime_config(Secret::new(runtime_config));
+        } else {
+            wl.update_runtime_config(runtime_config);
+        }
 
         if let Some(restart_policy) = self.wl_restart_policy.clone() {
             wl.update_restart_policy(restart_policy)?;
@@ -356,9 +540,10 @@ impl WorkloadBuilder {
 
 #[cfg(test)]
 mod tests {
-    use super::Workload;
+    use super::{Workload, WorkloadBuilder};
     use crate::AnkaiosError;
     use crate::components::workload_mod::file::File;
+    use crate::components::workload_mod::secret::Secret;
     use crate::components::workload_mod::test_helpers::{
         generate_test_runtime_config, generate_test_workload_proto,
     };
@@ -392,6 +577,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn utest_workload_builder_from_workload() {
+        let wl = Workload::builder()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config(Secret::new("image: alpine:latest"))
+            .restart_policy("ALWAYS")
+            .add_dependency("workload_A", "ADD_COND_SUCCEEDED")
+            .add_tag("key_test", "val_test")
+            .add_allow_rule("Read", vec!["desiredState.workloads.workload_A".to_owned()])
+            .add_deny_rule(
+                "Write",
+                vec!["desiredState.workloads.workload_B".to_owned()],
+            )
+            .add_config("alias_test", "config_1")
+            .add_file(File::from_data("mount_point", Secret::new("password")))
+            .build()
+            .unwrap();
+
+        let copy = WorkloadBuilder::from_workload(&wl)
+            .unwrap()
+            .workload_name("Copy")
+            .agent_name("agent_B")
+            .build()
+            .unwrap();
+
+        assert_eq!(copy.name, "Copy");
+        assert!(copy.secret_runtime_config);
+        assert!(!format!("{copy:?}").contains("password"));
+        assert!(!format!("{copy:?}").contains("alpine"));
+
+        let mut expected = wl.workload.clone();
+        expected.agent = Some("agent_B".to_owned());
+        assert_eq!(copy.to_proto(), expected);
+    }
+
     #[test]
     fn utest_build_return_err() {
         // No workload name
@@ -438,4 +660,89 @@ mod tests {
             AnkaiosError::WorkloadBuilderError(msg) if msg == "Workload can not be built without a runtime config."
         ));
     }
+
+    #[test]
+    fn utest_workload_builder_secret_runtime_config() {
+        let builder = WorkloadBuilder::new()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config(Secret::new("password: 1234"));
+        assert!(builder.wl_runtime_config_secret);
+        assert!(!format!("{builder:?}").contains("1234"));
+
+        let wl = builder.build().unwrap();
+        assert!(wl.secret_runtime_config);
+        assert_eq!(
+            wl.workload.runtime_config,
+            Some("password: 1234".to_owned())
+        );
+    }
+
+    #[test]
+    fn utest_workload_builder_envs() {
+        let builder = WorkloadBuilder::new()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config("image: alpine:latest\ncommandOptions: [\"--network=host\"]")
+            .add_env("B_KEY", "b_value")
+            .envs([("A_KEY", "a_value")]);
+        assert!(!format!("{builder:?}").contains("a_value"));
+
+        let wl = builder.build().unwrap();
+        let runtime_config: serde_yaml::Value =
+            serde_yaml::from_str(&wl.workload.runtime_config.unwrap()).unwrap();
+        assert_eq!(runtime_config["image"], "alpine:latest");
+        assert_eq!(
+            runtime_config["commandOptions"],
+            serde_yaml::from_str::<serde_yaml::Value>(
+                "[\"--network=host\", \"--env=A_KEY=a_value\", \"--env=B_KEY=b_value\"]"
+            )
+            .unwrap()
+        );
+
+        // The command options are created if missing
+        let wl = WorkloadBuilder::new()
+            .workload_name("Test")
+            .agent_name("agent_A")
+            .runtime("podman")
+            .runtime_config("image: alpine:latest")
+            .add_env("KEY", "value")
+            .build()
+            .unwrap();
+        assert!(
+            wl.workload
+                .runtime_config
+                .unwrap()
+                .contains("commandOptions:\n- --env=KEY=value")
+        );
+    }
+
+    #[test]
+    fn utest_workload_builder_envs_err() {
+        let build_with_env = |runtime: &str, runtime_config: &str| {
+            WorkloadBuilder::new()
+                .workload_name("Test")
+                .agent_name("agent_A")
+                .runtime(runtime)
+                .runtime_config(runtime_config)
+                .add_env("KEY", "value")
+                .build()
+                .unwrap_err()
+        };
+
+        assert!(matches!(
+            build_with_env("podman-kube", "manifest: {}"),
+            AnkaiosError::WorkloadBuilderError(msg) if msg == "Environment variables are only supported for the podman runtime."
+        ));
+        assert!(matches!(
+            build_with_env("podman", "not a mapping"),
+            AnkaiosError::WorkloadBuilderError(msg) if msg == "The runtime config is not a YAML mapping."
+        ));
+        assert!(matches!(
+            build_with_env("podman", "commandOptions: \"-p 80:80\""),
+            AnkaiosError::WorkloadBuilderError(msg) if msg == "The commandOptions of the runtime config are not a list."
+        ));
+    }
 }
@@ -0,0 +1,213 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`ClusterSummary`] and [`WorkloadStateCounts`] structs,
+//! which aggregate the workload states of the cluster.
+
+use std::collections::HashMap;
+
+use super::{WorkloadExecutionState, WorkloadInstanceName, WorkloadStateEnum};
+use crate::CompleteState;
+
+/// Struct counting the workload instances by state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkloadStateCounts {
+    /// The number of running workload instances.
+    pub running: usize,
+    /// The number of pending workload instances.
+    pub pending: usize,
+    /// The number of failed workload instances.
+    pub failed: usize,
+    /// The number of succeeded workload instances.
+    pub succeeded: usize,
+    /// The number of workload instances in any other state, e.g. stopping.
+    pub other: usize,
+}
+
+impl WorkloadStateCounts {
+    /// Returns the number of counted workload instances.
+    ///
+    /// ## Returns
+    ///
+    /// The sum of all the counts.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.running + self.pending + self.failed + self.succeeded + self.other
+    }
+
+    /// Counts a workload instance in the given state.
+    ///
+    /// ## Arguments
+    ///
+    /// * `state` - The [`WorkloadStateEnum`] of the workload instance.
+    fn count(&mut self, state: WorkloadStateEnum) {
+        match state {
+            WorkloadStateEnum::Running => self.running += 1,
+            WorkloadStateEnum::Pending => self.pending += 1,
+            WorkloadStateEnum::Failed => self.failed += 1,
+            WorkloadStateEnum::Succeeded => self.succeeded += 1,
+            _ => self.other += 1,
+        }
+    }
+}
+
+/// Struct summarizing the workload states of the cluster, as needed by dashboards
+/// and health endpoints.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClusterSummary {
+    /// The counts of all the workload instances.
+    pub totals: WorkloadStateCounts,
+    /// The counts of the workload instances, by agent name. Connected agents
+    /// without workloads are included with zero counts.
+    pub agents: HashMap<String, WorkloadStateCounts>,
+    /// The instance names of the workloads that failed or whose start or deletion failed,
+    /// sorted by agent, workload name and workload id.
+    pub failing_instances: Vec<WorkloadInstanceName>,
+}
+
+impl ClusterSummary {
+    #[doc(hidden)]
+    /// Creates a new `ClusterSummary` from a [`CompleteState`] containing the agents
+    /// and the workload states.
+    ///
+    /// ## Arguments
+    ///
+    /// * `complete_state` - The [`CompleteState`] to summarize.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`ClusterSummary`] instance.
+    pub(crate) fn new(complete_state: &CompleteState) -> Self {
+        let mut summary = Self {
+            agents: complete_state
+                .get_agents()
+                .into_keys()
+                .map(|agent_name| (agent_name, WorkloadStateCounts::default()))
+                .collect(),
+            ..Default::default()
+        };
+        for workload_state in complete_state.get_workload_states().as_list() {
+            summary.count(
+                workload_state.workload_instance_name,
+                &workload_state.execution_state,
+            );
+        }
+        summary.failing_instances.sort_by(|first, second| {
+            (&first.agent_name, &first.workload_name, &first.workload_id).cmp(&(
+                &second.agent_name,
+                &second.workload_name,
+                &second.workload_id,
+            ))
+        });
+        summary
+    }
+
+    /// Counts a workload instance.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload instance;
+    /// * `execution_state` - The [`WorkloadExecutionState`] of the workload instance.
+    fn count(
+        &mut self,
+        instance_name: WorkloadInstanceName,
+        execution_state: &WorkloadExecutionState,
+    ) {
+        self.totals.count(execution_state.state);
+        self.agents
+            .entry(instance_name.agent_name.clone())
+            .or_default()
+            .count(execution_state.state);
+        if execution_state.is_failure() {
+            self.failing_instances.push(instance_name);
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ClusterSummary, WorkloadStateCounts};
+    use crate::ankaios_api::ank_base;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::{CompleteState, WorkloadInstanceName};
+
+    #[test]
+    fn utest_cluster_summary() {
+        let mut proto = generate_complete_state_proto();
+        if let Some(agent_states) = proto
+            .workload_states
+            .as_mut()
+            .and_then(|states| states.agent_state_map.get_mut("agent_B"))
+        {
+            agent_states.wl_name_state_map.insert(
+                "broken".to_owned(),
+                ank_base::ExecutionsStatesForId {
+                    id_state_map: [(
+                        "1111".to_owned(),
+                        ank_base::ExecutionState {
+                            execution_state_enum: Some(ank_base::ExecutionStateEnum::Failed(
+                                ank_base::Failed::ExecFailed as i32,
+                            )),
+                            additional_info: None,
+                        },
+                    )]
+                    .into(),
+                },
+            );
+        }
+        let summary = ClusterSummary::new(&CompleteState::new_from_proto(proto));
+
+        assert_eq!(
+            summary.totals,
+            WorkloadStateCounts {
+                pending: 1,
+                failed: 1,
+                succeeded: 1,
+                other: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(summary.totals.total(), 4);
+        assert_eq!(summary.agents.len(), 2);
+        assert_eq!(summary.agents["agent_A"].succeeded, 1);
+        assert_eq!(summary.agents["agent_B"].total(), 3);
+        assert_eq!(
+            summary.failing_instances,
+            vec![WorkloadInstanceName::new(
+                "agent_B".to_owned(),
+                "broken".to_owned(),
+                "1111".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn utest_cluster_summary_agent_without_workloads() {
+        let mut proto = generate_complete_state_proto();
+        proto.workload_states = None;
+        let summary = ClusterSummary::new(&CompleteState::new_from_proto(proto));
+
+        assert_eq!(summary.totals.total(), 0);
+        assert_eq!(summary.agents["agent_A"], WorkloadStateCounts::default());
+        assert!(summary.failing_instances.is_empty());
+    }
+}
@@ -55,17 +55,23 @@
 //! let instance_name_dict = workload_instance_name.to_dict();
 //! ```
 
+mod cluster_summary;
+mod workload_diagnosis;
 mod workload_execution_state;
 mod workload_instance_name;
 mod workload_state;
 mod workload_state_enums;
+mod workload_states_watch;
 
-#[allow(unused)]
-pub use workload_execution_state::WorkloadExecutionState;
+pub use cluster_summary::{ClusterSummary, WorkloadStateCounts};
+pub use workload_diagnosis::{WorkloadDiagnosis, WorkloadIssue};
+pub use workload_execution_state::{ProcessOutcome, WorkloadExecutionState};
 pub use workload_instance_name::WorkloadInstanceName;
 pub use workload_state::{WorkloadState, WorkloadStateCollection};
-#[allow(unused)]
 pub use workload_state_enums::{WorkloadStateEnum, WorkloadSubStateEnum};
+pub(crate) use workload_state_enums::{unknown_enum_value_from_str, unknown_enum_value_to_str};
+pub(crate) use workload_states_watch::coalesce_workload_states;
+pub use workload_states_watch::{WorkloadStatesFilter, WorkloadStatesWatch};
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub use workload_state::generate_test_workload_states_proto;
@@ -0,0 +1,249 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`WorkloadDiagnosis`] struct and the [`WorkloadIssue`] enum,
+//! which are used to explain why a workload is not running.
+
+use std::collections::HashMap;
+
+use super::{WorkloadExecutionState, WorkloadInstanceName, WorkloadStateEnum};
+use crate::CompleteState;
+
+/// Condition of a dependency requiring the workload to be running.
+const ADD_COND_RUNNING: &str = "ADD_COND_RUNNING";
+/// Condition of a dependency requiring the workload to have succeeded.
+const ADD_COND_SUCCEEDED: &str = "ADD_COND_SUCCEEDED";
+/// Condition of a dependency requiring the workload to have failed.
+const ADD_COND_FAILED: &str = "ADD_COND_FAILED";
+
+/// Enum representing a reason why a workload is not running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkloadIssue {
+    /// The agent of the workload is not connected. Contains the name of the agent.
+    AgentNotConnected(String),
+    /// A dependency of the workload is not fulfilled.
+    UnsatisfiedDependency {
+        /// The name of the workload the workload depends on.
+        workload_name: String,
+        /// The condition the dependency has to fulfill, e.g. "`ADD_COND_RUNNING`".
+        condition: String,
+        /// The current state of the dependency, if it has a state.
+        state: Option<WorkloadStateEnum>,
+    },
+    /// The runtime failed to start, run or delete the workload. Contains the additional info of the state.
+    RuntimeFailure(String),
+}
+
+/// Struct gathering the information needed to understand why a workload is not running.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkloadDiagnosis {
+    /// The instance name of the diagnosed workload.
+    pub instance_name: WorkloadInstanceName,
+    /// The execution state of the workload, if it has a state.
+    pub execution_state: Option<WorkloadExecutionState>,
+    /// Whether the agent of the workload is connected.
+    pub agent_connected: bool,
+    /// The execution states of the dependencies of the workload, by workload name.
+    pub dependencies: HashMap<String, Option<WorkloadExecutionState>>,
+    /// The issues found that explain why the workload is not running.
+    pub issues: Vec<WorkloadIssue>,
+}
+
+impl WorkloadDiagnosis {
+    #[doc(hidden)]
+    /// Creates a new `WorkloadDiagnosis` from a [`CompleteState`] containing the desired
+    /// state of the workload, the agents and the workload states.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload to diagnose;
+    /// * `complete_state` - The [`CompleteState`] to gather the information from.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`WorkloadDiagnosis`] instance.
+    pub(crate) fn new(instance_name: WorkloadInstanceName, complete_state: &CompleteState) -> Self {
+        let workload_states = complete_state.get_workload_states();
+        let execution_state = workload_states
+            .get_for_instance_name(&instance_name)
+            .cloned();
+        let agent_connected = complete_state
+            .get_agents()
+            .contains_key(&instance_name.agent_name);
+
+        let mut issues = Vec::new();
+        if !agent_connected {
+            issues.push(WorkloadIssue::AgentNotConnected(
+                instance_name.agent_name.clone(),
+            ));
+        }
+
+        let dependency_conditions = complete_state
+            .get_workloads()
+            .into_iter()
+            .find(|workload| workload.name == instance_name.workload_name)
+            .map(|workload| workload.get_dependencies())
+            .unwrap_or_default();
+        let all_states = workload_states.as_list();
+        let mut dependencies = HashMap::new();
+        for (workload_name, condition) in dependency_conditions {
+            let dependency_state = all_states
+                .iter()
+                .find(|state| state.workload_instance_name.workload_name == workload_name)
+                .map(|state| state.execution_state.clone());
+            let expected_state = match condition.as_str() {
+                ADD_COND_RUNNING => Some(WorkloadStateEnum::Running),
+                ADD_COND_SUCCEEDED => Some(WorkloadStateEnum::Succeeded),
+                ADD_COND_FAILED => Some(WorkloadStateEnum::Failed),
+                _ => None,
+            };
+            let current_state = dependency_state.as_ref().map(|state| state.state);
+            if expected_state.is_some() && current_state != expected_state {
+                issues.push(WorkloadIssue::UnsatisfiedDependency {
+                    workload_name: workload_name.clone(),
+                    condition,
+                    state: current_state,
+                });
+            }
+            dependencies.insert(workload_name, dependency_state);
+        }
+
+        if let Some(state) = execution_state.as_ref() {
+            if state.is_failure() {
+                issues.push(WorkloadIssue::RuntimeFailure(state.additional_info.clone()));
+            }
+        }
+
+        Self {
+            instance_name,
+            execution_state,
+            agent_connected,
+            dependencies,
+            issues,
+        }
+    }
+
+    /// Checks whether the workload is running.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload is in the [`Running`](WorkloadStateEnum::Running) state, `false` otherwise.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.execution_state
+            .as_ref()
+            .is_some_and(|state| state.state == WorkloadStateEnum::Running)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{WorkloadDiagnosis, WorkloadIssue};
+    use crate::ankaios_api::ank_base;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::components::workload_mod::test_helpers::generate_test_workload_proto;
+    use crate::{CompleteState, WorkloadInstanceName, WorkloadStateEnum};
+
+    #[test]
+    fn utest_workload_diagnosis() {
+        let mut proto = generate_complete_state_proto();
+        if let Some(workloads) = proto
+            .desired_state
+            .as_mut()
+            .and_then(|state| state.workloads.as_mut())
+        {
+            workloads.workloads.insert(
+                "nginx".to_owned(),
+                generate_test_workload_proto("agent_B", "podman"),
+            );
+        }
+        let complete_state = CompleteState::new_from_proto(proto);
+
+        let diagnosis = WorkloadDiagnosis::new(
+            WorkloadInstanceName::new("agent_B".to_owned(), "nginx".to_owned(), "5678".to_owned()),
+            &complete_state,
+        );
+        assert!(!diagnosis.is_running());
+        assert!(!diagnosis.agent_connected);
+        assert_eq!(
+            diagnosis.execution_state.unwrap().state,
+            WorkloadStateEnum::Pending
+        );
+        assert_eq!(diagnosis.dependencies.len(), 2);
+        assert_eq!(diagnosis.issues.len(), 3);
+        assert_eq!(
+            diagnosis.issues[0],
+            WorkloadIssue::AgentNotConnected("agent_B".to_owned())
+        );
+        assert!(
+            diagnosis
+                .issues
+                .contains(&WorkloadIssue::UnsatisfiedDependency {
+                    workload_name: "workload_A".to_owned(),
+                    condition: "ADD_COND_SUCCEEDED".to_owned(),
+                    state: None,
+                })
+        );
+    }
+
+    #[test]
+    fn utest_workload_diagnosis_runtime_failure() {
+        let mut proto = generate_complete_state_proto();
+        if let Some(agent_states) = proto
+            .workload_states
+            .as_mut()
+            .and_then(|states| states.agent_state_map.get_mut("agent_A"))
+        {
+            agent_states.wl_name_state_map.insert(
+                "nginx_test".to_owned(),
+                ank_base::ExecutionsStatesForId {
+                    id_state_map: [(
+                        "1111".to_owned(),
+                        ank_base::ExecutionState {
+                            execution_state_enum: Some(ank_base::ExecutionStateEnum::Failed(
+                                ank_base::Failed::ExecFailed as i32,
+                            )),
+                            additional_info: Some("image not found".to_owned()),
+                        },
+                    )]
+                    .into(),
+                },
+            );
+        }
+        let complete_state = CompleteState::new_from_proto(proto);
+
+        let diagnosis = WorkloadDiagnosis::new(
+            WorkloadInstanceName::new(
+                "agent_A".to_owned(),
+                "nginx_test".to_owned(),
+                "1111".to_owned(),
+            ),
+            &complete_state,
+        );
+        assert!(diagnosis.agent_connected);
+        assert!(
+            diagnosis
+                .issues
+                .contains(&WorkloadIssue::RuntimeFailure("image not found".to_owned()))
+        );
+    }
+}
@@ -13,6 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use serde_yaml::Value;
+use std::process::ExitCode;
 
 use super::workload_state_enums::{WorkloadStateEnum, WorkloadSubStateEnum};
 use crate::ankaios_api;
@@ -29,6 +30,60 @@ pub struct WorkloadExecutionState {
     pub additional_info: String,
 }
 
+/// The keyword preceding the exit code in the additional info of a failed workload.
+const EXIT_CODE_KEYWORD: &str = "exit code";
+
+/// The outcome of a finished workload, with the semantics of a process exit status.
+///
+/// Returned by [`WorkloadExecutionState::as_process_outcome`] and convertible into an
+/// [`ExitCode`], so that the result of a batch workload can be returned from `main`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessOutcome {
+    /// Whether the workload succeeded.
+    success: bool,
+    /// The exit code of the workload, if known.
+    code: Option<i32>,
+}
+
+impl ProcessOutcome {
+    /// Checks whether the workload succeeded, like [`ExitStatus::success`](std::process::ExitStatus::success).
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload succeeded.
+    #[must_use]
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the exit code of the workload, like [`ExitStatus::code`](std::process::ExitStatus::code).
+    ///
+    /// ## Returns
+    ///
+    /// The exit code, `0` if the workload succeeded, [None] if the workload failed without
+    /// reporting an exit code, e.g. because it could not be started.
+    #[must_use]
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+}
+
+impl From<ProcessOutcome> for ExitCode {
+    /// Converts the outcome into the exit code of the current process. A failure without
+    /// exit code, or with an exit code that doesn't fit into the process exit code, is
+    /// converted into [`ExitCode::FAILURE`].
+    fn from(outcome: ProcessOutcome) -> Self {
+        if outcome.success {
+            return ExitCode::SUCCESS;
+        }
+        outcome
+            .code
+            .and_then(|code| u8::try_from(code).ok())
+            .filter(|code| *code != 0)
+            .map_or(ExitCode::FAILURE, ExitCode::from)
+    }
+}
+
 impl WorkloadExecutionState {
     #[doc(hidden)]
     /// Creates a new `WorkloadExecutionState`` from an [ExecutionState](ank_base::ExecutionState).
@@ -80,6 +135,57 @@ impl WorkloadExecutionState {
         map
     }
 
+    /// Checks whether the runtime failed to start, run or delete the workload.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload failed or its start or deletion failed, `false` otherwise.
+    pub(crate) fn is_failure(&self) -> bool {
+        self.state == WorkloadStateEnum::Failed
+            || self.substate == WorkloadSubStateEnum::PendingStartingFailed
+            || self.substate == WorkloadSubStateEnum::StoppingDeleteFailed
+    }
+
+    /// Maps a finished workload to the outcome of a process.
+    ///
+    /// A succeeded workload has the exit code `0`. The exit code of a failed workload is
+    /// read from the additional info, e.g. `Exit code: '3'` as reported by the runtimes.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ProcessOutcome`] of the workload, [None] if the workload has not
+    /// [succeeded](WorkloadStateEnum::Succeeded) or [failed](WorkloadStateEnum::Failed) (yet).
+    #[must_use]
+    pub fn as_process_outcome(&self) -> Option<ProcessOutcome> {
+        match self.state {
+            WorkloadStateEnum::Succeeded => Some(ProcessOutcome {
+                success: true,
+                code: Some(0),
+            }),
+            WorkloadStateEnum::Failed => Some(ProcessOutcome {
+                success: false,
+                code: self.exit_code(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Reads the exit code from the additional info.
+    ///
+    /// ## Returns
+    ///
+    /// The number directly following the exit code keyword and its punctuation,
+    /// [None] if there is none.
+    fn exit_code(&self) -> Option<i32> {
+        let info = self.additional_info.to_lowercase();
+        let digits = info[info.find(EXIT_CODE_KEYWORD)? + EXIT_CODE_KEYWORD.len()..]
+            .trim_start_matches(|c: char| !c.is_ascii_alphanumeric());
+        let end = digits
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+        digits[..end].parse().ok()
+    }
+
     #[doc(hidden)]
     /// Helper function to parse the state and substate from the [`ExecutionStateEnum`](ank_base::ExecutionStateEnum).
     ///
@@ -108,15 +214,51 @@ impl WorkloadExecutionState {
             }
             ank_base::ExecutionStateEnum::Removed(value) => (WorkloadStateEnum::Removed, value),
         };
-        // WorkloadSubStateEnum::new can fail, but in the current context, if the SDK is compatible
-        // with Ankaios, it should never fail.
+        // WorkloadSubStateEnum::new fails only for a substate added in a newer Ankaios version,
+        // which is kept with its raw value instead of being dropped.
         (
             state,
-            WorkloadSubStateEnum::new(state, value).unwrap_or_else(|_| unreachable!()),
+            WorkloadSubStateEnum::new(state, value).unwrap_or(WorkloadSubStateEnum::Unknown(value)),
         )
     }
 }
 
+#[cfg(feature = "proto-types")]
+impl From<ank_base::ExecutionState> for WorkloadExecutionState {
+    fn from(proto: ank_base::ExecutionState) -> Self {
+        Self::new(proto)
+    }
+}
+
+#[cfg(feature = "proto-types")]
+impl From<WorkloadExecutionState> for ank_base::ExecutionState {
+    /// Converts the execution state into its proto. A state unknown to the SDK can't be
+    /// represented and is left unset, which is read back as [`WorkloadStateEnum::NotScheduled`].
+    fn from(execution_state: WorkloadExecutionState) -> Self {
+        let substate = execution_state.substate.to_i32();
+        let execution_state_enum = match execution_state.state {
+            WorkloadStateEnum::AgentDisconnected => {
+                Some(ank_base::ExecutionStateEnum::AgentDisconnected(substate))
+            }
+            WorkloadStateEnum::Pending => Some(ank_base::ExecutionStateEnum::Pending(substate)),
+            WorkloadStateEnum::Running => Some(ank_base::ExecutionStateEnum::Running(substate)),
+            WorkloadStateEnum::Stopping => Some(ank_base::ExecutionStateEnum::Stopping(substate)),
+            WorkloadStateEnum::Succeeded => Some(ank_base::ExecutionStateEnum::Succeeded(substate)),
+            WorkloadStateEnum::Failed => Some(ank_base::ExecutionStateEnum::Failed(substate)),
+            WorkloadStateEnum::NotScheduled => {
+                Some(ank_base::ExecutionStateEnum::NotScheduled(substate))
+            }
+            WorkloadStateEnum::Removed => Some(ank_base::ExecutionStateEnum::Removed(substate)),
+            WorkloadStateEnum::Unknown(_) => None,
+        };
+        ank_base::ExecutionState {
+            execution_state_enum,
+            additional_info: (!execution_state.additional_info.is_empty())
+                .then_some(execution_state.additional_info),
+        }
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -128,8 +270,9 @@ impl WorkloadExecutionState {
 #[cfg(test)]
 mod tests {
     use super::ank_base;
-    use super::{WorkloadExecutionState, WorkloadStateEnum, WorkloadSubStateEnum};
+    use super::{ProcessOutcome, WorkloadExecutionState, WorkloadStateEnum, WorkloadSubStateEnum};
     use serde_yaml::Value;
+    use std::process::ExitCode;
 
     #[test]
     fn utest_default_functionality() {
@@ -230,4 +373,97 @@ mod tests {
         Removed,
         ank_base::ExecutionStateEnum::Removed(ank_base::Removed::Removed as i32)
     );
+
+    #[test]
+    fn utest_as_process_outcome() {
+        let exec_state = |execution_state_enum, additional_info: &str| {
+            WorkloadExecutionState::new(ank_base::ExecutionState {
+                execution_state_enum: Some(execution_state_enum),
+                additional_info: Some(additional_info.to_owned()),
+            })
+        };
+
+        let succeeded = exec_state(
+            ank_base::ExecutionStateEnum::Succeeded(ank_base::Succeeded::Ok as i32),
+            "",
+        )
+        .as_process_outcome()
+        .unwrap();
+        assert!(succeeded.success());
+        assert_eq!(succeeded.code(), Some(0));
+        assert_eq!(ExitCode::from(succeeded), ExitCode::SUCCESS);
+
+        let failed = exec_state(
+            ank_base::ExecutionStateEnum::Failed(ank_base::Failed::ExecFailed as i32),
+            "Exit code: '3'",
+        )
+        .as_process_outcome()
+        .unwrap();
+        assert!(!failed.success());
+        assert_eq!(failed.code(), Some(3));
+        assert_eq!(ExitCode::from(failed), ExitCode::from(3));
+
+        let lost = exec_state(
+            ank_base::ExecutionStateEnum::Failed(ank_base::Failed::Lost as i32),
+            "The workload was lost",
+        )
+        .as_process_outcome()
+        .unwrap();
+        assert_eq!(lost.code(), None);
+        assert_eq!(ExitCode::from(lost), ExitCode::FAILURE);
+        assert_eq!(
+            ExitCode::from(ProcessOutcome {
+                success: false,
+                code: Some(256),
+            }),
+            ExitCode::FAILURE
+        );
+
+        assert!(
+            exec_state(
+                ank_base::ExecutionStateEnum::Running(ank_base::Running::Ok as i32),
+                "",
+            )
+            .as_process_outcome()
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn utest_unknown_substate() {
+        let exec_state = WorkloadExecutionState::new(ank_base::ExecutionState {
+            execution_state_enum: Some(ank_base::ExecutionStateEnum::Running(42)),
+            additional_info: None,
+        });
+        assert_eq!(exec_state.state, WorkloadStateEnum::Running);
+        assert_eq!(exec_state.substate, WorkloadSubStateEnum::Unknown(42));
+        assert_eq!(
+            exec_state.to_dict().get("substate"),
+            Some(&Value::String("Unknown(42)".to_owned()))
+        );
+    }
+
+    #[cfg(feature = "proto-types")]
+    #[test]
+    fn utest_proto_conversion() {
+        let proto = ank_base::ExecutionState {
+            execution_state_enum: Some(ank_base::ExecutionStateEnum::Failed(
+                ank_base::Failed::ExecFailed as i32,
+            )),
+            additional_info: Some("Exit code: '3'".to_owned()),
+        };
+        let execution_state = WorkloadExecutionState::from(proto.clone());
+        assert_eq!(execution_state.state, WorkloadStateEnum::Failed);
+        assert_eq!(ank_base::ExecutionState::from(execution_state), proto);
+
+        let unknown = WorkloadExecutionState {
+            state: WorkloadStateEnum::Unknown(42),
+            substate: WorkloadSubStateEnum::Unknown(1),
+            additional_info: String::new(),
+        };
+        assert_eq!(
+            ank_base::ExecutionState::from(unknown),
+            ank_base::ExecutionState::default()
+        );
+    }
 }
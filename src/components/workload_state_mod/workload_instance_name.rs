@@ -15,7 +15,9 @@
 use serde_yaml::Value;
 use std::fmt;
 
+use crate::ankaios::WORKLOAD_STATES_PREFIX;
 use crate::ankaios_api;
+use crate::components::workload_mod::WORKLOADS_PREFIX;
 
 /// Helper struct that contains information about a Workload instance.
 ///
@@ -32,7 +34,7 @@ use crate::ankaios_api;
 ///     "1234".to_owned()
 /// );
 /// ```
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WorkloadInstanceName {
     /// The name of the agent.
     pub agent_name: String,
@@ -90,18 +92,41 @@ impl WorkloadInstanceName {
         map
     }
 
-    /// Returns the filter mask of the Workload Instance Name.
+    /// Returns the filter mask of the workload state of the Workload Instance Name.
+    /// Kept for compatibility, it is the same as [`state_mask`](WorkloadInstanceName::state_mask).
     ///
     /// ## Returns
     ///
     /// A [String] that represents the filter mask.
     #[must_use]
     pub fn get_filter_mask(&self) -> String {
+        self.state_mask()
+    }
+
+    /// Returns the field mask of the workload state of this instance,
+    /// selecting it by agent, workload name and workload id.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] that represents the field mask.
+    #[must_use]
+    pub fn state_mask(&self) -> String {
         format!(
-            "workloadStates.{}.{}.{}",
+            "{WORKLOAD_STATES_PREFIX}.{}.{}.{}",
             self.agent_name, self.workload_name, self.workload_id
         )
     }
+
+    /// Returns the field mask of the workload of this instance in the desired state.
+    /// The desired state is not specific to an instance, so only the workload name is used.
+    ///
+    /// ## Returns
+    ///
+    /// A [String] that represents the field mask.
+    #[must_use]
+    pub fn desired_state_mask(&self) -> String {
+        format!("{WORKLOADS_PREFIX}.{}", self.workload_name)
+    }
 }
 
 impl fmt::Display for WorkloadInstanceName {
@@ -186,6 +211,11 @@ mod tests {
             instance_name.get_filter_mask(),
             "workloadStates.agent_Test.workload_Test.1234"
         );
+        assert_eq!(instance_name.state_mask(), instance_name.get_filter_mask());
+        assert_eq!(
+            instance_name.desired_state_mask(),
+            "desiredState.workloads.workload_Test"
+        );
         assert_eq!(
             instance_name.to_dict(),
             serde_yaml::Mapping::from_iter([
@@ -13,7 +13,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use serde_yaml::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+use std::vec;
 
 use super::workload_execution_state::WorkloadExecutionState;
 use super::workload_instance_name::WorkloadInstanceName;
@@ -35,6 +37,9 @@ pub struct WorkloadState {
     pub execution_state: WorkloadExecutionState,
     /// The instance name of the workload.
     pub workload_instance_name: WorkloadInstanceName,
+    /// The time at which the SDK first observed the execution state.
+    /// Only tracked for the states received through a [`WorkloadStatesWatch`](super::WorkloadStatesWatch).
+    pub observed_at: Option<Instant>,
 }
 
 /// Helper struct that specializes in managing a collection of [`WorkloadStates`](WorkloadState).
@@ -71,6 +76,7 @@ impl WorkloadState {
                 workload_name,
                 workload_id,
             ),
+            observed_at: None,
         }
     }
 
@@ -100,8 +106,20 @@ impl WorkloadState {
                 workload_name,
                 workload_id,
             ),
+            observed_at: None,
         }
     }
+
+    /// Gets for how long the workload has been in its execution state, as observed by the SDK.
+    ///
+    /// ## Returns
+    ///
+    /// The [Duration] since the execution state was first observed,
+    /// or [None] if the observation time is not tracked.
+    #[must_use]
+    pub fn time_in_state(&self) -> Option<Duration> {
+        self.observed_at.map(|observed_at| observed_at.elapsed())
+    }
 }
 
 impl WorkloadStateCollection {
@@ -166,6 +184,30 @@ impl WorkloadStateCollection {
             .insert(workload_id.clone(), workload_state.execution_state);
     }
 
+    /// Returns the number of [`WorkloadStates`](WorkloadState) in the collection.
+    ///
+    /// ## Returns
+    ///
+    /// The number of [`WorkloadStates`](WorkloadState) in the collection.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.workload_states
+            .values()
+            .flat_map(HashMap::values)
+            .map(HashMap::len)
+            .sum()
+    }
+
+    /// Checks if the collection contains no [`WorkloadState`].
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the collection is empty, `false` otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Converts the `WorkloadStateCollection` to a [`WorkloadStatesMap`].
     ///
     /// ## Returns
@@ -196,6 +238,32 @@ impl WorkloadStateCollection {
         Vec::from(self)
     }
 
+    /// Returns the [`WorkloadExecutionState`] of a workload instance.
+    /// The states are indexed by agent, workload name and id, so nothing is iterated or cloned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `agent_name` - The name of the agent of the workload;
+    /// * `workload_name` - The name of the workload;
+    /// * `workload_id` - The id of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadExecutionState`] of the workload instance.
+    /// If the workload instance is not found, `None` is returned.
+    #[must_use]
+    pub fn get(
+        &self,
+        agent_name: &str,
+        workload_name: &str,
+        workload_id: &str,
+    ) -> Option<&WorkloadExecutionState> {
+        self.workload_states
+            .get(agent_name)?
+            .get(workload_name)?
+            .get(workload_id)
+    }
+
     /// Returns the [`WorkloadState`] for a given [`WorkloadInstanceName`].
     ///
     /// ## Arguments
@@ -211,10 +279,11 @@ impl WorkloadStateCollection {
         &self,
         instance_name: &WorkloadInstanceName,
     ) -> Option<&WorkloadExecutionState> {
-        self.workload_states
-            .get(&instance_name.agent_name)
-            .and_then(|workloads| workloads.get(&instance_name.workload_name))
-            .and_then(|workload| workload.get(&instance_name.workload_id))
+        self.get(
+            &instance_name.agent_name,
+            &instance_name.workload_name,
+            &instance_name.workload_id,
+        )
     }
 }
 
@@ -224,6 +293,58 @@ impl From<ank_base::WorkloadStatesMap> for WorkloadStateCollection {
     }
 }
 
+#[cfg(feature = "proto-types")]
+impl From<WorkloadStateCollection> for ank_base::WorkloadStatesMap {
+    fn from(collection: WorkloadStateCollection) -> Self {
+        ank_base::WorkloadStatesMap {
+            agent_state_map: collection
+                .workload_states
+                .into_iter()
+                .map(|(agent_name, workloads)| {
+                    let wl_name_state_map = workloads
+                        .into_iter()
+                        .map(|(workload_name, states_for_id)| {
+                            let id_state_map = states_for_id
+                                .into_iter()
+                                .map(|(workload_id, state)| (workload_id, state.into()))
+                                .collect();
+                            (
+                                workload_name,
+                                ank_base::ExecutionsStatesForId { id_state_map },
+                            )
+                        })
+                        .collect();
+                    (
+                        agent_name,
+                        ank_base::ExecutionsStatesOfWorkload { wl_name_state_map },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "proto-types")]
+impl From<ank_base::WorkloadState> for WorkloadState {
+    fn from(proto: ank_base::WorkloadState) -> Self {
+        WorkloadState {
+            execution_state: WorkloadExecutionState::new(proto.execution_state.unwrap_or_default()),
+            workload_instance_name: proto.instance_name.unwrap_or_default().into(),
+            observed_at: None,
+        }
+    }
+}
+
+#[cfg(feature = "proto-types")]
+impl From<WorkloadState> for ank_base::WorkloadState {
+    fn from(workload_state: WorkloadState) -> Self {
+        ank_base::WorkloadState {
+            instance_name: Some(workload_state.workload_instance_name.into()),
+            execution_state: Some(workload_state.execution_state.into()),
+        }
+    }
+}
+
 impl From<WorkloadStateCollection> for WorkloadStatesMap {
     fn from(collection: WorkloadStateCollection) -> Self {
         collection.workload_states
@@ -233,11 +354,19 @@ impl From<WorkloadStateCollection> for WorkloadStatesMap {
 impl From<WorkloadStateCollection> for serde_yaml::Mapping {
     fn from(collection: WorkloadStateCollection) -> Self {
         let mut map = serde_yaml::Mapping::new();
-        for (agent_name, workload_states) in &collection.workload_states {
+        for (agent_name, workload_states) in collection
+            .workload_states
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+        {
             let mut agent_map = serde_yaml::Mapping::new();
-            for (workload_name, workload_states_for_id) in workload_states {
+            for (workload_name, workload_states_for_id) in
+                workload_states.iter().collect::<BTreeMap<_, _>>()
+            {
                 let mut workload_map = serde_yaml::Mapping::new();
-                for (workload_id, workload_state) in workload_states_for_id {
+                for (workload_id, workload_state) in
+                    workload_states_for_id.iter().collect::<BTreeMap<_, _>>()
+                {
                     workload_map.insert(
                         Value::String(workload_id.clone()),
                         Value::Mapping(workload_state.to_dict()),
@@ -268,6 +397,7 @@ impl From<WorkloadStateCollection> for Vec<WorkloadState> {
                     list.push(WorkloadState {
                         execution_state: workload_state.clone(),
                         workload_instance_name,
+                        observed_at: None,
                     });
                 }
             }
@@ -276,6 +406,31 @@ impl From<WorkloadStateCollection> for Vec<WorkloadState> {
     }
 }
 
+impl IntoIterator for WorkloadStateCollection {
+    type Item = WorkloadState;
+    type IntoIter = vec::IntoIter<WorkloadState>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Vec::from(self).into_iter()
+    }
+}
+
+impl Extend<WorkloadState> for WorkloadStateCollection {
+    fn extend<T: IntoIterator<Item = WorkloadState>>(&mut self, iter: T) {
+        for workload_state in iter {
+            self.add_workload_state(workload_state);
+        }
+    }
+}
+
+impl FromIterator<WorkloadState> for WorkloadStateCollection {
+    fn from_iter<T: IntoIterator<Item = WorkloadState>>(iter: T) -> Self {
+        let mut collection = WorkloadStateCollection::new();
+        collection.extend(iter);
+        collection
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -284,7 +439,7 @@ impl From<WorkloadStateCollection> for Vec<WorkloadState> {
 //                    ##     #######   #########      ##                    //
 //////////////////////////////////////////////////////////////////////////////
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fixtures"))]
 pub fn generate_test_workload_states_proto() -> ank_base::WorkloadStatesMap {
     ank_base::WorkloadStatesMap {
         agent_state_map: HashMap::from([
@@ -469,5 +624,55 @@ mod tests {
             WorkloadSubStateEnum::PendingWaitingToStart
         );
         assert_eq!(workload_state.additional_info, "Random info");
+        assert_eq!(
+            state_collection.get("agent_B", "nginx", "5678"),
+            Some(workload_state)
+        );
+        assert!(state_collection.get("agent_B", "nginx", "1234").is_none());
+        assert!(state_collection.get("agent_C", "nginx", "5678").is_none());
+    }
+
+    #[test]
+    fn utest_workload_state_collection_iterators() {
+        let state_collection = WorkloadStateCollection::from(generate_test_workload_states_proto());
+        assert_eq!(state_collection.len(), 3);
+        assert!(!state_collection.is_empty());
+        assert!(WorkloadStateCollection::new().is_empty());
+
+        let pending_states: WorkloadStateCollection = state_collection
+            .clone()
+            .into_iter()
+            .filter(|workload_state| {
+                workload_state.execution_state.state == WorkloadStateEnum::Pending
+            })
+            .collect();
+        assert_eq!(pending_states.len(), 1);
+
+        let mut extended_collection = pending_states.clone();
+        extended_collection.extend(pending_states);
+        assert_eq!(extended_collection.len(), 1);
+        extended_collection.extend(state_collection);
+        assert_eq!(extended_collection.len(), 3);
+    }
+
+    #[cfg(feature = "proto-types")]
+    #[test]
+    fn utest_proto_conversion() {
+        let proto = generate_test_workload_states_proto();
+        let collection = WorkloadStateCollection::from(proto.clone());
+        assert_eq!(ank_base::WorkloadStatesMap::from(collection), proto);
+
+        let workload_state = WorkloadState::new_from_exec_state(
+            "agent_A".to_owned(),
+            "nginx".to_owned(),
+            "1234".to_owned(),
+            WorkloadExecutionState::default(),
+        );
+        let workload_state_proto = ank_base::WorkloadState::from(workload_state.clone());
+        assert_eq!(
+            workload_state_proto.instance_name.as_ref().unwrap().id,
+            "1234"
+        );
+        assert_eq!(WorkloadState::from(workload_state_proto), workload_state);
     }
 }
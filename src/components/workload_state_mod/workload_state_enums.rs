@@ -17,64 +17,71 @@ use std::str::FromStr;
 use crate::ankaios_api;
 use ankaios_api::ank_base;
 
+/// The name given to the proto enum values unknown to the SDK, e.g. `Unknown(7)`.
+const UNKNOWN_ENUM_VALUE: &str = "Unknown";
+
 /// Enum representing the state of a Workload.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum WorkloadStateEnum {
     /// The agent is disconnected.
-    AgentDisconnected = 0,
+    #[default]
+    AgentDisconnected,
     /// The workload is pending.
-    Pending = 1,
+    Pending,
     /// The workload is running.
-    Running = 2,
+    Running,
     /// The workload is stopping.
-    Stopping = 3,
+    Stopping,
     /// The workload has succeeded.
-    Succeeded = 4,
+    Succeeded,
     /// The workload has failed.
-    Failed = 5,
+    Failed,
     /// The workload is not scheduled.
-    NotScheduled = 6,
+    NotScheduled,
     /// The workload has been removed.
-    Removed = 7,
+    Removed,
+    /// A state sent by a newer server that the SDK does not know, containing its raw value.
+    Unknown(i32),
 }
 
 /// Enum representing the substate of a Workload.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum WorkloadSubStateEnum {
     /// The agent is disconnected.
-    AgentDisconnected = 0,
+    #[default]
+    AgentDisconnected,
     /// The workload is pending and in the initial state.
-    PendingInitial = 1,
+    PendingInitial,
     /// The workload is pending and waiting to start.
-    PendingWaitingToStart = 2,
+    PendingWaitingToStart,
     /// The workload is pending and starting.
-    PendingStarting = 3,
+    PendingStarting,
     /// The workload is pending and starting failed.
-    PendingStartingFailed = 4,
+    PendingStartingFailed,
     /// The workload is running and ok.
-    RunningOk = 5,
+    RunningOk,
     /// The workload is stopping.
-    Stopping = 6,
+    Stopping,
     /// The workload is stopping and waiting to stop.
-    StoppingWaitingToStop = 7,
+    StoppingWaitingToStop,
     /// The workload is stopping, requested at runtime.
-    StoppingRequestedAtRuntime = 8,
+    StoppingRequestedAtRuntime,
     /// The workload is stopping, but the delete failed.
-    StoppingDeleteFailed = 9,
+    StoppingDeleteFailed,
     /// The workload has succeeded.
-    SucceededOk = 10,
+    SucceededOk,
     /// The workload has failed, execution failed.
-    FailedExecFailed = 11,
+    FailedExecFailed,
     /// The workload has failed with unknown reason.
-    FailedUnknown = 12,
+    FailedUnknown,
     /// The workload has failed and is lost.
-    FailedLost = 13,
+    FailedLost,
     /// The workload is not scheduled.
-    NotScheduled = 14,
+    NotScheduled,
     /// The workload has been removed.
-    Removed = 15,
+    Removed,
+    /// A substate sent by a newer server that the SDK does not know, containing its raw value.
+    Unknown(i32),
 }
 
 impl WorkloadStateEnum {
@@ -101,10 +108,54 @@ impl WorkloadStateEnum {
             "Failed" => Ok(WorkloadStateEnum::Failed),
             "NotScheduled" => Ok(WorkloadStateEnum::NotScheduled),
             "Removed" => Ok(WorkloadStateEnum::Removed),
-            _ => Err("Invalid value for WorkloadStateEnum".to_owned()),
+            other => unknown_enum_value_from_str(other)
+                .map(WorkloadStateEnum::Unknown)
+                .ok_or_else(|| "Invalid value for WorkloadStateEnum".to_owned()),
         }
     }
 
+    /// Creates a new `WorkloadStateEnum` from an [i32] value, keeping the values
+    /// the SDK does not know as [`Unknown`](WorkloadStateEnum::Unknown).
+    ///
+    /// ## Arguments
+    ///
+    /// * `value` - An [i32] value that represents the state.
+    ///
+    /// ## Returns
+    ///
+    /// A [`WorkloadStateEnum`] instance.
+    #[must_use]
+    pub fn from_i32(value: i32) -> WorkloadStateEnum {
+        match value {
+            0 => WorkloadStateEnum::AgentDisconnected,
+            1 => WorkloadStateEnum::Pending,
+            2 => WorkloadStateEnum::Running,
+            3 => WorkloadStateEnum::Stopping,
+            4 => WorkloadStateEnum::Succeeded,
+            5 => WorkloadStateEnum::Failed,
+            6 => WorkloadStateEnum::NotScheduled,
+            7 => WorkloadStateEnum::Removed,
+            other => WorkloadStateEnum::Unknown(other),
+        }
+    }
+
+    /// Checks whether an [i32] value is a state known by the SDK.
+    ///
+    /// ## Arguments
+    ///
+    /// * `value` - An [i32] value that represents the state.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the value is a known state.
+    #[must_use]
+    pub fn is_valid(value: i32) -> bool {
+        !matches!(
+            WorkloadStateEnum::from_i32(value),
+            WorkloadStateEnum::Unknown(_)
+        )
+    }
+
     /// Converts the [`WorkloadStateEnum`] to an [i32].
     ///
     /// ## Returns
@@ -112,7 +163,103 @@ impl WorkloadStateEnum {
     /// An [i32] value representing the [`WorkloadStateEnum`].
     #[must_use]
     pub fn as_i32(&self) -> i32 {
-        *self as i32
+        match *self {
+            WorkloadStateEnum::AgentDisconnected => 0,
+            WorkloadStateEnum::Pending => 1,
+            WorkloadStateEnum::Running => 2,
+            WorkloadStateEnum::Stopping => 3,
+            WorkloadStateEnum::Succeeded => 4,
+            WorkloadStateEnum::Failed => 5,
+            WorkloadStateEnum::NotScheduled => 6,
+            WorkloadStateEnum::Removed => 7,
+            WorkloadStateEnum::Unknown(value) => value,
+        }
+    }
+
+    /// Checks whether the execution of the workload ended, i.e. it succeeded, failed or was removed.
+    /// A restart policy can still move a succeeded or failed workload back to
+    /// [`Pending`](WorkloadStateEnum::Pending).
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the state is terminal.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            WorkloadStateEnum::Succeeded | WorkloadStateEnum::Failed | WorkloadStateEnum::Removed
+        )
+    }
+
+    /// Checks whether the workload is moving between two states, i.e. it is pending or stopping.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the state is transitional.
+    #[must_use]
+    pub fn is_transitional(self) -> bool {
+        matches!(
+            self,
+            WorkloadStateEnum::Pending | WorkloadStateEnum::Stopping
+        )
+    }
+
+    /// Returns the states a workload can move to from this state, including this state itself.
+    /// Any known state can move to and from [`AgentDisconnected`](WorkloadStateEnum::AgentDisconnected),
+    /// the transitions of an [`Unknown`](WorkloadStateEnum::Unknown) state are unknown as well.
+    ///
+    /// ## Returns
+    ///
+    /// A slice of the valid next [`WorkloadStateEnum`] values.
+    #[must_use]
+    pub fn valid_next_states(self) -> &'static [WorkloadStateEnum] {
+        use WorkloadStateEnum::{
+            AgentDisconnected, Failed, NotScheduled, Pending, Removed, Running, Stopping,
+            Succeeded, Unknown,
+        };
+        match self {
+            AgentDisconnected => &[
+                AgentDisconnected,
+                Pending,
+                Running,
+                Stopping,
+                Succeeded,
+                Failed,
+                NotScheduled,
+                Removed,
+            ],
+            Pending => &[
+                Pending,
+                Running,
+                Stopping,
+                Succeeded,
+                Failed,
+                Removed,
+                AgentDisconnected,
+            ],
+            Running => &[Running, Stopping, Succeeded, Failed, AgentDisconnected],
+            Stopping => &[Stopping, Succeeded, Failed, Removed, AgentDisconnected],
+            Succeeded => &[Succeeded, Pending, Stopping, Removed, AgentDisconnected],
+            Failed => &[Failed, Pending, Stopping, Removed, AgentDisconnected],
+            NotScheduled => &[NotScheduled, Pending, Removed, AgentDisconnected],
+            Removed => &[Removed, Pending, NotScheduled, AgentDisconnected],
+            Unknown(_) => &[],
+        }
+    }
+
+    /// Checks whether a workload can move from this state to the given state.
+    /// An invalid transition indicates a mismatch between the server and the SDK.
+    ///
+    /// ## Arguments
+    ///
+    /// * `next` - The [`WorkloadStateEnum`] the workload moves to.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the transition is valid.
+    #[must_use]
+    pub fn can_transition_to(self, next: WorkloadStateEnum) -> bool {
+        self.valid_next_states().contains(&next)
     }
 }
 
@@ -129,11 +276,30 @@ impl FromStr for WorkloadStateEnum {
             "Failed" => Ok(WorkloadStateEnum::Failed),
             "NotScheduled" => Ok(WorkloadStateEnum::NotScheduled),
             "Removed" => Ok(WorkloadStateEnum::Removed),
-            _ => Err(()),
+            other => unknown_enum_value_from_str(other)
+                .map(WorkloadStateEnum::Unknown)
+                .ok_or(()),
+        }
+    }
+}
+
+impl TryFrom<i32> for WorkloadStateEnum {
+    type Error = prost::UnknownEnumValue;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match WorkloadStateEnum::from_i32(value) {
+            WorkloadStateEnum::Unknown(unknown) => Err(prost::UnknownEnumValue(unknown)),
+            state => Ok(state),
         }
     }
 }
 
+impl From<WorkloadStateEnum> for i32 {
+    fn from(value: WorkloadStateEnum) -> i32 {
+        value.as_i32()
+    }
+}
+
 impl WorkloadSubStateEnum {
     /// Creates a new `WorkloadSubStateEnum` from a [`WorkloadStateEnum`] and an [i32] value.
     ///
@@ -148,7 +314,8 @@ impl WorkloadSubStateEnum {
     ///
     /// ## Errors
     ///
-    /// If the value is not a valid substate for the given state.
+    /// If the state is [`Unknown`](WorkloadStateEnum::Unknown) or the value is not a valid
+    /// substate for the given state.
     pub fn new(state: WorkloadStateEnum, value: i32) -> Result<WorkloadSubStateEnum, String> {
         match state {
             WorkloadStateEnum::AgentDisconnected => ank_base::AgentDisconnected::try_from(value)
@@ -210,9 +377,79 @@ impl WorkloadSubStateEnum {
                     ank_base::Removed::Removed => WorkloadSubStateEnum::Removed,
                 })
                 .map_err(|_| "Invalid value for state Removed".to_owned()),
+            WorkloadStateEnum::Unknown(_) => Err("Unknown state".to_owned()),
         }
     }
 
+    /// Returns the substates of a state.
+    ///
+    /// ## Arguments
+    ///
+    /// * `state` - The [`WorkloadStateEnum`] to get the substates of.
+    ///
+    /// ## Returns
+    ///
+    /// A slice of the [`WorkloadSubStateEnum`] values belonging to the state, empty for an
+    /// [`Unknown`](WorkloadStateEnum::Unknown) state.
+    #[must_use]
+    pub fn of(state: WorkloadStateEnum) -> &'static [WorkloadSubStateEnum] {
+        match state {
+            WorkloadStateEnum::AgentDisconnected => &[WorkloadSubStateEnum::AgentDisconnected],
+            WorkloadStateEnum::Pending => &[
+                WorkloadSubStateEnum::PendingInitial,
+                WorkloadSubStateEnum::PendingWaitingToStart,
+                WorkloadSubStateEnum::PendingStarting,
+                WorkloadSubStateEnum::PendingStartingFailed,
+            ],
+            WorkloadStateEnum::Running => &[WorkloadSubStateEnum::RunningOk],
+            WorkloadStateEnum::Stopping => &[
+                WorkloadSubStateEnum::Stopping,
+                WorkloadSubStateEnum::StoppingWaitingToStop,
+                WorkloadSubStateEnum::StoppingRequestedAtRuntime,
+                WorkloadSubStateEnum::StoppingDeleteFailed,
+            ],
+            WorkloadStateEnum::Succeeded => &[WorkloadSubStateEnum::SucceededOk],
+            WorkloadStateEnum::Failed => &[
+                WorkloadSubStateEnum::FailedExecFailed,
+                WorkloadSubStateEnum::FailedUnknown,
+                WorkloadSubStateEnum::FailedLost,
+            ],
+            WorkloadStateEnum::NotScheduled => &[WorkloadSubStateEnum::NotScheduled],
+            WorkloadStateEnum::Removed => &[WorkloadSubStateEnum::Removed],
+            WorkloadStateEnum::Unknown(_) => &[],
+        }
+    }
+
+    /// Returns the state this substate belongs to.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadStateEnum`] of the substate, [None] for an
+    /// [`Unknown`](WorkloadSubStateEnum::Unknown) substate.
+    #[must_use]
+    pub fn state(self) -> Option<WorkloadStateEnum> {
+        let state = match self {
+            WorkloadSubStateEnum::AgentDisconnected => WorkloadStateEnum::AgentDisconnected,
+            WorkloadSubStateEnum::PendingInitial
+            | WorkloadSubStateEnum::PendingWaitingToStart
+            | WorkloadSubStateEnum::PendingStarting
+            | WorkloadSubStateEnum::PendingStartingFailed => WorkloadStateEnum::Pending,
+            WorkloadSubStateEnum::RunningOk => WorkloadStateEnum::Running,
+            WorkloadSubStateEnum::Stopping
+            | WorkloadSubStateEnum::StoppingWaitingToStop
+            | WorkloadSubStateEnum::StoppingRequestedAtRuntime
+            | WorkloadSubStateEnum::StoppingDeleteFailed => WorkloadStateEnum::Stopping,
+            WorkloadSubStateEnum::SucceededOk => WorkloadStateEnum::Succeeded,
+            WorkloadSubStateEnum::FailedExecFailed
+            | WorkloadSubStateEnum::FailedUnknown
+            | WorkloadSubStateEnum::FailedLost => WorkloadStateEnum::Failed,
+            WorkloadSubStateEnum::NotScheduled => WorkloadStateEnum::NotScheduled,
+            WorkloadSubStateEnum::Removed => WorkloadStateEnum::Removed,
+            WorkloadSubStateEnum::Unknown(_) => return None,
+        };
+        Some(state)
+    }
+
     /// Converts the `WorkloadSubStateEnum` to an [i32].
     ///
     /// ## Returns
@@ -240,6 +477,7 @@ impl WorkloadSubStateEnum {
             WorkloadSubStateEnum::FailedLost => ank_base::Failed::Lost as i32,
             WorkloadSubStateEnum::NotScheduled => ank_base::NotScheduled::NotScheduled as i32,
             WorkloadSubStateEnum::Removed => ank_base::Removed::Removed as i32,
+            WorkloadSubStateEnum::Unknown(value) => value,
         }
     }
 }
@@ -265,11 +503,46 @@ impl FromStr for WorkloadSubStateEnum {
             "FailedLost" => Ok(WorkloadSubStateEnum::FailedLost),
             "NotScheduled" => Ok(WorkloadSubStateEnum::NotScheduled),
             "Removed" => Ok(WorkloadSubStateEnum::Removed),
-            _ => Err(()),
+            other => unknown_enum_value_from_str(other)
+                .map(WorkloadSubStateEnum::Unknown)
+                .ok_or(()),
         }
     }
 }
 
+/// Formats a proto enum value unknown to the SDK, e.g. sent by a newer server,
+/// so that it is surfaced and kept instead of being dropped.
+/// The format matches the [Debug] representation of the unknown states and substates.
+///
+/// ## Arguments
+///
+/// * `value` - The raw value of the enum.
+///
+/// ## Returns
+///
+/// The value formatted as `Unknown(<value>)`.
+pub(crate) fn unknown_enum_value_to_str(value: i32) -> String {
+    format!("{UNKNOWN_ENUM_VALUE}({value})")
+}
+
+/// Parses a proto enum value formatted by [`unknown_enum_value_to_str`].
+///
+/// ## Arguments
+///
+/// * `value` - The formatted value, e.g. `Unknown(7)`.
+///
+/// ## Returns
+///
+/// The raw value of the enum, [None] if the value is not formatted as unknown.
+pub(crate) fn unknown_enum_value_from_str(value: &str) -> Option<i32> {
+    value
+        .strip_prefix(UNKNOWN_ENUM_VALUE)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .parse()
+        .ok()
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -292,6 +565,33 @@ mod tests {
         let state = WorkloadStateEnum::default();
         assert!(WorkloadStateEnum::is_valid(0));
         assert_eq!(WorkloadStateEnum::try_from(0).unwrap(), state);
+        assert_eq!(i32::from(WorkloadStateEnum::Removed), 7);
+    }
+
+    #[test]
+    fn utest_workload_state_enum_unknown() {
+        let state = WorkloadStateEnum::from_i32(42);
+        assert_eq!(state, WorkloadStateEnum::Unknown(42));
+        assert_eq!(state.as_i32(), 42);
+        assert!(!WorkloadStateEnum::is_valid(42));
+        assert_eq!(
+            WorkloadStateEnum::try_from(42).unwrap_err(),
+            prost::UnknownEnumValue(42)
+        );
+        assert_eq!(WorkloadStateEnum::from_i32(2), WorkloadStateEnum::Running);
+        assert_eq!(format!("{state:?}"), "Unknown(42)");
+        assert_eq!(state, "Unknown(42)".parse().unwrap());
+        assert_eq!(
+            WorkloadStateEnum::new_from_str("Unknown(42)").unwrap(),
+            state
+        );
+        assert!(WorkloadStateEnum::from_str("Unknown(x)").is_err());
+        assert!(!state.is_terminal());
+        assert!(!state.is_transitional());
+        assert!(state.valid_next_states().is_empty());
+        assert!(!state.can_transition_to(state));
+        assert!(WorkloadSubStateEnum::of(state).is_empty());
+        assert!(WorkloadSubStateEnum::new(state, 0).is_err());
     }
 
     macro_rules! generate_test_for_workload_state_enum {
@@ -327,6 +627,48 @@ mod tests {
     );
     generate_test_for_workload_state_enum!(utest_workload_state_enum_removed, Removed, 7);
 
+    #[test]
+    fn utest_workload_state_classification() {
+        assert!(WorkloadStateEnum::Succeeded.is_terminal());
+        assert!(WorkloadStateEnum::Failed.is_terminal());
+        assert!(WorkloadStateEnum::Removed.is_terminal());
+        assert!(!WorkloadStateEnum::Running.is_terminal());
+        assert!(WorkloadStateEnum::Pending.is_transitional());
+        assert!(WorkloadStateEnum::Stopping.is_transitional());
+        assert!(!WorkloadStateEnum::Running.is_transitional());
+        assert!(!WorkloadStateEnum::Failed.is_transitional());
+    }
+
+    #[test]
+    fn utest_workload_state_transitions() {
+        assert!(WorkloadStateEnum::Pending.can_transition_to(WorkloadStateEnum::Running));
+        assert!(WorkloadStateEnum::Running.can_transition_to(WorkloadStateEnum::Stopping));
+        assert!(WorkloadStateEnum::Failed.can_transition_to(WorkloadStateEnum::Pending));
+        assert!(!WorkloadStateEnum::Running.can_transition_to(WorkloadStateEnum::Pending));
+        assert!(!WorkloadStateEnum::Removed.can_transition_to(WorkloadStateEnum::Running));
+        for state in WorkloadStateEnum::AgentDisconnected.valid_next_states() {
+            assert!(state.can_transition_to(*state));
+            assert!(state.can_transition_to(WorkloadStateEnum::AgentDisconnected));
+            assert!(WorkloadStateEnum::AgentDisconnected.can_transition_to(*state));
+        }
+    }
+
+    #[test]
+    fn utest_workload_sub_state_of_state() {
+        for state in WorkloadStateEnum::AgentDisconnected.valid_next_states() {
+            let substates = WorkloadSubStateEnum::of(*state);
+            assert!(!substates.is_empty());
+            for substate in substates {
+                assert_eq!(substate.state(), Some(*state));
+                assert_eq!(
+                    WorkloadSubStateEnum::new(*state, substate.to_i32()).unwrap(),
+                    *substate
+                );
+            }
+        }
+        assert_eq!(WorkloadSubStateEnum::of(WorkloadStateEnum::Failed).len(), 3);
+    }
+
     #[test]
     fn utest_workload_state_str_invalid() {
         assert!(WorkloadStateEnum::from_str(stringify!(Invalid)).is_err());
@@ -337,7 +679,13 @@ mod tests {
     fn utest_workload_sub_state_enum_helpers() {
         let substate = WorkloadSubStateEnum::default();
         assert_eq!(substate.to_i32(), 0i32);
-        assert_eq!(WorkloadSubStateEnum::try_from(0).unwrap(), substate);
+        assert_eq!(substate, WorkloadSubStateEnum::AgentDisconnected);
+
+        let unknown = WorkloadSubStateEnum::Unknown(20);
+        assert_eq!(unknown.to_i32(), 20);
+        assert_eq!(unknown.state(), None);
+        assert_eq!(format!("{unknown:?}"), "Unknown(20)");
+        assert_eq!(unknown, "Unknown(20)".parse().unwrap());
     }
 
     macro_rules! generate_test_for_workload_state_enum {
@@ -0,0 +1,448 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`WorkloadStatesFilter`] and the [`WorkloadStatesWatch`],
+//! used to watch the workload states with rapid changes coalesced.
+//!
+//! # Example
+//!
+//! ## Watch the workload states of an agent:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::Ankaios;
+//! use ankaios_sdk::WorkloadStatesFilter;
+//! use tokio::time::Duration;
+//! #
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! # let ankaios = Ankaios::new().await.unwrap();
+//!
+//! let filter = WorkloadStatesFilter {
+//!     agent_name: Some("agent_A".to_owned()),
+//!     ..Default::default()
+//! };
+//! let mut watch = ankaios
+//!     .watch_workload_states(filter, Duration::from_millis(500))
+//!     .await
+//!     .unwrap();
+//! while let Some(workload_state) = watch.states_receiver.recv().await {
+//!     println!("{}: {:?}", workload_state.workload_instance_name, workload_state.execution_state);
+//! }
+//! # })
+//! ```
+
+use std::collections::HashMap;
+use std::time::Instant as StdInstant;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::time::{Duration, Instant, sleep_until};
+
+use super::{WorkloadExecutionState, WorkloadInstanceName, WorkloadState};
+use crate::components::event_types::EventEntry;
+use crate::logging::sdk_log;
+
+/// The prefix of the field masks of the workload states.
+const WORKLOAD_STATES_PREFIX: &str = "workloadStates";
+
+/// Struct that selects the workload states to watch.
+/// An empty filter selects all the workload states.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WorkloadStatesFilter {
+    /// Only watch the workloads running on this agent.
+    pub agent_name: Option<String>,
+    /// Only watch the workloads with this name.
+    pub workload_name: Option<String>,
+}
+
+impl WorkloadStatesFilter {
+    /// Gets the field masks to register to, as narrow as the filter allows.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of [String]s containing the field masks.
+    pub(crate) fn field_masks(&self) -> Vec<String> {
+        let mut mask = WORKLOAD_STATES_PREFIX.to_owned();
+        if let Some(agent_name) = &self.agent_name {
+            mask.push('.');
+            mask.push_str(agent_name);
+            if let Some(workload_name) = &self.workload_name {
+                mask.push('.');
+                mask.push_str(workload_name);
+            }
+        }
+        vec![mask]
+    }
+
+    /// Checks if a workload instance is selected by the filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] to check.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload instance is selected, `false` otherwise.
+    #[must_use]
+    pub fn matches(&self, instance_name: &WorkloadInstanceName) -> bool {
+        self.agent_name
+            .as_ref()
+            .is_none_or(|agent_name| *agent_name == instance_name.agent_name)
+            && self
+                .workload_name
+                .as_ref()
+                .is_none_or(|workload_name| *workload_name == instance_name.workload_name)
+    }
+}
+
+/// Struct that represents a watch of the workload states.
+#[derive(Debug)]
+pub struct WorkloadStatesWatch {
+    /// The request id as a [String] of the underlying events request.
+    request_id: String,
+    /// A [Receiver] of the coalesced workload states.
+    pub states_receiver: Receiver<WorkloadState>,
+}
+
+impl WorkloadStatesWatch {
+    /// Creates a new `WorkloadStatesWatch` object.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request_id` - The request id as a [String] of the underlying events request;
+    /// * `states_receiver` - A [Receiver] of the coalesced workload states.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`WorkloadStatesWatch`] object.
+    pub(crate) fn new(request_id: String, states_receiver: Receiver<WorkloadState>) -> Self {
+        WorkloadStatesWatch {
+            request_id,
+            states_receiver,
+        }
+    }
+
+    /// Gets the request id of the underlying events request.
+    ///
+    /// ## Returns
+    ///
+    /// The request id as a [String].
+    pub(crate) fn get_request_id(&self) -> String {
+        self.request_id.clone()
+    }
+}
+
+/// Forwards the workload states selected by the `filter`, merging the changes received
+/// within the `coalesce_window` into the latest state of every workload instance.
+/// A workload state is only forwarded if it differs from the last one forwarded.
+/// The forwarded states carry the time at which their execution state was first received.
+///
+/// ## Arguments
+///
+/// * `events_receiver` - The [Receiver] of the events of the workload states;
+/// * `states_sender` - The [Sender] towards the watch receiver;
+/// * `filter` - The [`WorkloadStatesFilter`] selecting the workload states;
+/// * `coalesce_window` - The [Duration] for which the changes are merged before being forwarded.
+pub(crate) async fn coalesce_workload_states(
+    mut events_receiver: Receiver<EventEntry>,
+    states_sender: Sender<WorkloadState>,
+    filter: WorkloadStatesFilter,
+    coalesce_window: Duration,
+) {
+    let mut last_forwarded: HashMap<WorkloadInstanceName, WorkloadExecutionState> = HashMap::new();
+    let mut first_observed: HashMap<WorkloadInstanceName, (WorkloadExecutionState, StdInstant)> =
+        HashMap::new();
+    let mut pending: Vec<WorkloadState> = Vec::new();
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        let receive_result = tokio::select! {
+            event = events_receiver.recv() => Some(event),
+            () = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => None,
+        };
+
+        match receive_result {
+            Some(Some(event)) => {
+                for mut workload_state in event.complete_state.get_workload_states() {
+                    if !filter.matches(&workload_state.workload_instance_name) {
+                        continue;
+                    }
+                    workload_state.observed_at = Some(observe_execution_state(
+                        &mut first_observed,
+                        &workload_state,
+                    ));
+                    if let Some(pending_state) = pending.iter_mut().find(|pending_state| {
+                        pending_state.workload_instance_name
+                            == workload_state.workload_instance_name
+                    }) {
+                        *pending_state = workload_state;
+                    } else {
+                        pending.push(workload_state);
+                    }
+                }
+                if deadline.is_none() && !pending.is_empty() {
+                    deadline = Some(Instant::now() + coalesce_window);
+                }
+            }
+            Some(None) => {
+                flush_pending(&mut pending, &mut last_forwarded, &states_sender).await;
+                sdk_log!(
+                    debug,
+                    "Workload states watch stopped, the events campaign was closed."
+                );
+                return;
+            }
+            None => {
+                deadline = None;
+                if !flush_pending(&mut pending, &mut last_forwarded, &states_sender).await {
+                    sdk_log!(
+                        debug,
+                        "Workload states watch stopped, the receiver was dropped."
+                    );
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Gets the time at which the execution state of a workload instance was first received,
+/// which is now if it differs from the previously received one.
+///
+/// ## Arguments
+///
+/// * `first_observed` - The last received execution state of every workload instance and when it was first received;
+/// * `workload_state` - The received [`WorkloadState`].
+///
+/// ## Returns
+///
+/// The time at which the execution state was first received.
+fn observe_execution_state(
+    first_observed: &mut HashMap<WorkloadInstanceName, (WorkloadExecutionState, StdInstant)>,
+    workload_state: &WorkloadState,
+) -> StdInstant {
+    match first_observed.get(&workload_state.workload_instance_name) {
+        Some((execution_state, observed_at))
+            if *execution_state == workload_state.execution_state =>
+        {
+            *observed_at
+        }
+        _ => {
+            let observed_at = StdInstant::now();
+            first_observed.insert(
+                workload_state.workload_instance_name.clone(),
+                (workload_state.execution_state.clone(), observed_at),
+            );
+            observed_at
+        }
+    }
+}
+
+/// Forwards the pending workload states that changed since they were last forwarded.
+///
+/// ## Arguments
+///
+/// * `pending` - The pending workload states, emptied by the call;
+/// * `last_forwarded` - The last forwarded execution state of every workload instance;
+/// * `states_sender` - The [Sender] towards the watch receiver.
+///
+/// ## Returns
+///
+/// `false` if the watch receiver was dropped, `true` otherwise.
+async fn flush_pending(
+    pending: &mut Vec<WorkloadState>,
+    last_forwarded: &mut HashMap<WorkloadInstanceName, WorkloadExecutionState>,
+    states_sender: &Sender<WorkloadState>,
+) -> bool {
+    for workload_state in pending.drain(..) {
+        if last_forwarded.get(&workload_state.workload_instance_name)
+            == Some(&workload_state.execution_state)
+        {
+            continue;
+        }
+        last_forwarded.insert(
+            workload_state.workload_instance_name.clone(),
+            workload_state.execution_state.clone(),
+        );
+        if states_sender.send(workload_state).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use tokio::sync::mpsc;
+    use tokio::time::{Duration, timeout};
+
+    use super::{WorkloadStatesFilter, coalesce_workload_states};
+    use crate::components::event_types::EventEntry;
+    use crate::components::workload_state_mod::{WorkloadInstanceName, WorkloadStateEnum};
+    use crate::{CompleteState, ankaios_api::ank_base};
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME: &str = "nginx";
+    const WORKLOAD_ID: &str = "1234";
+
+    fn generate_event(agent_name: &str, state: ank_base::ExecutionStateEnum) -> EventEntry {
+        let workload_states = ank_base::WorkloadStatesMap {
+            agent_state_map: HashMap::from([(
+                agent_name.to_owned(),
+                ank_base::ExecutionsStatesOfWorkload {
+                    wl_name_state_map: HashMap::from([(
+                        WORKLOAD_NAME.to_owned(),
+                        ank_base::ExecutionsStatesForId {
+                            id_state_map: HashMap::from([(
+                                WORKLOAD_ID.to_owned(),
+                                ank_base::ExecutionState {
+                                    execution_state_enum: Some(state),
+                                    additional_info: None,
+                                },
+                            )]),
+                        },
+                    )]),
+                },
+            )]),
+        };
+        EventEntry {
+            complete_state: CompleteState::new_from_proto(ank_base::CompleteState {
+                workload_states: Some(workload_states),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn utest_workload_states_filter() {
+        let instance_name = WorkloadInstanceName::new(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME.to_owned(),
+            WORKLOAD_ID.to_owned(),
+        );
+
+        let filter = WorkloadStatesFilter::default();
+        assert_eq!(filter.field_masks(), vec!["workloadStates".to_owned()]);
+        assert!(filter.matches(&instance_name));
+
+        let filter = WorkloadStatesFilter {
+            agent_name: Some(AGENT_A.to_owned()),
+            workload_name: Some(WORKLOAD_NAME.to_owned()),
+        };
+        assert_eq!(
+            filter.field_masks(),
+            vec![format!("workloadStates.{AGENT_A}.{WORKLOAD_NAME}")]
+        );
+        assert!(filter.matches(&instance_name));
+
+        let filter = WorkloadStatesFilter {
+            agent_name: None,
+            workload_name: Some(WORKLOAD_NAME.to_owned()),
+        };
+        assert_eq!(filter.field_masks(), vec!["workloadStates".to_owned()]);
+        assert!(filter.matches(&instance_name));
+
+        let filter = WorkloadStatesFilter {
+            agent_name: Some(AGENT_B.to_owned()),
+            workload_name: None,
+        };
+        assert_eq!(
+            filter.field_masks(),
+            vec![format!("workloadStates.{AGENT_B}")]
+        );
+        assert!(!filter.matches(&instance_name));
+    }
+
+    #[tokio::test]
+    async fn utest_coalesce_workload_states() {
+        let (events_sender, events_receiver) = mpsc::channel(10);
+        let (states_sender, mut states_receiver) = mpsc::channel(10);
+        let filter = WorkloadStatesFilter {
+            agent_name: Some(AGENT_A.to_owned()),
+            ..Default::default()
+        };
+        let handle = tokio::spawn(coalesce_workload_states(
+            events_receiver,
+            states_sender,
+            filter,
+            Duration::from_millis(50),
+        ));
+
+        let running = ank_base::ExecutionStateEnum::Running(ank_base::Running::Ok as i32);
+        let failed = ank_base::ExecutionStateEnum::Failed(ank_base::Failed::ExecFailed as i32);
+
+        // A flapping state is merged into the latest one
+        events_sender
+            .send(generate_event(AGENT_A, failed))
+            .await
+            .unwrap();
+        events_sender
+            .send(generate_event(AGENT_B, failed))
+            .await
+            .unwrap();
+        events_sender
+            .send(generate_event(AGENT_A, running))
+            .await
+            .unwrap();
+
+        let workload_state = states_receiver.recv().await.unwrap();
+        assert_eq!(workload_state.workload_instance_name.agent_name, AGENT_A);
+        assert_eq!(
+            workload_state.execution_state.state,
+            WorkloadStateEnum::Running
+        );
+        let running_observed_at = workload_state.observed_at.unwrap();
+        assert!(workload_state.time_in_state().is_some());
+        assert!(
+            timeout(Duration::from_millis(100), states_receiver.recv())
+                .await
+                .is_err()
+        );
+
+        // An unchanged state is not forwarded again
+        events_sender
+            .send(generate_event(AGENT_A, running))
+            .await
+            .unwrap();
+        assert!(
+            timeout(Duration::from_millis(100), states_receiver.recv())
+                .await
+                .is_err()
+        );
+
+        // The pending states are flushed when the events campaign is closed
+        events_sender
+            .send(generate_event(AGENT_A, failed))
+            .await
+            .unwrap();
+        drop(events_sender);
+        let workload_state = states_receiver.recv().await.unwrap();
+        assert_eq!(
+            workload_state.execution_state.state,
+            WorkloadStateEnum::Failed
+        );
+        // A new execution state is observed later than the previous one
+        assert!(workload_state.observed_at.unwrap() > running_observed_at);
+        assert!(states_receiver.recv().await.is_none());
+        handle.await.unwrap();
+    }
+}
@@ -19,44 +19,281 @@
 //! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
 
 use std::io;
+use std::result::Result as StdResult;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::time::error::Elapsed;
+
+use crate::components::manifest_schema::SchemaViolation;
 
 /// An enumeration of possible errors that can occur in the Ankaios application.
 ///
 /// This enum uses the `thiserror::Error` derive macro to automatically generate
 /// implementations for the `std::error::Error` trait. Each variant represents a
 /// different type of error that can occur, with associated data providing more
-/// context about the error.
+/// context about the error. Variants caused by another error expose it through
+/// [`source`](std::error::Error::source).
 #[derive(Error, Debug)]
 pub enum AnkaiosError {
     /// Represents an I/O error, wrapping a `std::io::Error`.
     #[error("IO Error: {0}")]
     IoError(#[from] io::Error),
-    /// Represents a timeout error, wrapping a `tokio::time::error::Elapsed`.
-    #[error("Timeout error: {0}")]
-    TimeoutError(#[from] Elapsed),
+    /// Represents an error while converting from or to YAML, wrapping a `serde_yaml::Error`.
+    #[error("YAML error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+    /// Represents an error while decoding a proto message, wrapping a `prost::DecodeError`.
+    #[error("Decode error: {0}")]
+    DecodeError(#[from] prost::DecodeError),
+    /// Represents a timeout error, containing what was waited for and for how long.
+    #[error(
+        "Timeout error: {request_type}{} did not complete within {elapsed:?}.",
+        .request_id.as_ref().map(|id| format!(" '{id}'")).unwrap_or_default()
+    )]
+    TimeoutError {
+        /// The ID of the request that timed out, if the timeout is related to a single request.
+        request_id: Option<String>,
+        /// The type of the request or of the operation that timed out.
+        request_type: String,
+        /// The time waited before giving up.
+        elapsed: Duration,
+    },
 
     /// Represents an error related to an invalid value for a workload field.
     #[error("Invalid value for field {0}: {1}.")]
     WorkloadFieldError(String, String),
+    /// Represents an error that occurs when the content of a file exceeds the allowed size.
+    /// Contains the mount point, the size of the content and the maximum size in bytes.
+    #[error("File '{0}' has {1} bytes, exceeding the maximum of {2} bytes.")]
+    FileTooLargeError(String, usize, usize),
+    /// Represents an error that occurs when the templated content of a file can't be rendered.
+    /// Contains the mount point and the reason.
+    #[error("Could not render file '{0}': {1}")]
+    FileRenderingError(String, String),
     /// Represents an error that occurs during the building of a workload.
     #[error("Workload builder error: {0}")]
     WorkloadBuilderError(&'static str),
+    /// Represents an error that occurs when a mask has an invalid syntax.
+    #[error("Invalid mask: {0}")]
+    MaskError(String),
     /// Represents an error that occurs when the manifest can't be parsed.
     #[error("Manifest parsing error: {0}")]
     ManifestParsingError(String),
+    /// Represents an error that occurs when a manifest does not match the schema.
+    /// Contains the violations of the schema.
+    #[error("Manifest schema error: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    ManifestSchemaError(Vec<SchemaViolation>),
+    /// Represents an error that occurs when two manifests can't be merged.
+    #[error("Manifest merge error: {0}")]
+    ManifestMergeError(String),
+    /// Represents an error that occurs when a supervised workload can't be kept running.
+    #[error("Supervisor error: {0}")]
+    SupervisorError(String),
+    /// Represents an error that occurs in the MQTT bridge.
+    #[cfg(feature = "mqtt")]
+    #[error("MQTT error: {0}")]
+    MqttError(String),
+    /// Represents an error that occurs when serving the REST facade.
+    #[cfg(feature = "rest-facade")]
+    #[error("REST facade error: {0}")]
+    RestFacadeError(String),
+    /// Represents an error that occurs when a step of a plan fails.
+    /// Contains the index of the step and the error of the step.
+    #[error("Plan step {0} failed: {1}")]
+    PlanError(usize, #[source] Box<AnkaiosError>),
+    /// Represents an error that occurs when the API version of a manifest is not supported.
+    /// Contains the API version and the supported API versions.
+    #[error("Unsupported API version '{0}', supported versions: {1}")]
+    UnsupportedApiVersion(String, String),
     /// Represents an error that occurs when the connection is closed with Ankaios.
     #[error("Connection closed: {0}")]
     ConnectionClosedError(String),
+    /// Represents an error that occurs when Ankaios closed the connection because
+    /// the protocol version of the SDK is not supported.
+    #[error("Protocol version mismatch: {0}")]
+    VersionMismatch(String),
     /// Represents an error that occurs when the response is invalid.
     #[error("Response error: {0}")]
     ResponseError(String),
     /// Represents an error related to the connection with the control interface.
     #[error("Control interface error: {0}")]
     ControlInterfaceError(String),
+    /// Represents an error that occurs when the control interface is not available to the workload,
+    /// because the workload has no `controlInterfaceAccess` configured.
+    /// Contains hints on how to enable it.
+    #[error("Control interface not enabled: {0}")]
+    ControlInterfaceNotEnabled(String),
+    /// Represents an error that occurs when a request exceeds the control interface message limit.
+    /// Contains the request ID, the encoded size of the request and the maximum size in bytes.
+    #[error("Request '{0}' has {1} bytes, exceeding the maximum message size of {2} bytes.")]
+    MessageTooLargeError(String, usize, usize),
+    /// Represents an error that occurs when an operation was cancelled through a cancellation token.
+    #[error("Operation cancelled: {0}")]
+    CancelledError(String),
+    /// Represents an error that occurs when workloads reference agents that are not connected.
+    /// Contains the names of the unknown agents.
+    #[error("Agents not connected: {}", .0.join(", "))]
+    UnknownAgentsError(Vec<String>),
+    /// Represents an error that occurs when the workload using the SDK cannot determine its own instance.
+    #[error("Self introspection error: {0}")]
+    SelfIntrospectionError(String),
+    /// Represents an error that occurs when a deletion would delete the workload using the SDK
+    /// while it is protected. Contains the name of the workload.
+    #[error("Refusing to delete the own workload '{0}'.")]
+    SelfDeleteError(String),
+    /// Represents an error that occurs when the requested workload instance is not the
+    /// current instance of the workload. Contains the requested instance name.
+    #[error("Workload instance '{0}' is not the current instance of the workload.")]
+    InstanceMismatchError(String),
     /// Represents an error returned by the server in response to a distinct request.
     /// e.g. due to insufficient reading rights by the requester.
     #[error("Ankaios response error: {0}")]
     AnkaiosResponseError(String),
 }
+
+/// A [`Result`](StdResult) whose error is an [`AnkaiosError`] by default.
+///
+/// The error type can still be given explicitly, so the alias doesn't shadow the
+/// standard `Result` when imported through the [prelude](crate::prelude).
+pub type Result<T, E = AnkaiosError> = StdResult<T, E>;
+
+/// Prefix of the reason sent by [Ankaios](https://eclipse-ankaios.github.io/ankaios) when it rejects the protocol version,
+/// e.g. `Unsupported protocol version '0.1.0'. Currently supported '1.0.0'`.
+const VERSION_MISMATCH_PREFIX: &str = "Unsupported protocol version '";
+
+impl AnkaiosError {
+    /// Creates the error matching the reason received when [Ankaios] closed the connection.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reason` - The reason received from [Ankaios].
+    ///
+    /// ## Returns
+    ///
+    /// An [`AnkaiosError::VersionMismatch`] if the reason is the rejection of the protocol version,
+    /// an [`AnkaiosError::ConnectionClosedError`] otherwise.
+    ///
+    /// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+    pub(crate) fn from_connection_closed_reason(reason: String) -> Self {
+        if reason.starts_with(VERSION_MISMATCH_PREFIX) {
+            AnkaiosError::VersionMismatch(reason)
+        } else {
+            AnkaiosError::ConnectionClosedError(reason)
+        }
+    }
+
+    /// Checks whether the failed operation can succeed when it is retried unchanged,
+    /// e.g. after a timeout or while the connection to the agent is being restored.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the error is transient.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AnkaiosError::TimeoutError { .. } | AnkaiosError::ControlInterfaceError(_) => true,
+            AnkaiosError::IoError(err) => matches!(
+                err.kind(),
+                io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+            ),
+            AnkaiosError::PlanError(_, err) => err.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// Checks whether the error leaves the [Ankaios](crate::Ankaios) object unusable,
+    /// so that no further request can succeed without creating a new one.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the connection to [Ankaios] is lost for good.
+    ///
+    /// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            AnkaiosError::ConnectionClosedError(_)
+            | AnkaiosError::VersionMismatch(_)
+            | AnkaiosError::ControlInterfaceNotEnabled(_) => true,
+            AnkaiosError::PlanError(_, err) => err.is_fatal(),
+            _ => false,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::AnkaiosError;
+    use std::error::Error;
+    use std::io;
+    use std::time::Duration;
+
+    #[test]
+    fn utest_from_connection_closed_reason() {
+        assert!(matches!(
+            AnkaiosError::from_connection_closed_reason(
+                "Unsupported protocol version '0.1.0'. Currently supported '1.0.0'".to_owned()
+            ),
+            AnkaiosError::VersionMismatch(_)
+        ));
+        assert!(matches!(
+            AnkaiosError::from_connection_closed_reason("Workload deleted".to_owned()),
+            AnkaiosError::ConnectionClosedError(_)
+        ));
+        // Other reasons mentioning a version are not a version mismatch
+        assert!(matches!(
+            AnkaiosError::from_connection_closed_reason(
+                "Workload 'nginx' was deleted by the new version of the manifest".to_owned()
+            ),
+            AnkaiosError::ConnectionClosedError(_)
+        ));
+    }
+
+    #[test]
+    fn utest_error_source() {
+        let err = AnkaiosError::from(io::Error::other("broken"));
+        assert_eq!(err.source().unwrap().to_string(), "broken");
+
+        let yaml_err = serde_yaml::from_str::<u32>("not a number").unwrap_err();
+        let err = AnkaiosError::from(yaml_err);
+        assert!(err.source().unwrap().is::<serde_yaml::Error>());
+
+        let err = AnkaiosError::PlanError(0, Box::new(err));
+        assert!(err.source().unwrap().is::<AnkaiosError>());
+
+        assert!(
+            AnkaiosError::ResponseError("invalid".to_owned())
+                .source()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn utest_is_retryable_and_is_fatal() {
+        let timeout = AnkaiosError::TimeoutError {
+            request_id: None,
+            request_type: "get_state".to_owned(),
+            elapsed: Duration::from_secs(1),
+        };
+        assert!(timeout.is_retryable());
+        assert!(!timeout.is_fatal());
+
+        let closed = AnkaiosError::ConnectionClosedError("Workload deleted".to_owned());
+        assert!(!closed.is_retryable());
+        assert!(closed.is_fatal());
+
+        assert!(AnkaiosError::from(io::Error::from(io::ErrorKind::TimedOut)).is_retryable());
+        assert!(!AnkaiosError::from(io::Error::from(io::ErrorKind::NotFound)).is_retryable());
+        assert!(AnkaiosError::PlanError(1, Box::new(timeout)).is_retryable());
+
+        let invalid = AnkaiosError::WorkloadBuilderError("invalid");
+        assert!(!invalid.is_retryable());
+        assert!(!invalid.is_fatal());
+    }
+}
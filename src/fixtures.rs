@@ -0,0 +1,121 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains deterministic fixtures of the SDK objects, for writing
+//! tests of crates built on the SDK without connecting to
+//! [Ankaios](https://eclipse-ankaios.github.io/ankaios).
+//!
+//! The module is only available with the `fixtures` feature, which is meant
+//! to be enabled for the `dev-dependencies`.
+//!
+//! # Example
+//!
+//! ## Get the workload states of the complete state fixture:
+//!
+//! ```rust
+//! use ankaios_sdk::fixtures;
+//!
+//! let complete_state = fixtures::complete_state();
+//! let workload_states = complete_state.get_workload_states();
+//! assert_eq!(workload_states.len(), 3);
+//! ```
+
+use crate::components::complete_state::generate_complete_state_proto;
+use crate::components::response::generate_test_proto_log_entries_response;
+use crate::components::workload_mod::test_helpers::generate_test_workload;
+use crate::components::workload_state_mod::generate_test_workload_states_proto;
+use crate::{CompleteState, LogEntry, Workload, WorkloadStateCollection};
+
+/// Returns a workload fixture with a runtime config, dependencies, tags,
+/// control interface access rules, a config alias and a file.
+///
+/// ## Arguments
+///
+/// * `agent_name` - The name of the agent of the workload;
+/// * `workload_name` - The name of the workload;
+/// * `runtime_name` - The name of the runtime of the workload.
+///
+/// ## Returns
+///
+/// A new [`Workload`] object.
+#[must_use]
+pub fn workload<T: Into<String>>(agent_name: T, workload_name: T, runtime_name: T) -> Workload {
+    generate_test_workload(agent_name, workload_name, runtime_name)
+}
+
+/// Returns a workload states fixture: `agent_A/nginx/1234` succeeded,
+/// `agent_B/nginx/5678` pending and `agent_B/dyn_nginx/9012` stopping.
+///
+/// ## Returns
+///
+/// A new [`WorkloadStateCollection`] object.
+#[must_use]
+pub fn workload_states() -> WorkloadStateCollection {
+    WorkloadStateCollection::new_from_proto(&generate_test_workload_states_proto())
+}
+
+/// Returns a complete state fixture, containing the workload `nginx_test` on `agent_A`,
+/// three configs, the [`workload_states`] and the connected agent `agent_A`.
+///
+/// ## Returns
+///
+/// A new [`CompleteState`] object.
+#[must_use]
+pub fn complete_state() -> CompleteState {
+    CompleteState::new_from_proto(generate_complete_state_proto())
+}
+
+/// Returns a log entries fixture, one of `agent_A/workload_A/id_a`
+/// and one of `agent_B/workload_B/id_b`.
+///
+/// ## Returns
+///
+/// A [Vec] of [`LogEntry`] objects.
+#[must_use]
+pub fn log_entries() -> Vec<LogEntry> {
+    generate_test_proto_log_entries_response()
+        .log_entries
+        .into_iter()
+        .map(LogEntry::from)
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complete_state, log_entries, workload, workload_states};
+
+    #[test]
+    fn utest_fixtures_are_deterministic() {
+        assert_eq!(
+            workload("agent_A", "nginx", "podman").to_proto(),
+            workload("agent_A", "nginx", "podman").to_proto()
+        );
+        assert_eq!(workload_states().len(), 3);
+        assert_eq!(
+            workload_states().as_mapping(),
+            workload_states().as_mapping()
+        );
+        assert_eq!(complete_state(), complete_state());
+        assert_eq!(log_entries(), log_entries());
+        assert_eq!(log_entries().len(), 2);
+    }
+}
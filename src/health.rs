@@ -0,0 +1,349 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`Probe`], which answers the liveness and readiness
+//! checks of workloads built on the SDK, e.g. from a container `HEALTHCHECK`.
+//!
+//! # Example
+//!
+//! ## Answer a readiness check:
+//!
+//! ```rust,no_run
+//! use ankaios_sdk::Ankaios;
+//! use ankaios_sdk::health::{Probe, ProbeOptions};
+//! # use tokio::runtime::Runtime;
+//! #
+//! # Runtime::new().unwrap().block_on(async {
+//! let ankaios = Ankaios::new().await.unwrap();
+//! let probe = Probe::new(ankaios, "my_workload", ProbeOptions::default());
+//!
+//! let status = probe.readiness().await;
+//! println!("Readiness: {status:?}");
+//! std::process::exit(status.exit_code());
+//! # })
+//! ```
+
+use std::sync::Mutex as StdMutex;
+use tokio::time::{Duration, Instant, timeout};
+
+use crate::ankaios::KEEPALIVE_FIELD_MASK;
+use crate::logging::sdk_log;
+use crate::{Ankaios, WorkloadStateEnum};
+
+/// The default time a probe result is reused.
+const DEFAULT_CACHE_TTL: u64 = 5; // seconds
+/// The default maximum time a probe waits for Ankaios.
+const DEFAULT_PROBE_TIMEOUT: u64 = 2; // seconds
+
+/// The result of a probe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProbeStatus {
+    /// The probed condition holds.
+    Healthy,
+    /// The probed condition does not hold. Contains the reason.
+    Unhealthy(String),
+}
+
+impl ProbeStatus {
+    /// Checks whether the probed condition holds.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if [`Healthy`](ProbeStatus::Healthy), `false` otherwise.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        *self == ProbeStatus::Healthy
+    }
+
+    /// Returns the exit code expected from a container `HEALTHCHECK` command.
+    ///
+    /// ## Returns
+    ///
+    /// `0` if [`Healthy`](ProbeStatus::Healthy), `1` otherwise.
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        i32::from(!self.is_healthy())
+    }
+}
+
+/// The options of a [`Probe`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProbeOptions {
+    /// The time a probe result is reused before Ankaios is asked again.
+    pub cache_ttl: Duration,
+    /// The maximum time a probe waits for Ankaios before reporting the check as unhealthy.
+    pub timeout: Duration,
+}
+
+impl Default for ProbeOptions {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(DEFAULT_CACHE_TTL),
+            timeout: Duration::from_secs(DEFAULT_PROBE_TIMEOUT),
+        }
+    }
+}
+
+/// Helper struct answering the liveness and readiness checks of a workload.
+///
+/// The liveness check reports whether the agent answers through the Control Interface,
+/// the readiness check whether the workload itself is running. Both answer within the
+/// [`timeout`](ProbeOptions::timeout) and reuse their result for the
+/// [`cache_ttl`](ProbeOptions::cache_ttl), so frequent checks do not load Ankaios.
+pub struct Probe {
+    /// The [Ankaios] object used to answer the checks.
+    ankaios: Ankaios,
+    /// The name of the probed workload.
+    workload_name: String,
+    /// The options of the probe.
+    options: ProbeOptions,
+    /// The last result of the liveness check and when it was made.
+    last_liveness: StdMutex<Option<(Instant, ProbeStatus)>>,
+    /// The last result of the readiness check and when it was made.
+    last_readiness: StdMutex<Option<(Instant, ProbeStatus)>>,
+}
+
+impl Probe {
+    /// Creates a new `Probe`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to answer the checks;
+    /// * `workload_name` - The name of the probed workload, usually the one of the caller;
+    /// * `options` - The [`ProbeOptions`] of the probe.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`Probe`] object.
+    #[must_use]
+    pub fn new<T: Into<String>>(ankaios: Ankaios, workload_name: T, options: ProbeOptions) -> Self {
+        let mut probe_ankaios = ankaios;
+        probe_ankaios.timeout = options.timeout;
+        Self {
+            ankaios: probe_ankaios,
+            workload_name: workload_name.into(),
+            options,
+            last_liveness: StdMutex::new(None),
+            last_readiness: StdMutex::new(None),
+        }
+    }
+
+    /// Checks whether the agent answers through the Control Interface.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ProbeStatus`] of the check, possibly reused from a previous check.
+    pub async fn liveness(&self) -> ProbeStatus {
+        if let Some(status) = self.cached(&self.last_liveness) {
+            return status;
+        }
+        let status = match timeout(
+            self.options.timeout,
+            // The state cache would answer for an agent that stopped responding
+            self.ankaios
+                .get_state_uncached(vec![KEEPALIVE_FIELD_MASK.to_owned()]),
+        )
+        .await
+        {
+            Ok(Ok(_)) => ProbeStatus::Healthy,
+            Ok(Err(err)) => ProbeStatus::Unhealthy(format!("The agent is not reachable: {err}")),
+            Err(_) => ProbeStatus::Unhealthy("The agent did not answer in time.".to_owned()),
+        };
+        self.store(&self.last_liveness, status)
+    }
+
+    /// Checks whether the workload is running. If the workload has several instances,
+    /// it is enough that one of them is running.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ProbeStatus`] of the check, possibly reused from a previous check.
+    pub async fn readiness(&self) -> ProbeStatus {
+        if let Some(status) = self.cached(&self.last_readiness) {
+            return status;
+        }
+        let status = match timeout(
+            self.options.timeout,
+            self.ankaios
+                .get_workload_states_for_name(self.workload_name.clone()),
+        )
+        .await
+        {
+            Ok(Ok(workload_states)) => {
+                let states: Vec<WorkloadStateEnum> = workload_states
+                    .as_list()
+                    .into_iter()
+                    .map(|workload_state| workload_state.execution_state.state)
+                    .collect();
+                if states.contains(&WorkloadStateEnum::Running) {
+                    ProbeStatus::Healthy
+                } else if states.is_empty() {
+                    ProbeStatus::Unhealthy(format!(
+                        "Workload '{}' has no state.",
+                        self.workload_name
+                    ))
+                } else {
+                    ProbeStatus::Unhealthy(format!(
+                        "Workload '{}' is not running: {states:?}.",
+                        self.workload_name
+                    ))
+                }
+            }
+            Ok(Err(err)) => {
+                ProbeStatus::Unhealthy(format!("The workload state is not available: {err}"))
+            }
+            Err(_) => ProbeStatus::Unhealthy("The agent did not answer in time.".to_owned()),
+        };
+        self.store(&self.last_readiness, status)
+    }
+
+    /// Returns the last result of a check, if it is still fresh.
+    ///
+    /// ## Arguments
+    ///
+    /// * `last_status` - The last result of the check.
+    ///
+    /// ## Returns
+    ///
+    /// The cached [`ProbeStatus`], or [None] if missing or expired.
+    fn cached(
+        &self,
+        last_status: &StdMutex<Option<(Instant, ProbeStatus)>>,
+    ) -> Option<ProbeStatus> {
+        last_status
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_ref()
+            .filter(|(checked, _)| checked.elapsed() < self.options.cache_ttl)
+            .map(|(_, status)| status.clone())
+    }
+
+    /// Stores the result of a check.
+    ///
+    /// ## Arguments
+    ///
+    /// * `last_status` - The last result of the check;
+    /// * `status` - The new result of the check.
+    ///
+    /// ## Returns
+    ///
+    /// The given [`ProbeStatus`].
+    fn store(
+        &self,
+        last_status: &StdMutex<Option<(Instant, ProbeStatus)>>,
+        status: ProbeStatus,
+    ) -> ProbeStatus {
+        if !status.is_healthy() {
+            sdk_log!(
+                warn,
+                "Probe of workload '{}' failed: {status:?}",
+                self.workload_name
+            );
+        }
+        *last_status.lock().unwrap_or_else(|_| unreachable!()) =
+            Some((Instant::now(), status.clone()));
+        status
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use tokio::time::Duration;
+
+    use super::{Probe, ProbeOptions, ProbeStatus};
+    use crate::CompleteState;
+    use crate::ankaios::generate_test_ankaios;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{GetStateRequest, Request};
+    use crate::components::response::{Response, ResponseType};
+
+    #[test]
+    fn utest_probe_status() {
+        assert!(ProbeStatus::Healthy.is_healthy());
+        assert_eq!(ProbeStatus::Healthy.exit_code(), 0);
+        assert!(!ProbeStatus::Unhealthy("reason".to_owned()).is_healthy());
+        assert_eq!(ProbeStatus::Unhealthy("reason".to_owned()).exit_code(), 1);
+    }
+
+    #[tokio::test]
+    async fn utest_probe_readiness() {
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let probe = Arc::new(Probe::new(ank, "nginx", ProbeOptions::default()));
+
+        let probe_clone = Arc::clone(&probe);
+        let method_handle = tokio::spawn(async move { probe_clone.readiness().await });
+
+        // None of the instances of the workload is running
+        let request = request_receiver.await.unwrap();
+        response_sender
+            .send(Response {
+                content: ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                    generate_complete_state_proto(),
+                ))),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+        let status = method_handle.await.unwrap();
+        assert!(matches!(status, ProbeStatus::Unhealthy(_)));
+
+        // The result is reused without asking again
+        assert_eq!(probe.readiness().await, status);
+    }
+
+    #[tokio::test]
+    async fn utest_probe_liveness_timeout() {
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(|_request: GetStateRequest| Ok(()));
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+        let probe = Probe::new(
+            ank,
+            "nginx",
+            ProbeOptions {
+                cache_ttl: Duration::from_secs(60),
+                timeout: Duration::from_millis(10),
+            },
+        );
+
+        assert!(matches!(probe.liveness().await, ProbeStatus::Unhealthy(_)));
+        assert!(!probe.liveness().await.is_healthy());
+    }
+}
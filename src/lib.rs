@@ -127,14 +127,14 @@
 //! examples folder by running `./run_example.sh hello_ankaios`.
 //!
 //! ```rust,no_run
-//! use ankaios_sdk::{Ankaios, AnkaiosError, Workload, WorkloadStateEnum};
+//! use ankaios_sdk::prelude::*;
 //! use tokio::time::Duration;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     // Create a new Ankaios object.
 //!     // The connection to the control interface is automatically done at this step.
-//!     let mut ank = Ankaios::new().await.expect("Failed to initialize");
+//!     let ank = Ankaios::new().await.expect("Failed to initialize");
 //!
 //!     // Create a new workload
 //!     let workload = Workload::builder()
@@ -167,7 +167,7 @@
 //!         Ok(_) => {
 //!             println!("Workload reached the RUNNING state.");
 //!         }
-//!         Err(AnkaiosError::TimeoutError(_)) => {
+//!         Err(AnkaiosError::TimeoutError { .. }) => {
 //!             println!("Workload didn't reach the required state in time.");
 //!         }
 //!         Err(err) => {
@@ -212,21 +212,130 @@ mod docs;
 pub mod extensions;
 
 mod errors;
-pub use errors::AnkaiosError;
+pub use errors::{AnkaiosError, Result};
 
 mod components;
 
-pub use components::complete_state::{AgentAttributes, CompleteState};
-pub use components::control_interface::ControlInterfaceState;
+pub use components::audit_log::AuditEntry;
+pub use components::complete_state::{
+    AgentAttributes, AgentInfo, AgentStatus, CompleteState, SUPPORTED_API_VERSIONS,
+};
+pub use components::config_value::ConfigValue;
+pub use components::control_interface::{ConnectionInfo, ControlInterfaceState, MAX_MESSAGE_SIZE};
+pub use components::control_socket::{ANKAIOS_CONTROL_SOCKET_ENV, ControlSocketAddress};
 pub use components::event_types::{EventEntry, EventsCampaignResponse};
-pub use components::log_types::{LogCampaignResponse, LogEntry, LogResponse, LogsRequest};
-pub use components::manifest::Manifest;
+pub use components::log_types::{
+    LogCampaignInfo, LogCampaignResponse, LogEntry, LogOverflowPolicy, LogResponse, LogTail,
+    LogsRequest, TailedLogEntry,
+};
+pub use components::manifest::{
+    Manifest, ManifestConfigsMut, ManifestMergePolicy, ManifestWorkloadsMut,
+};
+pub use components::manifest_schema::{MANIFEST_SCHEMA, SchemaViolation};
+pub use components::mask::{Mask, MaskSet};
+pub use components::placement::PlacementStrategy;
 pub use components::request::{GetStateRequest, Request, UpdateStateRequest};
-pub use components::response::{Response, UpdateStateSuccess};
-pub use components::workload_mod::{File, FileContent, Workload, WorkloadBuilder};
+pub use components::response::{
+    ApplyWorkloadResult, BatchResult, ConfigUpdateResult, Ensured, Response, TappedResponse,
+    UpdateStateSuccess,
+};
+pub use components::server_capabilities::ServerCapabilities;
+pub use components::state_cache::CachePolicy;
+pub use components::state_query::StateQuery;
+pub use components::workload_mod::{
+    File, FileContent, MAX_FILE_SIZE, MaybeSecret, Secret, Workload, WorkloadBuilder,
+};
 pub use components::workload_state_mod::{
-    WorkloadInstanceName, WorkloadState, WorkloadStateCollection, WorkloadStateEnum,
+    ClusterSummary, ProcessOutcome, WorkloadDiagnosis, WorkloadExecutionState,
+    WorkloadInstanceName, WorkloadIssue, WorkloadState, WorkloadStateCollection,
+    WorkloadStateCounts, WorkloadStateEnum, WorkloadStatesFilter, WorkloadStatesWatch,
+    WorkloadSubStateEnum,
 };
 
 mod ankaios;
-pub use ankaios::Ankaios;
+pub use ankaios::{Ankaios, AnkaiosBuilder, AnkaiosHandle};
+
+mod plan;
+pub use plan::{Plan, PlanProgress, PlanStep};
+pub use tokio_util::sync::CancellationToken;
+
+mod scope;
+pub use scope::AnkaiosScope;
+
+mod snapshot;
+pub use snapshot::StateSnapshotter;
+
+mod supervisor;
+pub use supervisor::{SupervisorEvent, SupervisorPolicy, WorkloadSupervisor};
+
+mod log_aggregator;
+#[cfg(feature = "log-rotation")]
+pub use log_aggregator::LogRotation;
+pub use log_aggregator::{LogAggregator, LogAggregatorConfig};
+
+pub mod bridges;
+
+pub mod health;
+
+pub mod logging;
+
+#[cfg(feature = "rest-facade")]
+pub mod rest_facade;
+
+/// The types used by most of the applications, to be imported at once:
+///
+/// ```rust
+/// use ankaios_sdk::prelude::*;
+///
+/// fn build_workload() -> Result<Workload> {
+///     Workload::builder()
+///         .workload_name("nginx")
+///         .agent_name("agent_A")
+///         .runtime("podman")
+///         .runtime_config("image: docker.io/library/nginx")
+///         .build()
+/// }
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Ankaios, AnkaiosBuilder, AnkaiosError, CancellationToken, CompleteState, ConfigValue,
+        LogResponse, LogsRequest, Manifest, Result, UpdateStateSuccess, Workload, WorkloadBuilder,
+        WorkloadExecutionState, WorkloadInstanceName, WorkloadStateEnum, WorkloadSubStateEnum,
+    };
+}
+
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench;
+
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+
+/// The generated [Ankaios](https://eclipse-ankaios.github.io/ankaios) proto types.
+///
+/// These types are not covered by the stability guarantees of the SDK.
+#[cfg(feature = "unstable-proto")]
+pub use ankaios_api::ank_base;
+
+/// The generated [Ankaios](https://eclipse-ankaios.github.io/ankaios) proto types,
+/// available with the `proto-types` feature.
+///
+/// ## Stability
+///
+/// The module paths are stable, but the types themselves are generated from the
+/// `.proto` files of the supported Ankaios version. They follow the Ankaios API and can
+/// change with any release of the SDK that updates the supported Ankaios version, without
+/// a major version bump of the SDK.
+///
+/// ## Conversions
+///
+/// The SDK types convert from and into their proto with [From], e.g. [`Workload`] together
+/// with its name, [`WorkloadInstanceName`], [`WorkloadExecutionState`], [`WorkloadState`]
+/// and [`WorkloadStateCollection`].
+#[cfg(feature = "proto-types")]
+pub mod proto {
+    pub use crate::ankaios_api::{ank_base, control_api};
+}
@@ -0,0 +1,723 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`LogAggregator`], which records the log entries of
+//! one or more log campaigns into a size-bounded buffer, e.g. as a black-box recorder.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "log-rotation")]
+use flate2::{Compression, write::GzEncoder};
+#[cfg(feature = "log-rotation")]
+use std::{fs, io, io::Write, mem, path::PathBuf};
+
+use crate::logging::sdk_log;
+use crate::{AnkaiosError, LogCampaignResponse, LogEntry, LogResponse};
+
+/// The default maximum size of the recorded log messages, in bytes.
+const DEFAULT_MAX_BYTES: usize = 1024 * 1024;
+#[cfg(feature = "log-rotation")]
+/// The prefix of the names of the rotated log files.
+const ROTATED_FILE_PREFIX: &str = "logs-";
+#[cfg(feature = "log-rotation")]
+/// The extension of the rotated log files.
+const ROTATED_FILE_EXTENSION: &str = ".log.gz";
+
+/// The configuration of a [`LogAggregator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogAggregatorConfig {
+    /// The maximum size of the recorded log messages, in bytes (default: 1 MiB).
+    pub max_bytes: usize,
+    /// The maximum size of the recorded log messages of a single workload, in bytes (default: None).
+    /// When reached, the oldest entries of the workload are dropped.
+    pub workload_quota: Option<usize>,
+    /// The rotation of the recorded entries to gzip files when `max_bytes` is reached (default: None).
+    /// Without rotation, the oldest entries are dropped instead.
+    #[cfg(feature = "log-rotation")]
+    pub rotation: Option<LogRotation>,
+}
+
+impl Default for LogAggregatorConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+            workload_quota: None,
+            #[cfg(feature = "log-rotation")]
+            rotation: None,
+        }
+    }
+}
+
+/// The rotation of the entries recorded by a [`LogAggregator`] to gzip files,
+/// available with the `log-rotation` feature.
+#[cfg(feature = "log-rotation")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRotation {
+    /// The directory the gzip files are written to.
+    pub directory: PathBuf,
+    /// The maximum number of gzip files kept, the oldest files are deleted first.
+    /// At least the last written file is kept.
+    pub max_files: usize,
+}
+
+#[cfg(feature = "log-rotation")]
+impl LogRotation {
+    /// Finds the sequence number following the rotated files already in the directory,
+    /// so that the files of a previous run are not overwritten.
+    ///
+    /// ## Returns
+    ///
+    /// The sequence number of the next rotated file.
+    fn next_sequence(&self) -> usize {
+        let dir_entries = match fs::read_dir(&self.directory) {
+            Ok(dir_entries) => dir_entries,
+            Err(err) => {
+                if err.kind() != io::ErrorKind::NotFound {
+                    sdk_log!(
+                        warn,
+                        "Could not read the rotated logs in '{}': {err}",
+                        self.directory.display()
+                    );
+                }
+                return 0;
+            }
+        };
+        dir_entries
+            .filter_map(Result::ok)
+            .filter_map(|dir_entry| {
+                dir_entry
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix(ROTATED_FILE_PREFIX)?
+                    .strip_suffix(ROTATED_FILE_EXTENSION)?
+                    .parse::<usize>()
+                    .ok()
+            })
+            .max()
+            .map_or(0, |sequence| sequence + 1)
+    }
+}
+
+/// The recorded entries of a [`LogAggregator`].
+#[derive(Debug, Default)]
+struct RecordedLogs {
+    /// The recorded entries, the oldest first.
+    entries: VecDeque<LogEntry>,
+    /// The size of the recorded log messages, in bytes.
+    size: usize,
+    /// The size of the recorded log messages by workload name, in bytes.
+    workload_sizes: HashMap<String, usize>,
+    /// The number of entries dropped because a limit was reached.
+    dropped_count: usize,
+    /// The sequence number of the next rotated file.
+    #[cfg(feature = "log-rotation")]
+    next_file: usize,
+    /// The entries taken out of the buffer and not yet written, by sequence number of their file.
+    #[cfg(feature = "log-rotation")]
+    pending_rotations: Vec<(usize, VecDeque<LogEntry>)>,
+}
+
+impl RecordedLogs {
+    /// Removes the entry at the given position.
+    fn remove(&mut self, index: usize) {
+        if let Some(entry) = self.entries.remove(index) {
+            self.size -= entry.message.len();
+            if let Some(workload_size) = self
+                .workload_sizes
+                .get_mut(&entry.workload_name.workload_name)
+            {
+                *workload_size -= entry.message.len();
+            }
+        }
+    }
+
+    /// Removes all the entries.
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.workload_sizes.clear();
+        self.size = 0;
+    }
+}
+
+/// Struct that records the log entries of log campaigns into a buffer bounded in size,
+/// with optional quotas per workload and optional rotation to gzip files.
+///
+/// The aggregator can be cloned to consume several log campaigns concurrently,
+/// all the clones share the same buffer.
+///
+/// # Example
+///
+/// ## Record the logs of a workload:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, LogAggregator, LogAggregatorConfig, LogsRequest, WorkloadInstanceName};
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// # let instance_name: WorkloadInstanceName = WorkloadInstanceName::default();
+/// let aggregator = LogAggregator::new(LogAggregatorConfig {
+///     workload_quota: Some(64 * 1024),
+///     ..Default::default()
+/// });
+/// let mut log_campaign = ankaios
+///     .request_logs(LogsRequest {
+///         workload_names: vec![instance_name],
+///         follow: true,
+///         ..Default::default()
+///     })
+///     .await
+///     .unwrap();
+///
+/// let recorder = aggregator.clone();
+/// tokio::spawn(async move { recorder.consume(&mut log_campaign).await });
+///
+/// // Later, e.g. when a diagnosis is requested
+/// for entry in aggregator.entries() {
+///     println!("{}: {}", entry.workload_name.workload_name, entry.message);
+/// }
+/// # })
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogAggregator {
+    /// The configuration of the aggregator.
+    config: Arc<LogAggregatorConfig>,
+    /// The recorded entries, shared by all the clones.
+    recorded: Arc<Mutex<RecordedLogs>>,
+}
+
+impl LogAggregator {
+    /// Creates a new empty `LogAggregator`.
+    /// With rotation, the numbering of the files resumes after the files already in the directory.
+    ///
+    /// ## Arguments
+    ///
+    /// * `config` - The [`LogAggregatorConfig`] of the aggregator.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`LogAggregator`] instance.
+    #[must_use]
+    pub fn new(config: LogAggregatorConfig) -> Self {
+        let recorded = RecordedLogs {
+            #[cfg(feature = "log-rotation")]
+            next_file: config
+                .rotation
+                .as_ref()
+                .map_or(0, LogRotation::next_sequence),
+            ..Default::default()
+        };
+        Self {
+            config: Arc::new(config),
+            recorded: Arc::new(Mutex::new(recorded)),
+        }
+    }
+
+    /// Records the log entries of a log campaign until the logs of all the accepted
+    /// workloads stopped or the log campaign was stopped.
+    ///
+    /// ## Arguments
+    ///
+    /// * `log_campaign` - The [`LogCampaignResponse`] of the log campaign to record.
+    pub async fn consume(&self, log_campaign: &mut LogCampaignResponse) {
+        let mut running: HashSet<_> = log_campaign
+            .accepted_workload_names
+            .iter()
+            .cloned()
+            .collect();
+        while let Some(log_response) = log_campaign.logs_receiver.recv().await {
+            match log_response {
+                LogResponse::LogEntries(log_entries) => {
+                    for log_entry in log_entries {
+                        self.record_entry(log_entry);
+                    }
+                    #[cfg(feature = "log-rotation")]
+                    self.write_rotations_in_background().await;
+                }
+                LogResponse::LogsStopResponse(instance_name) => {
+                    running.remove(&instance_name);
+                    if running.is_empty() {
+                        break;
+                    }
+                }
+                LogResponse::Resubscribed => {
+                    sdk_log!(
+                        debug,
+                        "Log campaign resubscribed, log entries might have been lost."
+                    );
+                }
+            }
+        }
+    }
+
+    /// Records a log entry, making room for it as configured.
+    ///
+    /// Entries larger than the quota of the workload or the size of the buffer are dropped.
+    ///
+    /// ## Arguments
+    ///
+    /// * `log_entry` - The [`LogEntry`] to record.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the entries could not be rotated.
+    ///   The entry is recorded anyway and the entries that could not be rotated are counted as dropped.
+    pub fn record(&self, log_entry: LogEntry) -> Result<(), AnkaiosError> {
+        self.record_entry(log_entry);
+        #[cfg(feature = "log-rotation")]
+        self.write_rotations()?;
+        Ok(())
+    }
+
+    /// Records a log entry, making room for it as configured.
+    /// With rotation, the entries making room are only taken out of the buffer,
+    /// see [`write_rotations`](LogAggregator::write_rotations).
+    ///
+    /// ## Arguments
+    ///
+    /// * `log_entry` - The [`LogEntry`] to record.
+    fn record_entry(&self, log_entry: LogEntry) {
+        let entry_size = log_entry.message.len();
+        let workload_name = log_entry.workload_name.workload_name.clone();
+        let mut recorded = self.recorded.lock().unwrap_or_else(|_| unreachable!());
+        let quota = self.config.workload_quota.unwrap_or(usize::MAX);
+        if entry_size > self.config.max_bytes || entry_size > quota {
+            recorded.dropped_count += 1;
+            return;
+        }
+
+        while recorded
+            .workload_sizes
+            .get(&workload_name)
+            .is_some_and(|workload_size| workload_size + entry_size > quota)
+        {
+            let Some(index) = recorded
+                .entries
+                .iter()
+                .position(|entry| entry.workload_name.workload_name == workload_name)
+            else {
+                break;
+            };
+            recorded.remove(index);
+            recorded.dropped_count += 1;
+        }
+
+        #[cfg(feature = "log-rotation")]
+        if self.config.rotation.is_some() && recorded.size + entry_size > self.config.max_bytes {
+            let sequence = recorded.next_file;
+            recorded.next_file += 1;
+            let entries = mem::take(&mut recorded.entries);
+            recorded.clear();
+            recorded.pending_rotations.push((sequence, entries));
+        }
+        while recorded.size + entry_size > self.config.max_bytes {
+            recorded.remove(0);
+            recorded.dropped_count += 1;
+        }
+
+        recorded.size += entry_size;
+        *recorded.workload_sizes.entry(workload_name).or_default() += entry_size;
+        recorded.entries.push_back(log_entry);
+    }
+
+    /// Writes the rotated entries on a blocking thread, so that the async consumer is not blocked.
+    /// Nothing is spawned if no rotation is pending.
+    #[cfg(feature = "log-rotation")]
+    async fn write_rotations_in_background(&self) {
+        if self
+            .recorded
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .pending_rotations
+            .is_empty()
+        {
+            return;
+        }
+        let aggregator = self.clone();
+        match tokio::task::spawn_blocking(move || aggregator.write_rotations()).await {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => sdk_log!(warn, "Could not rotate the recorded logs: {err}"),
+            Err(err) => sdk_log!(warn, "The rotation of the recorded logs stopped: {err}"),
+        }
+    }
+
+    /// Writes the entries taken out of the buffer to their gzip files, outside the lock of the buffer.
+    /// For each written file, the file that exceeds `max_files` is deleted.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if a file could not be written or deleted,
+    ///   in which case the entries of the file are counted as dropped.
+    #[cfg(feature = "log-rotation")]
+    fn write_rotations(&self) -> Result<(), AnkaiosError> {
+        let Some(rotation) = self.config.rotation.as_ref() else {
+            return Ok(());
+        };
+        let pending_rotations = mem::take(
+            &mut self
+                .recorded
+                .lock()
+                .unwrap_or_else(|_| unreachable!())
+                .pending_rotations,
+        );
+
+        let mut result = Ok(());
+        for (sequence, entries) in pending_rotations {
+            if let Err(err) = Self::write_rotated_file(rotation, sequence, &entries) {
+                self.recorded
+                    .lock()
+                    .unwrap_or_else(|_| unreachable!())
+                    .dropped_count += entries.len();
+                result = Err(err);
+            }
+        }
+        result
+    }
+
+    /// Writes entries to a new gzip file and deletes the file that exceeds `max_files`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rotation` - The [`LogRotation`] configuration;
+    /// * `sequence` - The sequence number of the file;
+    /// * `entries` - The entries to write.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the file could not be written or the expired file deleted.
+    #[cfg(feature = "log-rotation")]
+    fn write_rotated_file(
+        rotation: &LogRotation,
+        sequence: usize,
+        entries: &VecDeque<LogEntry>,
+    ) -> Result<(), AnkaiosError> {
+        fs::create_dir_all(&rotation.directory)?;
+        let path = rotation.directory.join(Self::rotated_file_name(sequence));
+        let mut encoder = GzEncoder::new(fs::File::create(&path)?, Compression::default());
+        for entry in entries {
+            writeln!(encoder, "{}: {}", entry.workload_name, entry.message)?;
+        }
+        encoder.finish()?;
+        sdk_log!(debug, "Rotated the recorded logs to '{}'.", path.display());
+
+        if let Some(expired_sequence) = sequence.checked_sub(rotation.max_files.max(1)) {
+            fs::remove_file(
+                rotation
+                    .directory
+                    .join(Self::rotated_file_name(expired_sequence)),
+            )
+            .or_else(|err| {
+                // The file might have been deleted by hand
+                if err.kind() == io::ErrorKind::NotFound {
+                    Ok(())
+                } else {
+                    Err(err)
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the name of a rotated file.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sequence` - The sequence number of the file.
+    ///
+    /// ## Returns
+    ///
+    /// The name of the file.
+    #[cfg(feature = "log-rotation")]
+    fn rotated_file_name(sequence: usize) -> String {
+        format!("{ROTATED_FILE_PREFIX}{sequence:06}{ROTATED_FILE_EXTENSION}")
+    }
+
+    /// Returns the recorded entries.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of the recorded [`LogEntry`] objects, the oldest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.recorded
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .entries
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the recorded entries of a workload.
+    ///
+    /// ## Arguments
+    ///
+    /// * `workload_name` - The name of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// A [Vec] of the recorded [`LogEntry`] objects of the workload, the oldest first.
+    #[must_use]
+    pub fn entries_for(&self, workload_name: &str) -> Vec<LogEntry> {
+        self.recorded
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .entries
+            .iter()
+            .filter(|entry| entry.workload_name.workload_name == workload_name)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the size of the recorded log messages.
+    ///
+    /// ## Returns
+    ///
+    /// The size in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.recorded.lock().unwrap_or_else(|_| unreachable!()).size
+    }
+
+    /// Returns the number of entries dropped because a limit was reached.
+    /// Rotated entries are not counted.
+    ///
+    /// ## Returns
+    ///
+    /// The number of dropped entries.
+    #[must_use]
+    pub fn dropped_count(&self) -> usize {
+        self.recorded
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .dropped_count
+    }
+
+    /// Removes all the recorded entries.
+    pub fn clear(&self) {
+        self.recorded
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clear();
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::{LogAggregator, LogAggregatorConfig};
+    use crate::{LogCampaignResponse, LogEntry, LogResponse, WorkloadInstanceName};
+
+    fn generate_log_entry(workload_name: &str, message: &str) -> LogEntry {
+        LogEntry {
+            workload_name: WorkloadInstanceName::new(
+                "agent_A".to_owned(),
+                workload_name.to_owned(),
+                "1234".to_owned(),
+            ),
+            message: message.to_owned(),
+        }
+    }
+
+    fn messages(entries: &[LogEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.message.as_str()).collect()
+    }
+
+    #[test]
+    fn utest_log_aggregator_bounded_size() {
+        let aggregator = LogAggregator::new(LogAggregatorConfig {
+            max_bytes: 10,
+            ..Default::default()
+        });
+        for message in ["aaaa", "bbbb", "cccc", "dddddddddddd"] {
+            aggregator
+                .record(generate_log_entry("workload_A", message))
+                .unwrap();
+        }
+
+        assert_eq!(messages(&aggregator.entries()), vec!["bbbb", "cccc"]);
+        assert_eq!(aggregator.size(), 8);
+        assert_eq!(aggregator.dropped_count(), 2);
+
+        aggregator.clear();
+        assert!(aggregator.entries().is_empty());
+        assert_eq!(aggregator.size(), 0);
+    }
+
+    #[test]
+    fn utest_log_aggregator_workload_quota() {
+        let aggregator = LogAggregator::new(LogAggregatorConfig {
+            max_bytes: 100,
+            workload_quota: Some(8),
+            ..Default::default()
+        });
+        aggregator
+            .record(generate_log_entry("workload_A", "a1a1"))
+            .unwrap();
+        aggregator
+            .record(generate_log_entry("workload_B", "b1b1"))
+            .unwrap();
+        aggregator
+            .record(generate_log_entry("workload_A", "a2a2"))
+            .unwrap();
+        aggregator
+            .record(generate_log_entry("workload_A", "a3a3"))
+            .unwrap();
+
+        assert_eq!(
+            messages(&aggregator.entries()),
+            vec!["b1b1", "a2a2", "a3a3"]
+        );
+        assert_eq!(
+            messages(&aggregator.entries_for("workload_A")),
+            vec!["a2a2", "a3a3"]
+        );
+        assert_eq!(aggregator.dropped_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn utest_log_aggregator_consume() {
+        let (logs_sender, logs_receiver) = mpsc::channel(10);
+        let instance_name = generate_log_entry("workload_A", "").workload_name;
+        let mut log_campaign = LogCampaignResponse::new(
+            "request_id".to_owned(),
+            vec![instance_name.clone()],
+            logs_receiver,
+        );
+        let aggregator = LogAggregator::new(LogAggregatorConfig::default());
+
+        logs_sender
+            .send(LogResponse::LogEntries(vec![
+                generate_log_entry("workload_A", "first"),
+                generate_log_entry("workload_A", "second"),
+            ]))
+            .await
+            .unwrap();
+        logs_sender.send(LogResponse::Resubscribed).await.unwrap();
+        logs_sender
+            .send(LogResponse::LogsStopResponse(instance_name))
+            .await
+            .unwrap();
+
+        // Returns once the logs of the accepted workload stopped
+        aggregator.consume(&mut log_campaign).await;
+        assert_eq!(messages(&aggregator.entries()), vec!["first", "second"]);
+    }
+
+    #[cfg(feature = "log-rotation")]
+    #[test]
+    fn utest_log_aggregator_rotation() {
+        use super::LogRotation;
+        use std::io::Read;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let aggregator = LogAggregator::new(LogAggregatorConfig {
+            max_bytes: 8,
+            workload_quota: None,
+            rotation: Some(LogRotation {
+                directory: tmpdir.path().to_path_buf(),
+                max_files: 1,
+            }),
+        });
+        for message in ["aaaa", "bbbb", "cccc", "dddd", "eeee"] {
+            aggregator
+                .record(generate_log_entry("workload_A", message))
+                .unwrap();
+        }
+
+        // Two rotations, only the last file is kept
+        assert_eq!(messages(&aggregator.entries()), vec!["eeee"]);
+        assert_eq!(aggregator.dropped_count(), 0);
+        assert!(!tmpdir.path().join("logs-000000.log.gz").exists());
+        let mut content = String::new();
+        flate2::read::GzDecoder::new(
+            std::fs::File::open(tmpdir.path().join("logs-000001.log.gz")).unwrap(),
+        )
+        .read_to_string(&mut content)
+        .unwrap();
+        assert_eq!(
+            content,
+            "workload_A.1234.agent_A: cccc\nworkload_A.1234.agent_A: dddd\n"
+        );
+    }
+
+    #[cfg(feature = "log-rotation")]
+    #[test]
+    fn utest_log_aggregator_rotation_keeps_last_file() {
+        use super::LogRotation;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let aggregator = LogAggregator::new(LogAggregatorConfig {
+            max_bytes: 4,
+            workload_quota: None,
+            rotation: Some(LogRotation {
+                directory: tmpdir.path().to_path_buf(),
+                max_files: 0,
+            }),
+        });
+        for message in ["aaaa", "bbbb", "cccc"] {
+            aggregator
+                .record(generate_log_entry("workload_A", message))
+                .unwrap();
+        }
+
+        assert_eq!(messages(&aggregator.entries()), vec!["cccc"]);
+        assert!(!tmpdir.path().join("logs-000000.log.gz").exists());
+        assert!(tmpdir.path().join("logs-000001.log.gz").exists());
+    }
+
+    #[cfg(feature = "log-rotation")]
+    #[test]
+    fn utest_log_aggregator_rotation_resumes_numbering() {
+        use super::LogRotation;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let config = LogAggregatorConfig {
+            max_bytes: 4,
+            workload_quota: None,
+            rotation: Some(LogRotation {
+                directory: tmpdir.path().to_path_buf(),
+                max_files: 2,
+            }),
+        };
+        let aggregator = LogAggregator::new(config.clone());
+        for message in ["aaaa", "bbbb", "cccc"] {
+            aggregator
+                .record(generate_log_entry("workload_A", message))
+                .unwrap();
+        }
+        assert!(tmpdir.path().join("logs-000001.log.gz").exists());
+
+        // A new run does not overwrite the files of the previous one
+        let aggregator = LogAggregator::new(config);
+        for message in ["dddd", "eeee"] {
+            aggregator
+                .record(generate_log_entry("workload_A", message))
+                .unwrap();
+        }
+        assert!(!tmpdir.path().join("logs-000000.log.gz").exists());
+        assert!(tmpdir.path().join("logs-000001.log.gz").exists());
+        assert!(tmpdir.path().join("logs-000002.log.gz").exists());
+    }
+}
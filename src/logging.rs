@@ -0,0 +1,238 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module controls where the diagnostics of the SDK are emitted.
+//!
+//! By default, the SDK logs through the [`log`](https://docs.rs/log) crate, so a
+//! global logger like `env_logger` has to be installed to see the messages.
+//! With the `tracing` feature, the messages are emitted as
+//! [`tracing`](https://docs.rs/tracing) events instead.
+//!
+//! Environments without a global logger can install a sink with [`set_log_sink`],
+//! which then receives all the messages of the SDK instead of the backend.
+//!
+//! # Example
+//!
+//! ## Capture the SDK diagnostics with a callback:
+//!
+//! ```rust
+//! use ankaios_sdk::logging::{self, LogLevel};
+//!
+//! logging::set_log_sink(|level, target, message| {
+//!     if level <= LogLevel::Warn {
+//!         eprintln!("[{level:?}] {target}: {message}");
+//!     }
+//! });
+//!
+//! // Go back to the default backend
+//! logging::clear_log_sink();
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// The severity of a message emitted by the SDK, ordered from the most to the least severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// An error that stops the current operation.
+    Error,
+    /// An unexpected situation the SDK recovers from.
+    Warn,
+    /// Information about the normal operation.
+    Info,
+    /// Information useful when debugging.
+    Debug,
+    /// Very detailed information.
+    Trace,
+}
+
+/// The callback receiving the messages of the SDK, see [`set_log_sink`].
+type LogSink = dyn Fn(LogLevel, &str, fmt::Arguments<'_>) + Send + Sync;
+
+/// The currently installed sink, [None] if the default backend is used.
+static LOG_SINK: RwLock<Option<Arc<LogSink>>> = RwLock::new(None);
+
+/// Whether a sink is installed, checked without locking [`LOG_SINK`].
+static LOG_SINK_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Installs a sink that receives all the messages of the SDK.
+///
+/// The sink replaces the default backend (`log` or `tracing`) and is called with
+/// the level, the target (the module of the SDK) and the message.
+/// Installing a new sink replaces the previous one.
+///
+/// ## Arguments
+///
+/// * `sink` - The callback to call for each message.
+pub fn set_log_sink<F>(sink: F)
+where
+    F: Fn(LogLevel, &str, fmt::Arguments<'_>) + Send + Sync + 'static,
+{
+    let mut installed_sink = LOG_SINK.write().unwrap_or_else(PoisonError::into_inner);
+    *installed_sink = Some(Arc::new(sink));
+    LOG_SINK_INSTALLED.store(true, Ordering::Release);
+}
+
+/// Removes the sink installed with [`set_log_sink`], so the messages of the SDK
+/// are emitted through the default backend again.
+pub fn clear_log_sink() {
+    let mut installed_sink = LOG_SINK.write().unwrap_or_else(PoisonError::into_inner);
+    *installed_sink = None;
+    LOG_SINK_INSTALLED.store(false, Ordering::Release);
+}
+
+#[doc(hidden)]
+/// Checks whether a sink is installed, without taking the lock of the sink.
+/// Used by the [`sdk_log`] macro to choose between the sink and the default backend.
+///
+/// ## Returns
+///
+/// `true` if a sink was installed with [`set_log_sink`].
+pub(crate) fn sink_installed() -> bool {
+    LOG_SINK_INSTALLED.load(Ordering::Acquire)
+}
+
+#[doc(hidden)]
+/// Emits a message to the installed sink.
+/// Use the [`sdk_log`] macro instead of calling it directly.
+///
+/// ## Arguments
+///
+/// * `level` - The [`LogLevel`] of the message;
+/// * `target` - The module emitting the message;
+/// * `args` - The formatted message.
+pub(crate) fn emit_to_sink(level: LogLevel, target: &str, args: fmt::Arguments<'_>) {
+    let installed_sink = LOG_SINK
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    if let Some(sink_callback) = installed_sink {
+        sink_callback(level, target, args);
+    }
+}
+
+impl From<LogLevel> for log::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => log::Level::Error,
+            LogLevel::Warn => log::Level::Warn,
+            LogLevel::Info => log::Level::Info,
+            LogLevel::Debug => log::Level::Debug,
+            LogLevel::Trace => log::Level::Trace,
+        }
+    }
+}
+
+/// Emits a message of the SDK to the installed sink or to the default backend.
+///
+/// The level is given as `error`, `warn`, `info`, `debug` or `trace`, followed
+/// by the format string and its arguments, e.g. `sdk_log!(warn, "Lost {count} messages.")`.
+/// The backend event is created at the call site, so that its target is the calling module
+/// and its level is checked before the message is formatted.
+macro_rules! sdk_log {
+    (error, $($arg:tt)+) => {
+        $crate::logging::sdk_log!(@emit Error, error, $($arg)+)
+    };
+    (warn, $($arg:tt)+) => {
+        $crate::logging::sdk_log!(@emit Warn, warn, $($arg)+)
+    };
+    (info, $($arg:tt)+) => {
+        $crate::logging::sdk_log!(@emit Info, info, $($arg)+)
+    };
+    (debug, $($arg:tt)+) => {
+        $crate::logging::sdk_log!(@emit Debug, debug, $($arg)+)
+    };
+    (trace, $($arg:tt)+) => {
+        $crate::logging::sdk_log!(@emit Trace, trace, $($arg)+)
+    };
+    (@emit $level:ident, $backend_macro:ident, $($arg:tt)+) => {
+        if $crate::logging::sink_installed() {
+            $crate::logging::emit_to_sink(
+                $crate::logging::LogLevel::$level,
+                module_path!(),
+                format_args!($($arg)+),
+            );
+        } else {
+            $crate::logging::backend_log!($backend_macro, $($arg)+);
+        }
+    };
+}
+pub(crate) use sdk_log;
+
+/// Emits a message of the SDK as a [`tracing`](https://docs.rs/tracing) event, see [`sdk_log`].
+#[cfg(feature = "tracing")]
+macro_rules! backend_log {
+    ($backend_macro:ident, $($arg:tt)+) => {
+        tracing::$backend_macro!($($arg)+)
+    };
+}
+
+/// Emits a message of the SDK through the [`log`](https://docs.rs/log) crate, see [`sdk_log`].
+#[cfg(not(feature = "tracing"))]
+macro_rules! backend_log {
+    ($backend_macro:ident, $($arg:tt)+) => {
+        log::$backend_macro!($($arg)+)
+    };
+}
+pub(crate) use backend_log;
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{LogLevel, clear_log_sink, sdk_log, set_log_sink};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn utest_log_sink_receives_messages() {
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let captured_clone = Arc::clone(&captured);
+        set_log_sink(move |level, target, message| {
+            if target == module_path!() {
+                captured_clone
+                    .lock()
+                    .unwrap()
+                    .push((level, message.to_string()));
+            }
+        });
+
+        let value = 42;
+        sdk_log!(warn, "The value is {value}.");
+        sdk_log!(trace, "Tracing.");
+        clear_log_sink();
+        sdk_log!(error, "Not captured.");
+
+        assert_eq!(
+            *captured.lock().unwrap(),
+            vec![
+                (LogLevel::Warn, "The value is 42.".to_owned()),
+                (LogLevel::Trace, "Tracing.".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn utest_log_level_order() {
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Debug < LogLevel::Trace);
+        assert_eq!(log::Level::from(LogLevel::Info), log::Level::Info);
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [Plan] struct, which describes a sequence of
+//! steps executed with [`Ankaios::execute_plan`].
+
+use std::fmt;
+use tokio::sync::mpsc;
+use tokio::time::{Duration, sleep, timeout as tokio_timeout};
+
+use crate::ankaios::CHANNEL_SIZE;
+use crate::logging::sdk_log;
+use crate::{Ankaios, AnkaiosError, Manifest, Workload, WorkloadStateEnum};
+
+/// A single step of a [Plan].
+#[derive(Debug, Clone)]
+pub enum PlanStep {
+    /// Applies a [Workload].
+    ApplyWorkload(Box<Workload>),
+    /// Deletes the workload with the given name.
+    DeleteWorkload(String),
+    /// Applies a [Manifest].
+    ApplyManifest(Manifest),
+    /// Adds or updates a config.
+    UpdateConfig {
+        /// The name of the config.
+        name: String,
+        /// The value of the config.
+        value: serde_yaml::Value,
+    },
+    /// Waits until an instance of the workload reaches the state, polling the state without the state cache.
+    WaitForWorkloadState {
+        /// The name of the workload.
+        workload_name: String,
+        /// The [`WorkloadStateEnum`] to wait for.
+        state: WorkloadStateEnum,
+    },
+}
+
+impl fmt::Display for PlanStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanStep::ApplyWorkload(workload) => write!(f, "apply workload '{}'", workload.name),
+            PlanStep::DeleteWorkload(workload_name) => {
+                write!(f, "delete workload '{workload_name}'")
+            }
+            PlanStep::ApplyManifest(_) => write!(f, "apply manifest"),
+            PlanStep::UpdateConfig { name, .. } => write!(f, "update config '{name}'"),
+            PlanStep::WaitForWorkloadState {
+                workload_name,
+                state,
+            } => write!(
+                f,
+                "wait for workload '{workload_name}' to reach state {state:?}"
+            ),
+        }
+    }
+}
+
+/// The progress reported while a [Plan] is executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanProgress {
+    /// The step started.
+    StepStarted {
+        /// The index of the step in the plan.
+        index: usize,
+        /// The description of the step.
+        description: String,
+    },
+    /// The step completed successfully.
+    StepCompleted {
+        /// The index of the step in the plan.
+        index: usize,
+    },
+    /// The step failed and the plan is aborted.
+    StepFailed {
+        /// The index of the step in the plan.
+        index: usize,
+        /// The description of the error.
+        error: String,
+    },
+}
+
+/// Struct that describes a sequence of [`PlanSteps`](PlanStep) that are executed in order.
+///
+/// Each step can have its own timeout. The execution is aborted at the first failing step.
+///
+/// # Examples
+///
+/// ## Apply a workload, wait for it and update a config:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, Plan, PlanStep, Workload, WorkloadStateEnum};
+/// # use std::time::Duration;
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// # let workload = Workload::builder().workload_name("nginx").agent_name("agent_A")
+/// #     .runtime("podman").runtime_config("image: nginx").build().unwrap();
+/// let plan = Plan::new()
+///     .step(PlanStep::ApplyWorkload(Box::new(workload)))
+///     .step_with_timeout(
+///         PlanStep::WaitForWorkloadState {
+///             workload_name: "nginx".to_owned(),
+///             state: WorkloadStateEnum::Running,
+///         },
+///         Duration::from_secs(30),
+///     )
+///     .step(PlanStep::UpdateConfig {
+///         name: "nginx_ready".to_owned(),
+///         value: serde_yaml::Value::Bool(true),
+///     });
+/// ankaios.execute_plan(plan).await.unwrap();
+/// # })
+/// ```
+#[derive(Debug, Default)]
+pub struct Plan {
+    /// The steps of the plan and their optional timeouts.
+    steps: Vec<(PlanStep, Option<Duration>)>,
+    /// The sender used to report the progress, if subscribed.
+    progress_sender: Option<mpsc::Sender<PlanProgress>>,
+}
+
+impl Plan {
+    /// Creates a new empty `Plan`.
+    ///
+    /// ## Returns
+    ///
+    /// A new [Plan] object.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a step to the plan.
+    ///
+    /// Steps without timeout are only limited by the timeout of the requests
+    /// and, for waiting steps, by the timeout of the [Ankaios] object.
+    ///
+    /// ## Arguments
+    ///
+    /// * `step` - The [`PlanStep`] to add.
+    ///
+    /// ## Returns
+    ///
+    /// The [Plan] with the added step.
+    #[must_use]
+    pub fn step(mut self, step: PlanStep) -> Self {
+        self.steps.push((step, None));
+        self
+    }
+
+    /// Adds a step with a timeout to the plan.
+    ///
+    /// ## Arguments
+    ///
+    /// * `step` - The [`PlanStep`] to add;
+    /// * `timeout` - The maximum duration of the step.
+    ///
+    /// ## Returns
+    ///
+    /// The [Plan] with the added step.
+    #[must_use]
+    pub fn step_with_timeout(mut self, step: PlanStep, timeout: Duration) -> Self {
+        self.steps.push((step, Some(timeout)));
+        self
+    }
+
+    /// Subscribes to the progress of the plan.
+    /// A new subscription replaces the previous one.
+    ///
+    /// ## Returns
+    ///
+    /// A [`mpsc::Receiver`] of [`PlanProgress`] reports.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<PlanProgress> {
+        let (progress_sender, progress_receiver) = mpsc::channel(CHANNEL_SIZE);
+        self.progress_sender = Some(progress_sender);
+        progress_receiver
+    }
+
+    /// Returns the number of steps in the plan.
+    ///
+    /// ## Returns
+    ///
+    /// The number of steps.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Checks if the plan contains no steps.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the plan is empty, `false` otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Executes the steps of the plan in order.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to execute the steps.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`PlanError`](AnkaiosError::PlanError) containing the index and the error of the first failing step.
+    pub(crate) async fn execute(self, ankaios: &Ankaios) -> Result<(), AnkaiosError> {
+        for (index, (step, step_timeout)) in self.steps.into_iter().enumerate() {
+            let description = step.to_string();
+            sdk_log!(info, "Executing plan step {index}: {description}.");
+            Self::report(
+                self.progress_sender.as_ref(),
+                PlanProgress::StepStarted { index, description },
+            )
+            .await;
+
+            // Waiting steps are limited by the timeout of the Ankaios object by default
+            let timeout = match step {
+                PlanStep::WaitForWorkloadState { .. } => step_timeout.or(Some(ankaios.timeout)),
+                _ => step_timeout,
+            };
+            let result = match timeout {
+                Some(duration) => tokio_timeout(duration, Self::execute_step(ankaios, step))
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(AnkaiosError::TimeoutError {
+                            request_id: None,
+                            request_type: "PlanStep".to_owned(),
+                            elapsed: duration,
+                        })
+                    }),
+                None => Self::execute_step(ankaios, step).await,
+            };
+
+            if let Err(err) = result {
+                sdk_log!(error, "Plan step {index} failed: {err}");
+                Self::report(
+                    self.progress_sender.as_ref(),
+                    PlanProgress::StepFailed {
+                        index,
+                        error: err.to_string(),
+                    },
+                )
+                .await;
+                return Err(AnkaiosError::PlanError(index, Box::new(err)));
+            }
+            Self::report(
+                self.progress_sender.as_ref(),
+                PlanProgress::StepCompleted { index },
+            )
+            .await;
+        }
+        Ok(())
+    }
+
+    /// Executes a single step.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to execute the step;
+    /// * `step` - The [`PlanStep`] to execute.
+    ///
+    /// ## Errors
+    ///
+    /// Any error returned by the [Ankaios] request of the step.
+    async fn execute_step(ankaios: &Ankaios, step: PlanStep) -> Result<(), AnkaiosError> {
+        match step {
+            PlanStep::ApplyWorkload(workload) => ankaios.apply_workload(*workload).await.map(drop),
+            PlanStep::DeleteWorkload(workload_name) => {
+                ankaios.delete_workload(workload_name).await.map(drop)
+            }
+            PlanStep::ApplyManifest(manifest) => ankaios.apply_manifest(manifest).await.map(drop),
+            PlanStep::UpdateConfig { name, value } => {
+                ankaios.add_config(name, value).await.map(drop)
+            }
+            PlanStep::WaitForWorkloadState {
+                workload_name,
+                state,
+            } => loop {
+                let workload_states = ankaios
+                    .get_workload_states_for_name_uncached(&workload_name)
+                    .await?;
+                if workload_states
+                    .into_iter()
+                    .any(|workload_state| workload_state.execution_state.state == state)
+                {
+                    return Ok(());
+                }
+                sleep(ankaios.state_check_interval).await;
+            },
+        }
+    }
+
+    /// Reports the progress to the subscriber, if any.
+    ///
+    /// ## Arguments
+    ///
+    /// * `progress_sender` - The sender used to report the progress;
+    /// * `progress` - The [`PlanProgress`] to report.
+    async fn report(progress_sender: Option<&mpsc::Sender<PlanProgress>>, progress: PlanProgress) {
+        if let Some(sender) = progress_sender {
+            sender.send(progress).await.unwrap_or_else(|err| {
+                sdk_log!(debug, "Plan progress not delivered: '{err}'");
+            });
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::Duration;
+
+    use super::{AnkaiosError, Plan, PlanProgress, PlanStep};
+    use crate::WorkloadStateEnum;
+    use crate::ankaios::generate_test_ankaios;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{Request, UpdateStateRequest};
+    use crate::components::response::{
+        Response, ResponseType, generate_test_response_update_state_success,
+    };
+    use crate::components::workload_mod::test_helpers::generate_test_workload;
+
+    #[test]
+    fn utest_plan_step_display() {
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        assert_eq!(
+            PlanStep::ApplyWorkload(Box::new(workload)).to_string(),
+            "apply workload 'workload_test'"
+        );
+        assert_eq!(
+            PlanStep::WaitForWorkloadState {
+                workload_name: "workload_test".to_owned(),
+                state: WorkloadStateEnum::Running,
+            }
+            .to_string(),
+            "wait for workload 'workload_test' to reach state Running"
+        );
+    }
+
+    #[tokio::test]
+    async fn itest_execute_plan_aborts_on_failure() {
+        let (request_sender, mut request_receiver) = tokio::sync::mpsc::channel(2);
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: UpdateStateRequest| {
+                request_sender.try_send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let mut plan = Plan::new()
+            .step(PlanStep::ApplyWorkload(Box::new(generate_test_workload(
+                "agent_Test",
+                "workload_test",
+                "podman",
+            ))))
+            .step(PlanStep::DeleteWorkload("workload_other".to_owned()))
+            .step(PlanStep::DeleteWorkload("workload_never".to_owned()));
+        assert_eq!(plan.len(), 3);
+        let mut progress = plan.subscribe();
+
+        let method_handle = tokio::spawn(async move { ank.execute_plan(plan).await });
+
+        // The first step succeeds
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                request.get_id(),
+            ))
+            .await
+            .unwrap();
+
+        // The second step fails
+        let request = request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: ResponseType::Error("not allowed".to_owned()),
+                id: request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        let result = method_handle.await.unwrap();
+        assert!(matches!(
+            result,
+            Err(AnkaiosError::PlanError(1, err)) if matches!(*err, AnkaiosError::AnkaiosResponseError(_))
+        ));
+
+        assert!(matches!(
+            progress.recv().await,
+            Some(PlanProgress::StepStarted { index: 0, .. })
+        ));
+        assert_eq!(
+            progress.recv().await,
+            Some(PlanProgress::StepCompleted { index: 0 })
+        );
+        assert!(matches!(
+            progress.recv().await,
+            Some(PlanProgress::StepStarted { index: 1, .. })
+        ));
+        assert!(matches!(
+            progress.recv().await,
+            Some(PlanProgress::StepFailed { index: 1, .. })
+        ));
+        assert_eq!(progress.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn itest_execute_plan_step_timeout() {
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(|_: UpdateStateRequest| Ok(()));
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (mut ank, _response_sender) = generate_test_ankaios(ci_mock);
+        ank.timeout = Duration::from_secs(10);
+        let plan = Plan::new().step_with_timeout(
+            PlanStep::DeleteWorkload("workload_test".to_owned()),
+            Duration::from_millis(10),
+        );
+
+        assert!(matches!(
+            ank.execute_plan(plan).await,
+            Err(AnkaiosError::PlanError(0, err)) if matches!(*err, AnkaiosError::TimeoutError { .. })
+        ));
+    }
+}
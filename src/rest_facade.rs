@@ -0,0 +1,517 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`RestFacade`], a small HTTP server exposing the state of
+//! [Ankaios](https://eclipse-ankaios.github.io/ankaios) for diagnostic purposes.
+//!
+//! # Endpoints
+//!
+//! - `GET /state`: the complete state;
+//! - `GET /workloads`: the workloads of the desired state, by name;
+//! - `GET /workloads/{name}/logs`: the logs of all the instances of a workload.
+//!   The `tail`, `since` and `until` query parameters are forwarded to the logs request.
+//!   The `tail` is at most 1000 lines per instance, which is also its default.
+//!
+//! All the responses are JSON objects. Errors are returned as `{"error": "<message>"}`.
+//!
+//! # Example
+//!
+//! ## Serve the facade until the application shuts down:
+//!
+//! ```rust,no_run
+//! # use ankaios_sdk::{Ankaios, CancellationToken};
+//! use ankaios_sdk::rest_facade::RestFacade;
+//! #
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! # let ankaios = Ankaios::new().await.unwrap();
+//!
+//! let facade = RestFacade::new(ankaios);
+//! let cancellation_token = CancellationToken::new();
+//! facade
+//!     .serve("0.0.0.0:8080".parse().unwrap(), &cancellation_token)
+//!     .await
+//!     .unwrap();
+//! # })
+//! ```
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+use crate::logging::sdk_log;
+use crate::{Ankaios, AnkaiosError, LogResponse, LogsRequest, WorkloadInstanceName};
+
+/// The key of the message in the error responses.
+const ERROR_KEY: &str = "error";
+/// The maximum number of lines returned per workload instance by the logs endpoint.
+const MAX_LOGS_TAIL: i32 = 1000;
+
+/// Helper struct that serves the state of [Ankaios](https://eclipse-ankaios.github.io/ankaios) over HTTP.
+pub struct RestFacade {
+    /// The [Ankaios] object shared by the requests.
+    ankaios: Ankaios,
+}
+
+impl RestFacade {
+    /// Creates a new `RestFacade`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object shared by the requests.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`RestFacade`] object.
+    #[must_use]
+    pub fn new(ankaios: Ankaios) -> Self {
+        Self { ankaios }
+    }
+
+    /// Creates the [Router] of the facade, to be served or merged into another one.
+    ///
+    /// ## Returns
+    ///
+    /// The [Router] with the endpoints of the facade.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/state", get(get_state))
+            .route("/workloads", get(get_workloads))
+            .route("/workloads/{name}/logs", get(get_workload_logs))
+            .with_state(self.ankaios.clone())
+    }
+
+    /// Serves the facade until cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` - The [`SocketAddr`] to listen on;
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the server when cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`RestFacadeError`](AnkaiosError::RestFacadeError) if the address could not be bound or the server failed.
+    pub async fn serve(
+        &self,
+        address: SocketAddr,
+        cancellation_token: &CancellationToken,
+    ) -> Result<(), AnkaiosError> {
+        let listener = TcpListener::bind(address)
+            .await
+            .map_err(|err| AnkaiosError::RestFacadeError(err.to_string()))?;
+        sdk_log!(info, "Serving the REST facade on '{address}'.");
+        axum::serve(listener, self.router())
+            .with_graceful_shutdown(cancellation_token.clone().cancelled_owned())
+            .await
+            .map_err(|err| AnkaiosError::RestFacadeError(err.to_string()))
+    }
+}
+
+/// The query parameters of the logs endpoint.
+#[derive(Debug, Default, Deserialize)]
+struct LogsQuery {
+    /// The number of lines to be output at the end of the logs, at most [`MAX_LOGS_TAIL`].
+    tail: Option<i32>,
+    /// Show logs after the timestamp in RFC3339 format.
+    since: Option<String>,
+    /// Show logs before the timestamp in RFC3339 format.
+    until: Option<String>,
+}
+
+/// The errors returned by the endpoints.
+#[derive(Debug)]
+enum RestError {
+    /// The request is invalid.
+    BadRequest(String),
+    /// The requested resource does not exist.
+    NotFound(String),
+    /// The request to [Ankaios](https://eclipse-ankaios.github.io/ankaios) failed.
+    Ankaios(AnkaiosError),
+}
+
+impl From<AnkaiosError> for RestError {
+    fn from(err: AnkaiosError) -> Self {
+        RestError::Ankaios(err)
+    }
+}
+
+impl IntoResponse for RestError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            RestError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            RestError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+            RestError::Ankaios(err @ AnkaiosError::TimeoutError { .. }) => {
+                (StatusCode::GATEWAY_TIMEOUT, err.to_string())
+            }
+            RestError::Ankaios(err) => (StatusCode::BAD_GATEWAY, err.to_string()),
+        };
+        (status, Json(json!({ ERROR_KEY: message }))).into_response()
+    }
+}
+
+/// Converts a YAML mapping to a JSON value.
+///
+/// ## Arguments
+///
+/// * `mapping` - The [Mapping] to convert.
+///
+/// ## Returns
+///
+/// The converted [`JsonValue`].
+fn to_json(mapping: Mapping) -> JsonValue {
+    serde_json::to_value(Value::Mapping(mapping)).unwrap_or_else(|err| {
+        sdk_log!(error, "Could not convert the response to JSON: '{err}'");
+        JsonValue::Null
+    })
+}
+
+/// Handles `GET /state`.
+async fn get_state(State(ankaios): State<Ankaios>) -> Result<Json<JsonValue>, RestError> {
+    let complete_state = ankaios.get_state(Vec::new()).await?;
+    Ok(Json(to_json(complete_state.to_dict())))
+}
+
+/// Handles `GET /workloads`.
+async fn get_workloads(State(ankaios): State<Ankaios>) -> Result<Json<JsonValue>, RestError> {
+    let workloads: BTreeMap<String, JsonValue> = ankaios
+        .get_desired_workloads()
+        .await?
+        .into_iter()
+        .map(|workload| (workload.name.clone(), to_json(workload.to_dict())))
+        .collect();
+    Ok(Json(json!(workloads)))
+}
+
+/// Handles `GET /workloads/{name}/logs`, collecting the logs until all
+/// the instances of the workload stopped sending them.
+/// The tail is bounded by [`MAX_LOGS_TAIL`], so the collected logs are bounded too.
+async fn get_workload_logs(
+    State(ankaios): State<Ankaios>,
+    Path(workload_name): Path<String>,
+    Query(query): Query<LogsQuery>,
+) -> Result<Json<JsonValue>, RestError> {
+    let tail = query.tail.unwrap_or(MAX_LOGS_TAIL);
+    if !(0..=MAX_LOGS_TAIL).contains(&tail) {
+        return Err(RestError::BadRequest(format!(
+            "The tail must be between 0 and {MAX_LOGS_TAIL}, got {tail}."
+        )));
+    }
+
+    let workload_names: Vec<WorkloadInstanceName> = ankaios
+        .get_workload_states_for_name(workload_name.clone())
+        .await?
+        .as_list()
+        .into_iter()
+        .map(|workload_state| workload_state.workload_instance_name)
+        .collect();
+    if workload_names.is_empty() {
+        return Err(RestError::NotFound(format!(
+            "Workload '{workload_name}' not found."
+        )));
+    }
+
+    let mut log_campaign = ankaios
+        .request_logs(LogsRequest {
+            workload_names,
+            follow: false,
+            tail,
+            since: query.since,
+            until: query.until,
+            ..Default::default()
+        })
+        .await?;
+
+    let mut pending_workloads = log_campaign.accepted_workload_names.clone();
+    let mut log_entries = Vec::new();
+    let mut collect_result = Ok(());
+    while !pending_workloads.is_empty() {
+        match log_campaign.recv_timeout(ankaios.timeout).await {
+            Ok(Some(LogResponse::LogEntries(entries))) => log_entries.extend(entries),
+            Ok(Some(LogResponse::LogsStopResponse(instance_name))) => {
+                pending_workloads.retain(|pending_name| *pending_name != instance_name);
+            }
+            Ok(Some(LogResponse::Resubscribed)) => {}
+            Ok(None) => break,
+            Err(err) => {
+                collect_result = Err(err);
+                break;
+            }
+        }
+    }
+    ankaios
+        .stop_receiving_logs(log_campaign)
+        .await
+        .unwrap_or_else(|err| sdk_log!(warn, "Error while stopping the log campaign: '{err}'"));
+    collect_result?;
+
+    let logs: Vec<JsonValue> = log_entries
+        .into_iter()
+        .map(|log_entry| {
+            json!({
+                "workload": to_json(log_entry.workload_name.to_dict()),
+                "message": log_entry.message,
+            })
+        })
+        .collect();
+    Ok(Json(json!(logs)))
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        extract::{Path, Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+    };
+    use std::sync::{Arc, atomic::AtomicUsize};
+    use tokio::sync::mpsc;
+    use tokio::time::Duration;
+
+    use super::{LogsQuery, MAX_LOGS_TAIL, RestError, get_workload_logs, get_workloads};
+    use crate::ankaios::generate_test_ankaios;
+    use crate::ankaios_api::ank_base;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{
+        AnkaiosLogsRequest, GetStateRequest, LogsCancelRequest, Request,
+    };
+    use crate::components::response::{Response, ResponseType};
+    use crate::{AnkaiosError, CompleteState, LogEntry, LogResponse, WorkloadInstanceName};
+
+    fn generate_complete_state_response(request_id: String) -> Response {
+        Response {
+            id: request_id,
+            content: ResponseType::CompleteState(Box::new(CompleteState::new_from_proto(
+                generate_complete_state_proto(),
+            ))),
+        }
+    }
+
+    #[tokio::test]
+    async fn utest_rest_error_into_response() {
+        let response = RestError::BadRequest("invalid".to_owned()).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = RestError::NotFound("missing".to_owned()).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = RestError::from(AnkaiosError::TimeoutError {
+            request_id: Some("1234".to_owned()),
+            request_type: "GetStateRequest".to_owned(),
+            elapsed: Duration::ZERO,
+        })
+        .into_response();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+
+        let response =
+            RestError::from(AnkaiosError::ResponseError("invalid".to_owned())).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    }
+
+    #[tokio::test]
+    async fn itest_get_workloads() {
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let method_handle = tokio::spawn(async move { get_workloads(State(ank)).await });
+
+        let request = request_receiver.await.unwrap();
+        response_sender
+            .send(generate_complete_state_response(request.get_id()))
+            .await
+            .unwrap();
+
+        let workloads = method_handle.await.unwrap().unwrap().0;
+        assert_eq!(workloads["nginx_test"]["agent"], "agent_A");
+    }
+
+    #[tokio::test]
+    async fn itest_get_workload_logs() {
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (logs_request_sender, logs_request_receiver) = tokio::sync::oneshot::channel();
+        let (cancel_request_sender, cancel_request_receiver) = tokio::sync::oneshot::channel();
+
+        let instance_name = WorkloadInstanceName::new(
+            "agent_B".to_owned(),
+            "dyn_nginx".to_owned(),
+            "9012".to_owned(),
+        );
+        let cloned_instance_name = instance_name.clone();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_log_campaign().times(1).return_once(
+            move |_request_id: String,
+                  _logs_request: ank_base::Request,
+                  logs_sender: mpsc::Sender<LogResponse>,
+                  _entries_counter: Arc<AtomicUsize>| {
+                logs_sender
+                    .try_send(LogResponse::LogEntries(vec![LogEntry {
+                        workload_name: cloned_instance_name.clone(),
+                        message: "started".to_owned(),
+                    }]))
+                    .unwrap();
+                logs_sender
+                    .try_send(LogResponse::LogsStopResponse(cloned_instance_name))
+                    .unwrap();
+            },
+        );
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let method_handle = tokio::spawn(async move {
+            get_workload_logs(
+                State(ank),
+                Path("dyn_nginx".to_owned()),
+                Query(LogsQuery::default()),
+            )
+            .await
+        });
+
+        let request = get_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_complete_state_response(request.get_id()))
+            .await
+            .unwrap();
+        let request = logs_request_receiver.await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: ResponseType::LogsRequestAccepted(vec![instance_name]),
+            })
+            .await
+            .unwrap();
+        let request = cancel_request_receiver.await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: ResponseType::LogsCancelAccepted,
+            })
+            .await
+            .unwrap();
+
+        let logs = method_handle.await.unwrap().unwrap().0;
+        assert_eq!(logs[0]["message"], "started");
+        assert_eq!(logs[0]["workload"]["workload_name"], "dyn_nginx");
+    }
+
+    #[tokio::test]
+    async fn itest_get_workload_logs_not_found() {
+        let (request_sender, request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let method_handle = tokio::spawn(async move {
+            get_workload_logs(
+                State(ank),
+                Path("unknown".to_owned()),
+                Query(LogsQuery::default()),
+            )
+            .await
+        });
+
+        let request = request_receiver.await.unwrap();
+        response_sender
+            .send(generate_complete_state_response(request.get_id()))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(RestError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_get_workload_logs_tail_out_of_bounds() {
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock.expect_write_request::<GetStateRequest>().never();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+        for tail in [-1, MAX_LOGS_TAIL + 1] {
+            let result = get_workload_logs(
+                State(ank.clone()),
+                Path("dyn_nginx".to_owned()),
+                Query(LogsQuery {
+                    tail: Some(tail),
+                    ..Default::default()
+                }),
+            )
+            .await;
+            assert!(matches!(result, Err(RestError::BadRequest(_))));
+        }
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`AnkaiosScope`], which ties the background tasks
+//! and campaigns started through the SDK to a scope.
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::mem;
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+
+use crate::Ankaios;
+use crate::logging::sdk_log;
+
+/// The campaigns started through the [Ankaios] object of a scope and not stopped yet.
+#[derive(Debug, Default)]
+pub(crate) struct ScopeCampaigns {
+    /// The request ids of the log campaigns.
+    pub(crate) log_campaigns: HashSet<String>,
+    /// The request ids of the events campaigns.
+    pub(crate) events_campaigns: HashSet<String>,
+}
+
+/// The [`ScopeCampaigns`] shared between the clones of the [Ankaios] object of a scope.
+pub(crate) type SharedScopeCampaigns = Arc<StdMutex<ScopeCampaigns>>;
+
+/// Context of a scope started with [`Ankaios::scope`].
+///
+/// When the scope exits, the tasks spawned with [`spawn`](AnkaiosScope::spawn) are aborted,
+/// the [cancellation token](AnkaiosScope::cancellation_token) is cancelled and the log and
+/// events campaigns started through [`ankaios`](AnkaiosScope::ankaios), including the ones of
+/// the log tails and workload states watches, are stopped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, SupervisorPolicy, Workload, WorkloadSupervisor};
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// # let workload = Workload::builder().workload_name("nginx").agent_name("agent_A")
+/// #     .runtime("podman").runtime_config("image: nginx").build().unwrap();
+/// ankaios
+///     .scope(|ctx| async move {
+///         let supervisor =
+///             WorkloadSupervisor::new(ctx.ankaios().clone(), workload, SupervisorPolicy::default());
+///         let cancellation_token = ctx.cancellation_token();
+///         ctx.spawn(async move {
+///             if let Err(err) = supervisor.run(&cancellation_token).await {
+///                 println!("Supervisor stopped: {err}");
+///             }
+///         });
+///
+///         // The supervisor is stopped when the scope exits.
+///         tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+///     })
+///     .await;
+/// # })
+/// ```
+#[derive(Clone)]
+pub struct AnkaiosScope {
+    /// The [Ankaios] object whose campaigns are tied to the scope.
+    ankaios: Ankaios,
+    /// The token cancelled when the scope exits.
+    cancellation_token: CancellationToken,
+    /// The tasks spawned in the scope.
+    tasks: Arc<StdMutex<JoinSet<()>>>,
+}
+
+/// Cancels a scope when dropped, also if the scope future is dropped before completion.
+struct ScopeGuard {
+    /// The token cancelled when the scope exits.
+    cancellation_token: CancellationToken,
+    /// The tasks spawned in the scope.
+    tasks: Arc<StdMutex<JoinSet<()>>>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        self.cancellation_token.cancel();
+        self.tasks
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .abort_all();
+    }
+}
+
+impl AnkaiosScope {
+    /// Runs a scope, cleaning up its tasks and campaigns when it exits.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object the scope is started from;
+    /// * `scope_fn` - The function receiving the [`AnkaiosScope`].
+    ///
+    /// ## Returns
+    ///
+    /// The output of the future returned by `scope_fn`.
+    pub(crate) async fn run<F, Fut, T>(ankaios: &Ankaios, scope_fn: F) -> T
+    where
+        F: FnOnce(AnkaiosScope) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let campaigns = SharedScopeCampaigns::default();
+        let scope = Self {
+            ankaios: ankaios.scoped(Arc::clone(&campaigns)),
+            cancellation_token: CancellationToken::new(),
+            tasks: Arc::default(),
+        };
+        let guard = ScopeGuard {
+            cancellation_token: scope.cancellation_token.clone(),
+            tasks: Arc::clone(&scope.tasks),
+        };
+
+        let output = scope_fn(scope.clone()).await;
+
+        drop(guard);
+        scope.stop_campaigns(&campaigns).await;
+        output
+    }
+
+    /// Returns the [Ankaios] object of the scope. The log and events campaigns
+    /// started through it or its clones are stopped when the scope exits.
+    ///
+    /// ## Returns
+    ///
+    /// A reference to the [Ankaios] object of the scope.
+    #[must_use]
+    pub fn ankaios(&self) -> &Ankaios {
+        &self.ankaios
+    }
+
+    /// Returns a token cancelled when the scope exits, to be passed for example
+    /// to [`WorkloadSupervisor::run`](crate::WorkloadSupervisor::run).
+    ///
+    /// ## Returns
+    ///
+    /// A [`CancellationToken`] that is cancelled when the scope exits.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.child_token()
+    }
+
+    /// Spawns a task that is aborted when the scope exits.
+    /// Nothing is spawned if the scope already exited.
+    ///
+    /// ## Arguments
+    ///
+    /// * `task` - The future to run in the task.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        if self.cancellation_token.is_cancelled() {
+            sdk_log!(warn, "Not spawning a task, the scope already exited.");
+            return;
+        }
+        let mut tasks = self.tasks.lock().unwrap_or_else(|_| unreachable!());
+        // Drop the results of the tasks that already finished
+        while tasks.try_join_next().is_some() {}
+        tasks.spawn(task);
+    }
+
+    /// Stops the campaigns of the scope that are still active.
+    ///
+    /// ## Arguments
+    ///
+    /// * `campaigns` - The campaigns started through the [Ankaios] object of the scope.
+    async fn stop_campaigns(&self, campaigns: &SharedScopeCampaigns) {
+        let ScopeCampaigns {
+            log_campaigns,
+            events_campaigns,
+        } = mem::take(&mut *campaigns.lock().unwrap_or_else(|_| unreachable!()));
+
+        let mut active_log_campaigns: Vec<String> = self
+            .ankaios
+            .active_log_campaigns()
+            .into_iter()
+            .map(|log_campaign| log_campaign.request_id)
+            .filter(|request_id| log_campaigns.contains(request_id))
+            .collect();
+        active_log_campaigns.sort();
+        for request_id in active_log_campaigns {
+            if let Err(err) = self.ankaios.stop_log_campaign(request_id).await {
+                sdk_log!(
+                    warn,
+                    "Error while stopping a log campaign of the scope: '{err}'"
+                );
+            }
+        }
+
+        let mut active_events_campaigns: Vec<String> = events_campaigns.into_iter().collect();
+        active_events_campaigns.sort();
+        for request_id in active_events_campaigns {
+            if let Err(err) = self.ankaios.unregister_events_campaign(request_id).await {
+                sdk_log!(
+                    warn,
+                    "Error while stopping an events campaign of the scope: '{err}'"
+                );
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::sync::mpsc;
+    use tokio::time::Duration;
+
+    use crate::LogsRequest;
+    use crate::ankaios::generate_test_ankaios;
+    use crate::ankaios_api::ank_base;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::log_types::LogResponse;
+    use crate::components::request::{AnkaiosLogsRequest, LogsCancelRequest, Request};
+    use crate::components::response::{Response, ResponseType};
+    use crate::components::workload_state_mod::WorkloadInstanceName;
+
+    #[tokio::test]
+    async fn utest_scope_aborts_tasks() {
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+        let (ank, _response_sender) = generate_test_ankaios(ci_mock);
+
+        let (task_sender, mut task_receiver) = mpsc::channel::<()>(1);
+        let (scope, cancellation_token, output) = ank
+            .scope(|ctx| async move {
+                ctx.spawn(async move {
+                    let _sender = task_sender;
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                });
+                let cancellation_token = ctx.cancellation_token();
+                (ctx, cancellation_token, 42)
+            })
+            .await;
+
+        assert_eq!(output, 42);
+        assert!(cancellation_token.is_cancelled());
+        assert!(task_receiver.recv().await.is_none());
+
+        // Nothing is spawned once the scope exited
+        let (late_sender, mut late_receiver) = mpsc::channel(1);
+        scope.spawn(async move {
+            late_sender.send(()).await.unwrap();
+        });
+        assert!(late_receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn itest_scope_stops_log_campaigns() {
+        let (logs_request_sender, mut logs_request_receiver) = mpsc::unbounded_channel();
+        let (cancel_request_sender, mut cancel_request_receiver) = mpsc::unbounded_channel();
+
+        let instance_name = WorkloadInstanceName::new(
+            "agent_A".to_owned(),
+            "workload_A".to_owned(),
+            "1234".to_owned(),
+        );
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: AnkaiosLogsRequest| {
+                logs_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .returning(move |request: LogsCancelRequest| {
+                cancel_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_add_log_campaign().times(1).returning(
+            |_request_id: String,
+             _logs_request: ank_base::Request,
+             _logs_sender: mpsc::Sender<LogResponse>,
+             _entries_counter: Arc<AtomicUsize>| {},
+        );
+        ci_mock
+            .expect_remove_log_campaign()
+            .times(1)
+            .return_const(());
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+
+        let ank_clone = ank.clone();
+        let logs_request = LogsRequest {
+            workload_names: vec![instance_name.clone()],
+            ..Default::default()
+        };
+        let scope_handle = tokio::spawn(async move {
+            ank_clone
+                .scope(|ctx| async move { ctx.ankaios().request_logs(logs_request).await })
+                .await
+        });
+
+        let request = logs_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: request.get_id(),
+                content: ResponseType::LogsRequestAccepted(vec![instance_name]),
+            })
+            .await
+            .unwrap();
+
+        // The log campaign is stopped when the scope exits
+        let cancel_request = cancel_request_receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                id: cancel_request.get_id(),
+                content: ResponseType::LogsCancelAccepted,
+            })
+            .await
+            .unwrap();
+
+        let log_campaign_response = scope_handle.await.unwrap().unwrap();
+        assert_eq!(log_campaign_response.get_request_id(), request.get_id());
+        assert!(ank.active_log_campaigns().is_empty());
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`StateSnapshotter`], which is used to persist the
+//! state of the [Ankaios] cluster to disk and to restore it later.
+//!
+//! [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+
+use prost::Message;
+use std::path::Path;
+use tokio::fs;
+
+use crate::ankaios_api::ank_base;
+use crate::logging::sdk_log;
+use crate::{Ankaios, AnkaiosError, CompleteState, Manifest, UpdateStateSuccess};
+
+/// Helper struct used to capture the state of the [Ankaios] cluster into a
+/// snapshot file and to load or restore it later.
+///
+/// The snapshots are stored in the compact protobuf form of the [`CompleteState`].
+///
+/// # Examples
+///
+/// ## Capture a snapshot of the cluster:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, StateSnapshotter};
+/// # use std::path::Path;
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// let complete_state = StateSnapshotter::capture(&ankaios, Path::new("snapshot.bin")).await.unwrap();
+/// # })
+/// ```
+///
+/// ## Load a snapshot for offline analysis:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::StateSnapshotter;
+/// # use std::path::Path;
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// let complete_state = StateSnapshotter::load(Path::new("snapshot.bin")).await.unwrap();
+/// # })
+/// ```
+///
+/// ## Bring the cluster back to a snapshot:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, StateSnapshotter};
+/// # use std::path::Path;
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// let update_state_success = StateSnapshotter::restore(&ankaios, Path::new("snapshot.bin")).await.unwrap();
+/// # })
+/// ```
+///
+/// [Ankaios]: https://eclipse-ankaios.github.io/ankaios
+pub struct StateSnapshotter;
+
+impl StateSnapshotter {
+    /// Captures the complete state of the cluster and saves it to a snapshot file.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to get the state;
+    /// * `path` - The [Path] of the snapshot file.
+    ///
+    /// ## Returns
+    ///
+    /// The captured [`CompleteState`].
+    ///
+    /// ## Errors
+    ///
+    /// - any error returned by [`Ankaios::get_state_uncached`];
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the snapshot file could not be written.
+    pub async fn capture(ankaios: &Ankaios, path: &Path) -> Result<CompleteState, AnkaiosError> {
+        let complete_state = ankaios.get_state_uncached(Vec::default()).await?;
+        Self::save(&complete_state, path).await?;
+        sdk_log!(info, "Captured state snapshot to '{}'.", path.display());
+        Ok(complete_state)
+    }
+
+    /// Saves a [`CompleteState`] to a snapshot file.
+    ///
+    /// ## Arguments
+    ///
+    /// * `complete_state` - The [`CompleteState`] to be saved;
+    /// * `path` - The [Path] of the snapshot file.
+    ///
+    /// ## Errors
+    ///
+    /// [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the snapshot file could not be written.
+    pub async fn save(complete_state: &CompleteState, path: &Path) -> Result<(), AnkaiosError> {
+        fs::write(path, complete_state.to_proto().encode_to_vec()).await?;
+        Ok(())
+    }
+
+    /// Loads a [`CompleteState`] from a snapshot file.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The [Path] of the snapshot file.
+    ///
+    /// ## Returns
+    ///
+    /// The [`CompleteState`] stored in the snapshot.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`IoError`](AnkaiosError::IoError) if the snapshot file could not be read;
+    /// - [`AnkaiosError`]::[`DecodeError`](AnkaiosError::DecodeError) if the snapshot file is not valid.
+    pub async fn load(path: &Path) -> Result<CompleteState, AnkaiosError> {
+        let content = fs::read(path).await?;
+        let proto = ank_base::CompleteState::decode(content.as_slice())?;
+        Ok(CompleteState::new_from_proto(proto))
+    }
+
+    /// Brings the cluster back to the desired state stored in a snapshot file.
+    ///
+    /// The workloads and configs of the snapshot are applied using [`Ankaios::apply_manifest`].
+    /// Workloads and configs that were added after the snapshot was captured are not deleted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to apply the state;
+    /// * `path` - The [Path] of the snapshot file.
+    ///
+    /// ## Returns
+    ///
+    /// An [`UpdateStateSuccess`] object if the state was applied successfully.
+    ///
+    /// ## Errors
+    ///
+    /// - any error returned by [`StateSnapshotter::load`];
+    /// - any error returned by [`Ankaios::apply_manifest`].
+    pub async fn restore(
+        ankaios: &Ankaios,
+        path: &Path,
+    ) -> Result<UpdateStateSuccess, AnkaiosError> {
+        let complete_state = Self::load(path).await?;
+        sdk_log!(info, "Restoring state snapshot from '{}'.", path.display());
+        ankaios.apply_manifest(Manifest::from(complete_state)).await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+    use uuid::Uuid;
+
+    use super::{AnkaiosError, CompleteState, StateSnapshotter};
+    use crate::ankaios::generate_test_ankaios;
+    use crate::ankaios_api::ank_base::RequestContent;
+    use crate::components::complete_state::generate_complete_state_proto;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{GetStateRequest, Request, UpdateStateRequest};
+    use crate::components::response::{
+        Response, ResponseType, generate_test_response_update_state_success,
+    };
+
+    fn generate_snapshot_path() -> PathBuf {
+        env::temp_dir().join(format!("ankaios_snapshot_{}.bin", Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn utest_save_load() {
+        let path = generate_snapshot_path();
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+
+        StateSnapshotter::save(&complete_state, &path)
+            .await
+            .unwrap();
+        let loaded_state = StateSnapshotter::load(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(loaded_state, complete_state);
+    }
+
+    #[tokio::test]
+    async fn utest_load_errors() {
+        let path = generate_snapshot_path();
+        assert!(matches!(
+            StateSnapshotter::load(&path).await,
+            Err(AnkaiosError::IoError(_))
+        ));
+
+        tokio::fs::write(&path, b"invalid snapshot").await.unwrap();
+        let result = StateSnapshotter::load(&path).await;
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert!(matches!(result, Err(AnkaiosError::DecodeError(_))));
+    }
+
+    #[tokio::test]
+    async fn itest_capture_restore() {
+        let path = generate_snapshot_path();
+
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let complete_state = CompleteState::new_from_proto(generate_complete_state_proto());
+
+        // Capture the snapshot
+        let (capture_result, get_request) =
+            tokio::join!(StateSnapshotter::capture(&ank, &path), async {
+                let request = get_request_receiver.await.unwrap();
+                response_sender
+                    .send(Response {
+                        content: ResponseType::CompleteState(Box::new(complete_state.clone())),
+                        id: request.get_id(),
+                    })
+                    .await
+                    .unwrap();
+                request
+            });
+        assert!(matches!(
+            get_request.to_proto().request_content,
+            Some(RequestContent::CompleteStateRequest(content)) if content.field_mask.is_empty()
+        ));
+        assert_eq!(capture_result.unwrap(), complete_state);
+
+        // Restore the snapshot
+        let (restore_result, ()) = tokio::join!(StateSnapshotter::restore(&ank, &path), async {
+            let request = update_request_receiver.await.unwrap();
+            response_sender
+                .send(generate_test_response_update_state_success(
+                    request.get_id(),
+                ))
+                .await
+                .unwrap();
+        });
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert_eq!(restore_result.unwrap().added_workloads.len(), 1);
+    }
+}
@@ -0,0 +1,821 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module contains the [`WorkloadSupervisor`], which keeps a workload
+//! running by re-applying it when it fails.
+
+use tokio::sync::mpsc;
+use tokio::time::{Duration, sleep};
+use tokio_util::sync::CancellationToken;
+
+use crate::ankaios::CHANNEL_SIZE;
+use crate::logging::sdk_log;
+use crate::{
+    Ankaios, AnkaiosError, StateQuery, UpdateStateSuccess, Workload, WorkloadExecutionState,
+    WorkloadInstanceName, WorkloadStateEnum,
+};
+
+/// The default maximum number of restarts.
+const DEFAULT_MAX_RESTARTS: u32 = 3;
+/// The default backoff before the first restart.
+const DEFAULT_INITIAL_BACKOFF: u64 = 1; // seconds
+/// The default maximum backoff between two restarts.
+const DEFAULT_MAX_BACKOFF: u64 = 30; // seconds
+
+/// The policy used by the [`WorkloadSupervisor`] to restart a failed workload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupervisorPolicy {
+    /// The maximum number of restarts before giving up.
+    pub max_restarts: u32,
+    /// The backoff before the first restart. It doubles with every restart.
+    pub initial_backoff: Duration,
+    /// The maximum backoff between two restarts.
+    pub max_backoff: Duration,
+}
+
+impl Default for SupervisorPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            initial_backoff: Duration::from_secs(DEFAULT_INITIAL_BACKOFF),
+            max_backoff: Duration::from_secs(DEFAULT_MAX_BACKOFF),
+        }
+    }
+}
+
+impl SupervisorPolicy {
+    /// Calculates the backoff before a restart.
+    ///
+    /// ## Arguments
+    ///
+    /// * `restart` - The number of the restart, starting with 1.
+    ///
+    /// ## Returns
+    ///
+    /// The [Duration] to wait before the restart.
+    #[must_use]
+    pub fn backoff(&self, restart: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(restart.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// The events emitted by the [`WorkloadSupervisor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupervisorEvent {
+    /// The workload was applied.
+    Applied(UpdateStateSuccess),
+    /// The execution state of the workload changed.
+    StateChanged(WorkloadExecutionState),
+    /// The workload failed and will be restarted after the backoff.
+    Restarting {
+        /// The number of the restart, starting with 1.
+        restart: u32,
+        /// The time to wait before the restart.
+        backoff: Duration,
+    },
+    /// The workload failed and the maximum number of restarts was reached.
+    GaveUp {
+        /// The number of restarts that were made.
+        restarts: u32,
+    },
+}
+
+/// Helper struct that applies a [Workload], watches its execution state and
+/// re-applies it when it fails, according to a [`SupervisorPolicy`].
+///
+/// # Examples
+///
+/// ## Supervise a workload until the application shuts down:
+///
+/// ```rust,no_run
+/// # use ankaios_sdk::{Ankaios, CancellationToken, SupervisorPolicy, Workload, WorkloadSupervisor};
+/// # use tokio::runtime::Runtime;
+/// #
+/// # Runtime::new().unwrap().block_on(async {
+/// # let ankaios = Ankaios::new().await.unwrap();
+/// # let workload = Workload::builder().workload_name("nginx").agent_name("agent_A")
+/// #     .runtime("podman").runtime_config("image: nginx").build().unwrap();
+/// let mut supervisor = WorkloadSupervisor::new(ankaios, workload, SupervisorPolicy::default());
+/// let mut events = supervisor.subscribe();
+/// tokio::spawn(async move {
+///     while let Some(event) = events.recv().await {
+///         println!("Supervisor event: {event:?}");
+///     }
+/// });
+///
+/// let cancellation_token = CancellationToken::new();
+/// supervisor.run(&cancellation_token).await.unwrap();
+/// # })
+/// ```
+pub struct WorkloadSupervisor {
+    /// The [Ankaios] object used to apply and watch the workload.
+    ankaios: Ankaios,
+    /// The supervised workload.
+    workload: Workload,
+    /// The policy used to restart the workload.
+    policy: SupervisorPolicy,
+    /// The sender used to emit the events, if subscribed.
+    events_sender: Option<mpsc::Sender<SupervisorEvent>>,
+}
+
+impl WorkloadSupervisor {
+    /// Creates a new `WorkloadSupervisor`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ankaios` - The [Ankaios] object used to apply and watch the workload;
+    /// * `workload` - The [Workload] to supervise;
+    /// * `policy` - The [`SupervisorPolicy`] used to restart the workload.
+    ///
+    /// ## Returns
+    ///
+    /// A new [`WorkloadSupervisor`] object.
+    #[must_use]
+    pub fn new(ankaios: Ankaios, workload: Workload, policy: SupervisorPolicy) -> Self {
+        Self {
+            ankaios,
+            workload,
+            policy,
+            events_sender: None,
+        }
+    }
+
+    /// Subscribes to the events of the supervisor.
+    /// A new subscription replaces the previous one.
+    ///
+    /// ## Returns
+    ///
+    /// A [`mpsc::Receiver`] of [`SupervisorEvent`]s.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<SupervisorEvent> {
+        let (events_sender, events_receiver) = mpsc::channel(CHANNEL_SIZE);
+        self.events_sender = Some(events_sender);
+        events_receiver
+    }
+
+    /// Applies the workload and supervises it until cancelled.
+    ///
+    /// When the workload fails, it is deleted and applied again after the backoff
+    /// of the [`SupervisorPolicy`], once the failed instance has been removed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the supervision when cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SupervisorError`](AnkaiosError::SupervisorError) if the maximum number of restarts was reached or the workload could not be found;
+    /// - any error returned while applying, deleting or getting the state of the workload.
+    pub async fn run(&self, cancellation_token: &CancellationToken) -> Result<(), AnkaiosError> {
+        let mut restarts = 0;
+        loop {
+            let instance_name = self.apply().await?;
+            if !self
+                .watch_until_failed(&instance_name, cancellation_token)
+                .await?
+            {
+                sdk_log!(
+                    info,
+                    "Supervision of workload '{}' cancelled.",
+                    self.workload.name
+                );
+                return Ok(());
+            }
+
+            if restarts >= self.policy.max_restarts {
+                sdk_log!(
+                    error,
+                    "Workload '{}' failed after {restarts} restarts, giving up.",
+                    self.workload.name
+                );
+                self.emit(SupervisorEvent::GaveUp { restarts }).await;
+                return Err(AnkaiosError::SupervisorError(format!(
+                    "Workload '{}' failed after {restarts} restarts.",
+                    self.workload.name
+                )));
+            }
+            restarts += 1;
+            let backoff = self.policy.backoff(restarts);
+            sdk_log!(
+                info,
+                "Workload '{}' failed, restarting in {backoff:?}.",
+                self.workload.name
+            );
+            self.emit(SupervisorEvent::Restarting {
+                restart: restarts,
+                backoff,
+            })
+            .await;
+
+            tokio::select! {
+                () = sleep(backoff) => {}
+                () = cancellation_token.cancelled() => return Ok(()),
+            }
+            // Ankaios does not restart a workload that is applied unchanged
+            self.ankaios
+                .delete_workload(self.workload.name.clone())
+                .await?;
+            // The new instance gets the same name, so the old one has to be gone first
+            if !self
+                .wait_until_removed(&instance_name, cancellation_token)
+                .await?
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Applies the workload and finds its instance name.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadInstanceName`] of the applied workload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`AnkaiosError`]::[`SupervisorError`](AnkaiosError::SupervisorError) if the workload could not be found;
+    /// - any error returned while applying or getting the state of the workload.
+    async fn apply(&self) -> Result<WorkloadInstanceName, AnkaiosError> {
+        let update_state_success = self.ankaios.apply_workload(self.workload.clone()).await?;
+        let added_instance_name = update_state_success
+            .added_workloads
+            .iter()
+            .find(|instance_name| instance_name.workload_name == self.workload.name)
+            .cloned();
+        self.emit(SupervisorEvent::Applied(update_state_success))
+            .await;
+
+        if let Some(instance_name) = added_instance_name {
+            return Ok(instance_name);
+        }
+        // The workload was already running unchanged, the instance on its agent is looked
+        // up on the server as a cached state could predate the apply
+        let agent_name = self.workload.workload.agent.clone().unwrap_or_default();
+        self.ankaios
+            .get_workload_states_for_name_uncached(&self.workload.name)
+            .await?
+            .into_iter()
+            .map(|workload_state| workload_state.workload_instance_name)
+            .find(|instance_name| instance_name.agent_name == agent_name)
+            .ok_or_else(|| {
+                AnkaiosError::SupervisorError(format!(
+                    "Workload '{}' not found after applying it.",
+                    self.workload.name
+                ))
+            })
+    }
+
+    /// Watches the execution state of the workload until it fails or the supervision is cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload;
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the watching when cancelled.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the workload failed, `false` if the supervision was cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// Any error returned while getting the state of the workload.
+    async fn watch_until_failed(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        cancellation_token: &CancellationToken,
+    ) -> Result<bool, AnkaiosError> {
+        let mut last_state: Option<WorkloadExecutionState> = None;
+        loop {
+            let reported_state = tokio::select! {
+                result = self.get_execution_state(instance_name) => result?,
+                () = cancellation_token.cancelled() => return Ok(false),
+            };
+            if let Some(execution_state) = reported_state {
+                if last_state.as_ref() != Some(&execution_state) {
+                    self.emit(SupervisorEvent::StateChanged(execution_state.clone()))
+                        .await;
+                }
+                if execution_state.state == WorkloadStateEnum::Failed {
+                    return Ok(true);
+                }
+                last_state = Some(execution_state);
+            } else {
+                sdk_log!(
+                    trace,
+                    "The state of workload '{instance_name}' is not reported yet."
+                );
+            }
+
+            tokio::select! {
+                () = sleep(self.ankaios.state_check_interval) => {}
+                () = cancellation_token.cancelled() => return Ok(false),
+            }
+        }
+    }
+
+    /// Waits until a deleted instance of the workload is removed or the supervision is cancelled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the deleted workload;
+    /// * `cancellation_token` - A [`CancellationToken`] that stops the waiting when cancelled.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the instance was removed, `false` if the supervision was cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// Any error returned while getting the state of the workload.
+    async fn wait_until_removed(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        cancellation_token: &CancellationToken,
+    ) -> Result<bool, AnkaiosError> {
+        loop {
+            let reported_state = tokio::select! {
+                result = self.get_execution_state(instance_name) => result?,
+                () = cancellation_token.cancelled() => return Ok(false),
+            };
+            if reported_state
+                .is_none_or(|execution_state| execution_state.state == WorkloadStateEnum::Removed)
+            {
+                return Ok(true);
+            }
+
+            tokio::select! {
+                () = sleep(self.ankaios.state_check_interval) => {}
+                () = cancellation_token.cancelled() => return Ok(false),
+            }
+        }
+    }
+
+    /// Gets the execution state of an instance of the workload.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instance_name` - The [`WorkloadInstanceName`] of the workload.
+    ///
+    /// ## Returns
+    ///
+    /// The [`WorkloadExecutionState`] of the instance, [None] if it is not reported.
+    ///
+    /// ## Errors
+    ///
+    /// Any error returned while getting the state of the workload.
+    async fn get_execution_state(
+        &self,
+        instance_name: &WorkloadInstanceName,
+    ) -> Result<Option<WorkloadExecutionState>, AnkaiosError> {
+        let complete_state = self
+            .ankaios
+            .get_state_uncached(StateQuery::new().workload_state(instance_name).build())
+            .await?;
+        Ok(complete_state.get_workload_state_for_instance_name(instance_name))
+    }
+
+    /// Emits an event to the subscriber, if any.
+    ///
+    /// ## Arguments
+    ///
+    /// * `event` - The [`SupervisorEvent`] to emit.
+    async fn emit(&self, event: SupervisorEvent) {
+        if let Some(events_sender) = self.events_sender.as_ref() {
+            events_sender.send(event).await.unwrap_or_else(|err| {
+                sdk_log!(debug, "Supervisor event not delivered: '{err}'");
+            });
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use tokio::sync::mpsc;
+    use tokio::time::Duration;
+
+    use super::{AnkaiosError, SupervisorEvent, SupervisorPolicy, WorkloadSupervisor};
+    use crate::ankaios::generate_test_ankaios;
+    use crate::ankaios_api::ank_base;
+    use crate::components::control_interface::MockControlInterface;
+    use crate::components::request::{GetStateRequest, Request, UpdateStateRequest};
+    use crate::components::response::{
+        Response, ResponseType, UpdateStateSuccess, generate_test_response_update_state_success,
+    };
+    use crate::components::workload_mod::test_helpers::generate_test_workload;
+    use crate::{CancellationToken, CompleteState, WorkloadStateEnum};
+
+    fn generate_failed_state() -> CompleteState {
+        CompleteState::new_from_proto(ank_base::CompleteState {
+            workload_states: Some(ank_base::WorkloadStatesMap {
+                agent_state_map: HashMap::from([(
+                    "agent_Test".to_owned(),
+                    ank_base::ExecutionsStatesOfWorkload {
+                        wl_name_state_map: HashMap::from([(
+                            "workload_test".to_owned(),
+                            ank_base::ExecutionsStatesForId {
+                                id_state_map: HashMap::from([(
+                                    "1234".to_owned(),
+                                    ank_base::ExecutionState {
+                                        execution_state_enum: Some(
+                                            ank_base::ExecutionStateEnum::Failed(
+                                                ank_base::Failed::ExecFailed as i32,
+                                            ),
+                                        ),
+                                        additional_info: None,
+                                    },
+                                )]),
+                            },
+                        )]),
+                    },
+                )]),
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn answer_update(
+        receiver: &mut mpsc::UnboundedReceiver<UpdateStateRequest>,
+        response_sender: &mpsc::Sender<Response>,
+    ) {
+        let update_request = receiver.recv().await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
+    }
+
+    async fn answer_get(
+        receiver: &mut mpsc::UnboundedReceiver<GetStateRequest>,
+        response_sender: &mpsc::Sender<Response>,
+        complete_state: CompleteState,
+    ) {
+        let get_request = receiver.recv().await.unwrap();
+        response_sender
+            .send(Response {
+                content: ResponseType::CompleteState(Box::new(complete_state)),
+                id: get_request.get_id(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn utest_policy_backoff() {
+        let policy = SupervisorPolicy {
+            max_restarts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn itest_supervisor_gives_up() {
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+        let (get_request_sender, get_request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        let mut supervisor = WorkloadSupervisor::new(
+            ank,
+            workload,
+            SupervisorPolicy {
+                max_restarts: 0,
+                ..Default::default()
+            },
+        );
+        let mut events = supervisor.subscribe();
+
+        let method_handle =
+            tokio::spawn(async move { supervisor.run(&CancellationToken::new()).await });
+
+        // Answer the apply request
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
+
+        // Answer the state request with a failed workload
+        let get_request = get_request_receiver.await.unwrap();
+        response_sender
+            .send(Response {
+                content: ResponseType::CompleteState(Box::new(generate_failed_state())),
+                id: get_request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::SupervisorError(_))
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::Applied(_))
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::StateChanged(state)) if state.state == WorkloadStateEnum::Failed
+        ));
+        assert_eq!(
+            events.recv().await,
+            Some(SupervisorEvent::GaveUp { restarts: 0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn itest_supervisor_restarts() {
+        let (update_request_sender, mut update_request_receiver) = mpsc::unbounded_channel();
+        let (get_request_sender, mut get_request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(3)
+            .returning(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(5)
+            .returning(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        let backoff = Duration::from_millis(10);
+        let mut supervisor = WorkloadSupervisor::new(
+            ank,
+            workload,
+            SupervisorPolicy {
+                max_restarts: 1,
+                initial_backoff: backoff,
+                max_backoff: backoff,
+            },
+        );
+        let mut events = supervisor.subscribe();
+
+        let method_handle =
+            tokio::spawn(async move { supervisor.run(&CancellationToken::new()).await });
+
+        // Apply the workload, which fails
+        answer_update(&mut update_request_receiver, &response_sender).await;
+        answer_get(
+            &mut get_request_receiver,
+            &response_sender,
+            generate_failed_state(),
+        )
+        .await;
+
+        // Delete the workload and wait until the failed instance is removed
+        answer_update(&mut update_request_receiver, &response_sender).await;
+        answer_get(
+            &mut get_request_receiver,
+            &response_sender,
+            generate_failed_state(),
+        )
+        .await;
+        answer_get(
+            &mut get_request_receiver,
+            &response_sender,
+            CompleteState::default(),
+        )
+        .await;
+
+        // Apply the workload again, whose state is not reported at first
+        answer_update(&mut update_request_receiver, &response_sender).await;
+        answer_get(
+            &mut get_request_receiver,
+            &response_sender,
+            CompleteState::default(),
+        )
+        .await;
+        answer_get(
+            &mut get_request_receiver,
+            &response_sender,
+            generate_failed_state(),
+        )
+        .await;
+
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::SupervisorError(_))
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::Applied(_))
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::StateChanged(state)) if state.state == WorkloadStateEnum::Failed
+        ));
+        assert_eq!(
+            events.recv().await,
+            Some(SupervisorEvent::Restarting {
+                restart: 1,
+                backoff
+            })
+        );
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::Applied(_))
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::StateChanged(state)) if state.state == WorkloadStateEnum::Failed
+        ));
+        assert_eq!(
+            events.recv().await,
+            Some(SupervisorEvent::GaveUp { restarts: 1 })
+        );
+    }
+
+    #[tokio::test]
+    async fn itest_supervisor_finds_unchanged_instance() {
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+        let (get_request_sender, mut get_request_receiver) = mpsc::unbounded_channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .times(2)
+            .returning(move |request: GetStateRequest| {
+                get_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        let mut supervisor = WorkloadSupervisor::new(
+            ank,
+            workload,
+            SupervisorPolicy {
+                max_restarts: 0,
+                ..Default::default()
+            },
+        );
+        let mut events = supervisor.subscribe();
+
+        let method_handle =
+            tokio::spawn(async move { supervisor.run(&CancellationToken::new()).await });
+
+        // The workload is applied unchanged, so no instance is added
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(Response {
+                content: ResponseType::UpdateStateSuccess(Box::default()),
+                id: update_request.get_id(),
+            })
+            .await
+            .unwrap();
+
+        // An instance with the same name runs on another agent
+        let mut failed_state = generate_failed_state().to_proto();
+        failed_state
+            .workload_states
+            .as_mut()
+            .unwrap()
+            .agent_state_map
+            .insert(
+                "agent_Other".to_owned(),
+                ank_base::ExecutionsStatesOfWorkload {
+                    wl_name_state_map: HashMap::from([(
+                        "workload_test".to_owned(),
+                        ank_base::ExecutionsStatesForId {
+                            id_state_map: HashMap::from([(
+                                "5678".to_owned(),
+                                ank_base::ExecutionState {
+                                    execution_state_enum: Some(
+                                        ank_base::ExecutionStateEnum::Running(
+                                            ank_base::Running::Ok as i32,
+                                        ),
+                                    ),
+                                    additional_info: None,
+                                },
+                            )]),
+                        },
+                    )]),
+                },
+            );
+        for _ in 0..2 {
+            answer_get(
+                &mut get_request_receiver,
+                &response_sender,
+                CompleteState::new_from_proto(failed_state.clone()),
+            )
+            .await;
+        }
+
+        assert_eq!(
+            events.recv().await,
+            Some(SupervisorEvent::Applied(UpdateStateSuccess::default()))
+        );
+        // The instance on the agent of the workload is watched
+        assert!(matches!(
+            events.recv().await,
+            Some(SupervisorEvent::StateChanged(state)) if state.state == WorkloadStateEnum::Failed
+        ));
+        assert!(matches!(
+            method_handle.await.unwrap(),
+            Err(AnkaiosError::SupervisorError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn itest_supervisor_cancelled() {
+        let (update_request_sender, update_request_receiver) = tokio::sync::oneshot::channel();
+
+        let mut ci_mock = MockControlInterface::default();
+        ci_mock
+            .expect_write_request()
+            .times(1)
+            .return_once(move |request: UpdateStateRequest| {
+                update_request_sender.send(request).unwrap();
+                Ok(())
+            });
+        ci_mock
+            .expect_write_request()
+            .returning(|_: GetStateRequest| Ok(()));
+        ci_mock.expect_disconnect().times(1).returning(|| Ok(()));
+
+        let (ank, response_sender) = generate_test_ankaios(ci_mock);
+        let workload = generate_test_workload("agent_Test", "workload_test", "podman");
+        let supervisor = WorkloadSupervisor::new(ank, workload, SupervisorPolicy::default());
+        let cancellation_token = CancellationToken::new();
+
+        let token_clone = cancellation_token.clone();
+        let method_handle = tokio::spawn(async move { supervisor.run(&token_clone).await });
+
+        // Answer the apply request and cancel while watching
+        let update_request = update_request_receiver.await.unwrap();
+        response_sender
+            .send(generate_test_response_update_state_success(
+                update_request.get_id(),
+            ))
+            .await
+            .unwrap();
+        cancellation_token.cancel();
+
+        assert!(method_handle.await.unwrap().is_ok());
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (c) 2026 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Soak test of the SDK against a simulated agent, run with
+//! `cargo test --features bench --test soak`.
+//!
+//! The test runs apply/get/delete cycles and then a log campaign against the
+//! [`FakeAgent`], and fails if the resident memory or the number of tokio tasks
+//! grew compared to the measurement taken after the warm-up.
+//!
+//! The defaults keep the run short, the long-running usage is validated by
+//! overriding them with environment variables:
+//!
+//! - `SOAK_CYCLES`: the number of apply/get/delete cycles (default: 1000);
+//! - `SOAK_LOG_DURATION_SECS`: the duration of the log campaign (default: 10),
+//!   e.g. 10800 for a three hours campaign;
+//! - `SOAK_MAX_RSS_GROWTH_KIB`: the allowed growth of the resident memory (default: 16384).
+
+use std::{
+    env, fs,
+    process::{self, ExitCode},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use ankaios_sdk::bench::FakeAgent;
+use ankaios_sdk::{
+    ANKAIOS_CONTROL_SOCKET_ENV, Ankaios, LogResponse, LogsRequest, Workload, WorkloadInstanceName,
+};
+use tokio::{runtime::Runtime, time::sleep};
+
+/// The number of cycles run before the baseline is measured.
+const WARMUP_CYCLES: usize = 100;
+/// The number of log entries streamed between two checks of the log campaign duration.
+const LOG_BATCH_SIZE: usize = 500;
+/// The time given to the finished tasks to be dropped before measuring.
+const SETTLE_TIME: Duration = Duration::from_millis(500);
+/// The name of the workload applied and deleted by the cycles.
+const WORKLOAD_NAME: &str = "soak_workload";
+
+/// The configuration of the soak test.
+struct SoakConfig {
+    /// The number of apply/get/delete cycles.
+    cycles: usize,
+    /// The duration of the log campaign.
+    log_duration: Duration,
+    /// The allowed growth of the resident memory, in KiB.
+    max_rss_growth_kib: u64,
+}
+
+impl SoakConfig {
+    /// Reads the configuration from the environment, using the defaults for the missing variables.
+    fn from_env() -> Self {
+        Self {
+            cycles: env_or("SOAK_CYCLES", 1000),
+            log_duration: Duration::from_secs(env_or("SOAK_LOG_DURATION_SECS", 10)),
+            max_rss_growth_kib: env_or("SOAK_MAX_RSS_GROWTH_KIB", 16384),
+        }
+    }
+}
+
+/// Reads a variable from the environment.
+fn env_or<T: FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// The resources used by the process at a point in time.
+#[derive(Debug, Clone, Copy)]
+struct Usage {
+    /// The resident memory, in KiB.
+    rss_kib: u64,
+    /// The number of alive tokio tasks.
+    tasks: usize,
+}
+
+impl Usage {
+    /// Measures the resources used by the process, after letting the finished tasks settle.
+    async fn measure() -> Self {
+        sleep(SETTLE_TIME).await;
+        let statm = fs::read_to_string("/proc/self/statm").unwrap_or_default();
+        let resident_pages: u64 = statm
+            .split_whitespace()
+            .nth(1)
+            .and_then(|pages| pages.parse().ok())
+            .unwrap_or_default();
+        // The pages are 4 KiB on the supported Linux targets
+        Self {
+            rss_kib: resident_pages * 4,
+            tasks: tokio::runtime::Handle::current()
+                .metrics()
+                .num_alive_tasks(),
+        }
+    }
+}
+
+/// Applies, gets and deletes a workload.
+async fn cycle(ankaios: &Ankaios) -> Result<(), String> {
+    let workload = Workload::builder()
+        .workload_name(WORKLOAD_NAME)
+        .agent_name("agent_A")
+        .runtime("podman")
+        .restart_policy("NEVER")
+        .runtime_config("image: docker.io/library/nginx")
+        .build()
+        .map_err(|err| err.to_string())?;
+    ankaios
+        .apply_workload(workload)
+        .await
+        .map_err(|err| format!("apply failed: {err}"))?;
+    ankaios
+        .get_state(Vec::new())
+        .await
+        .map_err(|err| format!("get failed: {err}"))?;
+    ankaios
+        .delete_workload(WORKLOAD_NAME.to_owned())
+        .await
+        .map_err(|err| format!("delete failed: {err}"))?;
+    Ok(())
+}
+
+/// Runs a log campaign for the given duration, returning the number of entries received.
+async fn log_campaign(
+    ankaios: &Ankaios,
+    agent: &FakeAgent,
+    duration: Duration,
+) -> Result<usize, String> {
+    let mut campaign = ankaios
+        .request_logs(LogsRequest {
+            workload_names: vec![WorkloadInstanceName::new(
+                "agent_A".to_owned(),
+                "workload_0".to_owned(),
+                "workload_0_id".to_owned(),
+            )],
+            follow: true,
+            ..Default::default()
+        })
+        .await
+        .map_err(|err| format!("logs request failed: {err}"))?;
+
+    let deadline = Instant::now() + duration;
+    let mut total = 0;
+    while Instant::now() < deadline {
+        let (_, received) = tokio::join!(agent.send_log_entries(LOG_BATCH_SIZE), async {
+            let mut received = 0;
+            while received < LOG_BATCH_SIZE {
+                match campaign.logs_receiver.recv().await {
+                    Some(LogResponse::LogEntries(entries)) => received += entries.len(),
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+            received
+        });
+        if received < LOG_BATCH_SIZE {
+            return Err(format!(
+                "log campaign closed after {} entries",
+                total + received
+            ));
+        }
+        total += received;
+    }
+
+    ankaios
+        .stop_receiving_logs(campaign)
+        .await
+        .map_err(|err| format!("stopping the logs failed: {err}"))?;
+    Ok(total)
+}
+
+/// Runs the soak test and checks the resources used.
+async fn soak(config: &SoakConfig, agent: &FakeAgent) -> Result<(), String> {
+    let ankaios = Ankaios::new_with_timeout(Duration::from_secs(5))
+        .await
+        .map_err(|err| format!("connection failed: {err}"))?;
+
+    for _ in 0..WARMUP_CYCLES {
+        cycle(&ankaios).await?;
+    }
+    let baseline = Usage::measure().await;
+    println!("Baseline after {WARMUP_CYCLES} warm-up cycles: {baseline:?}");
+
+    let start = Instant::now();
+    for _ in 0..config.cycles {
+        cycle(&ankaios).await?;
+    }
+    println!(
+        "Ran {} apply/get/delete cycles in {:?}",
+        config.cycles,
+        start.elapsed()
+    );
+
+    let entries = log_campaign(&ankaios, agent, config.log_duration).await?;
+    println!(
+        "Received {entries} log entries in {:?}",
+        config.log_duration
+    );
+    if !ankaios.active_log_campaigns().is_empty() {
+        return Err("log campaign still active after stopping it".to_owned());
+    }
+
+    let end = Usage::measure().await;
+    println!("Usage at the end: {end:?}");
+    if end.tasks > baseline.tasks {
+        return Err(format!(
+            "task leak: {} tasks alive, {} after the warm-up",
+            end.tasks, baseline.tasks
+        ));
+    }
+    let rss_growth_kib = end.rss_kib.saturating_sub(baseline.rss_kib);
+    if rss_growth_kib > config.max_rss_growth_kib {
+        return Err(format!(
+            "memory growth: {rss_growth_kib} KiB, at most {} KiB allowed",
+            config.max_rss_growth_kib
+        ));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let config = SoakConfig::from_env();
+    let socket_path = env::temp_dir().join(format!("ankaios-soak-{}.sock", process::id()));
+    // SAFETY: the variable is set before the runtime is created, no other thread is running.
+    unsafe {
+        env::set_var(ANKAIOS_CONTROL_SOCKET_ENV, socket_path.as_os_str());
+    }
+    let runtime = match Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("Failed to create the runtime: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = runtime.block_on(async {
+        let agent = FakeAgent::start(socket_path, 1)
+            .await
+            .map_err(|err| format!("failed to start the fake agent: {err}"))?;
+        soak(&config, &agent).await
+    });
+    match result {
+        Ok(()) => {
+            println!("Soak test passed.");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Soak test failed: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}